@@ -0,0 +1,110 @@
+use axum::http::HeaderValue;
+use identus_did_core::{Did, ResolutionResult};
+use identus_did_prism::did::PrismDid;
+use identus_did_resolver_http::ResolutionCachePolicy;
+
+use crate::cli::CacheControlArgs;
+
+/// `Cache-Control` policy of resolution responses, chosen by the publication state of the DID.
+#[derive(Debug, Clone)]
+pub struct DidCachePolicy {
+    published: Option<HeaderValue>,
+    unpublished: Option<HeaderValue>,
+    deactivated: Option<HeaderValue>,
+    status_list: Option<HeaderValue>,
+}
+
+impl DidCachePolicy {
+    pub fn new(args: &CacheControlArgs) -> Self {
+        Self {
+            published: args.cache_control_published.clone(),
+            unpublished: args.cache_control_unpublished.clone(),
+            deactivated: args.cache_control_deactivated.clone(),
+            status_list: args.cache_control_status_list.clone(),
+        }
+    }
+
+    /// `Cache-Control` header of status list credentials rendered from VDR entries.
+    pub fn status_list(&self) -> Option<HeaderValue> {
+        self.status_list.clone()
+    }
+}
+
+impl ResolutionCachePolicy for DidCachePolicy {
+    fn cache_control(&self, did: &Did, result: &ResolutionResult) -> Option<HeaderValue> {
+        if result.did_resolution_metadata.error.is_some() {
+            return None;
+        }
+        if result.did_document_metadata.deactivated == Some(true) {
+            return self.deactivated.clone();
+        }
+        // A long-form DID without a canonical ID has not been published yet.
+        let is_unpublished = matches!(did.to_string().parse::<PrismDid>(), Ok(PrismDid::LongForm(_)))
+            && result.did_document_metadata.canonical_id.is_none();
+        if is_unpublished {
+            self.unpublished.clone()
+        } else {
+            self.published.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use identus_did_core::{DidDocumentMetadata, DidResolutionError, DidResolutionErrorCode};
+
+    use super::*;
+
+    const CANONICAL_DID: &str = "did:prism:abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890";
+
+    fn policy() -> DidCachePolicy {
+        DidCachePolicy {
+            published: Some(HeaderValue::from_static("published")),
+            unpublished: Some(HeaderValue::from_static("unpublished")),
+            deactivated: Some(HeaderValue::from_static("deactivated")),
+            status_list: Some(HeaderValue::from_static("status-list")),
+        }
+    }
+
+    fn result(metadata: DidDocumentMetadata) -> ResolutionResult {
+        ResolutionResult {
+            did_document_metadata: metadata,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn published_did_uses_published_policy() {
+        let did: Did = CANONICAL_DID.parse().unwrap();
+        let cache_control = policy().cache_control(&did, &result(Default::default()));
+        assert_eq!(cache_control, Some(HeaderValue::from_static("published")));
+    }
+
+    #[test]
+    fn deactivated_did_uses_deactivated_policy() {
+        let did: Did = CANONICAL_DID.parse().unwrap();
+        let metadata = DidDocumentMetadata {
+            deactivated: Some(true),
+            ..Default::default()
+        };
+        let cache_control = policy().cache_control(&did, &result(metadata));
+        assert_eq!(cache_control, Some(HeaderValue::from_static("deactivated")));
+    }
+
+    #[test]
+    fn status_list_policy_is_independent_of_did() {
+        assert_eq!(policy().status_list(), Some(HeaderValue::from_static("status-list")));
+    }
+
+    #[test]
+    fn resolution_error_has_no_policy() {
+        let did: Did = CANONICAL_DID.parse().unwrap();
+        let mut result = result(Default::default());
+        result.did_resolution_metadata.error = Some(DidResolutionError {
+            r#type: DidResolutionErrorCode::NotFound,
+            title: None,
+            detail: None,
+        });
+        assert_eq!(policy().cache_control(&did, &result), None);
+    }
+}
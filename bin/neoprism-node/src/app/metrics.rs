@@ -0,0 +1,74 @@
+use std::sync::LazyLock;
+use std::time::Instant;
+
+use prometheus::{
+    Encoder, HistogramVec, IntCounterVec, IntGauge, TextEncoder, register_histogram_vec, register_int_counter_vec,
+    register_int_gauge,
+};
+
+use crate::app::service::error::ResolutionError;
+
+static DID_RESOLUTION_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "neoprism_did_resolution_duration_seconds",
+        "Duration of DID resolution, labeled by success or DID resolution error code",
+        &["outcome"]
+    )
+    .expect("metric neoprism_did_resolution_duration_seconds must be registered")
+});
+
+static RESOLUTION_CACHE_LOOKUPS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "neoprism_resolution_cache_lookups_total",
+        "Number of DID state lookups in the resolution cache, labeled by hit or miss",
+        &["result"]
+    )
+    .expect("metric neoprism_resolution_cache_lookups_total must be registered")
+});
+
+static RESOLUTION_CACHE_ENTRIES: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "neoprism_resolution_cache_entries",
+        "Approximate number of DID states in the resolution cache"
+    )
+    .expect("metric neoprism_resolution_cache_entries must be registered")
+});
+
+pub fn observe_resolution<T>(started_at: Instant, result: &Result<T, ResolutionError>) {
+    let outcome = match result {
+        Ok(_) => "success".to_string(),
+        Err(e) => format!("{:?}", e.error_code()),
+    };
+    DID_RESOLUTION_DURATION
+        .with_label_values(&[outcome.as_str()])
+        .observe(started_at.elapsed().as_secs_f64());
+}
+
+pub fn record_resolution_cache_lookup(hit: bool) {
+    let result = if hit { "hit" } else { "miss" };
+    RESOLUTION_CACHE_LOOKUPS.with_label_values(&[result]).inc();
+}
+
+pub fn set_resolution_cache_entries(entries: u64) {
+    RESOLUTION_CACHE_ENTRIES.set(entries.try_into().unwrap_or(i64::MAX));
+}
+
+/// Encode all metrics from the default registry using the Prometheus text format.
+pub fn encode_text() -> anyhow::Result<String> {
+    let mut buffer = Vec::new();
+    TextEncoder::new().encode(&prometheus::gather(), &mut buffer)?;
+    Ok(String::from_utf8(buffer)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_text_includes_observed_metrics() {
+        observe_resolution::<()>(Instant::now(), &Err(ResolutionError::NotFound));
+
+        let text = encode_text().unwrap();
+        assert!(text.contains(r#"neoprism_did_resolution_duration_seconds_count{outcome="NotFound"}"#));
+    }
+}
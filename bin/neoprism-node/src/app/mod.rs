@@ -1,2 +1,6 @@
+pub mod cache_policy;
+pub mod metrics;
+pub mod readiness;
 pub mod service;
+pub mod status_list;
 pub mod worker;
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use identus_did_prism::dlt::{DltChainTip, DltCursor};
+use identus_did_prism_indexer::repo::DltCursorRepo;
+
+use crate::ReadinessState;
+
+#[derive(Debug, PartialEq, Eq, derive_more::Display)]
+pub enum NotReady {
+    #[display("database is unreachable")]
+    DatabaseUnreachable,
+    #[display("sync cursor is {lag_slots} slots behind the chain tip (max {max_lag_slots})")]
+    Lagging { lag_slots: u64, max_lag_slots: u64 },
+    #[display("sync cursor block time is {lag_seconds}s behind the current time (max {max_lag_seconds}s)")]
+    LaggingBehindClock { lag_seconds: u64, max_lag_seconds: u64 },
+    #[display("DLT source reported an error at {at}")]
+    SourceErrored { at: DateTime<Utc> },
+}
+
+/// Check whether the node is ready to serve up-to-date resolution results.
+pub async fn check_readiness(state: &ReadinessState) -> Result<(), NotReady> {
+    if let Err(e) = state.db.get_cursor().await {
+        tracing::warn!("Readiness check failed to reach the database: {e}");
+        return Err(NotReady::DatabaseUnreachable);
+    }
+
+    let Some(dlt_source) = state.dlt_source.as_ref() else {
+        return Ok(());
+    };
+    let cursor = dlt_source.cursor_rx.borrow().clone();
+    let chain_tip = dlt_source.chain_tip_rx.borrow().clone();
+    let last_error = *dlt_source.last_error_rx.borrow();
+    check_sync_status(
+        cursor.as_ref(),
+        chain_tip.as_ref(),
+        last_error,
+        Utc::now(),
+        state.max_lag_slots,
+        state.max_lag_seconds,
+        state.error_window,
+    )
+}
+
+fn check_sync_status(
+    cursor: Option<&DltCursor>,
+    chain_tip: Option<&DltChainTip>,
+    last_error: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    max_lag_slots: u64,
+    max_lag_seconds: u64,
+    error_window: Duration,
+) -> Result<(), NotReady> {
+    if let Some(at) = last_error {
+        let within_window = now
+            .signed_duration_since(at)
+            .to_std()
+            .map(|elapsed| elapsed < error_window)
+            .unwrap_or(true); // error timestamp is in the future
+        if within_window {
+            return Err(NotReady::SourceErrored { at });
+        }
+    }
+
+    match (chain_tip, cursor.and_then(|c| c.cbt)) {
+        (Some(chain_tip), _) => {
+            let cursor_slot = cursor.map(|c| c.slot).unwrap_or_default();
+            let lag_slots = chain_tip.slot.saturating_sub(cursor_slot);
+            if lag_slots > max_lag_slots {
+                return Err(NotReady::Lagging {
+                    lag_slots,
+                    max_lag_slots,
+                });
+            }
+        }
+        // Sources that do not observe the chain tip are checked against the block time of the cursor.
+        (None, Some(cbt)) => {
+            let lag_seconds = now.signed_duration_since(cbt).num_seconds().max(0) as u64;
+            if lag_seconds > max_lag_seconds {
+                return Err(NotReady::LaggingBehindClock {
+                    lag_seconds,
+                    max_lag_seconds,
+                });
+            }
+        }
+        (None, None) => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(300);
+    const MAX_LAG_SECONDS: u64 = 3600;
+
+    fn cursor(slot: u64) -> DltCursor {
+        DltCursor {
+            slot,
+            block_hash: vec![],
+            cbt: None,
+            blockfrost_page: None,
+        }
+    }
+
+    fn chain_tip(slot: u64) -> DltChainTip {
+        DltChainTip { slot, cbt: Utc::now() }
+    }
+
+    #[test]
+    fn ready_when_tip_unknown() {
+        let result = check_sync_status(None, None, None, Utc::now(), 10, MAX_LAG_SECONDS, WINDOW);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn ready_when_within_max_lag() {
+        let result = check_sync_status(
+            Some(&cursor(95)),
+            Some(&chain_tip(105)),
+            None,
+            Utc::now(),
+            10,
+            MAX_LAG_SECONDS,
+            WINDOW,
+        );
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn not_ready_when_lagging() {
+        let result = check_sync_status(
+            Some(&cursor(90)),
+            Some(&chain_tip(105)),
+            None,
+            Utc::now(),
+            10,
+            MAX_LAG_SECONDS,
+            WINDOW,
+        );
+        assert_eq!(
+            result,
+            Err(NotReady::Lagging {
+                lag_slots: 15,
+                max_lag_slots: 10
+            })
+        );
+    }
+
+    #[test]
+    fn not_ready_when_cursor_missing_and_tip_far_ahead() {
+        let result = check_sync_status(
+            None,
+            Some(&chain_tip(105)),
+            None,
+            Utc::now(),
+            10,
+            MAX_LAG_SECONDS,
+            WINDOW,
+        );
+        assert!(matches!(result, Err(NotReady::Lagging { lag_slots: 105, .. })));
+    }
+
+    #[test]
+    fn ready_when_tip_unknown_and_cursor_recent() {
+        let now = Utc::now();
+        let cursor = DltCursor {
+            cbt: Some(now - chrono::Duration::seconds(600)),
+            ..cursor(95)
+        };
+        let result = check_sync_status(Some(&cursor), None, None, now, 10, MAX_LAG_SECONDS, WINDOW);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn not_ready_when_tip_unknown_and_cursor_old() {
+        let now = Utc::now();
+        let cursor = DltCursor {
+            cbt: Some(now - chrono::Duration::seconds(7200)),
+            ..cursor(95)
+        };
+        let result = check_sync_status(Some(&cursor), None, None, now, 10, MAX_LAG_SECONDS, WINDOW);
+        assert_eq!(
+            result,
+            Err(NotReady::LaggingBehindClock {
+                lag_seconds: 7200,
+                max_lag_seconds: MAX_LAG_SECONDS
+            })
+        );
+    }
+
+    #[test]
+    fn not_ready_after_recent_error() {
+        let now = Utc::now();
+        let at = now - chrono::Duration::seconds(60);
+        let result = check_sync_status(None, None, Some(at), now, 10, MAX_LAG_SECONDS, WINDOW);
+        assert_eq!(result, Err(NotReady::SourceErrored { at }));
+    }
+
+    #[test]
+    fn ready_after_error_window_elapsed() {
+        let now = Utc::now();
+        let at = now - chrono::Duration::seconds(600);
+        let result = check_sync_status(None, None, Some(at), now, 10, MAX_LAG_SECONDS, WINDOW);
+        assert_eq!(result, Ok(()));
+    }
+}
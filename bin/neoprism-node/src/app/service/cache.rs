@@ -0,0 +1,192 @@
+use std::sync::{Arc, Mutex};
+
+use identus_did_prism::did::{CanonicalPrismDid, DidState};
+use moka::sync::Cache;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::app::metrics;
+use crate::cli::ResolutionCacheArgs;
+
+/// State of a DID derived from its published operations.
+#[derive(Debug, Clone)]
+pub(super) enum PublishedDidState {
+    /// No operation of the DID has been indexed.
+    Unpublished,
+    /// Operations of the DID have been indexed, but none of them creates a valid DID.
+    Invalid,
+    Published(DidState),
+}
+
+/// In-memory LRU cache of published DID states, keyed by canonical DID.
+///
+/// Entries are invalidated when the indexer indexes a new operation of the DID,
+/// so a cached state is never older than the index.
+#[derive(Clone)]
+pub struct DidStateCache {
+    cache: Cache<CanonicalPrismDid, PublishedDidState>,
+    /// Incremented on every invalidation. Guards against caching a state that was read
+    /// from storage before an invalidation but is inserted after it.
+    generation: Arc<Mutex<u64>>,
+}
+
+impl DidStateCache {
+    /// Create the cache, or return `None` if it is disabled by a zero size.
+    pub fn new(args: &ResolutionCacheArgs) -> Option<Self> {
+        if args.resolution_cache_size == 0 {
+            return None;
+        }
+        let cache = Cache::builder()
+            .max_capacity(args.resolution_cache_size)
+            .time_to_live(args.resolution_cache_ttl)
+            .build();
+        Some(Self {
+            cache,
+            generation: Default::default(),
+        })
+    }
+
+    pub(super) fn get(&self, did: &CanonicalPrismDid) -> Option<PublishedDidState> {
+        let state = self.cache.get(did);
+        metrics::record_resolution_cache_lookup(state.is_some());
+        state
+    }
+
+    /// Current generation, to be captured before reading a DID state from storage.
+    pub(super) fn generation(&self) -> u64 {
+        *self
+            .generation
+            .lock()
+            .expect("resolution cache lock must not be poisoned")
+    }
+
+    /// Insert a DID state read from storage at `generation`.
+    /// The state is discarded if the cache was invalidated in the meantime, since it might be outdated.
+    pub(super) fn insert(&self, did: CanonicalPrismDid, state: PublishedDidState, generation: u64) {
+        let current = self
+            .generation
+            .lock()
+            .expect("resolution cache lock must not be poisoned");
+        if *current == generation {
+            self.cache.insert(did, state);
+        }
+        drop(current);
+        metrics::set_resolution_cache_entries(self.cache.entry_count());
+    }
+
+    pub fn invalidate(&self, did: &CanonicalPrismDid) {
+        let mut generation = self
+            .generation
+            .lock()
+            .expect("resolution cache lock must not be poisoned");
+        *generation += 1;
+        self.cache.invalidate(did);
+    }
+
+    pub fn invalidate_all(&self) {
+        let mut generation = self
+            .generation
+            .lock()
+            .expect("resolution cache lock must not be poisoned");
+        *generation += 1;
+        self.cache.invalidate_all();
+    }
+
+    /// Invalidate the entries of DIDs reported by the indexer until the channel is closed.
+    pub async fn run_invalidation(
+        self,
+        mut indexed_did_rx: broadcast::Receiver<CanonicalPrismDid>,
+    ) -> anyhow::Result<()> {
+        loop {
+            match indexed_did_rx.recv().await {
+                Ok(did) => self.invalidate(&did),
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(
+                        "Resolution cache missed {} indexed DID notifications, invalidating all entries",
+                        skipped
+                    );
+                    self.invalidate_all();
+                }
+                Err(RecvError::Closed) => return Ok(()),
+            }
+            metrics::set_resolution_cache_entries(self.cache.entry_count());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use identus_apollo::hash::sha256;
+    use identus_apollo::hex::HexStr;
+
+    use super::*;
+
+    fn new_cache(size: u64) -> Option<DidStateCache> {
+        DidStateCache::new(&ResolutionCacheArgs {
+            resolution_cache_size: size,
+            resolution_cache_ttl: Duration::from_secs(60),
+        })
+    }
+
+    fn did(seed: &[u8]) -> CanonicalPrismDid {
+        CanonicalPrismDid::from_suffix(HexStr::from(sha256(seed).to_vec())).unwrap()
+    }
+
+    #[test]
+    fn zero_size_disables_cache() {
+        assert!(new_cache(0).is_none());
+    }
+
+    #[test]
+    fn invalidate_removes_entry() {
+        let cache = new_cache(10).unwrap();
+        cache.insert(did(b"a"), PublishedDidState::Unpublished, cache.generation());
+        cache.insert(did(b"b"), PublishedDidState::Invalid, cache.generation());
+
+        cache.invalidate(&did(b"a"));
+        assert!(cache.get(&did(b"a")).is_none());
+        assert!(matches!(cache.get(&did(b"b")), Some(PublishedDidState::Invalid)));
+    }
+
+    #[test]
+    fn insert_after_invalidation_is_discarded() {
+        let cache = new_cache(10).unwrap();
+        let generation = cache.generation();
+        cache.invalidate(&did(b"a"));
+        cache.insert(did(b"a"), PublishedDidState::Unpublished, generation);
+        assert!(cache.get(&did(b"a")).is_none());
+    }
+
+    #[tokio::test]
+    async fn run_invalidation_invalidates_indexed_dids() {
+        let cache = new_cache(10).unwrap();
+        cache.insert(did(b"a"), PublishedDidState::Unpublished, cache.generation());
+        cache.insert(did(b"b"), PublishedDidState::Unpublished, cache.generation());
+
+        let (tx, rx) = broadcast::channel(16);
+        tx.send(did(b"a")).unwrap();
+        drop(tx);
+        cache.clone().run_invalidation(rx).await.unwrap();
+
+        assert!(cache.get(&did(b"a")).is_none());
+        assert!(cache.get(&did(b"b")).is_some());
+    }
+
+    #[tokio::test]
+    async fn run_invalidation_invalidates_all_when_lagged() {
+        let cache = new_cache(10).unwrap();
+        cache.insert(did(b"a"), PublishedDidState::Unpublished, cache.generation());
+        cache.insert(did(b"b"), PublishedDidState::Unpublished, cache.generation());
+
+        let (tx, rx) = broadcast::channel(1);
+        tx.send(did(b"c")).unwrap();
+        tx.send(did(b"d")).unwrap();
+        drop(tx);
+        cache.clone().run_invalidation(rx).await.unwrap();
+
+        assert!(cache.get(&did(b"a")).is_none());
+        assert!(cache.get(&did(b"b")).is_none());
+    }
+}
@@ -16,6 +16,15 @@ pub enum ResolutionError {
 }
 
 impl ResolutionError {
+    pub fn error_code(&self) -> DidResolutionErrorCode {
+        match self {
+            ResolutionError::InvalidDid { .. } => DidResolutionErrorCode::InvalidDid,
+            ResolutionError::NotFound => DidResolutionErrorCode::NotFound,
+            ResolutionError::InternalError { .. } => DidResolutionErrorCode::InternalError,
+            ResolutionError::MethodNotSupported => DidResolutionErrorCode::MethodNotSupported,
+        }
+    }
+
     pub fn log_internal_error(&self) {
         if let ResolutionError::InternalError { source } = self {
             let msg = source.chain().map(|e| e.to_string()).collect::<Vec<_>>().join("\n");
@@ -24,6 +33,39 @@ impl ResolutionError {
     }
 }
 
+#[derive(Debug, derive_more::From, derive_more::Display, derive_more::Error)]
+pub enum WebhookError {
+    #[display("invalid webhook subscription: {message}")]
+    InvalidSubscription { message: String },
+    #[display("webhook subscription is not found")]
+    NotFound,
+    #[display("webhook admin API is not enabled")]
+    AdminApiDisabled,
+    #[display("missing or invalid webhook admin token")]
+    Unauthorized,
+    #[from]
+    #[display("unexpected server error")]
+    InternalError { source: anyhow::Error },
+}
+
+#[derive(Debug, derive_more::From, derive_more::Display, derive_more::Error)]
+pub enum IpfsError {
+    #[display("invalid ipfs cid: {message}")]
+    InvalidCid { message: String },
+    #[display("ipfs content is not supported: {message}")]
+    UnsupportedContent { message: String },
+    #[display("ipfs content does not match the hash of cid {cid}")]
+    ContentMismatch { cid: String },
+    #[display("ipfs content exceeds the maximum block size of {limit} bytes")]
+    ContentTooLarge {
+        #[error(not(source))]
+        limit: usize,
+    },
+    #[from]
+    #[display("failed to fetch content from ipfs gateway")]
+    GatewayError { source: reqwest::Error },
+}
+
 #[derive(Debug, derive_more::From, derive_more::Display, derive_more::Error)]
 pub enum InvalidDid {
     #[from]
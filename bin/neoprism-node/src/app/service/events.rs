@@ -0,0 +1,533 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use futures::{Stream, StreamExt};
+use identus_apollo::hash::Sha256Digest;
+use identus_did_prism::did::CanonicalPrismDid;
+use identus_did_prism::did::operation::OperationId;
+use identus_did_prism::dlt::{BlockNo, OperationMetadata};
+use identus_did_prism::prelude::SignedPrismOperation;
+use identus_did_prism::proto::prism::prism_operation::Operation;
+use identus_did_prism::protocol::resolver::resolve_published;
+use identus_did_prism_indexer::repo::{IndexerStateRepo, OperationPosition, RawOperationRepo};
+use node_storage::StorageBackend;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use utoipa::ToSchema;
+
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+const EVENT_PAGE_SIZE: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, derive_more::Display)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationType {
+    #[display("create_did")]
+    CreateDid,
+    #[display("update_did")]
+    UpdateDid,
+    #[display("deactivate_did")]
+    DeactivateDid,
+    #[display("protocol_version_update")]
+    ProtocolVersionUpdate,
+    #[display("create_storage_entry")]
+    CreateStorageEntry,
+    #[display("update_storage_entry")]
+    UpdateStorageEntry,
+    #[display("deactivate_storage_entry")]
+    DeactivateStorageEntry,
+}
+
+impl FromStr for OperationType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create_did" => Ok(Self::CreateDid),
+            "update_did" => Ok(Self::UpdateDid),
+            "deactivate_did" => Ok(Self::DeactivateDid),
+            "protocol_version_update" => Ok(Self::ProtocolVersionUpdate),
+            "create_storage_entry" => Ok(Self::CreateStorageEntry),
+            "update_storage_entry" => Ok(Self::UpdateStorageEntry),
+            "deactivate_storage_entry" => Ok(Self::DeactivateStorageEntry),
+            _ => Err(format!("unknown operation type {s}")),
+        }
+    }
+}
+
+impl OperationType {
+    pub fn from_signed_operation(signed_operation: &SignedPrismOperation) -> Option<Self> {
+        match signed_operation.operation.as_ref()?.operation.as_ref()? {
+            Operation::CreateDid(_) => Some(Self::CreateDid),
+            Operation::UpdateDid(_) => Some(Self::UpdateDid),
+            Operation::DeactivateDid(_) => Some(Self::DeactivateDid),
+            Operation::ProtocolVersionUpdate(_) => Some(Self::ProtocolVersionUpdate),
+            Operation::CreateStorageEntry(_) => Some(Self::CreateStorageEntry),
+            Operation::UpdateStorageEntry(_) => Some(Self::UpdateStorageEntry),
+            Operation::DeactivateStorageEntry(_) => Some(Self::DeactivateStorageEntry),
+            _ => None,
+        }
+    }
+}
+
+/// An operation indexed for a DID.
+#[derive(Debug, Clone)]
+pub struct DidEvent {
+    pub did: CanonicalPrismDid,
+    pub operation_type: OperationType,
+    pub operation_id: OperationId,
+    pub metadata: OperationMetadata,
+    /// Whether the operation was applied when resolving the DID.
+    pub accepted: bool,
+    /// Hash of the operation that created the VDR entry, for storage operations.
+    pub vdr_entry_hash: Option<Sha256Digest>,
+}
+
+impl DidEvent {
+    pub fn position(&self) -> OperationPosition {
+        OperationPosition::from(&self.metadata)
+    }
+}
+
+/// Criteria selecting the [`DidEvent`] delivered to a subscriber. Unset criteria match every event.
+#[derive(Debug, Clone, Default)]
+pub struct DidEventFilter {
+    pub did: Option<CanonicalPrismDid>,
+    pub operation_type: Option<OperationType>,
+    pub vdr_entry_hash: Option<Sha256Digest>,
+}
+
+impl DidEventFilter {
+    pub fn matches(&self, event: &DidEvent) -> bool {
+        self.did.as_ref().is_none_or(|i| *i == event.did)
+            && self.operation_type.is_none_or(|i| i == event.operation_type)
+            && self
+                .vdr_entry_hash
+                .as_ref()
+                .is_none_or(|i| event.vdr_entry_hash.as_ref() == Some(i))
+    }
+}
+
+/// Feed of [`DidEvent`] for operations as they get indexed.
+#[derive(Clone)]
+pub struct DidEventFeed {
+    db: Arc<dyn StorageBackend>,
+    events_tx: broadcast::Sender<DidEvent>,
+}
+
+impl DidEventFeed {
+    pub fn new(db: Arc<dyn StorageBackend>) -> Self {
+        let (events_tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self { db, events_tx }
+    }
+
+    /// Publish the events of newly indexed operations each time the indexer reports indexed DIDs.
+    pub async fn run(self, mut indexed_did_rx: broadcast::Receiver<CanonicalPrismDid>) -> anyhow::Result<()> {
+        // Operations indexed before startup are only available by resuming from a cursor.
+        let mut position = match self.db.get_last_indexed_block().await? {
+            Some((_, block_number)) => OperationPosition {
+                block_number,
+                absn: u32::MAX,
+                osn: u32::MAX,
+            },
+            None => OperationPosition {
+                block_number: BlockNo::from(0),
+                absn: 0,
+                osn: 0,
+            },
+        };
+        loop {
+            match indexed_did_rx.recv().await {
+                // A single query catches up with all DIDs indexed so far, including the missed ones.
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => return Ok(()),
+            }
+            loop {
+                let events = match self.events_after(&position, EVENT_PAGE_SIZE).await {
+                    Ok(events) => events,
+                    Err(e) => {
+                        tracing::error!("{:?}", e);
+                        break;
+                    }
+                };
+                let is_last_page = events.len() < EVENT_PAGE_SIZE as usize;
+                for event in events {
+                    position = event.position();
+                    // Sending only fails when there are no subscribers
+                    let _ = self.events_tx.send(event);
+                }
+                if is_last_page {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Events of operations positioned after `position`, in ledger order.
+    pub async fn events_after(&self, position: &OperationPosition, limit: u32) -> anyhow::Result<Vec<DidEvent>> {
+        let records = self.db.get_raw_operations_after(position, limit).await?;
+        let dids = records
+            .iter()
+            .map(|(_, did)| did.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let mut operations_by_did: HashMap<CanonicalPrismDid, Vec<_>> = HashMap::new();
+        for (record, did) in self.db.get_raw_operations_by_dids(&dids).await? {
+            operations_by_did
+                .entry(did)
+                .or_default()
+                .push((record.metadata, record.signed_operation));
+        }
+
+        let mut accepted_operations = HashSet::new();
+        let mut vdr_entries = HashMap::new();
+        for operations in operations_by_did.into_values() {
+            vdr_entries.extend(vdr_entry_hashes(&operations));
+            let (_, debug) = resolve_published(operations);
+            accepted_operations.extend(
+                debug
+                    .into_iter()
+                    .filter(|(_, _, error)| error.is_none())
+                    .map(|(_, signed_operation, _)| signed_operation.operation_id()),
+            );
+        }
+
+        let events = records
+            .into_iter()
+            .filter_map(|(record, did)| {
+                let operation_type = OperationType::from_signed_operation(&record.signed_operation)?;
+                let operation_id = record.signed_operation.operation_id();
+                let vdr_entry_hash = record
+                    .signed_operation
+                    .operation_hash()
+                    .and_then(|hash| vdr_entries.get(&hash).cloned());
+                Some(DidEvent {
+                    did,
+                    operation_type,
+                    accepted: accepted_operations.contains(&operation_id),
+                    operation_id,
+                    metadata: record.metadata,
+                    vdr_entry_hash,
+                })
+            })
+            .collect();
+        Ok(events)
+    }
+
+    /// Stream the events of newly indexed operations matching `filter`.
+    /// When `cursor` is set, the events of operations positioned after it are replayed first.
+    ///
+    /// The stream ends if it falls behind the feed and cannot catch up from storage.
+    pub fn stream(
+        &self,
+        cursor: Option<OperationPosition>,
+        filter: DidEventFilter,
+    ) -> impl Stream<Item = DidEvent> + Send + 'static {
+        let state = EventStreamState {
+            feed: self.clone(),
+            live_rx: self.events_tx.subscribe(),
+            replay_position: cursor,
+            last_position: cursor,
+            buffer: VecDeque::new(),
+        };
+        futures::stream::unfold(state, |mut state| async move {
+            let event = state.next_event().await?;
+            Some((event, state))
+        })
+        .filter(move |event| std::future::ready(filter.matches(event)))
+    }
+}
+
+struct EventStreamState {
+    feed: DidEventFeed,
+    live_rx: broadcast::Receiver<DidEvent>,
+    /// Position to replay events from storage, until the replay reaches the live events.
+    replay_position: Option<OperationPosition>,
+    last_position: Option<OperationPosition>,
+    buffer: VecDeque<DidEvent>,
+}
+
+impl EventStreamState {
+    async fn next_event(&mut self) -> Option<DidEvent> {
+        loop {
+            if let Some(event) = self.buffer.pop_front() {
+                self.last_position = Some(event.position());
+                return Some(event);
+            }
+
+            if let Some(position) = self.replay_position {
+                let events = match self.feed.events_after(&position, EVENT_PAGE_SIZE).await {
+                    Ok(events) => events,
+                    Err(e) => {
+                        tracing::error!("{:?}", e);
+                        return None;
+                    }
+                };
+                self.replay_position = events.last().map(|i| i.position());
+                self.buffer.extend(events);
+                continue;
+            }
+
+            match self.live_rx.recv().await {
+                Ok(event) => {
+                    // Skip events already delivered by the replay
+                    if self.last_position.is_none_or(|i| event.position() > i) {
+                        self.buffer.push_back(event);
+                    }
+                }
+                Err(RecvError::Lagged(_)) => match self.last_position {
+                    Some(position) => self.replay_position = Some(position),
+                    None => return None,
+                },
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+/// Map the hash of each storage operation to the hash of the operation that created its VDR entry.
+fn vdr_entry_hashes(operations: &[(OperationMetadata, SignedPrismOperation)]) -> HashMap<Sha256Digest, Sha256Digest> {
+    let mut operations = operations.iter().collect::<Vec<_>>();
+    operations.sort_by(|a, b| OperationMetadata::compare_time_asc(&a.0, &b.0));
+
+    let mut entries = HashMap::new();
+    for (_, signed_operation) in operations {
+        let Some(operation) = signed_operation.operation.as_ref() else {
+            continue;
+        };
+        let operation_hash = operation.operation_hash();
+        let entry_hash = match &operation.operation {
+            Some(Operation::CreateStorageEntry(_)) => Some(operation_hash.clone()),
+            Some(Operation::UpdateStorageEntry(op)) => Sha256Digest::from_bytes(&op.previous_event_hash)
+                .ok()
+                .and_then(|prev| entries.get(&prev).cloned()),
+            Some(Operation::DeactivateStorageEntry(op)) => Sha256Digest::from_bytes(&op.previous_event_hash)
+                .ok()
+                .and_then(|prev| entries.get(&prev).cloned()),
+            _ => None,
+        };
+        if let Some(entry_hash) = entry_hash {
+            entries.insert(operation_hash, entry_hash);
+        }
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use identus_apollo::crypto::secp256k1::Secp256k1PrivateKey;
+    use identus_apollo::hash::sha256;
+    use identus_did_prism::did::PrismDidOps;
+    use identus_did_prism::dlt::{BlockMetadata, TxId};
+    use identus_did_prism::prelude::{MessageExt, PrismOperation};
+    use identus_did_prism::proto;
+    use identus_did_prism::proto::prism_ssi::KeyUsage;
+    use identus_did_prism_indexer::run_indexer_loop;
+    use node_storage::SqliteDb;
+
+    use super::*;
+
+    const MASTER_KEY_NAME: &str = "master-0";
+    const VDR_KEY_NAME: &str = "vdr-0";
+
+    fn master_sk() -> Secp256k1PrivateKey {
+        Secp256k1PrivateKey::from_slice(&[1; 32]).unwrap()
+    }
+
+    fn vdr_sk() -> Secp256k1PrivateKey {
+        Secp256k1PrivateKey::from_slice(&[2; 32]).unwrap()
+    }
+
+    fn new_public_key(id: &str, usage: KeyUsage, sk: &Secp256k1PrivateKey) -> proto::prism_ssi::PublicKey {
+        proto::prism_ssi::PublicKey {
+            id: id.to_string(),
+            usage: usage.into(),
+            key_data: Some(proto::prism_ssi::public_key::Key_data::CompressedEcKeyData(
+                proto::prism_ssi::CompressedECKeyData {
+                    curve: "secp256k1".to_string(),
+                    data: sk.to_public_key().encode_compressed().into(),
+                    special_fields: Default::default(),
+                },
+            )),
+            special_fields: Default::default(),
+        }
+    }
+
+    fn sign(signed_with: &str, sk: &Secp256k1PrivateKey, operation: Operation) -> (SignedPrismOperation, Sha256Digest) {
+        let operation = PrismOperation {
+            operation: Some(operation),
+            special_fields: Default::default(),
+        };
+        let operation_hash = operation.operation_hash();
+        let signed_operation = SignedPrismOperation {
+            signed_with: signed_with.to_string(),
+            signature: sk.sign(&operation.encode_to_vec()),
+            operation: Some(operation).into(),
+            special_fields: Default::default(),
+        };
+        (signed_operation, operation_hash)
+    }
+
+    fn new_create_did_operation() -> (SignedPrismOperation, Sha256Digest, CanonicalPrismDid) {
+        let operation = Operation::CreateDid(proto::prism_ssi::ProtoCreateDID {
+            did_data: Some(proto::prism_ssi::proto_create_did::DIDCreationData {
+                public_keys: vec![
+                    new_public_key(MASTER_KEY_NAME, KeyUsage::MASTER_KEY, &master_sk()),
+                    new_public_key(VDR_KEY_NAME, KeyUsage::VDR_KEY, &vdr_sk()),
+                ],
+                services: vec![],
+                context: vec![],
+                special_fields: Default::default(),
+            })
+            .into(),
+            special_fields: Default::default(),
+        });
+        let (signed_operation, operation_hash) = sign(MASTER_KEY_NAME, &master_sk(), operation);
+        let did = CanonicalPrismDid::from_operation(signed_operation.operation.as_ref().unwrap()).unwrap();
+        (signed_operation, operation_hash, did)
+    }
+
+    fn new_deactivate_did_operation(did: &CanonicalPrismDid, previous_hash: &[u8]) -> SignedPrismOperation {
+        let operation = Operation::DeactivateDid(proto::prism_ssi::ProtoDeactivateDID {
+            id: did.suffix_hex().to_string(),
+            previous_operation_hash: previous_hash.to_vec(),
+            special_fields: Default::default(),
+        });
+        sign(MASTER_KEY_NAME, &master_sk(), operation).0
+    }
+
+    fn metadata(block_number: u64, osn: u32) -> OperationMetadata {
+        OperationMetadata {
+            block_metadata: BlockMetadata {
+                slot_number: block_number.into(),
+                block_number: block_number.into(),
+                cbt: DateTime::UNIX_EPOCH,
+                absn: 0,
+                tx_id: TxId::from(sha256(block_number.to_le_bytes())),
+            },
+            osn,
+        }
+    }
+
+    async fn setup_feed(operations: Vec<(OperationMetadata, SignedPrismOperation)>) -> DidEventFeed {
+        let db = SqliteDb::connect("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        for operation in operations {
+            db.insert_raw_operations(vec![operation]).await.unwrap();
+            run_indexer_loop(&db).await.unwrap();
+        }
+        DidEventFeed::new(Arc::new(db))
+    }
+
+    fn start_position() -> OperationPosition {
+        OperationPosition {
+            block_number: BlockNo::from(0),
+            absn: 0,
+            osn: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn events_after_reports_rejected_operations() {
+        let (create_op, create_hash, did) = new_create_did_operation();
+        let invalid_deactivate_op = new_deactivate_did_operation(&did, &[0; 32]);
+        let deactivate_op = new_deactivate_did_operation(&did, &create_hash.to_vec());
+        let feed = setup_feed(vec![
+            (metadata(1, 0), create_op),
+            (metadata(2, 0), invalid_deactivate_op),
+            (metadata(3, 0), deactivate_op),
+        ])
+        .await;
+
+        let events = feed.events_after(&start_position(), 10).await.unwrap();
+        let summary = events
+            .iter()
+            .map(|i| (i.operation_type, i.accepted))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (OperationType::CreateDid, true),
+                (OperationType::DeactivateDid, false),
+                (OperationType::DeactivateDid, true),
+            ]
+        );
+        assert!(events.iter().all(|i| i.did == did));
+
+        let events = feed.events_after(&events[0].position(), 1).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].metadata.block_metadata.block_number, BlockNo::from(2));
+    }
+
+    #[tokio::test]
+    async fn events_after_reports_vdr_entry_of_storage_operations() {
+        let (create_op, _, did) = new_create_did_operation();
+        let (create_storage_op, entry_hash) = sign(
+            VDR_KEY_NAME,
+            &vdr_sk(),
+            Operation::CreateStorageEntry(proto::prism_storage::ProtoCreateStorageEntry {
+                did_prism_hash: did.suffix.to_vec(),
+                nonce: vec![0],
+                data: Some(proto::prism_storage::proto_create_storage_entry::Data::Bytes(vec![1])),
+                special_fields: Default::default(),
+            }),
+        );
+        let (update_storage_op, _) = sign(
+            VDR_KEY_NAME,
+            &vdr_sk(),
+            Operation::UpdateStorageEntry(proto::prism_storage::ProtoUpdateStorageEntry {
+                previous_event_hash: entry_hash.to_vec(),
+                data: Some(proto::prism_storage::proto_update_storage_entry::Data::Bytes(vec![2])),
+                special_fields: Default::default(),
+            }),
+        );
+        let feed = setup_feed(vec![
+            (metadata(1, 0), create_op),
+            (metadata(2, 0), create_storage_op),
+            (metadata(2, 1), update_storage_op),
+        ])
+        .await;
+
+        let events = feed.events_after(&start_position(), 10).await.unwrap();
+        let vdr_entries = events.iter().map(|i| i.vdr_entry_hash.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            vdr_entries,
+            vec![None, Some(entry_hash.clone()), Some(entry_hash.clone())]
+        );
+        assert!(events.iter().all(|i| i.accepted));
+
+        let filter = DidEventFilter {
+            vdr_entry_hash: Some(entry_hash),
+            operation_type: Some(OperationType::UpdateStorageEntry),
+            ..Default::default()
+        };
+        let matched = events.iter().filter(|i| filter.matches(i)).count();
+        assert_eq!(matched, 1);
+    }
+
+    #[tokio::test]
+    async fn stream_replays_events_after_cursor() {
+        let (create_op, create_hash, did) = new_create_did_operation();
+        let deactivate_op = new_deactivate_did_operation(&did, &create_hash.to_vec());
+        let feed = setup_feed(vec![(metadata(1, 0), create_op), (metadata(2, 0), deactivate_op)]).await;
+
+        let events = feed
+            .stream(Some(start_position()), DidEventFilter::default())
+            .take(2)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events.len(), 2);
+
+        let cursor = events[0].position();
+        let events = feed
+            .stream(Some(cursor), DidEventFilter::default())
+            .take(1)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events[0].operation_type, OperationType::DeactivateDid);
+    }
+}
@@ -0,0 +1,285 @@
+use identus_apollo::hash::{Sha256Digest, sha256};
+use identus_apollo::multibase;
+use reqwest::Url;
+
+use crate::app::service::error::IpfsError;
+use crate::cli::IpfsArgs;
+
+const RAW_BLOCK_CONTENT_TYPE: &str = "application/vnd.ipld.raw";
+/// Largest block exchanged between IPFS peers. Content split across blocks is not supported.
+const MAX_BLOCK_SIZE: usize = 2 * 1024 * 1024;
+
+const CODEC_RAW: u64 = 0x55;
+const CODEC_DAG_PB: u64 = 0x70;
+const MULTIHASH_SHA2_256: u64 = 0x12;
+
+/// A content identifier whose content is addressed by a SHA-256 multihash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cid {
+    pub codec: u64,
+    pub digest: Sha256Digest,
+}
+
+impl Cid {
+    /// Parse a CIDv0 or a base32/base58btc encoded CIDv1.
+    pub fn parse(cid: &str) -> Result<Self, IpfsError> {
+        let invalid = |message: &str| IpfsError::InvalidCid {
+            message: format!("{message} ({cid})"),
+        };
+        // CIDv0 is a sha2-256 multihash of a dag-pb block, base58btc encoded without the multibase prefix
+        if cid.len() == 46 && cid.starts_with("Qm") {
+            let bytes = multibase::decode(&format!("z{cid}")).map_err(|_| invalid("cid is not valid base58"))?;
+            let digest = parse_multihash(&bytes).ok_or_else(|| invalid("cid has an unsupported multihash"))?;
+            return Ok(Self {
+                codec: CODEC_DAG_PB,
+                digest,
+            });
+        }
+
+        let bytes = multibase::decode(cid).map_err(|e| match e {
+            multibase::Error::UnsupportedBase { .. } => invalid("cid has an unsupported multibase"),
+            _ => invalid("cid is not valid for its multibase"),
+        })?;
+
+        let mut buf = bytes.as_slice();
+        if read_varint(&mut buf) != Some(1) {
+            return Err(invalid("cid version is not supported"));
+        }
+        let codec = read_varint(&mut buf).ok_or_else(|| invalid("cid has no codec"))?;
+        let digest = parse_multihash(buf).ok_or_else(|| invalid("cid has an unsupported multihash"))?;
+        Ok(Self { codec, digest })
+    }
+}
+
+/// Fetches content stored on IPFS through an HTTP gateway.
+///
+/// The raw block is requested from the gateway and checked against the hash of the CID,
+/// so an untrusted gateway cannot serve different content.
+#[derive(Clone)]
+pub struct IpfsGateway {
+    client: reqwest::Client,
+    base_url: Url,
+}
+
+impl IpfsGateway {
+    /// Create the gateway client, or return `None` if no gateway is configured.
+    pub fn new(args: &IpfsArgs) -> anyhow::Result<Option<Self>> {
+        let Some(url) = &args.ipfs_gateway_url else {
+            return Ok(None);
+        };
+        let base_url = Url::parse(url)?;
+        if !matches!(base_url.scheme(), "http" | "https") {
+            anyhow::bail!("ipfs gateway url must use http or https");
+        }
+        let client = reqwest::Client::builder().timeout(args.ipfs_gateway_timeout).build()?;
+        Ok(Some(Self { client, base_url }))
+    }
+
+    /// Fetch the content of a CID, verifying it against the CID hash.
+    ///
+    /// Raw blocks are returned as is, and single-block UnixFS files are unwrapped.
+    /// The response is read up to the maximum block size, so a gateway cannot exhaust the memory of the node.
+    pub async fn fetch(&self, cid: &str) -> Result<Vec<u8>, IpfsError> {
+        let parsed_cid = Cid::parse(cid)?;
+        if !matches!(parsed_cid.codec, CODEC_RAW | CODEC_DAG_PB) {
+            return Err(IpfsError::UnsupportedContent {
+                message: format!("codec 0x{:x} is not supported", parsed_cid.codec),
+            });
+        }
+
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .expect("http url must have path segments")
+            .pop_if_empty()
+            .extend(["ipfs", cid]);
+        url.query_pairs_mut().append_pair("format", "raw");
+        let mut response = self
+            .client
+            .get(url)
+            .header(reqwest::header::ACCEPT, RAW_BLOCK_CONTENT_TYPE)
+            .send()
+            .await?
+            .error_for_status()?;
+        let too_large = || IpfsError::ContentTooLarge { limit: MAX_BLOCK_SIZE };
+        if response.content_length().is_some_and(|i| i > MAX_BLOCK_SIZE as u64) {
+            return Err(too_large());
+        }
+        let mut block = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if block.len() + chunk.len() > MAX_BLOCK_SIZE {
+                return Err(too_large());
+            }
+            block.extend_from_slice(&chunk);
+        }
+
+        decode_block(cid, &parsed_cid, &block)
+    }
+}
+
+/// Verify a block against the CID and return the content it holds.
+fn decode_block(cid: &str, parsed_cid: &Cid, block: &[u8]) -> Result<Vec<u8>, IpfsError> {
+    if sha256(block) != parsed_cid.digest {
+        return Err(IpfsError::ContentMismatch { cid: cid.to_string() });
+    }
+    match parsed_cid.codec {
+        CODEC_RAW => Ok(block.to_vec()),
+        _ => decode_unixfs_file(block),
+    }
+}
+
+/// Extract the file content of a dag-pb block holding a single-block UnixFS file.
+fn decode_unixfs_file(block: &[u8]) -> Result<Vec<u8>, IpfsError> {
+    let unsupported = |message: &str| IpfsError::UnsupportedContent {
+        message: message.to_string(),
+    };
+    let mut unixfs = None;
+    for (field, value) in read_fields(block).ok_or_else(|| unsupported("dag-pb block is malformed"))? {
+        match (field, value) {
+            (1, FieldValue::Bytes(data)) => unixfs = Some(data),
+            (2, _) => return Err(unsupported("files split across multiple blocks are not supported")),
+            _ => {}
+        }
+    }
+    let unixfs = unixfs.ok_or_else(|| unsupported("dag-pb block has no UnixFS data"))?;
+
+    let mut data_type = None;
+    let mut data = Vec::new();
+    for (field, value) in read_fields(unixfs).ok_or_else(|| unsupported("UnixFS data is malformed"))? {
+        match (field, value) {
+            (1, FieldValue::Varint(t)) => data_type = Some(t),
+            (2, FieldValue::Bytes(d)) => data = d.to_vec(),
+            _ => {}
+        }
+    }
+    // UnixFS data type 0 is raw and 2 is file
+    match data_type {
+        Some(0 | 2) => Ok(data),
+        _ => Err(unsupported("only UnixFS files are supported")),
+    }
+}
+
+fn parse_multihash(bytes: &[u8]) -> Option<Sha256Digest> {
+    let mut buf = bytes;
+    let code = read_varint(&mut buf)?;
+    let len = read_varint(&mut buf)?;
+    if code != MULTIHASH_SHA2_256 || len != 32 || buf.len() != 32 {
+        return None;
+    }
+    Sha256Digest::from_bytes(buf).ok()
+}
+
+enum FieldValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+}
+
+/// Read the fields of a protobuf message, supporting only varint and length-delimited fields.
+fn read_fields(bytes: &[u8]) -> Option<Vec<(u64, FieldValue<'_>)>> {
+    let mut buf = bytes;
+    let mut fields = Vec::new();
+    while !buf.is_empty() {
+        let key = read_varint(&mut buf)?;
+        let value = match key & 0x7 {
+            0 => FieldValue::Varint(read_varint(&mut buf)?),
+            2 => {
+                let len = usize::try_from(read_varint(&mut buf)?).ok()?;
+                let (value, rest) = buf.split_at_checked(len)?;
+                buf = rest;
+                FieldValue::Bytes(value)
+            }
+            _ => return None,
+        };
+        fields.push((key >> 3, value));
+    }
+    Some(fields)
+}
+
+fn read_varint(buf: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (byte, rest) = buf.split_first()?;
+        *buf = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unixfs_file_block(content: &[u8]) -> Vec<u8> {
+        let mut unixfs = vec![0x08, 0x02, 0x12, content.len() as u8];
+        unixfs.extend_from_slice(content);
+        let mut block = vec![0x0a, unixfs.len() as u8];
+        block.extend(unixfs);
+        block
+    }
+
+    #[test]
+    fn parse_cid_v1_raw() {
+        // CID of the empty raw block
+        let cid = Cid::parse("bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku").unwrap();
+        assert_eq!(cid.codec, CODEC_RAW);
+        assert_eq!(cid.digest, sha256(b""));
+    }
+
+    #[test]
+    fn parse_cid_v0() {
+        let cid = Cid::parse("QmUNLLsPACCz1vLxQVkXqqLX5R1X345qqfHbsf67hvA3Nn").unwrap();
+        assert_eq!(cid.codec, CODEC_DAG_PB);
+    }
+
+    #[test]
+    fn parse_cid_rejects_invalid_input() {
+        assert!(Cid::parse("").is_err());
+        assert!(Cid::parse("not-a-cid").is_err());
+        assert!(Cid::parse("b0000").is_err());
+        assert!(Cid::parse("bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyk").is_err());
+    }
+
+    #[test]
+    fn decode_block_returns_verified_raw_content() {
+        let content = b"hello ipfs".to_vec();
+        let cid = Cid {
+            codec: CODEC_RAW,
+            digest: sha256(&content),
+        };
+        assert_eq!(decode_block("test-cid", &cid, &content).unwrap(), content);
+    }
+
+    #[test]
+    fn decode_block_rejects_content_mismatch() {
+        let cid = Cid {
+            codec: CODEC_RAW,
+            digest: sha256(b"hello ipfs"),
+        };
+        let result = decode_block("test-cid", &cid, b"tampered");
+        assert!(matches!(result, Err(IpfsError::ContentMismatch { .. })));
+    }
+
+    #[test]
+    fn decode_block_unwraps_unixfs_file() {
+        let block = unixfs_file_block(b"hello ipfs");
+        let cid = Cid {
+            codec: CODEC_DAG_PB,
+            digest: sha256(&block),
+        };
+        assert_eq!(decode_block("test-cid", &cid, &block).unwrap(), b"hello ipfs");
+    }
+
+    #[test]
+    fn decode_block_rejects_multi_block_file() {
+        let mut block = vec![0x12, 0x00];
+        block.extend(unixfs_file_block(b""));
+        let cid = Cid {
+            codec: CODEC_DAG_PB,
+            digest: sha256(&block),
+        };
+        let result = decode_block("test-cid", &cid, &block);
+        assert!(matches!(result, Err(IpfsError::UnsupportedContent { .. })));
+    }
+}
@@ -1,4 +1,12 @@
+mod cache;
 pub mod error;
+mod events;
+mod ipfs;
 mod prism;
+mod webhook;
 
-pub use prism::PrismDidService;
+pub use cache::DidStateCache;
+pub use events::{DidEvent, DidEventFeed, DidEventFilter, OperationType};
+pub use ipfs::IpfsGateway;
+pub use prism::{DidOperationRecord, PrismDidService, StorageType, VdrEntryHistory, VdrEntryStatus, VdrEntryVersion};
+pub use webhook::WebhookService;
@@ -1,35 +1,171 @@
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Instant;
 
 use identus_apollo::hash::Sha256Digest;
 use identus_apollo::hex::HexStr;
-use identus_did_core::{Did, DidResolver, ResolutionOptions, ResolutionResult};
+use identus_did_core::{Did, DidResolver, ResolutionOptions, ResolutionResult, VerificationMethodEncoding};
 use identus_did_prism::did::operation::{OperationId, StorageData};
 use identus_did_prism::did::{CanonicalPrismDid, DidState, PrismDid, PrismDidOps, StorageState};
 use identus_did_prism::dlt::{BlockNo, OperationMetadata, SlotNo, TxId};
 use identus_did_prism::prelude::SignedPrismOperation;
+use identus_did_prism::proto::prism::prism_operation::Operation;
+use identus_did_prism::protocol::error::ProcessError;
+use identus_did_prism::protocol::proof::ResolutionProof;
 use identus_did_prism::protocol::resolver::{ResolutionDebug, resolve_published, resolve_unpublished};
 use identus_did_prism::utils::paging::Paginated;
 use identus_did_prism_indexer::repo::{IndexerStateRepo, RawOperationRepo};
 use node_storage::StorageBackend;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
+use super::cache::{DidStateCache, PublishedDidState};
 use super::error::{InvalidDid, ResolutionError};
+use super::events::OperationType;
+use crate::app::metrics;
+
+/// Type of the data stored in a VDR entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, derive_more::Display)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageType {
+    #[display("bytes")]
+    Bytes,
+    #[display("ipfs")]
+    Ipfs,
+    #[display("status_list")]
+    StatusList,
+}
+
+impl StorageType {
+    pub fn of(data: &StorageData) -> Self {
+        match data {
+            StorageData::Bytes(_) => Self::Bytes,
+            StorageData::Ipfs(_) => Self::Ipfs,
+            StorageData::StatusList(_) => Self::StatusList,
+        }
+    }
+}
+
+/// Whether a VDR entry still accepts updates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, derive_more::Display)]
+#[serde(rename_all = "snake_case")]
+pub enum VdrEntryStatus {
+    #[display("active")]
+    Active,
+    #[display("deactivated")]
+    Deactivated,
+}
 
 /// Metadata about a VDR entry, including the latest event hash and status.
 #[derive(Debug, Clone)]
 pub struct VdrEntryMetadata {
     pub entry_hash: String,
     pub latest_event_hash: String,
-    pub status: String,
+    pub status: VdrEntryStatus,
+    /// Type of the latest stored data, absent if the entry was created without data.
+    pub storage_type: Option<StorageType>,
+}
+
+/// A version of a VDR entry, created by a storage operation or by the deactivation of the owning DID.
+#[derive(Debug, Clone)]
+pub struct VdrEntryVersion {
+    pub metadata: OperationMetadata,
+    pub operation_type: OperationType,
+    /// Hash of the operation creating this version.
+    pub event_hash: Sha256Digest,
+    /// Data of the entry in this version, `None` once the entry is deactivated.
+    pub data: Option<StorageData>,
+}
+
+/// The accepted versions of a VDR entry, oldest first.
+#[derive(Debug, Clone)]
+pub struct VdrEntryHistory {
+    pub owner: CanonicalPrismDid,
+    pub entry_hash: Sha256Digest,
+    pub versions: Vec<VdrEntryVersion>,
+}
+
+impl VdrEntryHistory {
+    pub fn latest(&self) -> &VdrEntryVersion {
+        self.versions.last().expect("vdr entry history must not be empty")
+    }
+
+    pub fn is_deactivated(&self) -> bool {
+        self.latest().data.is_none()
+    }
+
+    pub fn status(&self) -> VdrEntryStatus {
+        if self.is_deactivated() {
+            VdrEntryStatus::Deactivated
+        } else {
+            VdrEntryStatus::Active
+        }
+    }
+
+    pub fn version(&self, event_hash: &Sha256Digest) -> Option<&VdrEntryVersion> {
+        self.versions.iter().find(|i| i.event_hash == *event_hash)
+    }
+
+    /// Data of the latest version that has not deactivated the entry.
+    pub fn latest_data(&self) -> Option<&StorageData> {
+        self.versions.iter().rev().find_map(|i| i.data.as_ref())
+    }
+}
+
+/// An operation of a DID together with the outcome of processing it during resolution.
+#[derive(Debug)]
+pub struct DidOperationRecord {
+    pub metadata: OperationMetadata,
+    pub signed_operation: SignedPrismOperation,
+    pub operation_type: Option<OperationType>,
+    /// Hash of the last operation applied to the DID document after processing this operation.
+    /// Rejected operations and storage operations leave it unchanged.
+    pub version_id: Option<Sha256Digest>,
+    /// Why the operation was rejected, if it was.
+    pub error: Option<ProcessError>,
 }
 
 #[derive(Clone)]
 pub struct PrismDidService {
     db: Arc<dyn StorageBackend>,
+    cache: Option<DidStateCache>,
+    verification_method_encoding: VerificationMethodEncoding,
 }
 
 impl PrismDidService {
-    pub fn new(db: Arc<dyn StorageBackend>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<dyn StorageBackend>, cache: Option<DidStateCache>) -> Self {
+        Self {
+            db,
+            cache,
+            verification_method_encoding: VerificationMethodEncoding::default(),
+        }
+    }
+
+    /// Set the encoding of verification methods used when the resolution options do not request one.
+    pub fn with_verification_method_encoding(mut self, encoding: VerificationMethodEncoding) -> Self {
+        self.verification_method_encoding = encoding;
+        self
+    }
+
+    /// Encoding of verification methods requested by the resolution options, or the one configured for the node.
+    pub fn verification_method_encoding(&self, options: &ResolutionOptions) -> VerificationMethodEncoding {
+        options
+            .verification_method_encoding
+            .unwrap_or(self.verification_method_encoding)
+    }
+
+    /// Build the resolution result of a resolved DID as requested by the resolution options.
+    pub fn to_resolution_result(
+        &self,
+        did: &PrismDid,
+        state: &DidState,
+        options: &ResolutionOptions,
+    ) -> ResolutionResult {
+        let mut result = state.to_resolution_result_with_encoding(did, self.verification_method_encoding(options));
+        if options.include_operation_keys == Some(true) {
+            result.did_document_metadata.operation_keys = Some(state.to_operation_keys(&did.to_did()));
+        }
+        result
     }
 
     pub async fn get_indexer_stats(&self) -> anyhow::Result<Option<(SlotNo, BlockNo)>> {
@@ -48,8 +184,7 @@ impl PrismDidService {
         let Some(owner) = self.db.get_did_by_vdr_entry(&entry_hash).await? else {
             return Ok(None);
         };
-        let mut debug_acc = vec![];
-        let (_, did_state) = self.resolve_did_logic(&owner.to_string(), &mut debug_acc).await?;
+        let (_, did_state) = self.resolve_did_cached_logic(PrismDid::Canonical(owner)).await?;
         let storage = did_state
             .storage
             .iter()
@@ -58,36 +193,185 @@ impl PrismDidService {
         Ok(storage.map(|s| (entry_hash_hex, did_state, s)))
     }
 
-    pub async fn resolve_vdr(&self, entry_hash_hex: &str) -> anyhow::Result<Option<Vec<u8>>> {
+    pub async fn resolve_vdr(&self, entry_hash_hex: &str) -> anyhow::Result<Option<StorageData>> {
         let Some((_, _, storage)) = self.resolve_vdr_storage(entry_hash_hex).await? else {
             return Ok(None);
         };
-        match &*storage.data {
-            StorageData::Bytes(items) => Ok(Some(items.clone())),
-            _ => anyhow::bail!("vdr storage data types other than bytes are not yet supported"),
-        }
+        Ok(Some((*storage.data).clone()))
     }
 
     pub async fn resolve_vdr_entry_metadata(&self, entry_hash_hex: &str) -> anyhow::Result<Option<VdrEntryMetadata>> {
         let Some((hex, _, storage)) = self.resolve_vdr_storage(entry_hash_hex).await? else {
-            return Ok(None);
+            // Deactivated entries are filtered out during resolution, so look them up in the history
+            let Some(history) = self.get_vdr_entry_history(entry_hash_hex).await? else {
+                return Ok(None);
+            };
+            return Ok(Some(VdrEntryMetadata {
+                entry_hash: HexStr::from(history.entry_hash.to_vec()).to_string(),
+                latest_event_hash: HexStr::from(history.latest().event_hash.to_vec()).to_string(),
+                status: history.status(),
+                storage_type: history.latest_data().map(StorageType::of),
+            }));
         };
         Ok(Some(VdrEntryMetadata {
             entry_hash: hex.to_string(),
             latest_event_hash: HexStr::from(storage.last_operation_hash.to_vec()).to_string(),
-            // Status is always "active" here: deactivated DIDs have their storage entries
-            // revoked and filtered out during resolution, so resolve_vdr_storage() returns
-            // None (404) before we reach this point.
-            status: "active".to_string(),
+            status: VdrEntryStatus::Active,
+            storage_type: Some(StorageType::of(&storage.data)),
         }))
     }
 
+    /// Versions of a VDR entry, including its deactivation, in the order they were processed during resolution.
+    pub async fn get_vdr_entry_history(&self, entry_hash_hex: &str) -> anyhow::Result<Option<VdrEntryHistory>> {
+        let entry_hash_hex: HexStr = entry_hash_hex.parse()?;
+        let entry_hash = Sha256Digest::from_bytes(&entry_hash_hex.to_bytes())?;
+        let Some(owner) = self.db.get_did_by_vdr_entry(&entry_hash).await? else {
+            return Ok(None);
+        };
+        let mut debug_acc = vec![];
+        self.published_state(&owner, &mut debug_acc).await?;
+        // The entry has no history if the operation creating it was rejected
+        let history = to_vdr_entry_histories(&owner, debug_acc)
+            .into_iter()
+            .find(|i| i.entry_hash == entry_hash);
+        Ok(history)
+    }
+
+    /// Storage entries of a DID with their versions, including the deactivated ones, in the order they were created.
+    pub async fn get_did_vdr_entries(&self, did: &str) -> Result<Vec<VdrEntryHistory>, ResolutionError> {
+        let (canonical_did, debug) = self.published_debug(did).await?;
+        Ok(to_vdr_entry_histories(&canonical_did, debug))
+    }
+
     pub async fn resolve_did(&self, did: &str) -> (Result<(PrismDid, DidState), ResolutionError>, ResolutionDebug) {
+        let started_at = Instant::now();
         let mut debug_acc = vec![];
         let result = self.resolve_did_logic(did, &mut debug_acc).await;
+        metrics::observe_resolution(started_at, &result);
         (result, debug_acc)
     }
 
+    /// Operations of a DID in the order they were processed during resolution.
+    pub async fn get_did_operation_history(&self, did: &str) -> Result<Vec<DidOperationRecord>, ResolutionError> {
+        let (_, debug) = self.published_debug(did).await?;
+        Ok(to_operation_history(debug))
+    }
+
+    /// A page of the operations of a DID, optionally of a single type, in the order they were processed during resolution.
+    ///
+    /// The outcome of an operation depends on every operation before it, so the operations up to the end of the page
+    /// are queried from storage and resolved. Operation types are not stored, so a page of a single type is taken
+    /// from the operations of the DID resolved in full.
+    pub async fn get_did_operation_page(
+        &self,
+        did: &str,
+        operation_type: Option<OperationType>,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Paginated<DidOperationRecord>, ResolutionError> {
+        let offset = page.saturating_mul(page_size);
+        if let Some(operation_type) = operation_type {
+            let records = self
+                .get_did_operation_history(did)
+                .await?
+                .into_iter()
+                .filter(|record| record.operation_type == Some(operation_type))
+                .collect::<Vec<_>>();
+            return Ok(Paginated {
+                total_items: records.len() as u32,
+                items: records
+                    .into_iter()
+                    .skip(offset as usize)
+                    .take(page_size as usize)
+                    .collect(),
+                current_page: page,
+                page_size,
+            });
+        }
+
+        let did: PrismDid = did.parse().map_err(|e| InvalidDid::InvalidPrismDid { source: e })?;
+        let canonical_did = did.clone().into_canonical();
+        let operation_page = self
+            .db
+            .get_raw_operations_by_did_page(&canonical_did, 0, offset.saturating_add(page_size))
+            .await
+            .map_err(|e| ResolutionError::InternalError { source: e.into() })?;
+        if operation_page.total_items == 0 {
+            // Report unknown canonical DIDs and invalid long-form DIDs the same way as resolution does
+            resolve_with_published_state(did, PublishedDidState::Unpublished)?;
+        }
+        let operations = operation_page
+            .items
+            .into_iter()
+            .map(|record| (record.metadata, record.signed_operation))
+            .collect::<Vec<_>>();
+        let (_, debug) = resolve_published(operations);
+        Ok(Paginated {
+            items: to_operation_history(debug).into_iter().skip(offset as usize).collect(),
+            current_page: page,
+            page_size,
+            total_items: operation_page.total_items,
+        })
+    }
+
+    /// Operations of a DID bundled with the PRISM objects of the transactions publishing them,
+    /// as they were published in the transaction metadata, so the DID state can be verified offline.
+    /// Transactions indexed before PRISM objects were stored are included without their PRISM object.
+    pub async fn get_resolution_proof(&self, did: &str) -> Result<ResolutionProof, ResolutionError> {
+        let (canonical_did, debug) = self.published_debug(did).await?;
+        let operations = debug
+            .into_iter()
+            .map(|(metadata, signed_operation, _)| (metadata, signed_operation))
+            .collect::<Vec<_>>();
+
+        let mut prism_objects = HashMap::new();
+        for (metadata, _) in &operations {
+            let tx_id = &metadata.block_metadata.tx_id;
+            if prism_objects.contains_key(tx_id) {
+                continue;
+            }
+            let prism_object_bytes = self
+                .db
+                .get_prism_object_bytes(tx_id)
+                .await
+                .map_err(|e| ResolutionError::InternalError { source: e.into() })?;
+            prism_objects.insert(tx_id.clone(), prism_object_bytes);
+        }
+
+        // Every transaction was looked up above, the ones without a stored PRISM object map to None
+        Ok(ResolutionProof::new(canonical_did, operations, |block_metadata| {
+            prism_objects.remove(&block_metadata.tx_id).flatten()
+        }))
+    }
+
+    /// Operations processed while resolving the published state of a DID.
+    async fn published_debug(&self, did: &str) -> Result<(CanonicalPrismDid, ResolutionDebug), ResolutionError> {
+        let did: PrismDid = did.parse().map_err(|e| InvalidDid::InvalidPrismDid { source: e })?;
+        let canonical_did = did.clone().into_canonical();
+        let mut debug_acc = vec![];
+        let published_state = self.published_state(&canonical_did, &mut debug_acc).await?;
+        if debug_acc.is_empty() {
+            // Report unknown canonical DIDs and invalid long-form DIDs the same way as resolution does
+            resolve_with_published_state(did, published_state)?;
+        }
+        Ok((canonical_did, debug_acc))
+    }
+
+    /// Resolve multiple DIDs, fetching the operations of all of them in a single storage query.
+    /// Results are returned in the same order as the input.
+    pub async fn resolve_dids(
+        &self,
+        dids: &[String],
+    ) -> anyhow::Result<Vec<Result<(PrismDid, DidState), ResolutionError>>> {
+        let started_at = Instant::now();
+        let results = self.resolve_dids_logic(dids).await?;
+        for result in &results {
+            metrics::observe_resolution(started_at, result);
+        }
+        Ok(results)
+    }
+
+    #[tracing::instrument(skip_all, fields(did = %did))]
     async fn resolve_did_logic(
         &self,
         did: &str,
@@ -95,36 +379,105 @@ impl PrismDidService {
     ) -> Result<(PrismDid, DidState), ResolutionError> {
         let did: PrismDid = did.parse().map_err(|e| InvalidDid::InvalidPrismDid { source: e })?;
         let canonical_did = did.clone().into_canonical();
+        let published_state = self.published_state(&canonical_did, debug_acc).await?;
+        resolve_with_published_state(did, published_state)
+    }
+
+    /// Same as [`Self::resolve_did_logic`], but reads the published state from the cache if enabled.
+    /// Cached states carry no resolution debug.
+    #[tracing::instrument(skip_all, fields(did = %did))]
+    async fn resolve_did_cached_logic(&self, did: PrismDid) -> Result<(PrismDid, DidState), ResolutionError> {
+        let canonical_did = did.clone().into_canonical();
+        let Some(cache) = &self.cache else {
+            let published_state = self.published_state(&canonical_did, &mut vec![]).await?;
+            return resolve_with_published_state(did, published_state);
+        };
+        if let Some(published_state) = cache.get(&canonical_did) {
+            return resolve_with_published_state(did, published_state);
+        }
+        let generation = cache.generation();
+        let published_state = self.published_state(&canonical_did, &mut vec![]).await?;
+        cache.insert(canonical_did, published_state.clone(), generation);
+        resolve_with_published_state(did, published_state)
+    }
 
+    async fn published_state(
+        &self,
+        did: &CanonicalPrismDid,
+        debug_acc: &mut ResolutionDebug,
+    ) -> Result<PublishedDidState, ResolutionError> {
         let operations = self
             .db
-            .get_raw_operations_by_did(&canonical_did)
+            .get_raw_operations_by_did(did)
             .await
             .map_err(|e| ResolutionError::InternalError { source: e.into() })?
             .into_iter()
             .map(|record| (record.metadata, record.signed_operation))
             .collect::<Vec<_>>();
+        Ok(to_published_state(operations, debug_acc))
+    }
 
-        if operations.is_empty() {
-            match &did {
-                PrismDid::Canonical(_) => Err(ResolutionError::NotFound)?,
-                PrismDid::LongForm(long_form_did) => {
-                    let operation = long_form_did
-                        .operation()
-                        .map_err(|e| InvalidDid::InvalidPrismDid { source: e })?;
-                    let did_state =
-                        resolve_unpublished(operation).map_err(|e| InvalidDid::ProcessStateFailed { source: e })?;
-                    Ok((did, did_state))
+    #[tracing::instrument(skip_all, fields(dids = dids.len()))]
+    async fn resolve_dids_logic(
+        &self,
+        dids: &[String],
+    ) -> anyhow::Result<Vec<Result<(PrismDid, DidState), ResolutionError>>> {
+        let parsed_dids = dids
+            .iter()
+            .map(|did| {
+                did.parse::<PrismDid>()
+                    .map_err(|e| InvalidDid::InvalidPrismDid { source: e }.into())
+            })
+            .collect::<Vec<Result<_, ResolutionError>>>();
+
+        let canonical_dids = parsed_dids
+            .iter()
+            .flatten()
+            .map(|did| did.clone().into_canonical())
+            .collect::<HashSet<_>>();
+
+        let mut states_by_did: HashMap<CanonicalPrismDid, PublishedDidState> = HashMap::new();
+        let mut uncached_dids = vec![];
+        for did in canonical_dids {
+            match self.cache.as_ref().and_then(|cache| cache.get(&did)) {
+                Some(published_state) => {
+                    states_by_did.insert(did, published_state);
                 }
+                None => uncached_dids.push(did),
             }
-        } else {
-            let (did_state, debug) = resolve_published(operations);
-            debug_acc.extend(debug);
-            match did_state {
-                Some(did_state) => Ok((did, did_state)),
-                None => Err(ResolutionError::NotFound),
+        }
+
+        if !uncached_dids.is_empty() {
+            let generation = self.cache.as_ref().map(|cache| cache.generation());
+            let mut operations_by_did: HashMap<CanonicalPrismDid, Vec<_>> = HashMap::new();
+            for (record, did) in self.db.get_raw_operations_by_dids(&uncached_dids).await? {
+                operations_by_did
+                    .entry(did)
+                    .or_default()
+                    .push((record.metadata, record.signed_operation));
+            }
+            for did in uncached_dids {
+                let operations = operations_by_did.remove(&did).unwrap_or_default();
+                let published_state = to_published_state(operations, &mut vec![]);
+                if let (Some(cache), Some(generation)) = (&self.cache, generation) {
+                    cache.insert(did.clone(), published_state.clone(), generation);
+                }
+                states_by_did.insert(did, published_state);
             }
         }
+
+        let results = parsed_dids
+            .into_iter()
+            .map(|did| {
+                let did = did?;
+                let published_state = states_by_did
+                    .get(&did.clone().into_canonical())
+                    .cloned()
+                    .unwrap_or(PublishedDidState::Unpublished);
+                resolve_with_published_state(did, published_state)
+            })
+            .collect();
+        Ok(results)
     }
 
     pub async fn get_all_dids(&self, page: Option<u32>) -> anyhow::Result<Paginated<CanonicalPrismDid>> {
@@ -158,13 +511,144 @@ impl PrismDidService {
     }
 }
 
+fn to_published_state(
+    operations: Vec<(OperationMetadata, SignedPrismOperation)>,
+    debug_acc: &mut ResolutionDebug,
+) -> PublishedDidState {
+    if operations.is_empty() {
+        return PublishedDidState::Unpublished;
+    }
+    let (did_state, debug) = resolve_published(operations);
+    debug_acc.extend(debug);
+    match did_state {
+        Some(did_state) => PublishedDidState::Published(did_state),
+        None => PublishedDidState::Invalid,
+    }
+}
+
+fn to_operation_history(debug: ResolutionDebug) -> Vec<DidOperationRecord> {
+    let mut version_id = None;
+    debug
+        .into_iter()
+        .map(|(metadata, signed_operation, error)| {
+            let operation_type = OperationType::from_signed_operation(&signed_operation);
+            let changes_version = matches!(
+                operation_type,
+                Some(OperationType::CreateDid | OperationType::UpdateDid | OperationType::DeactivateDid)
+            );
+            if error.is_none() && changes_version {
+                version_id = signed_operation.operation_hash();
+            }
+            DidOperationRecord {
+                metadata,
+                signed_operation,
+                operation_type,
+                version_id: version_id.clone(),
+                error,
+            }
+        })
+        .collect()
+}
+
+fn to_vdr_entry_histories(owner: &CanonicalPrismDid, debug: ResolutionDebug) -> Vec<VdrEntryHistory> {
+    let mut histories: Vec<VdrEntryHistory> = vec![];
+    for (metadata, signed_operation, error) in debug {
+        let Some(operation) = signed_operation.operation.as_ref() else {
+            continue;
+        };
+        if error.is_some() {
+            continue;
+        }
+        let event_hash = operation.operation_hash();
+        match &operation.operation {
+            Some(Operation::CreateStorageEntry(op)) => histories.push(VdrEntryHistory {
+                owner: owner.clone(),
+                entry_hash: event_hash.clone(),
+                versions: vec![VdrEntryVersion {
+                    metadata,
+                    operation_type: OperationType::CreateStorageEntry,
+                    event_hash,
+                    data: op.data.clone().map(StorageData::from),
+                }],
+            }),
+            Some(Operation::UpdateStorageEntry(op)) => {
+                if let Some(history) = find_active_entry(&mut histories, &op.previous_event_hash) {
+                    history.versions.push(VdrEntryVersion {
+                        metadata,
+                        operation_type: OperationType::UpdateStorageEntry,
+                        event_hash,
+                        data: op.data.clone().map(StorageData::from),
+                    });
+                }
+            }
+            Some(Operation::DeactivateStorageEntry(op)) => {
+                if let Some(history) = find_active_entry(&mut histories, &op.previous_event_hash) {
+                    history.versions.push(VdrEntryVersion {
+                        metadata,
+                        operation_type: OperationType::DeactivateStorageEntry,
+                        event_hash,
+                        data: None,
+                    });
+                }
+            }
+            // Deactivating the DID revokes all of its storage entries
+            Some(Operation::DeactivateDid(_)) => {
+                for history in histories.iter_mut().filter(|i| !i.is_deactivated()) {
+                    history.versions.push(VdrEntryVersion {
+                        metadata: metadata.clone(),
+                        operation_type: OperationType::DeactivateDid,
+                        event_hash: event_hash.clone(),
+                        data: None,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    histories
+}
+
+fn find_active_entry<'a>(
+    histories: &'a mut [VdrEntryHistory],
+    previous_event_hash: &[u8],
+) -> Option<&'a mut VdrEntryHistory> {
+    histories
+        .iter_mut()
+        .find(|i| !i.is_deactivated() && i.latest().event_hash.as_bytes() == previous_event_hash)
+}
+
+fn resolve_with_published_state(
+    did: PrismDid,
+    published_state: PublishedDidState,
+) -> Result<(PrismDid, DidState), ResolutionError> {
+    match published_state {
+        PublishedDidState::Published(did_state) => Ok((did, did_state)),
+        PublishedDidState::Invalid => Err(ResolutionError::NotFound),
+        PublishedDidState::Unpublished => match &did {
+            PrismDid::Canonical(_) => Err(ResolutionError::NotFound)?,
+            PrismDid::LongForm(long_form_did) => {
+                let operation = long_form_did
+                    .operation()
+                    .map_err(|e| InvalidDid::InvalidPrismDid { source: e })?;
+                let did_state =
+                    resolve_unpublished(operation).map_err(|e| InvalidDid::ProcessStateFailed { source: e })?;
+                Ok((did, did_state))
+            }
+        },
+    }
+}
+
 #[async_trait::async_trait]
 impl DidResolver for PrismDidService {
-    async fn resolve(&self, did: &Did, _options: &ResolutionOptions) -> ResolutionResult {
-        let did_str = did.to_string();
-        let mut debug_acc = vec![];
-        match self.resolve_did_logic(&did_str, &mut debug_acc).await {
-            Ok((prism_did, state)) => state.to_resolution_result(&prism_did),
+    async fn resolve(&self, did: &Did, options: &ResolutionOptions) -> ResolutionResult {
+        let started_at = Instant::now();
+        let result = match did.to_string().parse::<PrismDid>() {
+            Ok(prism_did) => self.resolve_did_cached_logic(prism_did).await,
+            Err(e) => Err(InvalidDid::InvalidPrismDid { source: e }.into()),
+        };
+        metrics::observe_resolution(started_at, &result);
+        match result {
+            Ok((prism_did, state)) => self.to_resolution_result(&prism_did, &state, options),
             Err(e) => e.into(),
         }
     }
@@ -172,6 +656,8 @@ impl DidResolver for PrismDidService {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use chrono::DateTime;
     use identus_apollo::crypto::secp256k1::Secp256k1PrivateKey;
     use identus_apollo::hash::sha256;
@@ -180,13 +666,17 @@ mod tests {
     use identus_did_prism::prelude::MessageExt;
     use identus_did_prism::proto;
     use identus_did_prism::proto::prism_ssi::KeyUsage;
-    use identus_did_prism_indexer::run_indexer_loop;
+    use identus_did_prism::protocol::proof;
+    use identus_did_prism_indexer::{run_indexer_loop, run_indexer_loop_with_notifier};
     use node_storage::SqliteDb;
+    use tokio::sync::broadcast;
 
     use super::*;
+    use crate::cli::ResolutionCacheArgs;
 
     const MASTER_KEY: [u8; 32] = [1; 32];
     const MASTER_KEY_NAME: &str = "master-0";
+    const VDR_KEY_NAME: &str = "vdr-0";
 
     fn master_sk() -> Secp256k1PrivateKey {
         Secp256k1PrivateKey::from_slice(&MASTER_KEY).unwrap()
@@ -298,6 +788,91 @@ mod tests {
         }
     }
 
+    fn new_create_did_with_vdr_key_operation() -> (proto::prism::SignedPrismOperation, CanonicalPrismDid) {
+        let sk = master_sk();
+        let operation = proto::prism::PrismOperation {
+            operation: Some(proto::prism::prism_operation::Operation::CreateDid(
+                proto::prism_ssi::ProtoCreateDID {
+                    did_data: Some(proto::prism_ssi::proto_create_did::DIDCreationData {
+                        public_keys: vec![
+                            new_public_key(MASTER_KEY_NAME, KeyUsage::MASTER_KEY, &sk),
+                            new_public_key(VDR_KEY_NAME, KeyUsage::VDR_KEY, &sk),
+                        ],
+                        services: vec![],
+                        context: vec![],
+                        special_fields: Default::default(),
+                    })
+                    .into(),
+                    special_fields: Default::default(),
+                },
+            )),
+            special_fields: Default::default(),
+        };
+        let did = CanonicalPrismDid::from_operation(&operation).unwrap();
+        let signed_operation = proto::prism::SignedPrismOperation {
+            signed_with: MASTER_KEY_NAME.to_string(),
+            signature: sk.sign(&operation.encode_to_vec()),
+            operation: Some(operation).into(),
+            special_fields: Default::default(),
+        };
+        (signed_operation, did)
+    }
+
+    fn new_create_storage_entry_operation(
+        did: &CanonicalPrismDid,
+        data: proto::prism_storage::proto_create_storage_entry::Data,
+    ) -> (proto::prism::SignedPrismOperation, Sha256Digest) {
+        new_storage_operation(proto::prism::prism_operation::Operation::CreateStorageEntry(
+            proto::prism_storage::ProtoCreateStorageEntry {
+                did_prism_hash: did.suffix.to_vec(),
+                nonce: vec![0],
+                data: Some(data),
+                special_fields: Default::default(),
+            },
+        ))
+    }
+
+    fn new_update_storage_entry_operation(
+        previous_event_hash: &Sha256Digest,
+        data: proto::prism_storage::proto_update_storage_entry::Data,
+    ) -> (proto::prism::SignedPrismOperation, Sha256Digest) {
+        new_storage_operation(proto::prism::prism_operation::Operation::UpdateStorageEntry(
+            proto::prism_storage::ProtoUpdateStorageEntry {
+                previous_event_hash: previous_event_hash.to_vec(),
+                data: Some(data),
+                special_fields: Default::default(),
+            },
+        ))
+    }
+
+    fn new_deactivate_storage_entry_operation(
+        previous_event_hash: &Sha256Digest,
+    ) -> (proto::prism::SignedPrismOperation, Sha256Digest) {
+        new_storage_operation(proto::prism::prism_operation::Operation::DeactivateStorageEntry(
+            proto::prism_storage::ProtoDeactivateStorageEntry {
+                previous_event_hash: previous_event_hash.to_vec(),
+                special_fields: Default::default(),
+            },
+        ))
+    }
+
+    fn new_storage_operation(
+        operation_inner: proto::prism::prism_operation::Operation,
+    ) -> (proto::prism::SignedPrismOperation, Sha256Digest) {
+        let operation = proto::prism::PrismOperation {
+            operation: Some(operation_inner),
+            special_fields: Default::default(),
+        };
+        let operation_hash = operation.operation_hash();
+        let signed_operation = proto::prism::SignedPrismOperation {
+            signed_with: VDR_KEY_NAME.to_string(),
+            signature: master_sk().sign(&operation.encode_to_vec()),
+            operation: Some(operation).into(),
+            special_fields: Default::default(),
+        };
+        (signed_operation, operation_hash)
+    }
+
     fn dummy_metadata(osn: u32) -> OperationMetadata {
         OperationMetadata {
             block_metadata: BlockMetadata {
@@ -317,9 +892,22 @@ mod tests {
         Arc::new(db)
     }
 
+    /// Encode operations as the PrismObject published in a transaction.
+    fn prism_object(operations: Vec<SignedPrismOperation>) -> Vec<u8> {
+        proto::prism::PrismObject {
+            block_content: Some(proto::prism::PrismBlock {
+                operations,
+                special_fields: Default::default(),
+            })
+            .into(),
+            special_fields: Default::default(),
+        }
+        .encode_to_vec()
+    }
+
     async fn setup_service() -> (PrismDidService, Arc<dyn StorageBackend>) {
         let db = setup_db().await;
-        let service = PrismDidService::new(db.clone());
+        let service = PrismDidService::new(db.clone(), None);
         (service, db)
     }
 
@@ -472,6 +1060,220 @@ mod tests {
         assert!(did_state.is_deactivated(), "DID should be deactivated");
     }
 
+    // --- resolve_dids ---
+
+    #[tokio::test]
+    async fn resolve_dids_returns_results_in_input_order() {
+        let (service, db) = setup_service().await;
+
+        let (signed_op, _op_hash) = new_create_did_operation();
+        let operation = signed_op.operation.clone().into_option().unwrap();
+        db.insert_raw_operations(vec![(dummy_metadata(0), signed_op)])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+
+        let published_did = service.get_all_dids(None).await.unwrap().items[0].to_string();
+        let long_form_did = LongFormPrismDid::from_operation(&operation).unwrap().to_string();
+        let unknown_did = "did:prism:abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890".to_string();
+        let invalid_did = "not-a-did".to_string();
+
+        let results = service
+            .resolve_dids(&[published_did, unknown_did, invalid_did, long_form_did])
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok(), "published DID should resolve");
+        assert!(matches!(results[1], Err(ResolutionError::NotFound)));
+        assert!(matches!(results[2], Err(ResolutionError::InvalidDid { .. })));
+        let (prism_did, _) = results[3].as_ref().expect("long-form DID should resolve");
+        assert!(matches!(prism_did, PrismDid::LongForm(_)));
+    }
+
+    #[tokio::test]
+    async fn resolve_dids_empty_input() {
+        let (service, _) = setup_service().await;
+        let results = service.resolve_dids(&[]).await.unwrap();
+        assert!(results.is_empty());
+    }
+
+    // --- get_did_operation_history ---
+
+    #[tokio::test]
+    async fn get_did_operation_history_reports_rejected_operations() {
+        let (service, db) = setup_service().await;
+        let sk = master_sk();
+
+        let (create_op, create_hash) = new_create_did_operation();
+        db.insert_raw_operations(vec![(dummy_metadata(0), create_op)])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+
+        let all_dids = service.get_all_dids(None).await.unwrap();
+        let did_suffix_hex = HexStr::from(all_dids.items[0].suffix().as_bytes().to_owned()).to_string();
+        let canonical_str = all_dids.items[0].to_string();
+
+        // The first update references an unknown previous operation and is rejected
+        let rejected_op = new_update_did_operation(&did_suffix_hex, MASTER_KEY_NAME, &sk, &sha256([7u8; 32]));
+        let accepted_op = new_update_did_operation(&did_suffix_hex, MASTER_KEY_NAME, &sk, &create_hash);
+        let update_hash = accepted_op.operation_hash().unwrap();
+        db.insert_raw_operations(vec![(dummy_metadata(1), rejected_op), (dummy_metadata(2), accepted_op)])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+
+        let history = service.get_did_operation_history(&canonical_str).await.unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].operation_type, Some(OperationType::CreateDid));
+        assert!(history[0].error.is_none());
+        assert_eq!(history[0].version_id, Some(create_hash.clone()));
+        assert!(matches!(history[1].error, Some(ProcessError::DidStateConflict { .. })));
+        assert_eq!(history[1].version_id, Some(create_hash));
+        assert!(history[2].error.is_none());
+        assert_eq!(history[2].version_id, Some(update_hash));
+    }
+
+    #[tokio::test]
+    async fn get_did_operation_page_resolves_operations_before_page() {
+        let (service, db) = setup_service().await;
+        let sk = master_sk();
+
+        let (create_op, create_hash) = new_create_did_operation();
+        db.insert_raw_operations(vec![(dummy_metadata(0), create_op)])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+
+        let all_dids = service.get_all_dids(None).await.unwrap();
+        let did_suffix_hex = HexStr::from(all_dids.items[0].suffix().as_bytes().to_owned()).to_string();
+        let canonical_str = all_dids.items[0].to_string();
+
+        let rejected_op = new_update_did_operation(&did_suffix_hex, MASTER_KEY_NAME, &sk, &sha256([7u8; 32]));
+        let accepted_op = new_update_did_operation(&did_suffix_hex, MASTER_KEY_NAME, &sk, &create_hash);
+        let update_hash = accepted_op.operation_hash().unwrap();
+        db.insert_raw_operations(vec![(dummy_metadata(1), rejected_op), (dummy_metadata(2), accepted_op)])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+
+        let page = service
+            .get_did_operation_page(&canonical_str, None, 1, 2)
+            .await
+            .unwrap();
+        assert_eq!(page.total_items, 3);
+        assert_eq!(page.items.len(), 1);
+        assert!(page.items[0].error.is_none());
+        assert_eq!(page.items[0].version_id, Some(update_hash));
+
+        let page = service
+            .get_did_operation_page(&canonical_str, Some(OperationType::UpdateDid), 0, 1)
+            .await
+            .unwrap();
+        assert_eq!(page.total_items, 2);
+        assert!(matches!(
+            page.items[0].error,
+            Some(ProcessError::DidStateConflict { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_did_operation_page_not_found() {
+        let (service, _) = setup_service().await;
+        let did_str = "did:prism:abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890";
+        let err = service.get_did_operation_page(did_str, None, 0, 10).await.unwrap_err();
+        assert!(
+            matches!(err, ResolutionError::NotFound),
+            "expected NotFound, got: {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_did_operation_history_not_found() {
+        let (service, _) = setup_service().await;
+        let did_str = "did:prism:abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890";
+        let err = service.get_did_operation_history(did_str).await.unwrap_err();
+        assert!(
+            matches!(err, ResolutionError::NotFound),
+            "expected NotFound, got: {err:?}"
+        );
+    }
+
+    // --- get_resolution_proof ---
+
+    #[tokio::test]
+    async fn get_resolution_proof_verifies_to_resolved_state() {
+        let (service, db) = setup_service().await;
+        let sk = master_sk();
+
+        let (create_op, create_hash) = new_create_did_operation();
+        db.insert_raw_operations(vec![(dummy_metadata(0), create_op.clone())])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+
+        let all_dids = service.get_all_dids(None).await.unwrap();
+        let did_suffix_hex = HexStr::from(all_dids.items[0].suffix().as_bytes().to_owned()).to_string();
+        let canonical_str = all_dids.items[0].to_string();
+
+        // The operation at osn 1 carries no operation and is not indexed, but is part of the published object
+        let update_op = new_update_did_operation(&did_suffix_hex, MASTER_KEY_NAME, &sk, &create_hash);
+        db.insert_raw_operations(vec![(dummy_metadata(2), update_op.clone())])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+        let prism_object_bytes = prism_object(vec![create_op, SignedPrismOperation::default(), update_op]);
+        let tx_id = dummy_metadata(0).block_metadata.tx_id;
+        db.insert_prism_object_bytes(&tx_id, prism_object_bytes.clone())
+            .await
+            .unwrap();
+
+        let proof = service.get_resolution_proof(&canonical_str).await.unwrap();
+        assert_eq!(proof.transactions.len(), 1);
+        assert_eq!(
+            proof.transactions[0].prism_object.as_ref().unwrap().to_bytes(),
+            prism_object_bytes
+        );
+        let osns: Vec<_> = proof.transactions[0].operations.iter().map(|i| i.osn).collect();
+        assert_eq!(osns, [0, 2]);
+
+        let state = proof.verify().unwrap();
+        let (_, resolved) = service.resolve_did(&canonical_str).await.0.unwrap();
+        assert_eq!(state.did, resolved.did);
+        assert_eq!(state.last_operation_hash, resolved.last_operation_hash);
+        assert_eq!(state.services.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn get_resolution_proof_marks_prism_object_unavailable_when_not_stored() {
+        let (service, db) = setup_service().await;
+        let (create_op, _) = new_create_did_operation();
+        db.insert_raw_operations(vec![(dummy_metadata(0), create_op)])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+        let canonical_str = service.get_all_dids(None).await.unwrap().items[0].to_string();
+
+        let proof = service.get_resolution_proof(&canonical_str).await.unwrap();
+        assert_eq!(proof.transactions.len(), 1);
+        assert!(proof.transactions[0].prism_object.is_none());
+        assert!(matches!(
+            proof.verify(),
+            Err(proof::Error::PrismObjectUnavailable { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_resolution_proof_not_found() {
+        let (service, _) = setup_service().await;
+        let did_str = "did:prism:abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890";
+        let err = service.get_resolution_proof(did_str).await.unwrap_err();
+        assert!(
+            matches!(err, ResolutionError::NotFound),
+            "expected NotFound, got: {err:?}"
+        );
+    }
+
     // --- get_all_dids ---
 
     #[tokio::test]
@@ -619,6 +1421,369 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn did_resolver_trait_resolve_includes_operation_keys_when_requested() {
+        let (service, db) = setup_service().await;
+
+        let (signed_op, _) = new_create_did_operation();
+        db.insert_raw_operations(vec![(dummy_metadata(0), signed_op)])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+
+        let all_dids = service.get_all_dids(None).await.unwrap();
+        let did: Did = all_dids.items[0].to_string().parse().unwrap();
+
+        let result = service.resolve(&did, &ResolutionOptions::default()).await;
+        assert!(result.did_document_metadata.operation_keys.is_none());
+
+        let options = ResolutionOptions {
+            include_operation_keys: Some(true),
+            ..Default::default()
+        };
+        let result = service.resolve(&did, &options).await;
+        let operation_keys = result.did_document_metadata.operation_keys.unwrap();
+        assert_eq!(operation_keys.len(), 1);
+        assert_eq!(operation_keys[0].id, format!("{did}#{MASTER_KEY_NAME}"));
+        assert_eq!(operation_keys[0].purpose, "master");
+    }
+
+    #[tokio::test]
+    async fn did_resolver_endpoint_includes_operation_keys_when_requested() {
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode, header};
+        use identus_did_resolver_http::{DidResolverStateDyn, did_resolver_http_binding};
+        use tower::ServiceExt;
+
+        let (service, db) = setup_service().await;
+        let (signed_op, _) = new_create_did_operation();
+        db.insert_raw_operations(vec![(dummy_metadata(0), signed_op)])
+            .await
+            .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+        let did = service.get_all_dids(None).await.unwrap().items[0].to_string();
+
+        let app = did_resolver_http_binding(crate::http::urls::ApiDid::AXUM_PATH, Default::default())
+            .router
+            .with_state(DidResolverStateDyn {
+                resolver: Arc::new(service),
+                cache_policy: None,
+            });
+        let request = Request::builder()
+            .uri(format!("/api/dids/{did}?includeOperationKeys=true"))
+            .header(header::ACCEPT, "application/did-resolution")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let operation_keys = &json["didDocumentMetadata"]["operationKeys"];
+        assert_eq!(operation_keys.as_array().unwrap().len(), 1);
+        assert_eq!(operation_keys[0]["id"], format!("{did}#{MASTER_KEY_NAME}"));
+        assert_eq!(operation_keys[0]["purpose"], "master");
+    }
+
+    #[tokio::test]
+    async fn verification_method_encoding_prefers_resolution_options() {
+        let (service, _) = setup_service().await;
+        let service = service.with_verification_method_encoding(VerificationMethodEncoding::KeyType);
+
+        let encoding = service.verification_method_encoding(&ResolutionOptions::default());
+        assert_eq!(encoding, VerificationMethodEncoding::KeyType);
+
+        let options = ResolutionOptions {
+            verification_method_encoding: Some(VerificationMethodEncoding::Multikey),
+            ..Default::default()
+        };
+        assert_eq!(
+            service.verification_method_encoding(&options),
+            VerificationMethodEncoding::Multikey
+        );
+    }
+
+    // --- resolution cache ---
+
+    #[tokio::test]
+    async fn cached_state_is_invalidated_by_indexed_operations() {
+        let db = setup_db().await;
+        let cache = DidStateCache::new(&ResolutionCacheArgs {
+            resolution_cache_size: 10,
+            resolution_cache_ttl: Duration::from_secs(60),
+        });
+        let service = PrismDidService::new(db.clone(), cache.clone());
+        let (indexed_did_tx, indexed_did_rx) = broadcast::channel(16);
+        let sk = master_sk();
+
+        let (create_op, create_hash) = new_create_did_operation();
+        db.insert_raw_operations(vec![(dummy_metadata(0), create_op)])
+            .await
+            .unwrap();
+        run_indexer_loop_with_notifier(db.as_ref(), &indexed_did_tx)
+            .await
+            .unwrap();
+        let did = service.get_all_dids(None).await.unwrap().items[0].clone();
+        let results = service.resolve_dids(&[did.to_string()]).await.unwrap();
+        assert!(results[0].as_ref().unwrap().1.services.is_empty());
+
+        let did_suffix_hex = HexStr::from(did.suffix().as_bytes().to_owned());
+        let update_op = new_update_did_operation(&did_suffix_hex.to_string(), MASTER_KEY_NAME, &sk, &create_hash);
+        db.insert_raw_operations(vec![(dummy_metadata(1), update_op)])
+            .await
+            .unwrap();
+        run_indexer_loop_with_notifier(db.as_ref(), &indexed_did_tx)
+            .await
+            .unwrap();
+
+        // Until the notifications are consumed, the cached state is served,
+        // while resolution with debug information always reads from storage.
+        let results = service.resolve_dids(&[did.to_string()]).await.unwrap();
+        assert!(results[0].as_ref().unwrap().1.services.is_empty());
+        let (result, _) = service.resolve_did(&did.to_string()).await;
+        assert_eq!(result.unwrap().1.services.len(), 1);
+
+        drop(indexed_did_tx);
+        cache.unwrap().run_invalidation(indexed_did_rx).await.unwrap();
+        let results = service.resolve_dids(&[did.to_string()]).await.unwrap();
+        assert_eq!(results[0].as_ref().unwrap().1.services.len(), 1);
+    }
+
+    // --- resolve_vdr ---
+
+    async fn setup_vdr_entry(
+        data: proto::prism_storage::proto_create_storage_entry::Data,
+    ) -> (PrismDidService, String) {
+        let (service, db) = setup_service().await;
+        let (create_did_op, did) = new_create_did_with_vdr_key_operation();
+        let (create_storage_op, entry_hash) = new_create_storage_entry_operation(&did, data);
+        db.insert_raw_operations(vec![
+            (dummy_metadata(0), create_did_op),
+            (dummy_metadata(1), create_storage_op),
+        ])
+        .await
+        .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+        (service, HexStr::from(entry_hash.to_vec()).to_string())
+    }
+
+    #[tokio::test]
+    async fn resolve_vdr_returns_bytes_data() {
+        let (service, entry_hash) =
+            setup_vdr_entry(proto::prism_storage::proto_create_storage_entry::Data::Bytes(vec![
+                1, 2, 3,
+            ]))
+            .await;
+
+        let data = service.resolve_vdr(&entry_hash).await.unwrap();
+        assert_eq!(data, Some(StorageData::Bytes(vec![1, 2, 3])));
+
+        let history = service.get_vdr_entry_history(&entry_hash).await.unwrap().unwrap();
+        assert_eq!(HexStr::from(history.entry_hash.to_vec()).to_string(), entry_hash);
+        assert_eq!(history.latest().event_hash, history.entry_hash);
+        assert_eq!(history.latest_data(), Some(&StorageData::Bytes(vec![1, 2, 3])));
+
+        let metadata = service.resolve_vdr_entry_metadata(&entry_hash).await.unwrap().unwrap();
+        assert_eq!(metadata.storage_type, Some(StorageType::Bytes));
+    }
+
+    #[tokio::test]
+    async fn resolve_vdr_returns_ipfs_data() {
+        let cid = "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku";
+        let (service, entry_hash) = setup_vdr_entry(proto::prism_storage::proto_create_storage_entry::Data::Ipfs(
+            cid.to_string(),
+        ))
+        .await;
+
+        let data = service.resolve_vdr(&entry_hash).await.unwrap();
+        assert_eq!(data, Some(StorageData::Ipfs(cid.to_string())));
+
+        let metadata = service.resolve_vdr_entry_metadata(&entry_hash).await.unwrap().unwrap();
+        assert_eq!(metadata.storage_type, Some(StorageType::Ipfs));
+    }
+
+    #[tokio::test]
+    async fn resolve_vdr_returns_status_list_data() {
+        let (service, entry_hash) =
+            setup_vdr_entry(proto::prism_storage::proto_create_storage_entry::Data::StatusListEntry(
+                proto::prism_storage::StatusListEntry {
+                    state: 1,
+                    name: "revocation".to_string(),
+                    details: "list-0".to_string(),
+                    special_fields: Default::default(),
+                },
+            ))
+            .await;
+
+        let data = service.resolve_vdr(&entry_hash).await.unwrap();
+        let Some(StorageData::StatusList(status_list)) = data else {
+            panic!("expected status list data, got {data:?}");
+        };
+        assert_eq!(status_list.state, 1);
+        assert_eq!(status_list.name, "revocation");
+        assert_eq!(status_list.detail, "list-0");
+
+        let metadata = service.resolve_vdr_entry_metadata(&entry_hash).await.unwrap().unwrap();
+        assert_eq!(metadata.storage_type, Some(StorageType::StatusList));
+    }
+
+    #[tokio::test]
+    async fn resolve_vdr_returns_none_when_not_found() {
+        let (service, _) = setup_service().await;
+        let entry_hash = HexStr::from(sha256([0u8; 32]).to_vec()).to_string();
+        assert!(service.resolve_vdr(&entry_hash).await.unwrap().is_none());
+    }
+
+    // --- get_vdr_entry_history ---
+
+    #[tokio::test]
+    async fn get_vdr_entry_history_lists_versions_until_deactivation() {
+        let (service, db) = setup_service().await;
+        let (create_did_op, did) = new_create_did_with_vdr_key_operation();
+        let (create_op, entry_hash) = new_create_storage_entry_operation(
+            &did,
+            proto::prism_storage::proto_create_storage_entry::Data::Bytes(vec![1]),
+        );
+        let (update_op, update_hash) = new_update_storage_entry_operation(
+            &entry_hash,
+            proto::prism_storage::proto_update_storage_entry::Data::Bytes(vec![2]),
+        );
+        // Rejected: the previous event hash is not the latest one
+        let (stale_update_op, _) = new_update_storage_entry_operation(
+            &entry_hash,
+            proto::prism_storage::proto_update_storage_entry::Data::Bytes(vec![3]),
+        );
+        let (deactivate_op, deactivate_hash) = new_deactivate_storage_entry_operation(&update_hash);
+        let operations = [create_did_op, create_op, update_op, stale_update_op, deactivate_op]
+            .into_iter()
+            .enumerate()
+            .map(|(osn, op)| (dummy_metadata(osn as u32), op))
+            .collect();
+        db.insert_raw_operations(operations).await.unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+        let entry_hash_hex = HexStr::from(entry_hash.to_vec()).to_string();
+
+        let history = service.get_vdr_entry_history(&entry_hash_hex).await.unwrap().unwrap();
+        assert_eq!(history.owner, did);
+        assert!(history.is_deactivated());
+        let versions = history
+            .versions
+            .iter()
+            .map(|i| (i.operation_type, i.event_hash.clone(), i.data.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            versions,
+            vec![
+                (
+                    OperationType::CreateStorageEntry,
+                    entry_hash.clone(),
+                    Some(StorageData::Bytes(vec![1]))
+                ),
+                (
+                    OperationType::UpdateStorageEntry,
+                    update_hash.clone(),
+                    Some(StorageData::Bytes(vec![2]))
+                ),
+                (OperationType::DeactivateStorageEntry, deactivate_hash.clone(), None),
+            ]
+        );
+        assert_eq!(history.version(&update_hash).unwrap().metadata.osn, 2);
+
+        assert!(service.resolve_vdr(&entry_hash_hex).await.unwrap().is_none());
+        let metadata = service
+            .resolve_vdr_entry_metadata(&entry_hash_hex)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.status, VdrEntryStatus::Deactivated);
+        assert_eq!(metadata.storage_type, Some(StorageType::Bytes));
+        assert_eq!(
+            metadata.latest_event_hash,
+            HexStr::from(deactivate_hash.to_vec()).to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_vdr_entry_history_reports_did_deactivation() {
+        let (service, db) = setup_service().await;
+        let (create_did_op, did) = new_create_did_with_vdr_key_operation();
+        let create_did_hash = create_did_op.operation_hash().unwrap();
+        let (create_op, entry_hash) = new_create_storage_entry_operation(
+            &did,
+            proto::prism_storage::proto_create_storage_entry::Data::Bytes(vec![1]),
+        );
+        let deactivate_did_op = new_deactivate_did_operation(
+            &did.suffix_hex().to_string(),
+            MASTER_KEY_NAME,
+            &master_sk(),
+            &create_did_hash,
+        );
+        db.insert_raw_operations(vec![
+            (dummy_metadata(0), create_did_op),
+            (dummy_metadata(1), create_op),
+            (dummy_metadata(2), deactivate_did_op),
+        ])
+        .await
+        .unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+
+        let entry_hash_hex = HexStr::from(entry_hash.to_vec()).to_string();
+        let history = service.get_vdr_entry_history(&entry_hash_hex).await.unwrap().unwrap();
+        assert_eq!(history.versions.len(), 2);
+        assert_eq!(history.latest().operation_type, OperationType::DeactivateDid);
+        assert!(history.is_deactivated());
+    }
+
+    #[tokio::test]
+    async fn get_vdr_entry_history_returns_none_when_not_found() {
+        let (service, _) = setup_service().await;
+        let entry_hash = HexStr::from(sha256([0u8; 32]).to_vec()).to_string();
+        assert!(service.get_vdr_entry_history(&entry_hash).await.unwrap().is_none());
+    }
+
+    // --- get_did_vdr_entries ---
+
+    #[tokio::test]
+    async fn get_did_vdr_entries_lists_active_and_deactivated_entries() {
+        let (service, db) = setup_service().await;
+        let (create_did_op, did) = new_create_did_with_vdr_key_operation();
+        let (create_bytes_op, bytes_hash) = new_create_storage_entry_operation(
+            &did,
+            proto::prism_storage::proto_create_storage_entry::Data::Bytes(vec![1, 2]),
+        );
+        let (create_ipfs_op, ipfs_hash) = new_create_storage_entry_operation(
+            &did,
+            proto::prism_storage::proto_create_storage_entry::Data::Ipfs(
+                "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku".to_string(),
+            ),
+        );
+        let (deactivate_ipfs_op, _) = new_deactivate_storage_entry_operation(&ipfs_hash);
+        let operations = [create_did_op, create_bytes_op, create_ipfs_op, deactivate_ipfs_op]
+            .into_iter()
+            .enumerate()
+            .map(|(osn, op)| (dummy_metadata(osn as u32), op))
+            .collect();
+        db.insert_raw_operations(operations).await.unwrap();
+        run_indexer_loop(db.as_ref()).await.unwrap();
+
+        let entries = service.get_did_vdr_entries(&did.to_string()).await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].entry_hash, bytes_hash);
+        assert!(!entries[0].is_deactivated());
+        assert_eq!(entries[0].latest_data(), Some(&StorageData::Bytes(vec![1, 2])));
+        assert_eq!(entries[1].entry_hash, ipfs_hash);
+        assert!(entries[1].is_deactivated());
+        assert_eq!(entries[1].latest_data().map(StorageType::of), Some(StorageType::Ipfs));
+    }
+
+    #[tokio::test]
+    async fn get_did_vdr_entries_not_found() {
+        let (service, _) = setup_service().await;
+        let did = "did:prism:abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890";
+        let result = service.get_did_vdr_entries(did).await;
+        assert!(matches!(result, Err(ResolutionError::NotFound)));
+    }
+
     // --- VdrEntryMetadata debug/clone ---
 
     #[test]
@@ -626,14 +1791,16 @@ mod tests {
         let meta = VdrEntryMetadata {
             entry_hash: "abc".to_string(),
             latest_event_hash: "def".to_string(),
-            status: "active".to_string(),
+            status: VdrEntryStatus::Active,
+            storage_type: Some(StorageType::Bytes),
         };
         let cloned = meta.clone();
         assert_eq!(meta.entry_hash, cloned.entry_hash);
         assert_eq!(meta.latest_event_hash, cloned.latest_event_hash);
         assert_eq!(meta.status, cloned.status);
+        assert_eq!(meta.storage_type, cloned.storage_type);
         let debug_str = format!("{:?}", meta);
         assert!(debug_str.contains("abc"));
-        assert!(debug_str.contains("active"));
+        assert!(debug_str.contains("Active"));
     }
 }
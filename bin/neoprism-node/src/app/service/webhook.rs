@@ -0,0 +1,619 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use identus_apollo::hash::{Sha256Digest, hmac_sha256, sha256};
+use identus_apollo::hex::HexStr;
+use identus_did_prism::did::operation::OperationId;
+use identus_did_prism::did::{PrismDid, PrismDidOps};
+use identus_did_prism::dlt::{BlockNo, SlotNo, TxId};
+use lazybe::uuid::Uuid;
+use node_storage::StorageBackend;
+use node_storage::webhook::{
+    NewWebhookDelivery, NewWebhookSubscription, WebhookDelivery, WebhookFilter, WebhookRepo, WebhookSubscription,
+};
+use serde::Serialize;
+
+use crate::app::service::error::WebhookError;
+use crate::app::service::{DidEvent, DidEventFeed, DidEventFilter, OperationType};
+use crate::cli::WebhookArgs;
+
+pub const DELIVERY_ID_HEADER: &str = "x-neoprism-delivery";
+pub const TIMESTAMP_HEADER: &str = "x-neoprism-timestamp";
+pub const SIGNATURE_HEADER: &str = "x-neoprism-signature";
+
+const DELIVERY_BATCH_SIZE: u32 = 100;
+const DELIVERY_CONCURRENCY: usize = 16;
+const DELIVERY_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(3600);
+
+/// JSON body posted to a webhook subscription for each matching operation.
+#[derive(Debug, Clone, Serialize)]
+struct WebhookPayload {
+    subscription_id: Uuid,
+    did: String,
+    operation_type: OperationType,
+    operation_id: OperationId,
+    tx_id: TxId,
+    slot_number: SlotNo,
+    block_number: BlockNo,
+    block_timestamp: DateTime<Utc>,
+    absn: u32,
+    osn: u32,
+    accepted: bool,
+    vdr_entry_hash: Option<String>,
+}
+
+impl WebhookPayload {
+    fn new(subscription_id: Uuid, event: &DidEvent) -> Self {
+        let block_metadata = &event.metadata.block_metadata;
+        Self {
+            subscription_id,
+            did: event.did.to_string(),
+            operation_type: event.operation_type,
+            operation_id: event.operation_id.clone(),
+            tx_id: block_metadata.tx_id.clone(),
+            slot_number: block_metadata.slot_number,
+            block_number: block_metadata.block_number,
+            block_timestamp: block_metadata.cbt,
+            absn: block_metadata.absn,
+            osn: event.metadata.osn,
+            accepted: event.accepted,
+            vdr_entry_hash: event.vdr_entry_hash.as_ref().map(to_hex),
+        }
+    }
+}
+
+/// Manages webhook subscriptions and delivers the events of indexed operations to them.
+///
+/// Deliveries are persisted before being sent, so they survive restarts and are retried
+/// with exponential backoff until they succeed or exhaust their attempts.
+///
+/// Unless private targets are allowed, subscriptions cannot target loopback, link-local or private
+/// network addresses, neither when they are created nor when their host is resolved for a delivery.
+#[derive(Clone)]
+pub struct WebhookService {
+    db: Arc<dyn StorageBackend>,
+    client: reqwest::Client,
+    max_attempts: u32,
+    retry_backoff: Duration,
+    admin_token: Option<String>,
+    allow_private_targets: bool,
+}
+
+impl WebhookService {
+    /// Create the service, or return `None` if webhooks are disabled.
+    pub fn new(db: Arc<dyn StorageBackend>, args: &WebhookArgs) -> Option<Self> {
+        if !args.webhook_enabled {
+            return None;
+        }
+        let mut client = reqwest::Client::builder()
+            .timeout(args.webhook_timeout)
+            .redirect(reqwest::redirect::Policy::none());
+        if !args.webhook_allow_private_targets {
+            client = client.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+        let client = client.build().expect("webhook HTTP client must be built");
+        Some(Self {
+            db,
+            client,
+            max_attempts: args.webhook_max_attempts.max(1),
+            retry_backoff: args.webhook_retry_backoff,
+            admin_token: args.webhook_admin_token.clone().filter(|i| !i.is_empty()),
+            allow_private_targets: args.webhook_allow_private_targets,
+        })
+    }
+
+    /// Check the bearer token of a request to the admin API.
+    pub fn authorize(&self, token: Option<&str>) -> Result<(), WebhookError> {
+        let Some(admin_token) = &self.admin_token else {
+            Err(WebhookError::AdminApiDisabled)?
+        };
+        // Comparing digests keeps the comparison time independent of the length of the matching prefix
+        match token {
+            Some(token) if sha256(token) == sha256(admin_token) => Ok(()),
+            _ => Err(WebhookError::Unauthorized),
+        }
+    }
+
+    /// Create a subscription. A random secret is generated if none is given.
+    pub async fn create_subscription(
+        &self,
+        url: String,
+        secret: Option<String>,
+        filter: WebhookFilter,
+    ) -> Result<WebhookSubscription, WebhookError> {
+        let parsed_url = reqwest::Url::parse(&url).map_err(|e| WebhookError::InvalidSubscription {
+            message: format!("invalid url: {e}"),
+        })?;
+        if !matches!(parsed_url.scheme(), "http" | "https") {
+            return Err(WebhookError::InvalidSubscription {
+                message: "url must use http or https".to_string(),
+            });
+        }
+        self.check_target(&parsed_url).await?;
+        let secret = match secret {
+            Some(secret) if secret.is_empty() => Err(WebhookError::InvalidSubscription {
+                message: "secret must not be empty".to_string(),
+            })?,
+            Some(secret) => secret,
+            None => generate_secret(),
+        };
+        let subscription = NewWebhookSubscription {
+            url,
+            secret,
+            filter: normalize_filter(filter)?,
+        };
+        let subscription = self
+            .db
+            .insert_webhook_subscription(subscription)
+            .await
+            .map_err(anyhow::Error::from)?;
+        Ok(subscription)
+    }
+
+    pub async fn get_subscriptions(&self) -> anyhow::Result<Vec<WebhookSubscription>> {
+        Ok(self.db.get_webhook_subscriptions().await?)
+    }
+
+    pub async fn get_subscription(&self, id: &Uuid) -> Result<WebhookSubscription, WebhookError> {
+        self.db
+            .get_webhook_subscription(id)
+            .await
+            .map_err(anyhow::Error::from)?
+            .ok_or(WebhookError::NotFound)
+    }
+
+    pub async fn delete_subscription(&self, id: &Uuid) -> Result<(), WebhookError> {
+        let deleted = self
+            .db
+            .delete_webhook_subscription(id)
+            .await
+            .map_err(anyhow::Error::from)?;
+        if deleted { Ok(()) } else { Err(WebhookError::NotFound) }
+    }
+
+    /// Deliveries of a subscription that exhausted their attempts.
+    pub async fn get_dead_letters(&self, subscription_id: &Uuid) -> Result<Vec<WebhookDelivery>, WebhookError> {
+        self.get_subscription(subscription_id).await?;
+        let deliveries = self
+            .db
+            .get_webhook_deliveries_dead(subscription_id)
+            .await
+            .map_err(anyhow::Error::from)?;
+        Ok(deliveries)
+    }
+
+    /// Schedule a dead-lettered delivery of a subscription for immediate redelivery.
+    pub async fn retry_dead_letter(&self, subscription_id: &Uuid, delivery_id: &Uuid) -> Result<(), WebhookError> {
+        let dead_letters = self.get_dead_letters(subscription_id).await?;
+        if !dead_letters.iter().any(|i| i.id == *delivery_id) {
+            return Err(WebhookError::NotFound);
+        }
+        self.db
+            .retry_webhook_delivery(delivery_id, Utc::now())
+            .await
+            .map_err(anyhow::Error::from)?;
+        Ok(())
+    }
+
+    /// Persist a delivery for each subscription matching the events streamed by `feed`.
+    ///
+    /// The position of the last dispatched event is persisted, so after a restart the dispatch
+    /// resumes with the operations indexed while the node was down.
+    pub async fn run_dispatch(self, feed: DidEventFeed) -> anyhow::Result<()> {
+        let mut cursor = self.db.get_webhook_dispatch_cursor().await?;
+        loop {
+            let mut events = std::pin::pin!(feed.stream(cursor, DidEventFilter::default()));
+            while let Some(event) = events.next().await {
+                cursor = Some(event.position());
+                if let Err(e) = self.enqueue_deliveries(&event).await {
+                    tracing::error!("{:?}", e);
+                }
+            }
+            tracing::warn!("Webhook dispatch fell behind the DID event feed, resubscribing");
+            tokio::time::sleep(DELIVERY_POLL_INTERVAL).await;
+        }
+    }
+
+    async fn enqueue_deliveries(&self, event: &DidEvent) -> anyhow::Result<()> {
+        let now = Utc::now();
+        let deliveries = self
+            .db
+            .get_webhook_subscriptions()
+            .await?
+            .into_iter()
+            .filter(|subscription| filter_matches(&subscription.filter, event))
+            .map(|subscription| {
+                let payload = serde_json::to_string(&WebhookPayload::new(subscription.id, event))?;
+                Ok(NewWebhookDelivery {
+                    subscription_id: subscription.id,
+                    payload,
+                    next_attempt_at: now,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if !deliveries.is_empty() {
+            self.db.insert_webhook_deliveries(deliveries).await?;
+        }
+        self.db.set_webhook_dispatch_cursor(&event.position()).await?;
+        Ok(())
+    }
+
+    /// Send the deliveries that are due, until the task is aborted.
+    pub async fn run_delivery(self) -> anyhow::Result<()> {
+        loop {
+            match self.deliver_due().await {
+                Ok(count) if count == DELIVERY_BATCH_SIZE as usize => continue,
+                Ok(_) => {}
+                Err(e) => tracing::error!("{:?}", e),
+            }
+            tokio::time::sleep(DELIVERY_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Send one batch of due deliveries concurrently and return the size of the batch.
+    async fn deliver_due(&self) -> anyhow::Result<usize> {
+        let deliveries = self
+            .db
+            .get_webhook_deliveries_due(Utc::now(), DELIVERY_BATCH_SIZE)
+            .await?;
+        let subscriptions = self
+            .db
+            .get_webhook_subscriptions()
+            .await?
+            .into_iter()
+            .map(|i| (i.id, i))
+            .collect::<HashMap<_, _>>();
+
+        let sends = deliveries.iter().filter_map(|delivery| {
+            // The subscription was deleted along with its deliveries after the batch was read
+            let subscription = subscriptions.get(&delivery.subscription_id)?;
+            Some(self.deliver(subscription, delivery))
+        });
+        let results = futures::stream::iter(sends)
+            .buffer_unordered(DELIVERY_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await;
+        results.into_iter().collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(deliveries.len())
+    }
+
+    /// Send a delivery and record its outcome.
+    async fn deliver(&self, subscription: &WebhookSubscription, delivery: &WebhookDelivery) -> anyhow::Result<()> {
+        match self.send(subscription, delivery).await {
+            Ok(()) => self.db.delete_webhook_delivery(&delivery.id).await?,
+            Err(e) => {
+                let attempts = delivery.attempts + 1;
+                let next_attempt_at =
+                    (attempts < self.max_attempts).then(|| Utc::now() + retry_delay(self.retry_backoff, attempts));
+                if next_attempt_at.is_none() {
+                    tracing::warn!(
+                        "Webhook delivery {} to {} moved to the dead-letter list after {} attempts",
+                        delivery.id,
+                        subscription.url,
+                        attempts
+                    );
+                }
+                self.db
+                    .update_webhook_delivery_failed(&delivery.id, format!("{e:#}"), next_attempt_at)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn send(&self, subscription: &WebhookSubscription, delivery: &WebhookDelivery) -> anyhow::Result<()> {
+        // Hosts are resolved by the client, but URLs with an IP address are not
+        self.check_target(&reqwest::Url::parse(&subscription.url)?).await?;
+        let timestamp = Utc::now().timestamp();
+        let signature = sign_payload(&subscription.secret, timestamp, &delivery.payload);
+        let response = self
+            .client
+            .post(&subscription.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(DELIVERY_ID_HEADER, delivery.id.to_string())
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(SIGNATURE_HEADER, format!("sha256={signature}"))
+            .body(delivery.payload.clone())
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("webhook endpoint responded with {status}");
+        }
+        Ok(())
+    }
+
+    /// Reject URLs whose host has a non-public address, unless private targets are allowed.
+    async fn check_target(&self, url: &reqwest::Url) -> Result<(), WebhookError> {
+        if self.allow_private_targets {
+            return Ok(());
+        }
+        let invalid = |message: String| WebhookError::InvalidSubscription { message };
+        let host = url
+            .host_str()
+            .ok_or_else(|| invalid("url must have a host".to_string()))?
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        let port = url.port_or_known_default().unwrap_or_default();
+        let addresses = tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|e| invalid(format!("cannot resolve host {host}: {e}")))?;
+        for address in addresses {
+            if !is_public_address(address.ip()) {
+                return Err(invalid(format!(
+                    "url must not target the non-public address {}",
+                    address.ip()
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Resolves the hosts of webhook URLs to their public addresses only,
+/// so a host cannot be pointed to a private address once its subscription is validated.
+struct PublicAddressResolver;
+
+impl reqwest::dns::Resolve for PublicAddressResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        Box::pin(async move {
+            let addresses = match tokio::net::lookup_host((name.as_str(), 0)).await {
+                Ok(addresses) => addresses.filter(|i| is_public_address(i.ip())).collect::<Vec<_>>(),
+                Err(e) => return Err(Box::new(e) as Box<dyn std::error::Error + Send + Sync>),
+            };
+            if addresses.is_empty() {
+                let message = format!("{} does not resolve to a public address", name.as_str());
+                return Err(message.into());
+            }
+            Ok(Box::new(addresses.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// Whether an address is globally reachable, i.e. not loopback, link-local, private or otherwise reserved.
+fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            !(ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                // "this network", shared address space, benchmarking and reserved ranges
+                || a == 0
+                || (a == 100 && (b & 0xc0) == 64)
+                || (a == 198 && (b & 0xfe) == 18)
+                || a >= 240)
+        }
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_public_address(ip.into());
+            }
+            let first_segment = ip.segments()[0];
+            // Unique local and link-local ranges
+            !(ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_multicast()
+                || (first_segment & 0xfe00) == 0xfc00
+                || (first_segment & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+/// Sign a payload as the hex-encoded HMAC-SHA256 of `{timestamp}.{payload}`.
+/// Including the timestamp lets receivers reject replayed deliveries.
+fn sign_payload(secret: &str, timestamp: i64, payload: &str) -> String {
+    to_hex(&hmac_sha256(secret, format!("{timestamp}.{payload}")))
+}
+
+/// Delay before the next attempt of a delivery that failed `attempts` times.
+fn retry_delay(backoff: Duration, attempts: u32) -> Duration {
+    let factor = 2u32.checked_pow(attempts.saturating_sub(1)).unwrap_or(u32::MAX);
+    backoff.saturating_mul(factor).min(MAX_RETRY_BACKOFF)
+}
+
+fn generate_secret() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+fn to_hex(digest: &Sha256Digest) -> String {
+    HexStr::from(digest.as_bytes()).to_string()
+}
+
+/// Validate the filter and convert its values to the form compared against events.
+fn normalize_filter(filter: WebhookFilter) -> Result<WebhookFilter, WebhookError> {
+    let invalid = |message: String| WebhookError::InvalidSubscription { message };
+    let dids = filter
+        .dids
+        .iter()
+        .map(|did| {
+            PrismDid::from_str(did)
+                .map(|did| did.into_canonical().to_string())
+                .map_err(|e| invalid(format!("invalid did {did}: {e}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let vdr_entry_hashes = filter
+        .vdr_entry_hashes
+        .iter()
+        .map(|entry_hash| {
+            HexStr::from_str(entry_hash)
+                .ok()
+                .and_then(|hex| Sha256Digest::from_bytes(&hex.to_bytes()).ok())
+                .map(|digest| to_hex(&digest))
+                .ok_or_else(|| invalid(format!("invalid vdr entry hash {entry_hash}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let operation_types = filter
+        .operation_types
+        .iter()
+        .map(|operation_type| {
+            OperationType::from_str(operation_type)
+                .map(|i| i.to_string())
+                .map_err(invalid)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(WebhookFilter {
+        dids,
+        vdr_entry_hashes,
+        operation_types,
+    })
+}
+
+fn filter_matches(filter: &WebhookFilter, event: &DidEvent) -> bool {
+    let matches_did = filter.dids.is_empty() || filter.dids.contains(&event.did.to_string());
+    let matches_operation_type =
+        filter.operation_types.is_empty() || filter.operation_types.contains(&event.operation_type.to_string());
+    let matches_vdr_entry = filter.vdr_entry_hashes.is_empty()
+        || event
+            .vdr_entry_hash
+            .as_ref()
+            .is_some_and(|i| filter.vdr_entry_hashes.contains(&to_hex(i)));
+    matches_did && matches_operation_type && matches_vdr_entry
+}
+
+#[cfg(test)]
+mod tests {
+    use identus_apollo::hash::sha256;
+    use identus_did_prism::did::CanonicalPrismDid;
+    use identus_did_prism::dlt::{BlockMetadata, OperationMetadata};
+
+    use super::*;
+
+    fn did(seed: &[u8]) -> CanonicalPrismDid {
+        CanonicalPrismDid::from_suffix(HexStr::from(sha256(seed).to_vec())).unwrap()
+    }
+
+    fn event(did: CanonicalPrismDid, operation_type: OperationType, vdr_entry_hash: Option<Sha256Digest>) -> DidEvent {
+        DidEvent {
+            did,
+            operation_type,
+            operation_id: OperationId::from(sha256(b"operation")),
+            metadata: OperationMetadata {
+                block_metadata: BlockMetadata {
+                    slot_number: 1.into(),
+                    block_number: 1.into(),
+                    cbt: DateTime::UNIX_EPOCH,
+                    absn: 0,
+                    tx_id: TxId::from(sha256(b"tx")),
+                },
+                osn: 0,
+            },
+            accepted: true,
+            vdr_entry_hash,
+        }
+    }
+
+    #[test]
+    fn normalize_filter_canonicalizes_values() {
+        let entry_hash = sha256(b"entry");
+        let filter = WebhookFilter {
+            dids: vec![did(b"a").to_string()],
+            vdr_entry_hashes: vec![to_hex(&entry_hash).to_uppercase()],
+            operation_types: vec!["update_storage_entry".to_string()],
+        };
+        let normalized = normalize_filter(filter).unwrap();
+        assert_eq!(normalized.dids, vec![did(b"a").to_string()]);
+        assert_eq!(normalized.vdr_entry_hashes, vec![to_hex(&entry_hash)]);
+        assert_eq!(normalized.operation_types, vec!["update_storage_entry".to_string()]);
+    }
+
+    #[test]
+    fn normalize_filter_rejects_invalid_values() {
+        let filters = [
+            WebhookFilter {
+                dids: vec!["did:example:123".to_string()],
+                ..Default::default()
+            },
+            WebhookFilter {
+                vdr_entry_hashes: vec!["abcd".to_string()],
+                ..Default::default()
+            },
+            WebhookFilter {
+                operation_types: vec!["create".to_string()],
+                ..Default::default()
+            },
+        ];
+        for filter in filters {
+            assert!(matches!(
+                normalize_filter(filter),
+                Err(WebhookError::InvalidSubscription { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn filter_matches_all_criteria() {
+        let entry_hash = sha256(b"entry");
+        let filter = WebhookFilter {
+            dids: vec![did(b"a").to_string()],
+            vdr_entry_hashes: vec![to_hex(&entry_hash)],
+            operation_types: vec![],
+        };
+        assert!(filter_matches(
+            &filter,
+            &event(did(b"a"), OperationType::UpdateStorageEntry, Some(entry_hash.clone()))
+        ));
+        assert!(!filter_matches(
+            &filter,
+            &event(did(b"b"), OperationType::UpdateStorageEntry, Some(entry_hash))
+        ));
+        assert!(!filter_matches(
+            &filter,
+            &event(did(b"a"), OperationType::UpdateDid, None)
+        ));
+        assert!(filter_matches(
+            &WebhookFilter::default(),
+            &event(did(b"b"), OperationType::UpdateDid, None)
+        ));
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_maximum() {
+        let backoff = Duration::from_secs(10);
+        assert_eq!(retry_delay(backoff, 1), Duration::from_secs(10));
+        assert_eq!(retry_delay(backoff, 2), Duration::from_secs(20));
+        assert_eq!(retry_delay(backoff, 4), Duration::from_secs(80));
+        assert_eq!(retry_delay(backoff, 40), MAX_RETRY_BACKOFF);
+    }
+
+    #[test]
+    fn sign_payload_covers_timestamp_and_payload() {
+        let signature = sign_payload("secret", 1700000000, "{}");
+        assert_eq!(signature, to_hex(&hmac_sha256("secret", "1700000000.{}")));
+        assert_ne!(signature, sign_payload("secret", 1700000001, "{}"));
+        assert_ne!(signature, sign_payload("other", 1700000000, "{}"));
+    }
+
+    #[test]
+    fn is_public_address_rejects_non_public_ranges() {
+        let non_public = [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "255.255.255.255",
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ];
+        for ip in non_public {
+            assert!(!is_public_address(ip.parse().unwrap()), "{ip} must not be public");
+        }
+        for ip in ["1.1.1.1", "93.184.216.34", "2606:4700:4700::1111"] {
+            assert!(is_public_address(ip.parse().unwrap()), "{ip} must be public");
+        }
+    }
+}
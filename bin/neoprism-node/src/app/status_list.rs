@@ -0,0 +1,153 @@
+//! Rendering of VDR entries as [W3C Bitstring Status Lists](https://www.w3.org/TR/vc-bitstring-status-list/).
+
+use std::io::Write;
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use identus_apollo::base64::Base64UrlStrNoPad;
+use identus_did_prism::did::operation::StorageData;
+
+/// Minimum length of the bitstring in bits, so a single status cannot be correlated with its holder.
+pub const MIN_BITSTRING_LENGTH: usize = 131_072;
+
+const DEFAULT_STATUS_PURPOSE: &str = "revocation";
+
+/// Status purposes defined by the Bitstring Status List specification.
+pub const STATUS_PURPOSES: [&str; 4] = ["refresh", "revocation", "suspension", "message"];
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum StatusListError {
+    #[display("vdr entry is stored on ipfs and cannot be rendered as a status list")]
+    IpfsStorage,
+    #[display("status purpose {purpose} is not one of {}", STATUS_PURPOSES.join(", "))]
+    UnsupportedStatusPurpose { purpose: String },
+}
+
+/// The `BitstringStatusList` credential subject of a VDR entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitstringStatusList {
+    pub status_purpose: String,
+    /// The GZIP-compressed bitstring, multibase-encoded as base64url without padding.
+    pub encoded_list: String,
+}
+
+impl BitstringStatusList {
+    /// Render the data of a VDR entry as a status list.
+    ///
+    /// Bytes entries are the bitstring of a revocation list. Status list entries hold the status
+    /// of a single credential at index 0, set when the state is non-zero, and name the status purpose,
+    /// which must be one of [`STATUS_PURPOSES`]. Entries stored on IPFS cannot be rendered.
+    pub fn from_storage_data(data: &StorageData) -> Result<Self, StatusListError> {
+        let (status_purpose, bitstring) = match data {
+            StorageData::Bytes(bytes) => (DEFAULT_STATUS_PURPOSE.to_string(), bytes.clone()),
+            StorageData::StatusList(status_list) => {
+                let status_purpose = if status_list.name.is_empty() {
+                    DEFAULT_STATUS_PURPOSE.to_string()
+                } else if STATUS_PURPOSES.contains(&status_list.name.as_str()) {
+                    status_list.name.clone()
+                } else {
+                    return Err(StatusListError::UnsupportedStatusPurpose {
+                        purpose: status_list.name.clone(),
+                    });
+                };
+                let bitstring = if status_list.state != 0 { vec![0x80] } else { vec![0] };
+                (status_purpose, bitstring)
+            }
+            StorageData::Ipfs(_) => return Err(StatusListError::IpfsStorage),
+        };
+        Ok(Self {
+            status_purpose,
+            encoded_list: encode_bitstring(&bitstring),
+        })
+    }
+}
+
+/// Pad the bitstring to the minimum length, then GZIP-compress and multibase-encode it.
+pub fn encode_bitstring(bitstring: &[u8]) -> String {
+    let mut padded = bitstring.to_vec();
+    padded.resize(padded.len().max(MIN_BITSTRING_LENGTH / 8), 0);
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&padded).expect("writing to a vec must not fail");
+    let compressed = encoder.finish().expect("writing to a vec must not fail");
+    format!("u{}", Base64UrlStrNoPad::from(compressed))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::str::FromStr;
+
+    use flate2::read::GzDecoder;
+    use identus_did_prism::did::operation::StatusListData;
+
+    use super::*;
+
+    fn decode_bitstring(encoded_list: &str) -> Vec<u8> {
+        let compressed = Base64UrlStrNoPad::from_str(encoded_list.strip_prefix('u').unwrap())
+            .unwrap()
+            .to_bytes();
+        let mut bitstring = Vec::new();
+        GzDecoder::new(compressed.as_slice())
+            .read_to_end(&mut bitstring)
+            .unwrap();
+        bitstring
+    }
+
+    fn status_list_data(state: i64, name: &str) -> StorageData {
+        StorageData::StatusList(StatusListData {
+            state,
+            name: name.to_string(),
+            detail: String::new(),
+        })
+    }
+
+    #[test]
+    fn encode_bitstring_pads_to_minimum_length() {
+        let bitstring = decode_bitstring(&encode_bitstring(&[0b1010_0000]));
+        assert_eq!(bitstring.len(), MIN_BITSTRING_LENGTH / 8);
+        assert_eq!(bitstring[0], 0b1010_0000);
+        assert!(bitstring[1..].iter().all(|i| *i == 0));
+    }
+
+    #[test]
+    fn encode_bitstring_keeps_longer_bitstring() {
+        let bytes = vec![0xff; MIN_BITSTRING_LENGTH / 8 + 1];
+        assert_eq!(decode_bitstring(&encode_bitstring(&bytes)), bytes);
+    }
+
+    #[test]
+    fn bytes_entry_is_revocation_list() {
+        let status_list = BitstringStatusList::from_storage_data(&StorageData::Bytes(vec![0x01])).unwrap();
+        assert_eq!(status_list.status_purpose, "revocation");
+        assert_eq!(decode_bitstring(&status_list.encoded_list)[0], 0x01);
+    }
+
+    #[test]
+    fn status_list_entry_sets_first_bit_from_state() {
+        let status_list = BitstringStatusList::from_storage_data(&status_list_data(1, "suspension")).unwrap();
+        assert_eq!(status_list.status_purpose, "suspension");
+        assert_eq!(decode_bitstring(&status_list.encoded_list)[0], 0x80);
+
+        let status_list = BitstringStatusList::from_storage_data(&status_list_data(0, "")).unwrap();
+        assert_eq!(status_list.status_purpose, "revocation");
+        assert_eq!(decode_bitstring(&status_list.encoded_list)[0], 0x00);
+    }
+
+    #[test]
+    fn ipfs_entry_is_not_rendered() {
+        let data = StorageData::Ipfs("bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku".to_string());
+        assert!(matches!(
+            BitstringStatusList::from_storage_data(&data),
+            Err(StatusListError::IpfsStorage)
+        ));
+    }
+
+    #[test]
+    fn status_list_entry_rejects_unknown_status_purpose() {
+        let result = BitstringStatusList::from_storage_data(&status_list_data(1, "list-0"));
+        assert!(matches!(
+            result,
+            Err(StatusListError::UnsupportedStatusPurpose { purpose }) if purpose == "list-0"
+        ));
+    }
+}
@@ -1,10 +1,12 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use identus_did_prism::dlt::DltCursor;
-use identus_did_prism_indexer::{DltSource, run_indexer_loop, run_sync_loop};
+use chrono::{DateTime, Utc};
+use identus_did_prism::did::CanonicalPrismDid;
+use identus_did_prism::dlt::{DltChainTip, DltCursor};
+use identus_did_prism_indexer::{DltSource, run_indexer_loop_with_notifier, run_sync_loop};
 use node_storage::StorageBackend;
-use tokio::sync::watch;
+use tokio::sync::{broadcast, watch};
 
 type SharedStorage = Arc<dyn StorageBackend>;
 
@@ -25,6 +27,14 @@ where
         self.source.sync_cursor()
     }
 
+    pub fn chain_tip(&self) -> watch::Receiver<Option<DltChainTip>> {
+        self.source.chain_tip()
+    }
+
+    pub fn last_error(&self) -> watch::Receiver<Option<DateTime<Utc>>> {
+        self.source.last_error()
+    }
+
     pub async fn run(self) -> anyhow::Result<()> {
         run_sync_loop(self.store.as_ref(), self.source).await // block forever
     }
@@ -33,16 +43,25 @@ where
 pub struct DltIndexWorker {
     store: SharedStorage,
     index_interval: Duration,
+    indexed_did_tx: broadcast::Sender<CanonicalPrismDid>,
 }
 
 impl DltIndexWorker {
-    pub fn new(store: SharedStorage, index_interval: Duration) -> Self {
-        Self { store, index_interval }
+    pub fn new(
+        store: SharedStorage,
+        index_interval: Duration,
+        indexed_did_tx: broadcast::Sender<CanonicalPrismDid>,
+    ) -> Self {
+        Self {
+            store,
+            index_interval,
+            indexed_did_tx,
+        }
     }
 
     pub async fn run(self) -> anyhow::Result<()> {
         loop {
-            let result = run_indexer_loop(self.store.as_ref()).await;
+            let result = run_indexer_loop_with_notifier(self.store.as_ref(), &self.indexed_did_tx).await;
             if let Err(e) = result {
                 tracing::error!("{:?}", e);
             }
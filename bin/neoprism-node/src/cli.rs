@@ -2,7 +2,9 @@ use std::net::Ipv4Addr;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use axum::http::HeaderValue;
 use clap::{Args, Parser, Subcommand, ValueEnum};
+use identus_did_core::VerificationMethodEncoding;
 use identus_did_prism::dlt::NetworkIdentifier;
 
 #[derive(Parser)]
@@ -10,6 +12,8 @@ use identus_did_prism::dlt::NetworkIdentifier;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Command,
+    #[clap(flatten)]
+    pub telemetry: TelemetryArgs,
 }
 
 #[derive(Subcommand)]
@@ -22,6 +26,8 @@ pub enum Command {
     Standalone(StandaloneArgs),
     /// Start the node in standalone mode with an in-memory blockchain for development.
     Dev(DevArgs),
+    /// Start the node in proxy mode, resolving DIDs through upstream nodes without indexing the blockchain.
+    Proxy(ProxyArgs),
     /// Generate OpenAPI specification for the API.
     GenerateOpenapi(GenerateOpenApiArgs),
 }
@@ -34,6 +40,18 @@ pub struct IndexerArgs {
     pub db: DbArgs,
     #[clap(flatten)]
     pub dlt_source: DltSourceArgs,
+    #[clap(flatten)]
+    pub readiness: ReadinessArgs,
+    #[clap(flatten)]
+    pub cache_control: CacheControlArgs,
+    #[clap(flatten)]
+    pub resolution_cache: ResolutionCacheArgs,
+    #[clap(flatten)]
+    pub did_document: DidDocumentArgs,
+    #[clap(flatten)]
+    pub webhook: WebhookArgs,
+    #[clap(flatten)]
+    pub ipfs: IpfsArgs,
 }
 
 #[derive(Args)]
@@ -56,6 +74,18 @@ pub struct StandaloneArgs {
     pub dlt_source: DltSourceArgs,
     #[clap(flatten)]
     pub dlt_sink: DltSinkArgs,
+    #[clap(flatten)]
+    pub readiness: ReadinessArgs,
+    #[clap(flatten)]
+    pub cache_control: CacheControlArgs,
+    #[clap(flatten)]
+    pub resolution_cache: ResolutionCacheArgs,
+    #[clap(flatten)]
+    pub did_document: DidDocumentArgs,
+    #[clap(flatten)]
+    pub webhook: WebhookArgs,
+    #[clap(flatten)]
+    pub ipfs: IpfsArgs,
 }
 
 #[derive(Args)]
@@ -64,6 +94,30 @@ pub struct DevArgs {
     pub server: ServerArgs,
     #[clap(flatten)]
     pub db: DbArgs,
+    #[clap(flatten)]
+    pub readiness: ReadinessArgs,
+    #[clap(flatten)]
+    pub cache_control: CacheControlArgs,
+    #[clap(flatten)]
+    pub resolution_cache: ResolutionCacheArgs,
+    #[clap(flatten)]
+    pub did_document: DidDocumentArgs,
+    #[clap(flatten)]
+    pub webhook: WebhookArgs,
+    #[clap(flatten)]
+    pub ipfs: IpfsArgs,
+}
+
+#[derive(Args)]
+pub struct ProxyArgs {
+    #[clap(flatten)]
+    pub server: ServerArgs,
+    #[clap(flatten)]
+    pub upstream: UpstreamArgs,
+    #[clap(flatten)]
+    pub cache_control: CacheControlArgs,
+    #[clap(flatten)]
+    pub did_document: DidDocumentArgs,
 }
 
 #[derive(Args)]
@@ -88,7 +142,7 @@ pub struct ServerArgs {
     /// Enable permissive CORS (https://docs.rs/tower-http/latest/tower_http/cors/struct.CorsLayer.html#method.permissive)
     #[arg(long, env = "NPRISM_CORS_ENABLED")]
     pub cors_enabled: bool,
-    /// External URL for Swagger server list (e.g. https://example.com)
+    /// External URL of the node for the Swagger server list and status list credential ids (e.g. https://example.com)
     #[arg(long, env = "NPRISM_EXTERNAL_URL")]
     pub external_url: Option<String>,
 }
@@ -104,6 +158,166 @@ pub struct DbArgs {
     pub skip_migration: bool,
 }
 
+#[derive(Args)]
+#[command(next_help_heading = "Telemetry")]
+pub struct TelemetryArgs {
+    /// OTLP collector endpoint to export traces to (e.g. http://localhost:4317). Traces are not exported when omitted.
+    #[arg(long, global = true, env = "NPRISM_OTLP_ENDPOINT")]
+    pub otlp_endpoint: Option<String>,
+    /// Protocol used to export traces to the OTLP collector.
+    #[arg(
+        long,
+        global = true,
+        env = "NPRISM_OTLP_PROTOCOL",
+        value_enum,
+        default_value = "grpc"
+    )]
+    pub otlp_protocol: OtlpProtocol,
+    /// Service name reported in exported traces.
+    #[arg(long, global = true, env = "NPRISM_OTLP_SERVICE_NAME", default_value = "neoprism")]
+    pub otlp_service_name: String,
+}
+
+/// Transport protocol for OTLP export.
+#[derive(Clone, Debug, ValueEnum)]
+pub enum OtlpProtocol {
+    #[value(name = "grpc")]
+    Grpc,
+    #[value(name = "http")]
+    Http,
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "Readiness")]
+pub struct ReadinessArgs {
+    /// Maximum number of slots the sync cursor may lag behind the chain tip before the node is reported as not ready.
+    #[arg(long, env = "NPRISM_READINESS_MAX_LAG_SLOTS", default_value_t = 3600)]
+    pub readiness_max_lag_slots: u64,
+    /// Maximum number of seconds the block time of the sync cursor may lag behind the current time.
+    /// Only applies to DLT sources that do not observe the chain tip, such as Oura.
+    #[arg(long, env = "NPRISM_READINESS_MAX_LAG_SECONDS", default_value_t = 3600)]
+    pub readiness_max_lag_seconds: u64,
+    /// Duration after a DLT source error during which the node is reported as not ready.
+    #[arg(long, env = "NPRISM_READINESS_ERROR_WINDOW", default_value = "5m", value_parser = humantime::parse_duration)]
+    pub readiness_error_window: Duration,
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "Cache Control")]
+pub struct CacheControlArgs {
+    /// Cache-Control header of resolution responses for published DIDs (e.g. "public, max-age=60"). Omitted when not set.
+    #[arg(long, env = "NPRISM_CACHE_CONTROL_PUBLISHED", value_parser = parse_header_value)]
+    pub cache_control_published: Option<HeaderValue>,
+    /// Cache-Control header of resolution responses for unpublished long-form DIDs. Omitted when not set.
+    #[arg(long, env = "NPRISM_CACHE_CONTROL_UNPUBLISHED", value_parser = parse_header_value)]
+    pub cache_control_unpublished: Option<HeaderValue>,
+    /// Cache-Control header of resolution responses for deactivated DIDs. Omitted when not set.
+    #[arg(long, env = "NPRISM_CACHE_CONTROL_DEACTIVATED", value_parser = parse_header_value)]
+    pub cache_control_deactivated: Option<HeaderValue>,
+    /// Cache-Control header of status list credentials rendered from VDR entries. Omitted when not set.
+    #[arg(long, env = "NPRISM_CACHE_CONTROL_STATUS_LIST", value_parser = parse_header_value)]
+    pub cache_control_status_list: Option<HeaderValue>,
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "Resolution Cache")]
+pub struct ResolutionCacheArgs {
+    /// Maximum number of resolved DID states kept in memory. Set to 0 to disable the cache.
+    #[arg(long, env = "NPRISM_RESOLUTION_CACHE_SIZE", default_value_t = 10000)]
+    pub resolution_cache_size: u64,
+    /// Duration after which a cached DID state is evicted, even if the DID has not changed.
+    #[arg(long, env = "NPRISM_RESOLUTION_CACHE_TTL", default_value = "1h", value_parser = humantime::parse_duration)]
+    pub resolution_cache_ttl: Duration,
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "DID Document")]
+pub struct DidDocumentArgs {
+    /// Representation of verification methods in resolved DID documents, unless the resolution options request another one.
+    #[arg(long, env = "NPRISM_VERIFICATION_METHOD_ENCODING", value_enum, default_value = "jwk")]
+    pub verification_method_encoding: VerificationMethodEncodingCliOption,
+}
+
+/// Representation of verification methods in resolved DID documents.
+#[derive(Clone, Debug, ValueEnum)]
+pub enum VerificationMethodEncodingCliOption {
+    /// JsonWebKey2020 with publicKeyJwk.
+    #[value(name = "jwk")]
+    Jwk,
+    /// Multikey with publicKeyMultibase.
+    #[value(name = "multikey")]
+    Multikey,
+    /// EcdsaSecp256k1VerificationKey2019, Ed25519VerificationKey2020 or X25519KeyAgreementKey2020.
+    #[value(name = "key-type")]
+    KeyType,
+}
+
+impl From<VerificationMethodEncodingCliOption> for VerificationMethodEncoding {
+    fn from(value: VerificationMethodEncodingCliOption) -> Self {
+        match value {
+            VerificationMethodEncodingCliOption::Jwk => VerificationMethodEncoding::Jwk,
+            VerificationMethodEncodingCliOption::Multikey => VerificationMethodEncoding::Multikey,
+            VerificationMethodEncodingCliOption::KeyType => VerificationMethodEncoding::KeyType,
+        }
+    }
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "Webhook")]
+pub struct WebhookArgs {
+    /// Deliver indexed operations to webhook subscriptions and expose the webhook admin API.
+    #[arg(long, env = "NPRISM_WEBHOOK_ENABLED")]
+    pub webhook_enabled: bool,
+    /// Number of failed attempts after which a delivery is moved to the dead-letter list.
+    #[arg(long, env = "NPRISM_WEBHOOK_MAX_ATTEMPTS", default_value_t = 8)]
+    pub webhook_max_attempts: u32,
+    /// Delay before retrying a failed delivery, doubled after each attempt.
+    #[arg(long, env = "NPRISM_WEBHOOK_RETRY_BACKOFF", default_value = "10s", value_parser = humantime::parse_duration)]
+    pub webhook_retry_backoff: Duration,
+    /// Timeout of a single delivery request.
+    #[arg(long, env = "NPRISM_WEBHOOK_TIMEOUT", default_value = "10s", value_parser = humantime::parse_duration)]
+    pub webhook_timeout: Duration,
+    /// Bearer token required by the webhook admin API. The admin API is disabled if not set.
+    #[arg(long, env = "NPRISM_WEBHOOK_ADMIN_TOKEN")]
+    pub webhook_admin_token: Option<String>,
+    /// Allow webhook URLs targeting loopback, link-local or private network addresses, e.g. for local testing.
+    #[arg(long, env = "NPRISM_WEBHOOK_ALLOW_PRIVATE_TARGETS")]
+    pub webhook_allow_private_targets: bool,
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "IPFS")]
+pub struct IpfsArgs {
+    /// IPFS gateway used to fetch the content of VDR entries stored on IPFS, e.g. `https://ipfs.io`.
+    /// If not set, only the CID of such entries is returned.
+    #[arg(long, env = "NPRISM_IPFS_GATEWAY_URL")]
+    pub ipfs_gateway_url: Option<String>,
+    /// Timeout of a single request to the IPFS gateway.
+    #[arg(long, env = "NPRISM_IPFS_GATEWAY_TIMEOUT", default_value = "30s", value_parser = humantime::parse_duration)]
+    pub ipfs_gateway_timeout: Duration,
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "Upstream")]
+pub struct UpstreamArgs {
+    /// Base URLs of the upstream NeoPRISM nodes resolving PRISM DIDs, tried in order (e.g. https://neoprism.patlo.dev).
+    /// Their answers are verified by replaying the DID operations they serve.
+    #[arg(
+        long = "upstream-url",
+        env = "NPRISM_UPSTREAM_URLS",
+        value_delimiter = ',',
+        required = true
+    )]
+    pub upstream_urls: Vec<String>,
+    /// Timeout of a single request to an upstream node.
+    #[arg(long, env = "NPRISM_UPSTREAM_TIMEOUT", default_value = "10s", value_parser = humantime::parse_duration)]
+    pub upstream_timeout: Duration,
+}
+
+fn parse_header_value(value: &str) -> Result<HeaderValue, String> {
+    HeaderValue::from_str(value).map_err(|e| e.to_string())
+}
+
 #[derive(Args)]
 #[command(next_help_heading = "Network")]
 pub struct NetworkArgs {
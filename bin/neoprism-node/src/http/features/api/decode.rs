@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use axum::Json;
+use identus_apollo::hex::HexStr;
+use identus_did_prism::proto::MessageExt;
+use identus_did_prism::proto::json::ProtoJson;
+use identus_did_prism::proto::prism::{PrismObject, PrismOperation, SignedPrismOperation};
+use utoipa::OpenApi;
+
+use crate::http::features::api::decode::models::{DecodeRequest, ProtobufMessageType};
+use crate::http::features::api::error::{ApiError, ApiErrorResponseBody};
+use crate::http::features::api::tags;
+use crate::http::urls::ApiDecode;
+
+#[derive(OpenApi)]
+#[openapi(paths(decode_protobuf))]
+pub struct DecodeOpenApiDoc;
+
+mod models {
+    use serde::{Deserialize, Serialize};
+    use utoipa::ToSchema;
+
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ProtobufMessageType {
+        PrismObject,
+        SignedPrismOperation,
+        PrismOperation,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct DecodeRequest {
+        /// The protobuf message type of the data.
+        #[serde(rename = "type")]
+        pub message_type: ProtobufMessageType,
+        /// The hex-encoded protobuf message.
+        #[schema(
+            example = "0a086d61737465722d30124630440220442eec28ec60464acd8df155e73f88a1c7faf4549975582ff0601449525aba31022019257250071818066b377b83a8b1765df1b7dc21d9bccfc7d5da036801d3ba0e1a420a400a3e123c0a086d61737465722d3010014a2e0a09736563703235366b3112210398e61c14328a6a844eec6dc084b825ae8525f10204e9244aaf61260bd221a457"
+        )]
+        pub data: String,
+    }
+}
+
+#[utoipa::path(
+    post,
+    summary = "Decode protobuf message",
+    description = "Decodes a hex-encoded PrismObject, SignedPrismOperation or PrismOperation protobuf message to JSON. Bytes are rendered as hex strings, public keys are additionally decoded to JWK, and the operation hash and operation id are included.",
+    path = ApiDecode::AXUM_PATH,
+    tags = [tags::SYSTEM],
+    request_body = DecodeRequest,
+    responses(
+        (status = OK, description = "The decoded message.", body = Object),
+        (status = BAD_REQUEST, description = "The data is not valid hex or cannot be decoded to the message type.", body = ApiErrorResponseBody, content_type = "application/json"),
+    )
+)]
+pub async fn decode_protobuf(Json(req): Json<DecodeRequest>) -> Result<Json<serde_json::Value>, ApiError> {
+    let bytes = HexStr::from_str(&req.data)
+        .map_err(|e| ApiError::BadRequest {
+            message: format!("data is not a valid hex: {e}"),
+        })?
+        .to_bytes();
+    let message_type = req.message_type;
+    let json = match message_type {
+        ProtobufMessageType::PrismObject => decode::<PrismObject>(&bytes, message_type)?.to_json(),
+        ProtobufMessageType::SignedPrismOperation => decode::<SignedPrismOperation>(&bytes, message_type)?.to_json(),
+        ProtobufMessageType::PrismOperation => decode::<PrismOperation>(&bytes, message_type)?.to_json(),
+    };
+    Ok(Json(json))
+}
+
+fn decode<T: MessageExt>(bytes: &[u8], message_type: ProtobufMessageType) -> Result<T, ApiError> {
+    T::decode(bytes).map_err(|e| ApiError::BadRequest {
+        message: format!("data cannot be decoded to {message_type:?}: {e}"),
+    })
+}
@@ -1,9 +1,11 @@
+use std::error::Report;
+
 use axum::Json;
 use axum::http::{StatusCode, header};
 use axum::response::{IntoResponse, Response};
 use serde::{Deserialize, Serialize};
 
-use crate::app::service::error::ResolutionError;
+use crate::app::service::error::{IpfsError, ResolutionError, WebhookError};
 
 #[derive(Debug, derive_more::Display, derive_more::Error)]
 pub enum ApiError {
@@ -11,8 +13,16 @@ pub enum ApiError {
     NotImplemented,
     #[display("not found")]
     NotFound,
+    #[display("unauthorized: {message}")]
+    Unauthorized { message: String },
+    #[display("gone: {message}")]
+    Gone { message: String },
     #[display("bad request: {message}")]
     BadRequest { message: String },
+    #[display("service unavailable: {message}")]
+    ServiceUnavailable { message: String },
+    #[display("bad gateway: {message}")]
+    BadGateway { message: String },
     #[display("internal server error")]
     Internal { source: anyhow::Error },
 }
@@ -27,7 +37,11 @@ impl IntoResponse for ApiError {
         let status = match self {
             ApiError::NotImplemented => StatusCode::NOT_IMPLEMENTED,
             ApiError::NotFound => StatusCode::NOT_FOUND,
+            ApiError::Unauthorized { .. } => StatusCode::UNAUTHORIZED,
+            ApiError::Gone { .. } => StatusCode::GONE,
             ApiError::BadRequest { .. } => StatusCode::BAD_REQUEST,
+            ApiError::ServiceUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::BadGateway { .. } => StatusCode::BAD_GATEWAY,
             ApiError::Internal { .. } => StatusCode::INTERNAL_SERVER_ERROR,
         };
         let body = Json(ApiErrorResponseBody {
@@ -53,3 +67,23 @@ impl From<ResolutionError> for ApiError {
         }
     }
 }
+
+impl From<WebhookError> for ApiError {
+    fn from(value: WebhookError) -> Self {
+        match value {
+            WebhookError::NotFound => ApiError::NotFound,
+            WebhookError::AdminApiDisabled => ApiError::NotImplemented,
+            e @ WebhookError::Unauthorized => ApiError::Unauthorized { message: e.to_string() },
+            WebhookError::InternalError { source } => ApiError::Internal { source },
+            e @ WebhookError::InvalidSubscription { .. } => ApiError::BadRequest { message: e.to_string() },
+        }
+    }
+}
+
+impl From<IpfsError> for ApiError {
+    fn from(value: IpfsError) -> Self {
+        ApiError::BadGateway {
+            message: Report::new(value).to_string(),
+        }
+    }
+}
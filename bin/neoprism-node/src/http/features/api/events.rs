@@ -0,0 +1,248 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use futures::{Stream, StreamExt};
+use identus_apollo::hash::Sha256Digest;
+use identus_apollo::hex::HexStr;
+use identus_did_prism::did::{PrismDid, PrismDidOps};
+use identus_did_prism_indexer::repo::OperationPosition;
+use utoipa::OpenApi;
+
+use crate::IndexerState;
+use crate::app::service::{DidEvent, DidEventFilter};
+use crate::http::features::api::error::{ApiError, ApiErrorResponseBody};
+use crate::http::features::api::events::models::{DidEventResponse, EventQuery};
+use crate::http::features::api::tags;
+use crate::http::urls::{ApiEvents, ApiEventsWs};
+
+/// Header sent by SSE clients when reconnecting, holding the id of the last received event.
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+#[derive(OpenApi)]
+#[openapi(paths(did_events_sse, did_events_ws))]
+pub struct EventsOpenApiDoc;
+
+mod models {
+    use chrono::{DateTime, Utc};
+    use identus_did_core::Did;
+    use identus_did_prism::did::operation::OperationId;
+    use identus_did_prism::dlt::{BlockNo, SlotNo, TxId};
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    use crate::app::service::OperationType;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    pub struct EventQuery {
+        /// Only stream operations of this DID, either in canonical or long form.
+        pub did: Option<String>,
+        /// Only stream operations of this type.
+        pub operation_type: Option<OperationType>,
+        /// Only stream storage operations of the VDR entry with this hex-encoded entry hash.
+        pub vdr_entry: Option<String>,
+        /// Replay operations indexed after this cursor before streaming new ones.
+        pub cursor: Option<String>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct DidEventResponse {
+        /// Position of the operation in the ledger, usable to resume the stream.
+        #[schema(example = "42-0-1")]
+        pub cursor: String,
+        pub did: Did,
+        pub operation_type: OperationType,
+        pub operation_id: OperationId,
+        pub tx_id: TxId,
+        pub slot_number: SlotNo,
+        pub block_number: BlockNo,
+        pub block_timestamp: DateTime<Utc>,
+        pub absn: u32,
+        pub osn: u32,
+        /// Whether the operation was applied to the DID state.
+        pub accepted: bool,
+        /// Hex-encoded entry hash of the VDR entry, for storage operations.
+        pub vdr_entry_hash: Option<String>,
+    }
+}
+
+#[utoipa::path(
+    get,
+    summary = "Stream DID events",
+    description = "Streams newly indexed operations as server-sent events. Each event id is a cursor; reconnecting clients resume after it using the `cursor` parameter or the `Last-Event-ID` header. Operations that failed to process are also streamed, with `accepted` set to false.",
+    path = ApiEvents::AXUM_PATH,
+    tags = [tags::OP_INDEX],
+    responses(
+        (status = OK, description = "A stream of events, each carrying a DidEventResponse as JSON data.", body = DidEventResponse, content_type = "text/event-stream"),
+        (status = BAD_REQUEST, description = "A filter or the cursor is invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(EventQuery)
+)]
+pub async fn did_events_sse(
+    Query(query): Query<EventQuery>,
+    headers: HeaderMap,
+    State(state): State<IndexerState>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let last_event_id = headers.get(LAST_EVENT_ID_HEADER).and_then(|i| i.to_str().ok());
+    let cursor = query
+        .cursor
+        .as_deref()
+        .or(last_event_id)
+        .map(parse_cursor)
+        .transpose()?;
+    let filter = parse_filter(&query)?;
+    let stream = state.did_event_feed.stream(cursor, filter).map(|event| {
+        let response = to_response(event);
+        let sse_event = Event::default()
+            .id(response.cursor.clone())
+            .json_data(response)
+            .expect("event must serialize to JSON");
+        Ok(sse_event)
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[utoipa::path(
+    get,
+    summary = "Stream DID events over WebSocket",
+    description = "WebSocket equivalent of the server-sent events stream. Each newly indexed operation is sent as a text message containing a DidEventResponse as JSON.",
+    path = ApiEventsWs::AXUM_PATH,
+    tags = [tags::OP_INDEX],
+    responses(
+        (status = SWITCHING_PROTOCOLS, description = "The connection is upgraded to a WebSocket streaming DidEventResponse messages.", body = DidEventResponse),
+        (status = BAD_REQUEST, description = "A filter or the cursor is invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(EventQuery)
+)]
+pub async fn did_events_ws(
+    ws: WebSocketUpgrade,
+    Query(query): Query<EventQuery>,
+    State(state): State<IndexerState>,
+) -> Result<Response, ApiError> {
+    let cursor = query.cursor.as_deref().map(parse_cursor).transpose()?;
+    let filter = parse_filter(&query)?;
+    let stream = state.did_event_feed.stream(cursor, filter);
+    Ok(ws.on_upgrade(move |socket| send_events(socket, stream)).into_response())
+}
+
+async fn send_events(mut socket: WebSocket, stream: impl Stream<Item = DidEvent>) {
+    let mut stream = std::pin::pin!(stream);
+    while let Some(event) = stream.next().await {
+        let message = serde_json::to_string(&to_response(event)).expect("event must serialize to JSON");
+        if socket.send(Message::Text(message.into())).await.is_err() {
+            // The client closed the connection
+            return;
+        }
+    }
+    let _ = socket.send(Message::Close(None)).await;
+}
+
+fn parse_filter(query: &EventQuery) -> Result<DidEventFilter, ApiError> {
+    let did = query
+        .did
+        .as_deref()
+        .map(PrismDid::from_str)
+        .transpose()
+        .map_err(|e| ApiError::BadRequest {
+            message: format!("invalid did: {e}"),
+        })?
+        .map(|did| did.into_canonical());
+    let vdr_entry_hash = query
+        .vdr_entry
+        .as_deref()
+        .map(parse_entry_hash)
+        .transpose()
+        .map_err(|message| ApiError::BadRequest { message })?;
+    Ok(DidEventFilter {
+        did,
+        operation_type: query.operation_type,
+        vdr_entry_hash,
+    })
+}
+
+fn parse_entry_hash(entry_hash: &str) -> Result<Sha256Digest, String> {
+    let hex = HexStr::from_str(entry_hash).map_err(|e| format!("invalid vdr_entry: {e}"))?;
+    Sha256Digest::from_bytes(&hex.to_bytes()).map_err(|e| format!("invalid vdr_entry: {e}"))
+}
+
+/// Cursors are formatted as `{block_number}-{absn}-{osn}`.
+fn format_cursor(position: &OperationPosition) -> String {
+    format!("{}-{}-{}", position.block_number, position.absn, position.osn)
+}
+
+fn parse_cursor(cursor: &str) -> Result<OperationPosition, ApiError> {
+    let invalid = || ApiError::BadRequest {
+        message: format!("invalid cursor: {cursor}"),
+    };
+    let mut parts = cursor.splitn(3, '-');
+    let mut next = || parts.next().ok_or_else(invalid);
+    // Block numbers are stored as signed 64-bit integers
+    let block_number = next()?.parse::<i64>().map_err(|_| invalid())?;
+    let block_number = u64::try_from(block_number).map_err(|_| invalid())?;
+    let absn = next()?.parse::<u32>().map_err(|_| invalid())?;
+    let osn = next()?.parse::<u32>().map_err(|_| invalid())?;
+    Ok(OperationPosition {
+        block_number: block_number.into(),
+        absn,
+        osn,
+    })
+}
+
+fn to_response(event: DidEvent) -> DidEventResponse {
+    let block_metadata = &event.metadata.block_metadata;
+    DidEventResponse {
+        cursor: format_cursor(&event.position()),
+        did: event.did.to_did(),
+        operation_type: event.operation_type,
+        operation_id: event.operation_id,
+        tx_id: block_metadata.tx_id.clone(),
+        slot_number: block_metadata.slot_number,
+        block_number: block_metadata.block_number,
+        block_timestamp: block_metadata.cbt,
+        absn: block_metadata.absn,
+        osn: event.metadata.osn,
+        accepted: event.accepted,
+        vdr_entry_hash: event.vdr_entry_hash.map(|i| HexStr::from(i.to_vec()).to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use identus_did_prism::dlt::BlockNo;
+
+    use super::*;
+
+    #[test]
+    fn cursor_roundtrip() {
+        let position = OperationPosition {
+            block_number: BlockNo::from(42),
+            absn: 3,
+            osn: 7,
+        };
+        let cursor = format_cursor(&position);
+        assert_eq!(cursor, "42-3-7");
+        assert_eq!(parse_cursor(&cursor).unwrap(), position);
+    }
+
+    #[test]
+    fn parse_cursor_rejects_malformed_input() {
+        for cursor in ["", "42", "42-3", "42-3-x", "-1-0-0", "42-3-7-1"] {
+            assert!(parse_cursor(cursor).is_err(), "cursor {cursor:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn parse_cursor_rejects_out_of_range_block_number() {
+        let max = format!("{}-0-0", i64::MAX);
+        assert_eq!(parse_cursor(&max).unwrap().block_number, BlockNo::from(i64::MAX as u64));
+
+        let out_of_range = format!("{}-0-0", i64::MAX as u64 + 1);
+        assert!(matches!(parse_cursor(&out_of_range), Err(ApiError::BadRequest { .. })));
+        assert!(parse_cursor(&format!("{}-0-0", u64::MAX)).is_err());
+    }
+}
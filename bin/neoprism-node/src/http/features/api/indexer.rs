@@ -1,29 +1,60 @@
+use std::error::Report;
+
 use axum::Json;
 use axum::body::Bytes;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, HeaderValue, header};
+use axum::response::{IntoResponse, Response};
+use identus_apollo::hash::Sha256Digest;
 use identus_apollo::hex::HexStr;
-use identus_did_core::Did;
+use identus_did_core::{Did, ResolutionResult};
 use identus_did_prism::did::PrismDidOps;
-use identus_did_prism::did::operation::OperationId;
+use identus_did_prism::did::operation::{OperationId, StorageData};
 use identus_did_prism::dlt::TxId;
 use identus_did_prism::proto::MessageExt;
+use identus_did_prism::proto::json::ProtoJson;
 use identus_did_prism::proto::node_api::DIDData;
+use identus_did_prism::protocol::proof::ResolutionProof;
+use identus_did_resolver_http::CacheHeaders;
 use utoipa::OpenApi;
 
 use crate::IndexerState;
+use crate::app::service::{
+    DidOperationRecord, PrismDidService, StorageType, VdrEntryHistory, VdrEntryStatus, VdrEntryVersion,
+};
+use crate::app::status_list::BitstringStatusList;
 use crate::http::features::api::error::{ApiError, ApiErrorResponseBody};
 use crate::http::features::api::indexer::models::{
-    IndexerStats, OperationDetails, OperationSummary, TransactionDetails, VdrEntryMetadataResponse,
+    BatchResolutionEntry, BatchResolutionRequest, BatchResolutionResponse, BitstringStatusListCredential,
+    BitstringStatusListSubject, DecodeQuery, DidOperationEntry, DidOperationHistory, DidOperationsQuery, DidVdrEntries,
+    DidVdrEntriesQuery, DidVdrEntry, IndexerStats, OperationDetails, OperationSummary, TransactionDetails,
+    VdrDataModel, VdrDataQuery, VdrEntryHistoryResponse, VdrEntryMetadataResponse, VdrEntryVersionModel, VdrIpfsEntry,
+    VdrStatusListEntry,
 };
 use crate::http::features::api::tags;
-use crate::http::urls::{ApiDidProtobuf, ApiIndexerStats, ApiOperation, ApiTransaction, ApiVdrBlob, ApiVdrMetadata};
+use crate::http::urls::{
+    ApiDidOperations, ApiDidProof, ApiDidProtobuf, ApiDidVdrEntries, ApiDidsResolve, ApiIndexerStats, ApiOperation,
+    ApiTransaction, ApiVdrBlob, ApiVdrHistory, ApiVdrMetadata, ApiVdrStatusList,
+};
+
+const MAX_BATCH_RESOLUTION_SIZE: usize = 100;
+/// Header carrying the IPFS path of a VDR entry stored on IPFS, as set by IPFS gateways.
+const IPFS_PATH_HEADER: &str = "x-ipfs-path";
+const DEFAULT_PAGE_SIZE: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 100;
 
 #[derive(OpenApi)]
 #[openapi(paths(
     did_data,
+    did_operations,
+    did_proof,
+    did_vdr_entries,
+    resolve_dids,
     indexer_stats,
     resolve_vdr_blob,
     vdr_entry_metadata,
+    vdr_status_list,
+    vdr_entry_history,
     transaction_details,
     operation_details
 ))]
@@ -31,11 +62,13 @@ pub struct IndexerOpenApiDoc;
 
 mod models {
     use chrono::{DateTime, Utc};
-    use identus_did_core::Did;
+    use identus_did_core::{Did, ResolutionOptions, ResolutionResult};
     use identus_did_prism::did::operation::{OperationId, SignedPrismOperationHexStr};
     use identus_did_prism::dlt::{BlockNo, SlotNo, TxId};
     use serde::{Deserialize, Serialize};
-    use utoipa::ToSchema;
+    use utoipa::{IntoParams, ToSchema};
+
+    use crate::app::service::{OperationType, StorageType, VdrEntryStatus};
 
     #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
     pub struct IndexerStats {
@@ -43,6 +76,26 @@ mod models {
         pub last_prism_block_number: Option<BlockNo>,
     }
 
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct BatchResolutionRequest {
+        /// DIDs to resolve, either in canonical or long form.
+        pub dids: Vec<String>,
+        /// Resolution options applied to every DID in the batch.
+        #[serde(default)]
+        pub options: ResolutionOptions,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct BatchResolutionResponse {
+        pub results: Vec<BatchResolutionEntry>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct BatchResolutionEntry {
+        pub did: String,
+        pub resolution_result: ResolutionResult,
+    }
+
     #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
     pub struct TransactionDetails {
         pub tx_id: TxId,
@@ -58,6 +111,9 @@ mod models {
     pub struct OperationSummary {
         pub osn: u32,
         pub signed_operation_data: SignedPrismOperationHexStr,
+        /// The signed operation decoded to JSON, if requested.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub operation: Option<serde_json::Value>,
         pub operation_id: OperationId,
         pub did: Did,
     }
@@ -66,7 +122,188 @@ mod models {
     pub struct VdrEntryMetadataResponse {
         pub entry_hash: String,
         pub latest_event_hash: String,
-        pub status: String,
+        pub status: VdrEntryStatus,
+        /// Type of the latest stored data, absent if the entry was created without data.
+        pub storage_type: Option<StorageType>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct VdrStatusListEntry {
+        pub state: i64,
+        pub name: String,
+        pub detail: String,
+    }
+
+    /// An unsecured W3C Bitstring Status List credential.
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    #[serde(rename_all = "camelCase")]
+    pub struct BitstringStatusListCredential {
+        #[serde(rename = "@context")]
+        pub context: Vec<String>,
+        /// The URL of the credential, to be used as the `statusListCredential` of issued credentials.
+        pub id: String,
+        #[serde(rename = "type")]
+        pub r#type: Vec<String>,
+        /// The DID owning the VDR entry.
+        pub issuer: String,
+        /// The block time of the latest version of the VDR entry.
+        pub valid_from: DateTime<Utc>,
+        pub credential_subject: BitstringStatusListSubject,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    #[serde(rename_all = "camelCase")]
+    pub struct BitstringStatusListSubject {
+        #[serde(rename = "type")]
+        pub r#type: String,
+        #[schema(example = "revocation")]
+        pub status_purpose: String,
+        /// The GZIP-compressed bitstring, multibase-encoded as base64url without padding.
+        pub encoded_list: String,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct VdrIpfsEntry {
+        /// CID of the content stored on IPFS.
+        pub cid: String,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    pub struct DecodeQuery {
+        /// Include the operations decoded to JSON alongside the hex-encoded protobuf.
+        pub decode: Option<bool>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    pub struct DidOperationsQuery {
+        /// Zero-based page number.
+        pub page: Option<u32>,
+        /// Number of operations per page, at most 100.
+        pub page_size: Option<u32>,
+        /// Only return operations of this type.
+        pub operation_type: Option<OperationType>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct DidOperationHistory {
+        pub operations: Vec<DidOperationEntry>,
+        pub current_page: u32,
+        pub page_size: u32,
+        /// Number of operations matching the filter across all pages.
+        pub total_items: u32,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct DidOperationEntry {
+        pub operation_id: OperationId,
+        /// The operation type, absent if the signed operation carries no operation.
+        pub operation_type: Option<OperationType>,
+        /// The signed operation decoded to JSON.
+        pub operation: serde_json::Value,
+        pub signed_operation_data: SignedPrismOperationHexStr,
+        pub tx_id: TxId,
+        pub slot_number: SlotNo,
+        pub block_number: BlockNo,
+        pub block_timestamp: DateTime<Utc>,
+        pub absn: u32,
+        pub osn: u32,
+        /// Version id of the DID document after this operation was processed.
+        pub version_id: Option<String>,
+        /// Whether the operation was applied to the DID state.
+        pub accepted: bool,
+        /// Why the operation was rejected.
+        pub error: Option<String>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    pub struct DidVdrEntriesQuery {
+        /// Zero-based page number.
+        pub page: Option<u32>,
+        /// Number of entries per page, at most 100.
+        pub page_size: Option<u32>,
+        /// Include the deactivated entries.
+        pub include_deactivated: Option<bool>,
+        /// Only return entries storing this type of data.
+        pub storage_type: Option<StorageType>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct DidVdrEntries {
+        pub entries: Vec<DidVdrEntry>,
+        pub current_page: u32,
+        pub page_size: u32,
+        /// Number of entries matching the filter across all pages.
+        pub total_items: u32,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct DidVdrEntry {
+        /// Hash of the operation creating the entry.
+        pub entry_hash: String,
+        pub latest_event_hash: String,
+        /// Type of the latest stored data.
+        pub storage_type: Option<StorageType>,
+        /// Length in bytes of the latest stored data, for `bytes` entries.
+        pub size: Option<u64>,
+        pub created_slot_number: SlotNo,
+        pub created_block_number: BlockNo,
+        pub created_block_timestamp: DateTime<Utc>,
+        pub updated_slot_number: SlotNo,
+        pub updated_block_number: BlockNo,
+        pub updated_block_timestamp: DateTime<Utc>,
+        pub status: VdrEntryStatus,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    pub struct VdrDataQuery {
+        /// Return the data of the version created by this hex-encoded event hash instead of the latest one.
+        pub event_hash: Option<String>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct VdrEntryHistoryResponse {
+        pub entry_hash: String,
+        /// The DID owning the entry.
+        pub owner: String,
+        pub status: VdrEntryStatus,
+        /// Versions of the entry, oldest first.
+        pub versions: Vec<VdrEntryVersionModel>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct VdrEntryVersionModel {
+        /// Hash of the operation creating this version.
+        pub event_hash: String,
+        pub operation_type: OperationType,
+        pub tx_id: TxId,
+        pub slot_number: SlotNo,
+        pub block_number: BlockNo,
+        pub block_timestamp: DateTime<Utc>,
+        pub absn: u32,
+        pub osn: u32,
+        /// Data of the entry in this version, absent once the entry is deactivated.
+        pub data: Option<VdrDataModel>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    #[serde(tag = "storage_type", rename_all = "snake_case")]
+    pub enum VdrDataModel {
+        Bytes {
+            /// The hex-encoded bytes.
+            data: String,
+        },
+        Ipfs {
+            cid: String,
+        },
+        StatusList {
+            state: i64,
+            name: String,
+            detail: String,
+        },
     }
 
     #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -74,6 +311,9 @@ mod models {
         pub operation_id: OperationId,
         pub tx_id: TxId,
         pub signed_operation_data: SignedPrismOperationHexStr,
+        /// The signed operation decoded to JSON, if requested.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub operation: Option<serde_json::Value>,
         pub slot_number: SlotNo,
         pub block_number: BlockNo,
         pub block_timestamp: DateTime<Utc>,
@@ -86,34 +326,54 @@ mod models {
 #[utoipa::path(
     get,
     summary = "Resolve VDR entry",
-    description = "Returns the raw blob data for a VDR entry, using PrismDidService::resolve_vdr. The response is application/octet-stream.",
+    description = "Returns the data of a VDR entry, using PrismDidService::resolve_vdr. Bytes entries are returned as application/octet-stream and status list entries as JSON. IPFS entries are returned as JSON with their CID, unless an IPFS gateway is configured, in which case the content is fetched, verified against the CID and returned as application/octet-stream. Either way, the `X-Ipfs-Path` header of IPFS entries holds `/ipfs/{cid}`. IPFS content larger than a single 2 MiB block is rejected.",
     path = ApiVdrBlob::AXUM_PATH,
     tags = [tags::OP_INDEX],
     responses(
-        (status = OK, description = "Successfully resolved the VDR entry. Returns the blob data.", content_type = "application/octet-stream"),
-        (status = NOT_FOUND, description = "The VDR entry was not found.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = OK, description = "Successfully resolved the VDR entry. Returns the blob data, or a JSON object with `state`, `name` and `detail` for status list entries and with `cid` for IPFS entries that are not fetched.", content_type = "application/octet-stream"),
+        (status = BAD_REQUEST, description = "The provided event hash is invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_FOUND, description = "The VDR entry or the version was not found.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = GONE, description = "The VDR entry is deactivated, or the version is its deactivation.", body = ApiErrorResponseBody, content_type = "application/json"),
         (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred during VDR resolution.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = BAD_GATEWAY, description = "The IPFS content could not be fetched, is too large or does not match its CID.", body = ApiErrorResponseBody, content_type = "application/json"),
     ),
     params(
-        ("entry_hash" = String, Path, description = "The hex-encoded entry hash to resolve.")
+        ("entry_hash" = String, Path, description = "The hex-encoded entry hash to resolve."),
+        VdrDataQuery
     ),
 )]
 pub async fn resolve_vdr_blob(
     Path(entry_hash): Path<String>,
+    Query(query): Query<VdrDataQuery>,
     State(state): State<IndexerState>,
-) -> Result<Bytes, ApiError> {
-    let service = &state.prism_did_service;
-    match service.resolve_vdr(&entry_hash).await {
-        Ok(Some(blob)) => Ok(Bytes::from(blob)),
-        Ok(None) => Err(ApiError::NotFound)?,
-        Err(e) => Err(ApiError::Internal { source: e })?,
-    }
+) -> Result<Response, ApiError> {
+    let data = vdr_data(&state.prism_did_service, &entry_hash, query.event_hash.as_deref()).await?;
+    let response = match data {
+        StorageData::Bytes(blob) => Bytes::from(blob).into_response(),
+        StorageData::StatusList(status_list) => Json(VdrStatusListEntry {
+            state: status_list.state,
+            name: status_list.name,
+            detail: status_list.detail,
+        })
+        .into_response(),
+        StorageData::Ipfs(cid) => {
+            let content = match &state.ipfs_gateway {
+                Some(gateway) => Bytes::from(gateway.fetch(&cid).await?).into_response(),
+                None => Json(VdrIpfsEntry { cid: cid.clone() }).into_response(),
+            };
+            match HeaderValue::from_str(&format!("/ipfs/{cid}")) {
+                Ok(ipfs_path) => ([(IPFS_PATH_HEADER, ipfs_path)], content).into_response(),
+                Err(_) => content,
+            }
+        }
+    };
+    Ok(response)
 }
 
 #[utoipa::path(
     get,
     summary = "Get VDR entry metadata",
-    description = "Returns metadata for a VDR entry including the latest event hash and status. This is used by VDR clients to obtain the previous event hash required for update and delete operations.",
+    description = "Returns metadata for a VDR entry including the latest event hash, status and storage type. This is used by VDR clients to obtain the previous event hash required for update and delete operations.",
     path = ApiVdrMetadata::AXUM_PATH,
     tags = [tags::OP_INDEX],
     responses(
@@ -136,12 +396,195 @@ pub async fn vdr_entry_metadata(
             entry_hash: meta.entry_hash,
             latest_event_hash: meta.latest_event_hash,
             status: meta.status,
+            storage_type: meta.storage_type,
         })),
         Ok(None) => Err(ApiError::NotFound),
         Err(e) => Err(ApiError::Internal { source: e }),
     }
 }
 
+#[utoipa::path(
+    get,
+    summary = "Get VDR entry history",
+    description = "Returns every accepted version of a VDR entry, oldest first, with the data, block metadata and event hash of each version. A deactivated entry ends with the version deactivating it, either by a deactivate storage entry operation or by the deactivation of the owning DID. The data of a version can be fetched from the VDR entry endpoint with its event hash.",
+    path = ApiVdrHistory::AXUM_PATH,
+    tags = [tags::OP_INDEX],
+    responses(
+        (status = OK, description = "Successfully retrieved the VDR entry history.", body = VdrEntryHistoryResponse),
+        (status = NOT_FOUND, description = "The VDR entry was not found.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(
+        ("entry_hash" = String, Path, description = "The hex-encoded entry hash.")
+    ),
+)]
+pub async fn vdr_entry_history(
+    Path(entry_hash): Path<String>,
+    State(state): State<IndexerState>,
+) -> Result<Json<VdrEntryHistoryResponse>, ApiError> {
+    let service = &state.prism_did_service;
+    let history = match service.get_vdr_entry_history(&entry_hash).await {
+        Ok(Some(history)) => history,
+        Ok(None) => Err(ApiError::NotFound)?,
+        Err(e) => Err(ApiError::Internal { source: e })?,
+    };
+    Ok(Json(to_history_response(history)))
+}
+
+/// Data of the latest version of a VDR entry, or of the version created by `event_hash`.
+async fn vdr_data(
+    service: &PrismDidService,
+    entry_hash: &str,
+    event_hash: Option<&str>,
+) -> Result<StorageData, ApiError> {
+    let Some(event_hash) = event_hash else {
+        return match service.resolve_vdr(entry_hash).await {
+            Ok(Some(data)) => Ok(data),
+            Ok(None) => Err(vdr_entry_not_found(service, entry_hash).await),
+            Err(e) => Err(ApiError::Internal { source: e }),
+        };
+    };
+    let event_hash = event_hash
+        .parse::<HexStr>()
+        .ok()
+        .and_then(|i| Sha256Digest::from_bytes(&i.to_bytes()).ok())
+        .ok_or_else(|| ApiError::BadRequest {
+            message: "event hash is not a valid hex-encoded sha256 digest".to_string(),
+        })?;
+    let history = service
+        .get_vdr_entry_history(entry_hash)
+        .await
+        .map_err(|e| ApiError::Internal { source: e })?
+        .ok_or(ApiError::NotFound)?;
+    let version = history.version(&event_hash).ok_or(ApiError::NotFound)?;
+    version.data.clone().ok_or_else(|| ApiError::Gone {
+        message: "vdr entry is deactivated by this event".to_string(),
+    })
+}
+
+/// Report a VDR entry that does not resolve as deactivated if it has a history, or as not found otherwise.
+async fn vdr_entry_not_found(service: &PrismDidService, entry_hash: &str) -> ApiError {
+    match service.get_vdr_entry_history(entry_hash).await {
+        Ok(Some(history)) if history.is_deactivated() => ApiError::Gone {
+            message: "vdr entry is deactivated".to_string(),
+        },
+        Ok(_) => ApiError::NotFound,
+        Err(e) => ApiError::Internal { source: e },
+    }
+}
+
+fn to_history_response(history: VdrEntryHistory) -> VdrEntryHistoryResponse {
+    VdrEntryHistoryResponse {
+        entry_hash: HexStr::from(history.entry_hash.as_bytes()).to_string(),
+        owner: history.owner.to_string(),
+        status: history.status(),
+        versions: history.versions.into_iter().map(to_version_model).collect(),
+    }
+}
+
+fn to_version_model(version: VdrEntryVersion) -> VdrEntryVersionModel {
+    let block_metadata = version.metadata.block_metadata;
+    let data = version.data.map(|data| match data {
+        StorageData::Bytes(bytes) => VdrDataModel::Bytes {
+            data: HexStr::from(bytes).to_string(),
+        },
+        StorageData::Ipfs(cid) => VdrDataModel::Ipfs { cid },
+        StorageData::StatusList(status_list) => VdrDataModel::StatusList {
+            state: status_list.state,
+            name: status_list.name,
+            detail: status_list.detail,
+        },
+    });
+    VdrEntryVersionModel {
+        event_hash: HexStr::from(version.event_hash.as_bytes()).to_string(),
+        operation_type: version.operation_type,
+        tx_id: block_metadata.tx_id,
+        slot_number: block_metadata.slot_number,
+        block_number: block_metadata.block_number,
+        block_timestamp: block_metadata.cbt,
+        absn: block_metadata.absn,
+        osn: version.metadata.osn,
+        data,
+    }
+}
+
+#[utoipa::path(
+    get,
+    summary = "Get VDR entry as bitstring status list",
+    description = "Renders a bytes or status list VDR entry as an unsecured W3C Bitstring Status List credential issued by the DID owning the entry and valid from the block time of its latest version. The bitstring of a bytes entry is its data, while a status list entry holds the status of a single credential at index 0 and names one of the `refresh`, `revocation`, `suspension` or `message` status purposes. The response carries an ETag derived from the latest event hash of the entry and a Last-Modified header from its block time, and conditional requests are answered with `304 Not Modified`.",
+    path = ApiVdrStatusList::AXUM_PATH,
+    tags = [tags::OP_INDEX],
+    responses(
+        (status = OK, description = "Successfully rendered the status list credential.", body = BitstringStatusListCredential),
+        (status = NOT_MODIFIED, description = "The VDR entry has not changed since the conditional request validators."),
+        (status = BAD_REQUEST, description = "The VDR entry is stored on IPFS or names an unsupported status purpose.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_FOUND, description = "The VDR entry was not found.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = GONE, description = "The VDR entry is deactivated.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(
+        ("entry_hash" = String, Path, description = "The hex-encoded entry hash.")
+    ),
+)]
+pub async fn vdr_status_list(
+    Path(entry_hash): Path<String>,
+    State(state): State<IndexerState>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let service = &state.prism_did_service;
+    let history = match service.get_vdr_entry_history(&entry_hash).await {
+        Ok(Some(history)) => history,
+        Ok(None) => Err(ApiError::NotFound)?,
+        Err(e) => Err(ApiError::Internal { source: e })?,
+    };
+    let latest = history.latest();
+    let Some(data) = latest.data.as_ref() else {
+        Err(ApiError::Gone {
+            message: "vdr entry is deactivated".to_string(),
+        })?
+    };
+    let status_list =
+        BitstringStatusList::from_storage_data(data).map_err(|e| ApiError::BadRequest { message: e.to_string() })?;
+
+    let valid_from = latest.metadata.block_metadata.cbt;
+    let etag = format!("\"{}\"", HexStr::from(latest.event_hash.as_bytes()));
+    let cache_headers = CacheHeaders::for_resource(etag, Some(valid_from), state.cache_policy.status_list());
+    let credential = BitstringStatusListCredential {
+        context: vec!["https://www.w3.org/ns/credentials/v2".to_string()],
+        id: external_url(
+            state.external_url.as_deref(),
+            &headers,
+            &ApiVdrStatusList::new_uri(entry_hash),
+        ),
+        r#type: vec![
+            "VerifiableCredential".to_string(),
+            "BitstringStatusListCredential".to_string(),
+        ],
+        issuer: history.owner.to_string(),
+        valid_from,
+        credential_subject: BitstringStatusListSubject {
+            r#type: "BitstringStatusList".to_string(),
+            status_purpose: status_list.status_purpose,
+            encoded_list: status_list.encoded_list,
+        },
+    };
+    Ok(cache_headers.respond(&headers, || Json(credential).into_response()))
+}
+
+/// Absolute URL of a path served by the node, based on the configured external URL or the `Host` header.
+fn external_url(base_url: Option<&str>, headers: &HeaderMap, path: &str) -> String {
+    match base_url {
+        Some(base_url) => format!("{}{path}", base_url.trim_end_matches('/')),
+        None => {
+            let host = headers
+                .get(header::HOST)
+                .and_then(|i| i.to_str().ok())
+                .unwrap_or("localhost");
+            format!("http://{host}{path}")
+        }
+    }
+}
+
 #[utoipa::path(
     get,
     summary = "Get DIDData protobuf",
@@ -170,6 +613,224 @@ pub async fn did_data(Path(did): Path<String>, State(state): State<IndexerState>
     }
 }
 
+#[utoipa::path(
+    get,
+    summary = "Get DID operation history",
+    description = "Returns the operations of a DID in the order they were processed during resolution, including the operations that were rejected and the reason why. This exposes the same information as the resolver UI debug view.",
+    path = ApiDidOperations::AXUM_PATH,
+    tags = [tags::OP_INDEX],
+    responses(
+        (status = OK, description = "A page of the DID operation history.", body = DidOperationHistory),
+        (status = BAD_REQUEST, description = "The provided DID or page size is invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_FOUND, description = "The DID does not exist in the index.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred while reading the operations.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(
+        ("did" = Did, Path, description = "The DID whose operations are returned, either in canonical or long form."),
+        DidOperationsQuery
+    )
+)]
+pub async fn did_operations(
+    Path(did): Path<String>,
+    Query(query): Query<DidOperationsQuery>,
+    State(state): State<IndexerState>,
+) -> Result<Json<DidOperationHistory>, ApiError> {
+    let (page, page_size) = page_params(query.page, query.page_size)?;
+
+    let operation_page = state
+        .prism_did_service
+        .get_did_operation_page(&did, query.operation_type, page, page_size)
+        .await?;
+    Ok(Json(DidOperationHistory {
+        operations: operation_page.items.into_iter().map(to_operation_entry).collect(),
+        current_page: operation_page.current_page,
+        page_size: operation_page.page_size,
+        total_items: operation_page.total_items,
+    }))
+}
+
+fn to_operation_entry(record: DidOperationRecord) -> DidOperationEntry {
+    let DidOperationRecord {
+        metadata,
+        signed_operation,
+        operation_type,
+        version_id,
+        error,
+    } = record;
+    DidOperationEntry {
+        operation_id: signed_operation.operation_id(),
+        operation_type,
+        operation: signed_operation.to_json(),
+        tx_id: metadata.block_metadata.tx_id,
+        slot_number: metadata.block_metadata.slot_number,
+        block_number: metadata.block_metadata.block_number,
+        block_timestamp: metadata.block_metadata.cbt,
+        absn: metadata.block_metadata.absn,
+        osn: metadata.osn,
+        version_id: version_id.map(|i| HexStr::from(i.as_bytes()).to_string()),
+        accepted: error.is_none(),
+        error: error.map(|e| Report::new(e).to_string()),
+        signed_operation_data: signed_operation.into(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    summary = "Get DID resolution proof",
+    description = "Returns a self-contained proof of the DID state: every signed operation of the DID together with the Cardano transaction publishing it and the PrismObject carried in the transaction metadata, exactly as it was published. The proof can be archived as evidence of what the DID looked like, and verified offline by checking each operation against the PrismObject of its transaction and replaying the operations, as done by `ResolutionProof::verify` in the `identus-did-prism` crate.\n\nThe PrismObject is `null` for transactions indexed before this node started storing PrismObjects, and such a proof cannot be verified until the transaction is synced again. To avoid trusting this node, compare each PrismObject with the metadata of its transaction on chain. A proof cannot show that no operation was left out, so it is at most as recent as the index of this node.",
+    path = ApiDidProof::AXUM_PATH,
+    tags = [tags::OP_INDEX],
+    responses(
+        (status = OK, description = "The resolution proof of the DID.", body = ResolutionProof),
+        (status = BAD_REQUEST, description = "The provided DID is invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_FOUND, description = "The DID does not exist in the index.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred while reading the operations.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(
+        ("did" = Did, Path, description = "The DID to prove, either in canonical or long form."),
+    )
+)]
+pub async fn did_proof(
+    Path(did): Path<String>,
+    State(state): State<IndexerState>,
+) -> Result<Json<ResolutionProof>, ApiError> {
+    let proof = state.prism_did_service.get_resolution_proof(&did).await?;
+    Ok(Json(proof))
+}
+
+#[utoipa::path(
+    get,
+    summary = "List DID VDR entries",
+    description = "Returns the VDR storage entries owned by a DID in the order they were created, with the type and size of their latest data and the blocks where they were created and last updated. Deactivated entries, including those deactivated together with the DID, are only returned when requested.",
+    path = ApiDidVdrEntries::AXUM_PATH,
+    tags = [tags::OP_INDEX],
+    responses(
+        (status = OK, description = "A page of the DID VDR entries.", body = DidVdrEntries),
+        (status = BAD_REQUEST, description = "The provided DID or page size is invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_FOUND, description = "The DID does not exist in the index.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred while reading the entries.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(
+        ("did" = Did, Path, description = "The DID whose VDR entries are returned, either in canonical or long form."),
+        DidVdrEntriesQuery
+    )
+)]
+pub async fn did_vdr_entries(
+    Path(did): Path<String>,
+    Query(query): Query<DidVdrEntriesQuery>,
+    State(state): State<IndexerState>,
+) -> Result<Json<DidVdrEntries>, ApiError> {
+    let (page, page_size) = page_params(query.page, query.page_size)?;
+
+    let service = &state.prism_did_service;
+    let include_deactivated = query.include_deactivated.unwrap_or(false);
+    let histories = service
+        .get_did_vdr_entries(&did)
+        .await?
+        .into_iter()
+        .filter(|history| include_deactivated || history.status() == VdrEntryStatus::Active)
+        .filter(|history| {
+            query.storage_type.is_none() || history.latest_data().map(StorageType::of) == query.storage_type
+        })
+        .collect::<Vec<_>>();
+
+    let total_items = histories.len() as u32;
+    let entries = histories
+        .iter()
+        .skip(page.saturating_mul(page_size) as usize)
+        .take(page_size as usize)
+        .map(to_did_vdr_entry)
+        .collect();
+
+    Ok(Json(DidVdrEntries {
+        entries,
+        current_page: page,
+        page_size,
+        total_items,
+    }))
+}
+
+/// Validate the page and page size of a paginated query, falling back to the first page of the default size.
+fn page_params(page: Option<u32>, page_size: Option<u32>) -> Result<(u32, u32), ApiError> {
+    let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    if page_size == 0 || page_size > MAX_PAGE_SIZE {
+        return Err(ApiError::BadRequest {
+            message: format!("page_size must be between 1 and {MAX_PAGE_SIZE}"),
+        });
+    }
+    Ok((page.unwrap_or(0), page_size))
+}
+
+fn to_did_vdr_entry(history: &VdrEntryHistory) -> DidVdrEntry {
+    let data = history.latest_data();
+    let created = &history.versions[0].metadata.block_metadata;
+    let updated = &history.latest().metadata.block_metadata;
+    DidVdrEntry {
+        entry_hash: HexStr::from(history.entry_hash.as_bytes()).to_string(),
+        latest_event_hash: HexStr::from(history.latest().event_hash.as_bytes()).to_string(),
+        storage_type: data.map(StorageType::of),
+        size: data.and_then(|data| match data {
+            StorageData::Bytes(bytes) => Some(bytes.len() as u64),
+            _ => None,
+        }),
+        created_slot_number: created.slot_number,
+        created_block_number: created.block_number,
+        created_block_timestamp: created.cbt,
+        updated_slot_number: updated.slot_number,
+        updated_block_number: updated.block_number,
+        updated_block_timestamp: updated.cbt,
+        status: history.status(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    summary = "Resolve multiple DIDs",
+    description = "Resolves a batch of PRISM DIDs in one request. The operations of all DIDs are fetched from storage in a single query. Each DID gets its own resolution result in the same order as the request, so an invalid or unknown DID does not fail the whole batch.",
+    path = ApiDidsResolve::AXUM_PATH,
+    tags = [tags::OP_INDEX],
+    request_body = BatchResolutionRequest,
+    responses(
+        (status = OK, description = "The resolution result of each DID in the batch.", body = BatchResolutionResponse),
+        (status = BAD_REQUEST, description = "The batch is empty or exceeds the maximum size.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred while resolving the batch.", body = ApiErrorResponseBody, content_type = "application/json"),
+    )
+)]
+pub async fn resolve_dids(
+    State(state): State<IndexerState>,
+    Json(req): Json<BatchResolutionRequest>,
+) -> Result<Json<BatchResolutionResponse>, ApiError> {
+    if req.dids.is_empty() || req.dids.len() > MAX_BATCH_RESOLUTION_SIZE {
+        return Err(ApiError::BadRequest {
+            message: format!("batch must contain between 1 and {MAX_BATCH_RESOLUTION_SIZE} DIDs"),
+        });
+    }
+
+    let service = &state.prism_did_service;
+    let results = service
+        .resolve_dids(&req.dids)
+        .await
+        .map_err(|e| ApiError::Internal { source: e })?;
+
+    let results = req
+        .dids
+        .into_iter()
+        .zip(results)
+        .map(|(did, result)| {
+            let resolution_result: ResolutionResult = match result {
+                Ok((prism_did, did_state)) => service.to_resolution_result(&prism_did, &did_state, &req.options),
+                Err(e) => {
+                    e.log_internal_error();
+                    e.into()
+                }
+            };
+            BatchResolutionEntry { did, resolution_result }
+        })
+        .collect();
+
+    Ok(Json(BatchResolutionResponse { results }))
+}
+
 #[utoipa::path(
     get,
     summary = "Get indexer statistics",
@@ -210,13 +871,16 @@ pub async fn indexer_stats(State(state): State<IndexerState>) -> Result<Json<Ind
         (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred", body = ApiErrorResponseBody),
     ),
     params(
-        ("tx_id" = TxId, Path, description = "Cardano transaction hash (64-character hex string)")
+        ("tx_id" = TxId, Path, description = "Cardano transaction hash (64-character hex string)"),
+        DecodeQuery
     )
 )]
 pub async fn transaction_details(
     Path(tx_id): Path<TxId>,
+    Query(query): Query<DecodeQuery>,
     State(state): State<IndexerState>,
 ) -> Result<Json<TransactionDetails>, ApiError> {
+    let decode = query.decode.unwrap_or(false);
     let service = &state.prism_did_service;
     let operations = service
         .get_raw_operations_by_tx_id(&tx_id)
@@ -244,6 +908,7 @@ pub async fn transaction_details(
             .map(|(metadata, signed_op, did)| OperationSummary {
                 osn: metadata.osn,
                 operation_id: signed_op.operation_id(),
+                operation: decode.then(|| signed_op.to_json()),
                 signed_operation_data: signed_op.into(),
                 did: did.to_did(),
             })
@@ -265,11 +930,13 @@ pub async fn transaction_details(
         (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred", body = ApiErrorResponseBody),
     ),
     params(
-        ("operation_id" = OperationId, Path, description = "Operation hash (64-character hex string)")
+        ("operation_id" = OperationId, Path, description = "Operation hash (64-character hex string)"),
+        DecodeQuery
     )
 )]
 pub async fn operation_details(
     Path(operation_id): Path<OperationId>,
+    Query(query): Query<DecodeQuery>,
     State(state): State<IndexerState>,
 ) -> Result<Json<OperationDetails>, ApiError> {
     let service = &state.prism_did_service;
@@ -283,6 +950,7 @@ pub async fn operation_details(
     let details = OperationDetails {
         operation_id,
         tx_id: metadata.block_metadata.tx_id,
+        operation: query.decode.unwrap_or(false).then(|| signed_op.to_json()),
         signed_operation_data: signed_op.into(),
         slot_number: metadata.block_metadata.slot_number,
         block_number: metadata.block_metadata.block_number,
@@ -1,19 +1,29 @@
 use axum::Router;
 use axum::routing::{get, post};
-use identus_did_resolver_http::{HttpBindingOptions, did_resolver_http_binding};
+use identus_did_resolver_http::{
+    HttpBindingOptions, UniversalResolverDriverOptions, did_resolver_http_binding, universal_resolver_driver_binding,
+};
 use utoipa::OpenApi;
 use utoipa_scalar::{Scalar, Servable};
 
 use crate::RunMode;
+use crate::http::features::api::decode::DecodeOpenApiDoc;
+use crate::http::features::api::events::EventsOpenApiDoc;
 use crate::http::features::api::indexer::IndexerOpenApiDoc;
 use crate::http::features::api::submitter::SubmitterOpenApiDoc;
 use crate::http::features::api::system::SystemOpenApiDoc;
+use crate::http::features::api::verify::VerifyOpenApiDoc;
+use crate::http::features::api::webhook::WebhookOpenApiDoc;
 use crate::http::{Routers, urls};
 
+mod decode;
 mod error;
+mod events;
 mod indexer;
 mod submitter;
 mod system;
+mod verify;
+mod webhook;
 
 #[derive(OpenApi)]
 #[openapi(servers(
@@ -26,6 +36,7 @@ mod tags {
     pub const SYSTEM: &str = "System API";
     pub const OP_INDEX: &str = "Indexer API";
     pub const OP_SUBMIT: &str = "Submitter API";
+    pub const WEBHOOK: &str = "Webhook API";
 }
 
 fn build_openapi_servers(
@@ -63,14 +74,27 @@ pub fn build_openapi(mode: &RunMode, port: u16, external_url: Option<&str>) -> u
         },
     )
     .openapi;
-    let base_oas = BaseOpenApiDoc::openapi().merge_from(SystemOpenApiDoc::openapi());
-    let indexer_oas = IndexerOpenApiDoc::openapi().merge_from(did_resolver_oas);
+    let universal_resolver_oas = universal_resolver_driver_binding(UniversalResolverDriverOptions {
+        openapi_tags: Some(vec![tags::OP_INDEX.to_string()]),
+        ..Default::default()
+    })
+    .openapi;
+    let base_oas = BaseOpenApiDoc::openapi()
+        .merge_from(SystemOpenApiDoc::openapi())
+        .merge_from(DecodeOpenApiDoc::openapi());
+    let resolver_oas = did_resolver_oas.merge_from(universal_resolver_oas);
+    let indexer_oas = IndexerOpenApiDoc::openapi()
+        .merge_from(VerifyOpenApiDoc::openapi())
+        .merge_from(EventsOpenApiDoc::openapi())
+        .merge_from(WebhookOpenApiDoc::openapi())
+        .merge_from(resolver_oas.clone());
     let submitter_oas = SubmitterOpenApiDoc::openapi();
 
     let mut merged_oas = match mode {
         RunMode::Indexer => base_oas.merge_from(indexer_oas),
         RunMode::Submitter => base_oas.merge_from(submitter_oas),
         RunMode::Standalone => base_oas.merge_from(indexer_oas).merge_from(submitter_oas),
+        RunMode::Proxy => base_oas.merge_from(resolver_oas),
     };
 
     let servers = build_openapi_servers(port, external_url, merged_oas.servers.take());
@@ -84,15 +108,43 @@ pub fn router(mode: RunMode, port: u16, external_url: Option<&str>) -> Routers {
     let app_router = Router::new()
         .merge(Scalar::with_url(urls::OpenApi::AXUM_PATH, oas))
         .route(urls::ApiHealth::AXUM_PATH, get(system::health))
-        .route(urls::ApiAppMeta::AXUM_PATH, get(system::app_meta));
+        .route(urls::ApiReady::AXUM_PATH, get(system::ready))
+        .route(urls::ApiAppMeta::AXUM_PATH, get(system::app_meta))
+        .route(urls::Metrics::AXUM_PATH, get(system::metrics))
+        .route(urls::ApiDecode::AXUM_PATH, post(decode::decode_protobuf));
 
     let indexer_router = Router::new()
+        .route(urls::ApiDidsResolve::AXUM_PATH, post(indexer::resolve_dids))
         .route(urls::ApiDidProtobuf::AXUM_PATH, get(indexer::did_data))
+        .route(urls::ApiDidOperations::AXUM_PATH, get(indexer::did_operations))
+        .route(urls::ApiDidProof::AXUM_PATH, get(indexer::did_proof))
+        .route(urls::ApiDidVdrEntries::AXUM_PATH, get(indexer::did_vdr_entries))
         .route(urls::ApiIndexerStats::AXUM_PATH, get(indexer::indexer_stats))
         .route(urls::ApiVdrBlob::AXUM_PATH, get(indexer::resolve_vdr_blob))
         .route(urls::ApiVdrMetadata::AXUM_PATH, get(indexer::vdr_entry_metadata))
+        .route(urls::ApiVdrStatusList::AXUM_PATH, get(indexer::vdr_status_list))
+        .route(urls::ApiVdrHistory::AXUM_PATH, get(indexer::vdr_entry_history))
         .route(urls::ApiTransaction::AXUM_PATH, get(indexer::transaction_details))
-        .route(urls::ApiOperation::AXUM_PATH, get(indexer::operation_details));
+        .route(urls::ApiOperation::AXUM_PATH, get(indexer::operation_details))
+        .route(urls::ApiVerifyJws::AXUM_PATH, post(verify::verify_jws))
+        .route(urls::ApiEvents::AXUM_PATH, get(events::did_events_sse))
+        .route(urls::ApiEventsWs::AXUM_PATH, get(events::did_events_ws))
+        .route(
+            urls::ApiWebhooks::AXUM_PATH,
+            get(webhook::list_webhooks).post(webhook::create_webhook),
+        )
+        .route(
+            urls::ApiWebhook::AXUM_PATH,
+            get(webhook::get_webhook).delete(webhook::delete_webhook),
+        )
+        .route(
+            urls::ApiWebhookDeadLetters::AXUM_PATH,
+            get(webhook::list_webhook_dead_letters),
+        )
+        .route(
+            urls::ApiWebhookDeadLetterRetry::AXUM_PATH,
+            post(webhook::retry_webhook_dead_letter),
+        );
 
     let submitter_router = Router::new()
         .route(
@@ -101,7 +153,9 @@ pub fn router(mode: RunMode, port: u16, external_url: Option<&str>) -> Routers {
         )
         .route(urls::ApiSubmissionsObjects::AXUM_PATH, post(submitter::submit_object));
 
-    let did_resolver_router = did_resolver_http_binding(urls::ApiDid::AXUM_PATH, Default::default()).router;
+    let did_resolver_router = did_resolver_http_binding(urls::ApiDid::AXUM_PATH, Default::default())
+        .router
+        .merge(universal_resolver_driver_binding(Default::default()).router);
 
     Routers {
         app_router,
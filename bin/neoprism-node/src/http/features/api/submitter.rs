@@ -3,6 +3,7 @@ use axum::extract::State;
 use identus_did_prism::did::operation::OperationId;
 use identus_did_prism::dlt::TxId;
 use identus_did_prism::prelude::SignedPrismOperation;
+use tracing::Instrument;
 use utoipa::OpenApi;
 
 use crate::SubmitterState;
@@ -51,9 +52,12 @@ async fn publish_operations(
     }
     let operation_ids: Vec<_> = signed_operations.iter().map(|op| op.operation_id()).collect();
 
+    let sink_name = state.dlt_sink.sink_name();
+    let span = tracing::info_span!("dlt.submit", sink = sink_name, operations = operation_ids.len());
     let tx_id = state
         .dlt_sink
         .publish_operations(signed_operations)
+        .instrument(span)
         .await
         .map_err(|e| ApiError::Internal {
             source: anyhow::anyhow!(e),
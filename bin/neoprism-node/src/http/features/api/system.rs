@@ -1,15 +1,18 @@
 use axum::Json;
 use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
 use utoipa::OpenApi;
 
-use crate::http::features::api::error::ApiErrorResponseBody;
+use crate::app::{metrics as app_metrics, readiness};
+use crate::http::features::api::error::{ApiError, ApiErrorResponseBody};
 use crate::http::features::api::system::models::AppMeta;
 use crate::http::features::api::tags;
 use crate::http::urls;
 use crate::{AppState, VERSION};
 
 #[derive(OpenApi)]
-#[openapi(paths(health, app_meta))]
+#[openapi(paths(health, ready, app_meta, metrics))]
 pub struct SystemOpenApiDoc;
 
 mod models {
@@ -29,6 +32,7 @@ mod models {
         Indexer,
         Submitter,
         Standalone,
+        Proxy,
     }
 
     impl From<RunMode> for AppMetaRunMode {
@@ -37,6 +41,7 @@ mod models {
                 RunMode::Indexer => Self::Indexer,
                 RunMode::Submitter => Self::Submitter,
                 RunMode::Standalone => Self::Standalone,
+                RunMode::Proxy => Self::Proxy,
             }
         }
     }
@@ -55,6 +60,26 @@ pub async fn health() -> &'static str {
     "Ok"
 }
 
+#[utoipa::path(
+    get,
+    summary = "Readiness check",
+    description = "Reports whether the node is ready to serve up-to-date results. The node is not ready while the database is unreachable, the DLT sync cursor lags too far behind the chain tip, or the DLT source has recently reported an error.",
+    path = urls::ApiReady::AXUM_PATH,
+    tags = [tags::SYSTEM],
+    responses(
+        (status = OK, description = "Ready", body = String, example = "Ok"),
+        (status = SERVICE_UNAVAILABLE, description = "The node is not ready to serve requests", body = ApiErrorResponseBody),
+    )
+)]
+pub async fn ready(State(state): State<AppState>) -> Result<&'static str, ApiError> {
+    if let Some(readiness_state) = state.readiness.as_ref() {
+        readiness::check_readiness(readiness_state)
+            .await
+            .map_err(|e| ApiError::ServiceUnavailable { message: e.to_string() })?;
+    }
+    Ok("Ok")
+}
+
 #[utoipa::path(
     get,
     summary = "Get app metadata",
@@ -71,3 +96,19 @@ pub async fn app_meta(State(state): State<AppState>) -> Json<AppMeta> {
         mode: state.run_mode.into(),
     })
 }
+
+#[utoipa::path(
+    get,
+    summary = "Prometheus metrics",
+    description = "Exposes indexer, resolver and submitter metrics in the Prometheus text exposition format.",
+    path = urls::Metrics::AXUM_PATH,
+    tags = [tags::SYSTEM],
+    responses(
+        (status = OK, description = "Metrics in Prometheus text format", body = String, content_type = "text/plain"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred while encoding metrics", body = ApiErrorResponseBody, content_type = "application/json"),
+    )
+)]
+pub async fn metrics() -> Result<impl IntoResponse, ApiError> {
+    let body = app_metrics::encode_text().map_err(|e| ApiError::Internal { source: e })?;
+    Ok(([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], body))
+}
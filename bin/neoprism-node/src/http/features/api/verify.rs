@@ -0,0 +1,145 @@
+use std::error::Report;
+use std::str::FromStr;
+
+use axum::Json;
+use axum::extract::State;
+use identus_apollo::jws::CompactJws;
+use identus_did_prism::did::jws::{self, JwsKid};
+use utoipa::OpenApi;
+
+use crate::IndexerState;
+use crate::http::features::api::error::{ApiError, ApiErrorResponseBody};
+use crate::http::features::api::tags;
+use crate::http::features::api::verify::models::{JwsVerificationRequest, JwsVerificationResult};
+use crate::http::urls::ApiVerifyJws;
+
+#[derive(OpenApi)]
+#[openapi(paths(verify_jws))]
+pub struct VerifyOpenApiDoc;
+
+mod models {
+    use chrono::{DateTime, Utc};
+    use identus_did_prism::did::operation::KeyUsage;
+    use serde::{Deserialize, Serialize};
+    use utoipa::ToSchema;
+
+    /// A verification relationship of the DID document, backed by a PRISM key usage.
+    #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, ToSchema)]
+    #[serde(rename_all = "camelCase")]
+    pub enum VerificationRelationship {
+        Authentication,
+        #[default]
+        AssertionMethod,
+        CapabilityInvocation,
+        CapabilityDelegation,
+    }
+
+    impl VerificationRelationship {
+        pub fn key_usage(&self) -> KeyUsage {
+            match self {
+                Self::Authentication => KeyUsage::AuthenticationKey,
+                Self::AssertionMethod => KeyUsage::IssuingKey,
+                Self::CapabilityInvocation => KeyUsage::CapabilityInvocationKey,
+                Self::CapabilityDelegation => KeyUsage::CapabilityDelegationKey,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct JwsVerificationRequest {
+        /// A JWS or JWT in compact serialization, whose `kid` header is a PRISM DID URL.
+        pub jws: String,
+        /// The relationship the signing key must have, `assertionMethod` by default.
+        #[serde(default)]
+        pub verification_relationship: VerificationRelationship,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct JwsVerificationResult {
+        pub verified: bool,
+        /// The DID of the `kid` header.
+        pub did: String,
+        /// The key id of the `kid` header.
+        pub key_id: String,
+        /// The issuance time of the JWS, from the `iat` or `nbf` claim.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub issuance_time: Option<DateTime<Utc>>,
+        /// The time the signing key was added to the DID.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub key_added_at: Option<DateTime<Utc>>,
+        /// The time the signing key was revoked, if it was revoked after the issuance.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub key_revoked_at: Option<DateTime<Utc>>,
+        /// The reason the verification failed.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub error: Option<String>,
+    }
+}
+
+#[utoipa::path(
+    post,
+    summary = "Verify a JWS signed by a PRISM DID",
+    description = "Verifies a compact JWS or JWT signed with a key of a PRISM DID, referenced by the `kid` header as `did:prism:<suffix>#<key-id>`. The DID is resolved and the key must have existed and not been revoked at the issuance time of the JWS, given by its `iat` claim or else its `nbf` claim, and must have the requested verification relationship. A credential therefore remains valid after its issuing key is revoked. Supports the `ES256K` and `EdDSA` algorithms.",
+    path = ApiVerifyJws::AXUM_PATH,
+    tags = [tags::OP_INDEX],
+    request_body = JwsVerificationRequest,
+    responses(
+        (status = OK, description = "The verification result. A JWS failing verification has `verified` set to false and the reason in `error`.", body = JwsVerificationResult),
+        (status = BAD_REQUEST, description = "The JWS is malformed or its `kid` is not a PRISM DID URL.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_FOUND, description = "The DID of the `kid` does not exist.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred while resolving the DID.", body = ApiErrorResponseBody, content_type = "application/json"),
+    )
+)]
+pub async fn verify_jws(
+    State(state): State<IndexerState>,
+    Json(req): Json<JwsVerificationRequest>,
+) -> Result<Json<JwsVerificationResult>, ApiError> {
+    let jws = CompactJws::from_str(&req.jws).map_err(|e| ApiError::BadRequest {
+        message: Report::new(e).to_string(),
+    })?;
+    let kid = JwsKid::from_jws(&jws).map_err(|e| ApiError::BadRequest {
+        message: Report::new(e).to_string(),
+    })?;
+
+    let (result, _) = state.prism_did_service.resolve_did(&kid.did.to_string()).await;
+    let (_, did_state) = result?;
+
+    let result = match jws::verify_jws(&jws, &did_state, req.verification_relationship.key_usage()) {
+        Ok(verified) => JwsVerificationResult {
+            verified: true,
+            did: kid.did.to_string(),
+            key_id: kid.key_id.to_string(),
+            issuance_time: Some(verified.issuance_time),
+            key_added_at: Some(verified.public_key.added_at.block_metadata.cbt),
+            key_revoked_at: verified.public_key.revoked_at.map(|i| i.block_metadata.cbt),
+            error: None,
+        },
+        Err(e) => JwsVerificationResult {
+            verified: false,
+            did: kid.did.to_string(),
+            key_id: kid.key_id.to_string(),
+            issuance_time: None,
+            key_added_at: None,
+            key_revoked_at: None,
+            error: Some(Report::new(e).to_string()),
+        },
+    };
+    Ok(Json(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use identus_did_prism::did::operation::KeyUsage;
+
+    use super::models::JwsVerificationRequest;
+
+    #[test]
+    fn verification_relationship_defaults_to_assertion_method() {
+        let req: JwsVerificationRequest = serde_json::from_str(r#"{"jws":"a.b.c"}"#).unwrap();
+        assert_eq!(req.verification_relationship.key_usage(), KeyUsage::IssuingKey);
+
+        let req: JwsVerificationRequest =
+            serde_json::from_str(r#"{"jws":"a.b.c","verification_relationship":"authentication"}"#).unwrap();
+        assert_eq!(req.verification_relationship.key_usage(), KeyUsage::AuthenticationKey);
+    }
+}
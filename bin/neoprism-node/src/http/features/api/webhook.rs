@@ -0,0 +1,292 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode, header};
+use lazybe::uuid::Uuid;
+use node_storage::webhook::{WebhookDelivery, WebhookSubscription};
+use utoipa::OpenApi;
+
+use crate::IndexerState;
+use crate::app::service::WebhookService;
+use crate::http::features::api::error::{ApiError, ApiErrorResponseBody};
+use crate::http::features::api::tags;
+use crate::http::features::api::webhook::models::{
+    CreateWebhookRequest, CreatedWebhookSubscription, WebhookDeadLetter, WebhookSubscriptionResponse,
+};
+use crate::http::urls::{ApiWebhook, ApiWebhookDeadLetterRetry, ApiWebhookDeadLetters, ApiWebhooks};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    create_webhook,
+    list_webhooks,
+    get_webhook,
+    delete_webhook,
+    list_webhook_dead_letters,
+    retry_webhook_dead_letter
+))]
+pub struct WebhookOpenApiDoc;
+
+mod models {
+    use chrono::{DateTime, Utc};
+    use lazybe::uuid::Uuid;
+    use node_storage::webhook::WebhookFilter;
+    use serde::{Deserialize, Serialize};
+    use utoipa::ToSchema;
+
+    /// Operations a subscription is notified about. An empty list matches every value.
+    #[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+    pub struct WebhookFilterModel {
+        /// DIDs, either in canonical or long form.
+        #[serde(default)]
+        pub dids: Vec<String>,
+        /// Hex-encoded VDR entry hashes.
+        #[serde(default)]
+        pub vdr_entry_hashes: Vec<String>,
+        /// Operation types, e.g. `update_did` or `update_storage_entry`.
+        #[serde(default)]
+        pub operation_types: Vec<String>,
+    }
+
+    impl From<WebhookFilterModel> for WebhookFilter {
+        fn from(value: WebhookFilterModel) -> Self {
+            Self {
+                dids: value.dids,
+                vdr_entry_hashes: value.vdr_entry_hashes,
+                operation_types: value.operation_types,
+            }
+        }
+    }
+
+    impl From<WebhookFilter> for WebhookFilterModel {
+        fn from(value: WebhookFilter) -> Self {
+            Self {
+                dids: value.dids,
+                vdr_entry_hashes: value.vdr_entry_hashes,
+                operation_types: value.operation_types,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct CreateWebhookRequest {
+        /// URL receiving a POST request for each matching operation.
+        pub url: String,
+        /// Key signing the payloads. A random secret is generated if omitted.
+        pub secret: Option<String>,
+        #[serde(default)]
+        pub filter: WebhookFilterModel,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct WebhookSubscriptionResponse {
+        pub id: Uuid,
+        pub url: String,
+        pub filter: WebhookFilterModel,
+        pub created_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct CreatedWebhookSubscription {
+        #[serde(flatten)]
+        pub subscription: WebhookSubscriptionResponse,
+        /// Key signing the payloads. It is only returned when the subscription is created.
+        pub secret: String,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+    pub struct WebhookDeadLetter {
+        pub id: Uuid,
+        /// The payload that failed to be delivered.
+        pub payload: serde_json::Value,
+        pub attempts: u32,
+        pub last_error: Option<String>,
+        pub created_at: DateTime<Utc>,
+    }
+}
+
+/// The webhook service, if the request carries the admin token as a bearer token.
+fn webhook_service<'a>(state: &'a IndexerState, headers: &HeaderMap) -> Result<&'a WebhookService, ApiError> {
+    let service = state.webhook_service.as_ref().ok_or(ApiError::NotImplemented)?;
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|i| i.to_str().ok())
+        .and_then(|i| i.strip_prefix("Bearer "));
+    service.authorize(token)?;
+    Ok(service)
+}
+
+fn to_response(subscription: WebhookSubscription) -> WebhookSubscriptionResponse {
+    WebhookSubscriptionResponse {
+        id: subscription.id,
+        url: subscription.url,
+        filter: subscription.filter.into(),
+        created_at: subscription.created_at,
+    }
+}
+
+fn to_dead_letter(delivery: WebhookDelivery) -> WebhookDeadLetter {
+    WebhookDeadLetter {
+        id: delivery.id,
+        payload: serde_json::from_str(&delivery.payload).unwrap_or(serde_json::Value::String(delivery.payload)),
+        attempts: delivery.attempts,
+        last_error: delivery.last_error,
+        created_at: delivery.created_at,
+    }
+}
+
+#[utoipa::path(
+    post,
+    summary = "Create webhook subscription",
+    description = "Subscribes a URL to the operations matching the filter. URLs targeting loopback, link-local or private network addresses are rejected unless private targets are allowed. Each matching operation is delivered as a JSON POST request signed with the subscription secret: the `X-Neoprism-Signature` header is `sha256=` followed by the hex-encoded HMAC-SHA256 of `{timestamp}.{body}`, where the timestamp is the `X-Neoprism-Timestamp` header.",
+    path = ApiWebhooks::AXUM_PATH,
+    tags = [tags::WEBHOOK],
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = CREATED, description = "The subscription was created.", body = CreatedWebhookSubscription),
+        (status = BAD_REQUEST, description = "The URL or the filter is invalid, or the URL targets a non-public address.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = UNAUTHORIZED, description = "The admin token is missing or invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_IMPLEMENTED, description = "Webhooks or the webhook admin API are not enabled.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred.", body = ApiErrorResponseBody, content_type = "application/json"),
+    )
+)]
+pub async fn create_webhook(
+    State(state): State<IndexerState>,
+    headers: HeaderMap,
+    Json(req): Json<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<CreatedWebhookSubscription>), ApiError> {
+    let service = webhook_service(&state, &headers)?;
+    let subscription = service
+        .create_subscription(req.url, req.secret, req.filter.into())
+        .await?;
+    let secret = subscription.secret.clone();
+    let created = CreatedWebhookSubscription {
+        subscription: to_response(subscription),
+        secret,
+    };
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+#[utoipa::path(
+    get,
+    summary = "List webhook subscriptions",
+    path = ApiWebhooks::AXUM_PATH,
+    tags = [tags::WEBHOOK],
+    responses(
+        (status = OK, description = "All webhook subscriptions.", body = Vec<WebhookSubscriptionResponse>),
+        (status = UNAUTHORIZED, description = "The admin token is missing or invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_IMPLEMENTED, description = "Webhooks or the webhook admin API are not enabled.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred.", body = ApiErrorResponseBody, content_type = "application/json"),
+    )
+)]
+pub async fn list_webhooks(
+    State(state): State<IndexerState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<WebhookSubscriptionResponse>>, ApiError> {
+    let service = webhook_service(&state, &headers)?;
+    let subscriptions = service
+        .get_subscriptions()
+        .await
+        .map_err(|e| ApiError::Internal { source: e })?;
+    Ok(Json(subscriptions.into_iter().map(to_response).collect()))
+}
+
+#[utoipa::path(
+    get,
+    summary = "Get webhook subscription",
+    path = ApiWebhook::AXUM_PATH,
+    tags = [tags::WEBHOOK],
+    responses(
+        (status = OK, description = "The webhook subscription.", body = WebhookSubscriptionResponse),
+        (status = NOT_FOUND, description = "The subscription does not exist.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = UNAUTHORIZED, description = "The admin token is missing or invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_IMPLEMENTED, description = "Webhooks or the webhook admin API are not enabled.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(("id" = Uuid, Path, description = "The subscription id."))
+)]
+pub async fn get_webhook(
+    Path(id): Path<Uuid>,
+    State(state): State<IndexerState>,
+    headers: HeaderMap,
+) -> Result<Json<WebhookSubscriptionResponse>, ApiError> {
+    let service = webhook_service(&state, &headers)?;
+    let subscription = service.get_subscription(&id).await?;
+    Ok(Json(to_response(subscription)))
+}
+
+#[utoipa::path(
+    delete,
+    summary = "Delete webhook subscription",
+    description = "Deletes the subscription together with its pending and dead-lettered deliveries.",
+    path = ApiWebhook::AXUM_PATH,
+    tags = [tags::WEBHOOK],
+    responses(
+        (status = NO_CONTENT, description = "The subscription was deleted."),
+        (status = NOT_FOUND, description = "The subscription does not exist.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = UNAUTHORIZED, description = "The admin token is missing or invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_IMPLEMENTED, description = "Webhooks or the webhook admin API are not enabled.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(("id" = Uuid, Path, description = "The subscription id."))
+)]
+pub async fn delete_webhook(
+    Path(id): Path<Uuid>,
+    State(state): State<IndexerState>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ApiError> {
+    let service = webhook_service(&state, &headers)?;
+    service.delete_subscription(&id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    summary = "List dead-lettered webhook deliveries",
+    description = "Returns the deliveries of the subscription that failed on every attempt.",
+    path = ApiWebhookDeadLetters::AXUM_PATH,
+    tags = [tags::WEBHOOK],
+    responses(
+        (status = OK, description = "The dead-lettered deliveries, oldest first.", body = Vec<WebhookDeadLetter>),
+        (status = NOT_FOUND, description = "The subscription does not exist.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = UNAUTHORIZED, description = "The admin token is missing or invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_IMPLEMENTED, description = "Webhooks or the webhook admin API are not enabled.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(("id" = Uuid, Path, description = "The subscription id."))
+)]
+pub async fn list_webhook_dead_letters(
+    Path(id): Path<Uuid>,
+    State(state): State<IndexerState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<WebhookDeadLetter>>, ApiError> {
+    let service = webhook_service(&state, &headers)?;
+    let deliveries = service.get_dead_letters(&id).await?;
+    Ok(Json(deliveries.into_iter().map(to_dead_letter).collect()))
+}
+
+#[utoipa::path(
+    post,
+    summary = "Retry dead-lettered webhook delivery",
+    description = "Moves the delivery out of the dead-letter list and schedules it for immediate redelivery with its attempts reset.",
+    path = ApiWebhookDeadLetterRetry::AXUM_PATH,
+    tags = [tags::WEBHOOK],
+    responses(
+        (status = ACCEPTED, description = "The delivery was scheduled."),
+        (status = NOT_FOUND, description = "The subscription or the dead-lettered delivery does not exist.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = UNAUTHORIZED, description = "The admin token is missing or invalid.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = NOT_IMPLEMENTED, description = "Webhooks or the webhook admin API are not enabled.", body = ApiErrorResponseBody, content_type = "application/json"),
+        (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred.", body = ApiErrorResponseBody, content_type = "application/json"),
+    ),
+    params(
+        ("id" = Uuid, Path, description = "The subscription id."),
+        ("delivery_id" = Uuid, Path, description = "The dead-lettered delivery id.")
+    )
+)]
+pub async fn retry_webhook_dead_letter(
+    Path((id, delivery_id)): Path<(Uuid, Uuid)>,
+    State(state): State<IndexerState>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ApiError> {
+    let service = webhook_service(&state, &headers)?;
+    service.retry_dead_letter(&id, &delivery_id).await?;
+    Ok(StatusCode::ACCEPTED)
+}
@@ -35,7 +35,7 @@ pub fn router(assets_dir: &Path, mode: RunMode, port: u16, external_url: Option<
         .merge(ui_resolver::router());
 
     let home_router = match mode {
-        RunMode::Submitter => Router::new().route(
+        RunMode::Submitter | RunMode::Proxy => Router::new().route(
             urls::Home::AXUM_PATH,
             get(Redirect::temporary(&urls::OpenApi::new_uri())),
         ),
@@ -10,6 +10,7 @@ typed_uri!(AssetStyleSheet, "assets" / "styles.css");
 // misc
 typed_uri!(Home, "");
 typed_uri!(OpenApi, "openapi");
+typed_uri!(Metrics, "metrics");
 
 // UI resolver
 typed_uri!(Resolver, "resolver" ? Option<DidQuery>);
@@ -21,7 +22,9 @@ typed_uri!(ExplorerDidList, "explorer" / "did-list" ? Option<PageQuery>);
 
 // API system
 typed_uri!(ApiHealth, "api" / "_system" / "health");
+typed_uri!(ApiReady, "api" / "_system" / "ready");
 typed_uri!(ApiAppMeta, "api" / "_system" / "metadata");
+typed_uri!(ApiDecode, "api" / "decode");
 
 // API submitter
 typed_uri!(
@@ -32,9 +35,27 @@ typed_uri!(ApiSubmissionsObjects, "api" / "submissions" / "objects");
 
 // API indexer
 typed_uri!(ApiDid, "api" / "dids" / (did: String));
+typed_uri!(ApiDidsResolve, "api" / "dids" / "resolve");
 typed_uri!(ApiDidProtobuf, "api" / "dids" / (did: String) / "protobuf");
+typed_uri!(ApiDidOperations, "api" / "dids" / (did: String) / "operations");
+typed_uri!(ApiDidProof, "api" / "dids" / (did: String) / "proof");
+typed_uri!(ApiDidVdrEntries, "api" / "dids" / (did: String) / "vdr-entries");
 typed_uri!(ApiIndexerStats, "api" / "indexer-stats");
 typed_uri!(ApiVdrBlob, "api" / "vdr-data" / (entry_hash: String));
 typed_uri!(ApiVdrMetadata, "api" / "vdr-data" / (entry_hash: String) / "metadata");
+typed_uri!(ApiVdrStatusList, "api" / "vdr-data" / (entry_hash: String) / "status-list");
+typed_uri!(ApiVdrHistory, "api" / "vdr-data" / (entry_hash: String) / "history");
 typed_uri!(ApiTransaction, "api" / "transactions" / (tx_id: String));
 typed_uri!(ApiOperation, "api" / "operations" / (operation_id: String));
+typed_uri!(ApiVerifyJws, "api" / "verify" / "jws");
+typed_uri!(ApiEvents, "api" / "events");
+typed_uri!(ApiEventsWs, "api" / "events" / "ws");
+
+// API webhook
+typed_uri!(ApiWebhooks, "api" / "webhooks");
+typed_uri!(ApiWebhook, "api" / "webhooks" / (id: String));
+typed_uri!(ApiWebhookDeadLetters, "api" / "webhooks" / (id: String) / "dead-letters");
+typed_uri!(
+    ApiWebhookDeadLetterRetry,
+    "api" / "webhooks" / (id: String) / "dead-letters" / (delivery_id: String) / "retry"
+);
@@ -9,20 +9,25 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Context;
-use app::service::PrismDidService;
+use app::cache_policy::DidCachePolicy;
+use app::service::{DidEventFeed, DidStateCache, IpfsGateway, PrismDidService, WebhookService};
 use axum::Router;
+use chrono::{DateTime, Utc};
 use clap::Parser;
 use cli::Cli;
 use dirs::data_dir;
-use identus_did_prism::dlt::{DltCursor, NetworkIdentifier};
+use identus_did_core::{DidJwkResolver, DidKeyResolver, DidResolver, DidResolverRouter};
+use identus_did_prism::did::{CanonicalPrismDid, PrismDid};
+use identus_did_prism::dlt::{DltChainTip, DltCursor, NetworkIdentifier};
 use identus_did_prism_indexer::DltSource;
 use identus_did_prism_indexer::dlt::blockfrost::{BlockfrostConfig, BlockfrostSource};
 use identus_did_prism_indexer::dlt::dbsync::DbSyncSource;
 use identus_did_prism_indexer::dlt::oura::OuraN2NSource;
-use identus_did_prism_submitter::DltSink;
 use identus_did_prism_submitter::dlt::cardano_wallet::CardanoWalletSink;
-use identus_did_resolver_http::DidResolverStateDyn;
-use node_storage::{PostgresDb, SqliteDb, StorageBackend};
+use identus_did_prism_submitter::{DltSink, InstrumentedDltSink};
+use identus_did_resolver_http::{DidResolverStateDyn, PrismProxyResolver};
+use node_storage::{InstrumentedDb, PostgresDb, SqliteDb, StorageBackend};
+use tokio::sync::broadcast;
 use tokio::task::JoinSet;
 use tower::ServiceBuilder;
 use tower_http::cors::CorsLayer;
@@ -30,34 +35,36 @@ use tower_http::trace::TraceLayer;
 
 use crate::app::worker::{DltIndexWorker, DltSyncWorker};
 use crate::cli::{
-    DbArgs, DevArgs, DltSinkArgs, DltSinkType, DltSourceArgs, DltSourceType, IndexerArgs, ServerArgs, StandaloneArgs,
-    SubmitterArgs,
+    DbArgs, DevArgs, DidDocumentArgs, DltSinkArgs, DltSinkType, DltSourceArgs, DltSourceType, IndexerArgs, ProxyArgs,
+    ReadinessArgs, ResolutionCacheArgs, ServerArgs, StandaloneArgs, SubmitterArgs, WebhookArgs,
 };
 
 mod app;
 mod cli;
 mod http;
+mod telemetry;
 
-/// Return type of [`init_memory_ledger`]: cursor receiver, sink, and worker set.
+/// Return type of [`init_memory_ledger`]: source state, sink, and worker set.
 type MemoryLedger = (
-    tokio::sync::watch::Receiver<Option<DltCursor>>,
+    DltSourceState,
     Arc<dyn DltSink + Send + Sync + 'static>,
     JoinSet<anyhow::Result<()>>,
 );
 
-/// Return type of [`init_dlt_source`]: optional cursor receiver and worker set.
-type DltSourceOutput = (
-    Option<tokio::sync::watch::Receiver<Option<DltCursor>>>,
-    JoinSet<anyhow::Result<()>>,
-);
+/// Return type of [`init_dlt_source`]: optional source state and worker set.
+type DltSourceOutput = (Option<DltSourceState>, JoinSet<anyhow::Result<()>>);
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Number of indexed DID notifications buffered for each subscriber before it falls behind.
+const INDEXED_DID_CHANNEL_CAPACITY: usize = 1024;
+
 #[derive(Clone, Copy)]
 enum RunMode {
     Indexer,
     Submitter,
     Standalone,
+    Proxy,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -71,21 +78,37 @@ type SharedStorage = Arc<dyn StorageBackend>;
 #[derive(Clone)]
 struct AppState {
     run_mode: RunMode,
+    readiness: Option<ReadinessState>,
 }
 
 #[derive(Clone)]
 struct IndexerState {
     prism_did_service: PrismDidService,
+    cache_policy: DidCachePolicy,
+    did_event_feed: DidEventFeed,
+    webhook_service: Option<WebhookService>,
+    ipfs_gateway: Option<IpfsGateway>,
+    /// External URL of the node, used to build absolute URLs of the resources it serves.
+    external_url: Option<String>,
 }
 
 impl IndexerState {
     fn to_did_resolver_state_dyn(&self) -> DidResolverStateDyn {
         DidResolverStateDyn {
-            resolver: Arc::new(self.prism_did_service.clone()),
+            resolver: Arc::new(did_resolver_router(self.prism_did_service.clone())),
+            cache_policy: Some(Arc::new(self.cache_policy.clone())),
         }
     }
 }
 
+/// Resolve did:prism DIDs with the given resolver, and did:key and did:jwk DIDs locally.
+fn did_resolver_router(prism_resolver: impl DidResolver + Send + Sync + 'static) -> DidResolverRouter {
+    DidResolverRouter::new()
+        .with_resolver(PrismDid::METHOD, prism_resolver)
+        .with_resolver(DidKeyResolver::METHOD, DidKeyResolver)
+        .with_resolver(DidJwkResolver::METHOD, DidJwkResolver)
+}
+
 #[derive(Clone)]
 struct IndexerUiState {
     prism_did_service: PrismDidService,
@@ -97,21 +120,66 @@ struct SubmitterState {
     dlt_sink: Arc<dyn DltSink + Send + Sync>,
 }
 
+impl SubmitterState {
+    fn new(dlt_sink: Arc<dyn DltSink + Send + Sync>) -> Self {
+        Self {
+            dlt_sink: Arc::new(InstrumentedDltSink::new(dlt_sink)),
+        }
+    }
+}
+
 #[derive(Clone)]
 struct DltSourceState {
     cursor_rx: tokio::sync::watch::Receiver<Option<DltCursor>>,
+    chain_tip_rx: tokio::sync::watch::Receiver<Option<DltChainTip>>,
+    last_error_rx: tokio::sync::watch::Receiver<Option<DateTime<Utc>>>,
     network: NetworkIdentifier,
 }
 
+impl DltSourceState {
+    fn new<Src: DltSource>(sync_worker: &DltSyncWorker<Src>, network: NetworkIdentifier) -> Self {
+        Self {
+            cursor_rx: sync_worker.sync_cursor(),
+            chain_tip_rx: sync_worker.chain_tip(),
+            last_error_rx: sync_worker.last_error(),
+            network,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ReadinessState {
+    db: SharedStorage,
+    dlt_source: Option<DltSourceState>,
+    max_lag_slots: u64,
+    max_lag_seconds: u64,
+    error_window: Duration,
+}
+
+impl ReadinessState {
+    fn new(db: SharedStorage, dlt_source: Option<DltSourceState>, args: &ReadinessArgs) -> Self {
+        Self {
+            db,
+            dlt_source,
+            max_lag_slots: args.readiness_max_lag_slots,
+            max_lag_seconds: args.readiness_max_lag_seconds,
+            error_window: args.readiness_error_window,
+        }
+    }
+}
+
 pub async fn run_command() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    let tracing_guard = telemetry::init_tracing(&cli.telemetry)?;
     match cli.command {
         cli::Command::Indexer(args) => run_indexer_command(args).await?,
         cli::Command::Submitter(args) => run_submitter_command(args).await?,
         cli::Command::Standalone(args) => run_standalone_command(args).await?,
         cli::Command::Dev(args) => run_dev_command(args).await?,
+        cli::Command::Proxy(args) => run_proxy_command(args).await?,
         cli::Command::GenerateOpenapi(args) => generate_openapi(args)?,
     };
+    tracing_guard.shutdown()?;
     Ok(())
 }
 
@@ -130,20 +198,39 @@ fn generate_openapi(args: crate::cli::GenerateOpenApiArgs) -> anyhow::Result<()>
 async fn run_indexer_command(args: IndexerArgs) -> anyhow::Result<()> {
     let network = args.dlt_source.network.cardano_network.clone().into();
     let db = init_database(&args.db, Some(&network), &default_base_dir()).await;
-    let (cursor_rx, mut handles) = init_dlt_source(&args.dlt_source, &network, db.clone()).await;
+    let (indexed_did_tx, indexed_did_rx) = broadcast::channel(INDEXED_DID_CHANNEL_CAPACITY);
+    let did_event_rx = indexed_did_tx.subscribe();
+    let (dlt_source, mut handles) = init_dlt_source(&args.dlt_source, &network, db.clone(), indexed_did_tx).await;
+    let prism_did_service = init_prism_did_service(
+        db.clone(),
+        &args.resolution_cache,
+        &args.did_document,
+        indexed_did_rx,
+        &mut handles,
+    );
+    let did_event_feed = init_did_event_feed(db.clone(), did_event_rx, &mut handles);
+    let webhook_service = init_webhook_service(db.clone(), &args.webhook, &did_event_feed, &mut handles);
+    let ipfs_gateway = IpfsGateway::new(&args.ipfs)?;
     let app_state = AppState {
         run_mode: RunMode::Indexer,
+        readiness: Some(ReadinessState::new(db.clone(), dlt_source.clone(), &args.readiness)),
     };
     let indexer_state = IndexerState {
-        prism_did_service: PrismDidService::new(db.clone()),
+        prism_did_service: prism_did_service.clone(),
+        cache_policy: DidCachePolicy::new(&args.cache_control),
+        did_event_feed,
+        webhook_service,
+        ipfs_gateway,
+        external_url: args.server.external_url.clone(),
     };
     let indexer_ui_state = IndexerUiState {
-        prism_did_service: PrismDidService::new(db.clone()),
-        dlt_source: cursor_rx.map(|cursor_rx| DltSourceState { cursor_rx, network }),
+        prism_did_service,
+        dlt_source,
     };
     run_server(
         app_state,
         Some(indexer_ui_state),
+        Some(indexer_state.to_did_resolver_state_dyn()),
         Some(indexer_state),
         None,
         &args.server,
@@ -158,30 +245,50 @@ async fn run_submitter_command(args: SubmitterArgs) -> anyhow::Result<()> {
     let dlt_sink = init_dlt_sink(&args.dlt_sink, &network)?;
     let app_state = AppState {
         run_mode: RunMode::Submitter,
+        readiness: None,
     };
-    let submitter_state = SubmitterState { dlt_sink };
-    run_server(app_state, None, None, Some(submitter_state), &args.server).await
+    let submitter_state = SubmitterState::new(dlt_sink);
+    run_server(app_state, None, None, None, Some(submitter_state), &args.server).await
 }
 
 async fn run_standalone_command(args: StandaloneArgs) -> anyhow::Result<()> {
     let network = args.dlt_source.network.cardano_network.clone().into();
     let db = init_database(&args.db, Some(&network), &default_base_dir()).await;
-    let (cursor_rx, mut handles) = init_dlt_source(&args.dlt_source, &network, db.clone()).await;
+    let (indexed_did_tx, indexed_did_rx) = broadcast::channel(INDEXED_DID_CHANNEL_CAPACITY);
+    let did_event_rx = indexed_did_tx.subscribe();
+    let (dlt_source, mut handles) = init_dlt_source(&args.dlt_source, &network, db.clone(), indexed_did_tx).await;
+    let prism_did_service = init_prism_did_service(
+        db.clone(),
+        &args.resolution_cache,
+        &args.did_document,
+        indexed_did_rx,
+        &mut handles,
+    );
+    let did_event_feed = init_did_event_feed(db.clone(), did_event_rx, &mut handles);
+    let webhook_service = init_webhook_service(db.clone(), &args.webhook, &did_event_feed, &mut handles);
+    let ipfs_gateway = IpfsGateway::new(&args.ipfs)?;
     let dlt_sink = init_dlt_sink(&args.dlt_sink, &network)?;
     let app_state = AppState {
         run_mode: RunMode::Standalone,
+        readiness: Some(ReadinessState::new(db.clone(), dlt_source.clone(), &args.readiness)),
     };
     let indexer_state = IndexerState {
-        prism_did_service: PrismDidService::new(db.clone()),
+        prism_did_service: prism_did_service.clone(),
+        cache_policy: DidCachePolicy::new(&args.cache_control),
+        did_event_feed,
+        webhook_service,
+        ipfs_gateway,
+        external_url: args.server.external_url.clone(),
     };
     let indexer_ui_state = IndexerUiState {
-        prism_did_service: PrismDidService::new(db.clone()),
-        dlt_source: cursor_rx.map(|cursor_rx| DltSourceState { cursor_rx, network }),
+        prism_did_service,
+        dlt_source,
     };
-    let submitter_state = SubmitterState { dlt_sink };
+    let submitter_state = SubmitterState::new(dlt_sink);
     run_server(
         app_state,
         Some(indexer_ui_state),
+        Some(indexer_state.to_did_resolver_state_dyn()),
         Some(indexer_state),
         Some(submitter_state),
         &args.server,
@@ -193,24 +300,44 @@ async fn run_standalone_command(args: StandaloneArgs) -> anyhow::Result<()> {
 
 async fn run_dev_command(args: DevArgs) -> anyhow::Result<()> {
     let db = init_database(&args.db, Some(&NetworkIdentifier::Custom), &default_base_dir()).await;
-    let (cursor_rx, dlt_sink, mut handles) = init_memory_ledger(db.clone());
+    let (indexed_did_tx, indexed_did_rx) = broadcast::channel(INDEXED_DID_CHANNEL_CAPACITY);
+    let did_event_rx = indexed_did_tx.subscribe();
+    let (dlt_source, dlt_sink, mut handles) = init_memory_ledger(db.clone(), indexed_did_tx);
+    let prism_did_service = init_prism_did_service(
+        db.clone(),
+        &args.resolution_cache,
+        &args.did_document,
+        indexed_did_rx,
+        &mut handles,
+    );
+    let did_event_feed = init_did_event_feed(db.clone(), did_event_rx, &mut handles);
+    let webhook_service = init_webhook_service(db.clone(), &args.webhook, &did_event_feed, &mut handles);
+    let ipfs_gateway = IpfsGateway::new(&args.ipfs)?;
     let app_state = AppState {
         run_mode: RunMode::Standalone,
+        readiness: Some(ReadinessState::new(
+            db.clone(),
+            Some(dlt_source.clone()),
+            &args.readiness,
+        )),
     };
     let indexer_state = IndexerState {
-        prism_did_service: PrismDidService::new(db.clone()),
+        prism_did_service: prism_did_service.clone(),
+        cache_policy: DidCachePolicy::new(&args.cache_control),
+        did_event_feed,
+        webhook_service,
+        ipfs_gateway,
+        external_url: args.server.external_url.clone(),
     };
     let indexer_ui_state = IndexerUiState {
-        prism_did_service: PrismDidService::new(db.clone()),
-        dlt_source: Some(DltSourceState {
-            cursor_rx,
-            network: NetworkIdentifier::Custom,
-        }),
+        prism_did_service,
+        dlt_source: Some(dlt_source),
     };
-    let submitter_state = SubmitterState { dlt_sink };
+    let submitter_state = SubmitterState::new(dlt_sink);
     run_server(
         app_state,
         Some(indexer_ui_state),
+        Some(indexer_state.to_did_resolver_state_dyn()),
         Some(indexer_state),
         Some(submitter_state),
         &args.server,
@@ -220,15 +347,33 @@ async fn run_dev_command(args: DevArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn run_proxy_command(args: ProxyArgs) -> anyhow::Result<()> {
+    let client = reqwest::Client::builder()
+        .timeout(args.upstream.upstream_timeout)
+        .build()?;
+    let prism_resolver = PrismProxyResolver::new(client, args.upstream.upstream_urls)
+        .with_verification_method_encoding(args.did_document.verification_method_encoding.into());
+    let app_state = AppState {
+        run_mode: RunMode::Proxy,
+        readiness: None,
+    };
+    let did_resolver_state = DidResolverStateDyn {
+        resolver: Arc::new(did_resolver_router(prism_resolver)),
+        cache_policy: Some(Arc::new(DidCachePolicy::new(&args.cache_control))),
+    };
+    run_server(app_state, None, Some(did_resolver_state), None, None, &args.server).await
+}
+
 async fn run_server(
     app_state: AppState,
     indexer_ui_state: Option<IndexerUiState>,
+    did_resolver_state: Option<DidResolverStateDyn>,
     indexer_state: Option<IndexerState>,
     submitter_state: Option<SubmitterState>,
     server_args: &ServerArgs,
 ) -> anyhow::Result<()> {
     let layer = ServiceBuilder::new()
-        .layer(TraceLayer::new_for_http())
+        .layer(TraceLayer::new_for_http().make_span_with(telemetry::make_request_span))
         .option_layer(Some(CorsLayer::permissive()).filter(|_| server_args.cors_enabled));
     let routers = http::router(
         &server_args.assets_path,
@@ -239,9 +384,8 @@ async fn run_server(
     let router = Router::new()
         .merge(routers.app_router.with_state(app_state))
         .merge(
-            indexer_state
-                .as_ref()
-                .map(|s| routers.did_resolver_router.with_state(s.to_did_resolver_state_dyn()))
+            did_resolver_state
+                .map(|s| routers.did_resolver_router.with_state(s))
                 .unwrap_or_default(),
         )
         .merge(
@@ -302,10 +446,11 @@ async fn shutdown_signal() {
 async fn init_database(db_args: &DbArgs, network_hint: Option<&NetworkIdentifier>, base: &Path) -> SharedStorage {
     let db_config = resolve_db_config(db_args, network_hint, base);
 
-    match db_config.backend {
-        DbBackend::Postgres => Arc::new(init_postgres_database(&db_config.url, db_args).await),
+    let db = match db_config.backend {
+        DbBackend::Postgres => init_postgres_database(&db_config.url, db_args).await,
         DbBackend::Sqlite => init_sqlite_database(&db_config.url, db_args).await,
-    }
+    };
+    Arc::new(InstrumentedDb::new(db))
 }
 
 async fn init_postgres_database(db_url: &str, db_args: &DbArgs) -> SharedStorage {
@@ -324,33 +469,80 @@ async fn init_postgres_database(db_url: &str, db_args: &DbArgs) -> SharedStorage
     Arc::new(db)
 }
 
-fn init_memory_ledger(db: SharedStorage) -> MemoryLedger {
+/// Create the DID service, with its resolution cache invalidated by DIDs received from `indexed_did_rx`.
+fn init_prism_did_service(
+    db: SharedStorage,
+    cache_args: &ResolutionCacheArgs,
+    did_document_args: &DidDocumentArgs,
+    indexed_did_rx: broadcast::Receiver<CanonicalPrismDid>,
+    handles: &mut JoinSet<anyhow::Result<()>>,
+) -> PrismDidService {
+    let cache = DidStateCache::new(cache_args);
+    if let Some(cache) = &cache {
+        handles.spawn(cache.clone().run_invalidation(indexed_did_rx));
+    }
+    PrismDidService::new(db, cache)
+        .with_verification_method_encoding(did_document_args.verification_method_encoding.clone().into())
+}
+
+/// Create the DID event feed, publishing events of operations indexed for DIDs received from `indexed_did_rx`.
+fn init_did_event_feed(
+    db: SharedStorage,
+    indexed_did_rx: broadcast::Receiver<CanonicalPrismDid>,
+    handles: &mut JoinSet<anyhow::Result<()>>,
+) -> DidEventFeed {
+    let did_event_feed = DidEventFeed::new(db);
+    handles.spawn(did_event_feed.clone().run(indexed_did_rx));
+    did_event_feed
+}
+
+/// Create the webhook service if enabled, with workers dispatching and delivering the events of `did_event_feed`.
+fn init_webhook_service(
+    db: SharedStorage,
+    webhook_args: &WebhookArgs,
+    did_event_feed: &DidEventFeed,
+    handles: &mut JoinSet<anyhow::Result<()>>,
+) -> Option<WebhookService> {
+    let webhook_service = WebhookService::new(db, webhook_args)?;
+    handles.spawn(webhook_service.clone().run_dispatch(did_event_feed.clone()));
+    handles.spawn(webhook_service.clone().run_delivery());
+    Some(webhook_service)
+}
+
+fn init_memory_ledger(db: SharedStorage, indexed_did_tx: broadcast::Sender<CanonicalPrismDid>) -> MemoryLedger {
     let (dlt_source, dlt_sink) = identus_did_prism_ledger::in_memory::create_ledger();
     let sync_worker = DltSyncWorker::new(db.clone(), dlt_source);
-    let index_worker = DltIndexWorker::new(db.clone(), Duration::from_secs(1));
-    let cursor_rx = sync_worker.sync_cursor();
+    let index_worker = DltIndexWorker::new(db.clone(), Duration::from_secs(1), indexed_did_tx);
+    let dlt_source = DltSourceState::new(&sync_worker, NetworkIdentifier::Custom);
     let mut handles = JoinSet::new();
     handles.spawn(sync_worker.run());
     handles.spawn(index_worker.run());
-    (cursor_rx, dlt_sink, handles)
+    (dlt_source, dlt_sink, handles)
 }
 
 /// Helper to spawn sync and index workers from a DLT source.
 fn spawn_dlt_workers<Src: DltSource + Send + 'static>(
     db: SharedStorage,
     source: Src,
+    network: &NetworkIdentifier,
     index_interval: Duration,
+    indexed_did_tx: broadcast::Sender<CanonicalPrismDid>,
 ) -> DltSourceOutput {
     let sync_worker = DltSyncWorker::new(db.clone(), source);
-    let index_worker = DltIndexWorker::new(db, index_interval);
-    let cursor_rx = sync_worker.sync_cursor();
+    let index_worker = DltIndexWorker::new(db, index_interval, indexed_did_tx);
+    let dlt_source = DltSourceState::new(&sync_worker, *network);
     let mut handles = JoinSet::new();
     handles.spawn(sync_worker.run());
     handles.spawn(index_worker.run());
-    (Some(cursor_rx), handles)
+    (Some(dlt_source), handles)
 }
 
-async fn init_dlt_source(dlt_args: &DltSourceArgs, network: &NetworkIdentifier, db: SharedStorage) -> DltSourceOutput {
+async fn init_dlt_source(
+    dlt_args: &DltSourceArgs,
+    network: &NetworkIdentifier,
+    db: SharedStorage,
+    indexed_did_tx: broadcast::Sender<CanonicalPrismDid>,
+) -> DltSourceOutput {
     match dlt_args.dlt_source_type {
         DltSourceType::Oura => {
             let address = dlt_args
@@ -373,7 +565,7 @@ async fn init_dlt_source(dlt_args: &DltSourceArgs, network: &NetworkIdentifier,
             .await
             .expect("Failed to create DLT source");
 
-            spawn_dlt_workers(db, source, dlt_args.index_interval)
+            spawn_dlt_workers(db, source, network, dlt_args.index_interval, indexed_did_tx)
         }
         DltSourceType::Dbsync => {
             let dbsync_url = dlt_args
@@ -392,7 +584,7 @@ async fn init_dlt_source(dlt_args: &DltSourceArgs, network: &NetworkIdentifier,
             .await
             .expect("Failed to create DLT source");
 
-            spawn_dlt_workers(db, source, dlt_args.index_interval)
+            spawn_dlt_workers(db, source, network, dlt_args.index_interval, indexed_did_tx)
         }
         DltSourceType::Blockfrost => {
             let api_key = dlt_args
@@ -416,7 +608,7 @@ async fn init_dlt_source(dlt_args: &DltSourceArgs, network: &NetworkIdentifier,
             .await
             .expect("Failed to create Blockfrost source");
 
-            spawn_dlt_workers(db, source, dlt_args.index_interval)
+            spawn_dlt_workers(db, source, network, dlt_args.index_interval, indexed_did_tx)
         }
     }
 }
@@ -960,6 +1152,17 @@ mod tests {
         assert!(parsed.get("paths").is_some());
     }
 
+    #[test]
+    fn generate_openapi_proxy_mode() {
+        let oas = http::build_openapi(&RunMode::Proxy, 8080, None);
+        let json = oas.to_pretty_json().unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let paths = parsed.get("paths").unwrap().as_object().unwrap();
+        assert!(paths.contains_key("/api/dids/{did}"));
+        assert!(!paths.contains_key("/api/dids/{did}/operations"));
+        assert!(!paths.contains_key("/api/dids/{did}/proof"));
+    }
+
     #[test]
     fn generate_openapi_with_external_url() {
         let oas = http::build_openapi(&RunMode::Standalone, 9090, Some("https://example.com"));
@@ -1057,10 +1260,10 @@ mod tests {
             skip_migration: true,
         };
         let db = init_database(&db_args, Some(&NetworkIdentifier::Custom), dir.path()).await;
-        let (cursor_rx, _dlt_sink, mut handles) = init_memory_ledger(db);
+        let (dlt_source, _dlt_sink, mut handles) = init_memory_ledger(db, broadcast::channel(1).0);
 
         // Cursor should start as None
-        let cursor = cursor_rx.borrow().clone();
+        let cursor = dlt_source.cursor_rx.borrow().clone();
         assert!(cursor.is_none(), "cursor should start as None");
 
         // Workers should be spawned (JoinSet should have tasks)
@@ -1078,7 +1281,7 @@ mod tests {
             skip_migration: true,
         };
         let db = init_database(&db_args, Some(&NetworkIdentifier::Custom), dir.path()).await;
-        let (_cursor_rx, dlt_sink, mut handles) = init_memory_ledger(db);
+        let (_dlt_source, dlt_sink, mut handles) = init_memory_ledger(db, broadcast::channel(1).0);
 
         // The DLT sink should not be None (it's Arc<dyn DltSink>)
         // Verify it can be cloned
@@ -1102,6 +1305,7 @@ mod tests {
         let _indexer = RunMode::Indexer;
         let _submitter = RunMode::Submitter;
         let _standalone = RunMode::Standalone;
+        let _proxy = RunMode::Proxy;
     }
 
     #[test]
@@ -0,0 +1,114 @@
+use axum::http::Request;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_http::HeaderExtractor;
+use opentelemetry_otlp::{Protocol, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+use crate::cli::{OtlpProtocol, TelemetryArgs};
+
+/// Flushes pending spans to the OTLP collector on shutdown.
+pub struct TracingGuard {
+    tracer_provider: Option<SdkTracerProvider>,
+}
+
+impl TracingGuard {
+    /// Flush pending spans and shut the OTLP exporter down.
+    pub fn shutdown(mut self) -> anyhow::Result<()> {
+        if let Some(tracer_provider) = self.tracer_provider.take() {
+            tracer_provider
+                .shutdown()
+                .map_err(|e| anyhow::anyhow!("failed to shutdown tracer provider: {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        // Only reached when the guard is not shut down explicitly, e.g. when the command fails
+        if let Some(tracer_provider) = self.tracer_provider.take()
+            && let Err(e) = tracer_provider.shutdown()
+        {
+            tracing::error!("failed to shutdown tracer provider: {e}");
+        }
+    }
+}
+
+/// Install the global tracing subscriber.
+///
+/// Logs are always printed to stdout, at the `info` level unless `RUST_LOG` says otherwise.
+/// Spans are additionally exported to an OTLP collector when an endpoint is configured.
+pub fn init_tracing(args: &TelemetryArgs) -> anyhow::Result<TracingGuard> {
+    let tracer_provider = args
+        .otlp_endpoint
+        .as_deref()
+        .map(|endpoint| init_tracer_provider(endpoint, &args.otlp_protocol, &args.otlp_service_name))
+        .transpose()?;
+
+    let otel_layer = tracer_provider
+        .as_ref()
+        .map(|provider| tracing_opentelemetry::layer().with_tracer(provider.tracer("neoprism")));
+
+    let env_filter = EnvFilter::builder()
+        .with_default_directive(LevelFilter::INFO.into())
+        .from_env_lossy();
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(tracing_subscriber::fmt::layer())
+        .with(otel_layer)
+        .init();
+
+    if let Some(endpoint) = args.otlp_endpoint.as_deref() {
+        tracing::info!("Exporting traces to OTLP collector at {}", endpoint);
+    }
+
+    Ok(TracingGuard { tracer_provider })
+}
+
+fn init_tracer_provider(
+    endpoint: &str,
+    protocol: &OtlpProtocol,
+    service_name: &str,
+) -> anyhow::Result<SdkTracerProvider> {
+    let exporter = match protocol {
+        OtlpProtocol::Grpc => SpanExporter::builder().with_tonic().with_endpoint(endpoint).build()?,
+        OtlpProtocol::Http => SpanExporter::builder()
+            .with_http()
+            .with_protocol(Protocol::HttpBinary)
+            .with_endpoint(endpoint)
+            .build()?,
+    };
+    let resource = Resource::builder().with_service_name(service_name.to_string()).build();
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource)
+        .build();
+
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+    opentelemetry::global::set_tracer_provider(tracer_provider.clone());
+    Ok(tracer_provider)
+}
+
+/// Create the span of an incoming HTTP request, continuing the trace from the
+/// W3C `traceparent` header when the caller provides one.
+pub fn make_request_span<B>(request: &Request<B>) -> Span {
+    let parent_context = opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+    let span = tracing::info_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        version = ?request.version(),
+    );
+    span.set_parent(parent_context);
+    span
+}
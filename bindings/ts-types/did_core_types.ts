@@ -14,6 +14,6 @@ export type StringOrMap = string | Record<string, any>;
 
 export type Uri = string;
 
-export type VerificationMethod = { id: string, type: string, controller: string, publicKeyJwk: Record<string, any> | null, };
+export type VerificationMethod = { id: string, type: string, controller: string, publicKeyJwk?: Record<string, any>, publicKeyMultibase?: string, };
 
 export type VerificationMethodOrRef = VerificationMethod | string;
@@ -1,10 +1,24 @@
+use std::fmt::Debug;
+
+use ed25519_dalek::Signer;
+use subtle::ConstantTimeEq;
+
 use super::{EncodeArray, EncodeVec, Error, Verifiable};
 use crate::base64::Base64UrlStrNoPad;
-use crate::jwk::{EncodeJwk, Jwk};
+#[cfg(feature = "x25519")]
+use crate::crypto::x25519::{X25519PrivateKey, X25519PublicKey};
+use crate::jwk::{self, DecodeJwk, EncodeJwk, Jwk};
+use crate::multibase::{EncodeMultikey, Multicodec, Multikey};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Ed25519PublicKey(ed25519_dalek::VerifyingKey);
 
+/// An Ed25519 private key.
+///
+/// The key is zeroized on drop and compared in constant time.
+#[derive(Clone)]
+pub struct Ed25519PrivateKey(ed25519_dalek::SigningKey);
+
 impl Ed25519PublicKey {
     pub fn from_slice(slice: &[u8]) -> Result<Ed25519PublicKey, Error> {
         let Some((key, _)) = slice.split_first_chunk::<32>() else {
@@ -17,6 +31,54 @@ impl Ed25519PublicKey {
         let key = ed25519_dalek::VerifyingKey::from_bytes(key)?;
         Ok(Ed25519PublicKey(key))
     }
+
+    /// Convert to the X25519 public key of the same key pair, from its Montgomery form.
+    #[cfg(feature = "x25519")]
+    pub fn to_x25519_public_key(&self) -> X25519PublicKey {
+        X25519PublicKey::from(self.0.to_montgomery().to_bytes())
+    }
+}
+
+impl Ed25519PrivateKey {
+    pub fn from_slice(slice: &[u8]) -> Result<Self, Error> {
+        let Ok(key) = <&[u8; 32]>::try_from(slice) else {
+            Err(Error::InvalidKeySize {
+                expected: 32,
+                actual: slice.len(),
+                key_type: std::any::type_name::<Ed25519PrivateKey>(),
+            })?
+        };
+        Ok(Self(ed25519_dalek::SigningKey::from_bytes(key)))
+    }
+
+    pub fn to_public_key(&self) -> Ed25519PublicKey {
+        Ed25519PublicKey(self.0.verifying_key())
+    }
+
+    /// Sign the message, returning the 64-byte signature.
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        self.0.sign(message).to_bytes().to_vec()
+    }
+
+    /// Convert to the X25519 private key of the same key pair, so the key can also be used for key agreement.
+    #[cfg(feature = "x25519")]
+    pub fn to_x25519_private_key(&self) -> X25519PrivateKey {
+        X25519PrivateKey::from(self.0.to_scalar_bytes())
+    }
+}
+
+impl PartialEq for Ed25519PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl Eq for Ed25519PrivateKey {}
+
+impl Debug for Ed25519PrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Ed25519PrivateKey").finish_non_exhaustive()
+    }
 }
 
 impl EncodeVec for Ed25519PublicKey {
@@ -48,6 +110,43 @@ impl EncodeJwk for Ed25519PublicKey {
             crv: "Ed25519".to_string(),
             x: Some(Base64UrlStrNoPad::from(x)),
             y: None,
+            d: None,
+        }
+    }
+}
+
+impl EncodeJwk for Ed25519PrivateKey {
+    fn encode_jwk(&self) -> Jwk {
+        Jwk {
+            d: Some(Base64UrlStrNoPad::from(self.0.to_bytes())),
+            ..self.to_public_key().encode_jwk()
+        }
+    }
+}
+
+impl DecodeJwk for Ed25519PublicKey {
+    fn decode_jwk(jwk: &Jwk) -> Result<Self, jwk::Error> {
+        jwk.expect_key_type("OKP", "Ed25519", std::any::type_name::<Self>())?;
+        let x = jwk::required_parameter("x", &jwk.x)?.to_bytes();
+        Self::from_slice(&x).map_err(|e| jwk::Error::InvalidKey { source: e })
+    }
+}
+
+impl DecodeJwk for Ed25519PrivateKey {
+    fn decode_jwk(jwk: &Jwk) -> Result<Self, jwk::Error> {
+        jwk.expect_key_type("OKP", "Ed25519", std::any::type_name::<Self>())?;
+        let d = jwk::required_parameter("d", &jwk.d)?.to_bytes();
+        let key = Self::from_slice(&d).map_err(|e| jwk::Error::InvalidKey { source: e })?;
+        jwk.expect_public_jwk(&key.to_public_key().encode_jwk())?;
+        Ok(key)
+    }
+}
+
+impl EncodeMultikey for Ed25519PublicKey {
+    fn encode_multikey(&self) -> Multikey {
+        Multikey {
+            codec: Multicodec::Ed25519Pub,
+            key: self.encode_vec(),
         }
     }
 }
@@ -21,6 +21,9 @@ pub enum Error {
     #[from]
     #[display("unable to parse secp256k1 key")]
     Secp256k1KeyParsing { source: ::k256::elliptic_curve::Error },
+    #[cfg(feature = "x25519")]
+    #[display("X25519 key agreement with a low order public key is not allowed")]
+    NonContributoryKeyAgreement,
 }
 
 pub trait EncodeVec {
@@ -6,7 +6,8 @@ use k256::elliptic_curve::sec1::{EncodedPoint, ToEncodedPoint};
 
 use super::{EncodeArray, EncodeVec, Error, Verifiable};
 use crate::base64::Base64UrlStrNoPad;
-use crate::jwk::{EncodeJwk, Jwk};
+use crate::jwk::{self, DecodeJwk, EncodeJwk, Jwk};
+use crate::multibase::{EncodeMultikey, Multicodec, Multikey};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Secp256k1PublicKey(k256::PublicKey);
@@ -142,6 +143,47 @@ impl EncodeJwk for Secp256k1PublicKey {
             crv: "secp256k1".to_string(),
             x: Some(Base64UrlStrNoPad::from(point.x)),
             y: Some(Base64UrlStrNoPad::from(point.y)),
+            d: None,
+        }
+    }
+}
+
+impl EncodeJwk for Secp256k1PrivateKey {
+    fn encode_jwk(&self) -> Jwk {
+        Jwk {
+            d: Some(Base64UrlStrNoPad::from(self.0.to_bytes())),
+            ..self.to_public_key().encode_jwk()
+        }
+    }
+}
+
+impl DecodeJwk for Secp256k1PublicKey {
+    fn decode_jwk(jwk: &Jwk) -> Result<Self, jwk::Error> {
+        jwk.expect_key_type("EC", "secp256k1", std::any::type_name::<Self>())?;
+        let x = jwk::required_parameter("x", &jwk.x)?.to_bytes();
+        let y = jwk::required_parameter("y", &jwk.y)?.to_bytes();
+        let mut uncompressed = vec![0x04];
+        uncompressed.extend(x);
+        uncompressed.extend(y);
+        Self::from_slice(&uncompressed).map_err(|e| jwk::Error::InvalidKey { source: e })
+    }
+}
+
+impl DecodeJwk for Secp256k1PrivateKey {
+    fn decode_jwk(jwk: &Jwk) -> Result<Self, jwk::Error> {
+        jwk.expect_key_type("EC", "secp256k1", std::any::type_name::<Self>())?;
+        let d = jwk::required_parameter("d", &jwk.d)?.to_bytes();
+        let key = Self::from_slice(&d).map_err(|e| jwk::Error::InvalidKey { source: e })?;
+        jwk.expect_public_jwk(&key.to_public_key().encode_jwk())?;
+        Ok(key)
+    }
+}
+
+impl EncodeMultikey for Secp256k1PublicKey {
+    fn encode_multikey(&self) -> Multikey {
+        Multikey {
+            codec: Multicodec::Secp256k1Pub,
+            key: self.encode_compressed().to_vec(),
         }
     }
 }
@@ -1,10 +1,24 @@
+use std::fmt::Debug;
+
+use subtle::ConstantTimeEq;
+
 use super::{EncodeArray, EncodeVec, Error};
 use crate::base64::Base64UrlStrNoPad;
-use crate::jwk::{EncodeJwk, Jwk};
+use crate::jwk::{self, DecodeJwk, EncodeJwk, Jwk};
+use crate::multibase::{EncodeMultikey, Multicodec, Multikey};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct X25519PublicKey(x25519_dalek::PublicKey);
 
+/// An X25519 private key.
+///
+/// The key is zeroized on drop and compared in constant time.
+#[derive(Clone)]
+pub struct X25519PrivateKey(x25519_dalek::StaticSecret);
+
+/// The shared secret of an X25519 key agreement, zeroized on drop.
+pub struct X25519SharedSecret(x25519_dalek::SharedSecret);
+
 impl X25519PublicKey {
     pub fn from_slice(slice: &[u8]) -> Result<X25519PublicKey, Error> {
         if slice.len() != 32 {
@@ -19,6 +33,72 @@ impl X25519PublicKey {
     }
 }
 
+impl From<[u8; 32]> for X25519PublicKey {
+    fn from(value: [u8; 32]) -> Self {
+        Self(x25519_dalek::PublicKey::from(value))
+    }
+}
+
+impl X25519PrivateKey {
+    pub fn from_slice(slice: &[u8]) -> Result<Self, Error> {
+        let Ok(key) = <[u8; 32]>::try_from(slice) else {
+            Err(Error::InvalidKeySize {
+                expected: 32,
+                actual: slice.len(),
+                key_type: std::any::type_name::<X25519PrivateKey>(),
+            })?
+        };
+        Ok(Self::from(key))
+    }
+
+    pub fn to_public_key(&self) -> X25519PublicKey {
+        X25519PublicKey(x25519_dalek::PublicKey::from(&self.0))
+    }
+
+    /// Perform an ECDH key agreement with the public key of the other party.
+    ///
+    /// Low order public keys are rejected, since they would yield a shared secret known in advance.
+    pub fn diffie_hellman(&self, public_key: &X25519PublicKey) -> Result<X25519SharedSecret, Error> {
+        let shared_secret = self.0.diffie_hellman(&public_key.0);
+        if !shared_secret.was_contributory() {
+            Err(Error::NonContributoryKeyAgreement)?
+        }
+        Ok(X25519SharedSecret(shared_secret))
+    }
+}
+
+impl From<[u8; 32]> for X25519PrivateKey {
+    fn from(value: [u8; 32]) -> Self {
+        Self(x25519_dalek::StaticSecret::from(value))
+    }
+}
+
+impl PartialEq for X25519PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_bytes().as_slice().ct_eq(other.0.as_bytes().as_slice()).into()
+    }
+}
+
+impl Eq for X25519PrivateKey {}
+
+impl Debug for X25519PrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("X25519PrivateKey").finish_non_exhaustive()
+    }
+}
+
+impl X25519SharedSecret {
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        self.0.as_bytes()
+    }
+}
+
+impl Debug for X25519SharedSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("X25519SharedSecret").finish_non_exhaustive()
+    }
+}
+
 impl EncodeVec for X25519PublicKey {
     fn encode_vec(&self) -> Vec<u8> {
         self.0.as_bytes().to_vec()
@@ -39,6 +119,43 @@ impl EncodeJwk for X25519PublicKey {
             crv: "X25519".to_string(),
             x: Some(Base64UrlStrNoPad::from(x)),
             y: None,
+            d: None,
+        }
+    }
+}
+
+impl EncodeJwk for X25519PrivateKey {
+    fn encode_jwk(&self) -> Jwk {
+        Jwk {
+            d: Some(Base64UrlStrNoPad::from(self.0.to_bytes())),
+            ..self.to_public_key().encode_jwk()
+        }
+    }
+}
+
+impl DecodeJwk for X25519PublicKey {
+    fn decode_jwk(jwk: &Jwk) -> Result<Self, jwk::Error> {
+        jwk.expect_key_type("OKP", "X25519", std::any::type_name::<Self>())?;
+        let x = jwk::required_parameter("x", &jwk.x)?.to_bytes();
+        Self::from_slice(&x).map_err(|e| jwk::Error::InvalidKey { source: e })
+    }
+}
+
+impl DecodeJwk for X25519PrivateKey {
+    fn decode_jwk(jwk: &Jwk) -> Result<Self, jwk::Error> {
+        jwk.expect_key_type("OKP", "X25519", std::any::type_name::<Self>())?;
+        let d = jwk::required_parameter("d", &jwk.d)?.to_bytes();
+        let key = Self::from_slice(&d).map_err(|e| jwk::Error::InvalidKey { source: e })?;
+        jwk.expect_public_jwk(&key.to_public_key().encode_jwk())?;
+        Ok(key)
+    }
+}
+
+impl EncodeMultikey for X25519PublicKey {
+    fn encode_multikey(&self) -> Multikey {
+        Multikey {
+            codec: Multicodec::X25519Pub,
+            key: self.encode_vec(),
         }
     }
 }
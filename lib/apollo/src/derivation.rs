@@ -0,0 +1,335 @@
+//! Hierarchical deterministic key derivation compatible with the Identus SDK key paths.
+//!
+//! A [`Seed`] is created from a [BIP39](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki) mnemonic.
+//! Secp256k1 keys are derived with [BIP32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki)
+//! and Ed25519 keys with [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md),
+//! which only supports hardened child indexes.
+//!
+//! # Example
+//! ```
+//! use identus_apollo::derivation::{DerivationPath, DidKeyUsage, Seed};
+//!
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let seed = Seed::from_mnemonic(mnemonic, "").unwrap();
+//! let path = DerivationPath::identus_did_key(0, DidKeyUsage::Master, 0).unwrap();
+//! assert_eq!(path.to_string(), "m/29'/29'/0'/1'/0'");
+//!
+//! let master_key = seed.derive_secp256k1(&path).unwrap();
+//! let _public_key = master_key.to_public_key();
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use k256::elliptic_curve::PrimeField;
+use ring::hmac;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::crypto::ed25519::Ed25519PrivateKey;
+use crate::crypto::secp256k1::Secp256k1PrivateKey;
+
+const HARDENED_OFFSET: u32 = 1 << 31;
+const SECP256K1_CURVE_KEY: &[u8] = b"Bitcoin seed";
+const ED25519_CURVE_KEY: &[u8] = b"ed25519 seed";
+
+/// Purpose of the Identus DID key derivation path.
+const IDENTUS_PURPOSE: u32 = 29;
+/// DID method of the Identus DID key derivation path, which is `did:prism`.
+const IDENTUS_DID_METHOD: u32 = 29;
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum Error {
+    #[display("unable to parse bip39 mnemonic")]
+    InvalidMnemonic { source: bip39::Error },
+    #[display("derivation path '{path}' is not valid")]
+    InvalidDerivationPath { path: String },
+    #[display("child index {index} is not less than 2^31")]
+    InvalidChildIndex { index: u32 },
+    #[display("ed25519 derivation does not support non-hardened child index {index}")]
+    NonHardenedEd25519Derivation { index: u32 },
+    #[display("derived key is not a valid private key")]
+    InvalidDerivedKey,
+}
+
+/// A child index of a derivation path, which is either hardened or not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChildNumber {
+    index: u32,
+    hardened: bool,
+}
+
+impl ChildNumber {
+    pub fn normal(index: u32) -> Result<Self, Error> {
+        Self::new(index, false)
+    }
+
+    pub fn hardened(index: u32) -> Result<Self, Error> {
+        Self::new(index, true)
+    }
+
+    fn new(index: u32, hardened: bool) -> Result<Self, Error> {
+        if index >= HARDENED_OFFSET {
+            Err(Error::InvalidChildIndex { index })?
+        }
+        Ok(Self { index, hardened })
+    }
+
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn is_hardened(&self) -> bool {
+        self.hardened
+    }
+
+    /// The serialized index, with the hardened bit set for hardened children.
+    fn to_bytes(self) -> [u8; 4] {
+        let index = if self.hardened {
+            self.index | HARDENED_OFFSET
+        } else {
+            self.index
+        };
+        index.to_be_bytes()
+    }
+}
+
+impl Display for ChildNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.hardened {
+            write!(f, "{}'", self.index)
+        } else {
+            write!(f, "{}", self.index)
+        }
+    }
+}
+
+/// A derivation path such as `m/29'/29'/0'/1'/0'`.
+///
+/// Hardened children are written with a `'` suffix, and `h` is also accepted when parsing.
+///
+/// # Example
+/// ```
+/// use identus_apollo::derivation::DerivationPath;
+///
+/// let path: DerivationPath = "m/44h/0'/1".parse().unwrap();
+/// assert_eq!(path.to_string(), "m/44'/0'/1");
+///
+/// assert!("44'/0'".parse::<DerivationPath>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, derive_more::From)]
+pub struct DerivationPath(Vec<ChildNumber>);
+
+impl DerivationPath {
+    /// The path of an Identus DID key: `m/29'/29'/{did_index}'/{key_usage}'/{key_index}'`.
+    pub fn identus_did_key(did_index: u32, key_usage: DidKeyUsage, key_index: u32) -> Result<Self, Error> {
+        let children = [
+            IDENTUS_PURPOSE,
+            IDENTUS_DID_METHOD,
+            did_index,
+            key_usage.index(),
+            key_index,
+        ]
+        .into_iter()
+        .map(ChildNumber::hardened)
+        .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(children))
+    }
+
+    pub fn children(&self) -> &[ChildNumber] {
+        &self.0
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid_path = || Error::InvalidDerivationPath { path: s.to_string() };
+        let mut segments = s.split('/');
+        if segments.next() != Some("m") {
+            Err(invalid_path())?
+        }
+        let children = segments
+            .map(|segment| {
+                let (index, hardened) = match segment.strip_suffix(['\'', 'h']) {
+                    Some(index) => (index, true),
+                    None => (segment, false),
+                };
+                if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+                    Err(invalid_path())?
+                }
+                let index = index.parse::<u32>().map_err(|_| invalid_path())?;
+                ChildNumber::new(index, hardened)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(children))
+    }
+}
+
+impl Display for DerivationPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "m")?;
+        for child in &self.0 {
+            write!(f, "/{child}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The key usage segment of the Identus DID key derivation path.
+///
+/// The index of each usage is its value in the `KeyUsage` enum of the PRISM protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DidKeyUsage {
+    Master,
+    Issuing,
+    KeyAgreement,
+    Authentication,
+    Revocation,
+    CapabilityInvocation,
+    CapabilityDelegation,
+    Vdr,
+}
+
+impl DidKeyUsage {
+    pub const fn index(&self) -> u32 {
+        match self {
+            Self::Master => 1,
+            Self::Issuing => 2,
+            Self::KeyAgreement => 3,
+            Self::Authentication => 4,
+            Self::Revocation => 5,
+            Self::CapabilityInvocation => 6,
+            Self::CapabilityDelegation => 7,
+            Self::Vdr => 8,
+        }
+    }
+}
+
+/// A 64-byte BIP39 seed.
+///
+/// The seed is zeroized on drop.
+#[derive(Clone, derive_more::Debug)]
+#[debug("Seed(..)")]
+pub struct Seed([u8; 64]);
+
+impl Seed {
+    /// Create the seed from an English BIP39 mnemonic and an optional passphrase, which may be empty.
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self, Error> {
+        let mnemonic = bip39::Mnemonic::parse(mnemonic).map_err(|e| Error::InvalidMnemonic { source: e })?;
+        Ok(Self(mnemonic.to_seed(passphrase)))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn derive_secp256k1(&self, path: &DerivationPath) -> Result<Secp256k1PrivateKey, Error> {
+        derive_secp256k1(&self.0, path)
+    }
+
+    pub fn derive_ed25519(&self, path: &DerivationPath) -> Result<Ed25519PrivateKey, Error> {
+        derive_ed25519(&self.0, path)
+    }
+}
+
+impl From<[u8; 64]> for Seed {
+    fn from(value: [u8; 64]) -> Self {
+        Self(value)
+    }
+}
+
+impl Drop for Seed {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Derive a secp256k1 private key from the seed following BIP32.
+///
+/// A child key that is not a valid private key is reported as [`Error::InvalidDerivedKey`]
+/// instead of proceeding with the next index, which happens with a probability lower than 2^-127.
+pub fn derive_secp256k1(seed: &[u8], path: &DerivationPath) -> Result<Secp256k1PrivateKey, Error> {
+    let mut key = ExtendedKey::master(SECP256K1_CURVE_KEY, seed);
+    let mut private_key = to_secp256k1_private_key(&key.key)?;
+    for child in path.children() {
+        let child_bytes = child.to_bytes();
+        let tweak = if child.is_hardened() {
+            key.child(&[&[0], key.key.as_slice(), &child_bytes])
+        } else {
+            let public_key = private_key.to_public_key().encode_compressed();
+            key.child(&[&public_key, &child_bytes])
+        };
+        key = ExtendedKey {
+            key: add_secp256k1_scalars(&key.key, &tweak.key)?,
+            chain_code: tweak.chain_code,
+        };
+        private_key = to_secp256k1_private_key(&key.key)?;
+    }
+    Ok(private_key)
+}
+
+/// Derive an Ed25519 private key from the seed following SLIP-0010.
+///
+/// Every child index of the path must be hardened.
+pub fn derive_ed25519(seed: &[u8], path: &DerivationPath) -> Result<Ed25519PrivateKey, Error> {
+    if let Some(child) = path.children().iter().find(|i| !i.is_hardened()) {
+        Err(Error::NonHardenedEd25519Derivation { index: child.index() })?
+    }
+    let mut key = ExtendedKey::master(ED25519_CURVE_KEY, seed);
+    for child in path.children() {
+        key = key.child(&[&[0], key.key.as_slice(), &child.to_bytes()]);
+    }
+    Ed25519PrivateKey::from_slice(key.key.as_slice()).map_err(|_| Error::InvalidDerivedKey)
+}
+
+/// A private key with its chain code, both zeroized on drop.
+struct ExtendedKey {
+    key: Zeroizing<[u8; 32]>,
+    chain_code: Zeroizing<[u8; 32]>,
+}
+
+impl ExtendedKey {
+    fn master(curve_key: &[u8], seed: &[u8]) -> Self {
+        hmac_sha512(curve_key, &[seed])
+    }
+
+    fn child(&self, data: &[&[u8]]) -> Self {
+        hmac_sha512(self.chain_code.as_slice(), data)
+    }
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ExtendedKey {
+    let key = hmac::Key::new(hmac::HMAC_SHA512, key);
+    let mut context = hmac::Context::with_key(&key);
+    for i in data {
+        context.update(i);
+    }
+    let tag = context.sign();
+    let (left, right) = tag.as_ref().split_at(32);
+    let mut extended_key = ExtendedKey {
+        key: Zeroizing::new([0; 32]),
+        chain_code: Zeroizing::new([0; 32]),
+    };
+    extended_key.key.copy_from_slice(left);
+    extended_key.chain_code.copy_from_slice(right);
+    extended_key
+}
+
+fn to_secp256k1_private_key(key: &[u8; 32]) -> Result<Secp256k1PrivateKey, Error> {
+    Secp256k1PrivateKey::from_slice(key).map_err(|_| Error::InvalidDerivedKey)
+}
+
+/// Add the tweak to the parent key modulo the curve order, rejecting a tweak not less than the order and a zero sum.
+fn add_secp256k1_scalars(parent: &[u8; 32], tweak: &[u8; 32]) -> Result<Zeroizing<[u8; 32]>, Error> {
+    let tweak =
+        Option::<k256::Scalar>::from(k256::Scalar::from_repr((*tweak).into())).ok_or(Error::InvalidDerivedKey)?;
+    let parent = k256::SecretKey::from_slice(parent)
+        .map_err(|_| Error::InvalidDerivedKey)?
+        .to_nonzero_scalar();
+    let child = Option::<k256::NonZeroScalar>::from(k256::NonZeroScalar::new(*parent + tweak))
+        .ok_or(Error::InvalidDerivedKey)?;
+    let mut bytes = Zeroizing::new([0; 32]);
+    bytes.copy_from_slice(&child.to_bytes());
+    Ok(bytes)
+}
@@ -1,4 +1,4 @@
-use ring::digest;
+use ring::{digest, hmac};
 
 #[derive(Debug, derive_more::Display, derive_more::Error)]
 pub enum Error {
@@ -63,3 +63,22 @@ pub fn sha256<B: AsRef<[u8]>>(bytes: B) -> Sha256Digest {
         .expect("The digest must have length of 32 bytes");
     Sha256Digest(digest)
 }
+
+/// Compute the HMAC-SHA256 of `bytes` authenticated with `key`.
+///
+/// # Example
+///
+/// ```
+/// use identus_apollo::hash::hmac_sha256;
+/// let mac = hmac_sha256(b"key", b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(
+///     identus_apollo::hex::HexStr::from(mac.as_bytes()).to_string(),
+///     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+/// );
+/// ```
+pub fn hmac_sha256<K: AsRef<[u8]>, B: AsRef<[u8]>>(key: K, bytes: B) -> Sha256Digest {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key.as_ref());
+    let tag = hmac::sign(&key, bytes.as_ref());
+    let tag: [u8; 32] = tag.as_ref().try_into().expect("The tag must have length of 32 bytes");
+    Sha256Digest(tag)
+}
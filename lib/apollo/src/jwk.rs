@@ -1,6 +1,28 @@
 use crate::base64::Base64UrlStrNoPad;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum Error {
+    #[display("jwk with kty '{kty}' and crv '{crv}' is not a {key_type} key")]
+    UnexpectedKeyType {
+        kty: String,
+        crv: String,
+        key_type: &'static str,
+    },
+    #[display("jwk with kty '{kty}' is not supported")]
+    UnsupportedKeyType { kty: String },
+    #[display("jwk is missing the '{parameter}' parameter")]
+    MissingParameter { parameter: &'static str },
+    #[display("jwk does not contain a valid key")]
+    InvalidKey { source: crate::crypto::Error },
+    #[display("jwk public key parameters do not match its private key")]
+    PublicKeyMismatch,
+}
+
+/// A JSON Web Key as defined in [RFC 7517](https://www.rfc-editor.org/rfc/rfc7517).
+///
+/// A private key has the `d` parameter, which is omitted when serialized if absent
+/// and never shown by `Debug`.
+#[derive(Clone, PartialEq, Eq, Hash, derive_more::Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Jwk {
@@ -8,8 +30,124 @@ pub struct Jwk {
     pub crv: String,
     pub x: Option<Base64UrlStrNoPad>,
     pub y: Option<Base64UrlStrNoPad>,
+    #[debug(skip)]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub d: Option<Base64UrlStrNoPad>,
+}
+
+impl Jwk {
+    pub fn is_private(&self) -> bool {
+        self.d.is_some()
+    }
+
+    /// The JWK without its private key parameter.
+    pub fn to_public_jwk(&self) -> Jwk {
+        Jwk {
+            d: None,
+            ..self.clone()
+        }
+    }
+
+    /// The base64url encoded SHA-256 [JWK thumbprint](https://www.rfc-editor.org/rfc/rfc7638) of the public key.
+    ///
+    /// # Example
+    /// ```
+    /// use identus_apollo::jwk::Jwk;
+    ///
+    /// let jwk = Jwk {
+    ///     kty: "OKP".to_string(),
+    ///     crv: "Ed25519".to_string(),
+    ///     x: Some(
+    ///         "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    ///             .parse()
+    ///             .unwrap(),
+    ///     ),
+    ///     y: None,
+    ///     d: None,
+    /// };
+    /// let thumbprint = jwk.thumbprint().unwrap();
+    /// assert_eq!(
+    ///     thumbprint.to_string(),
+    ///     "kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k"
+    /// );
+    /// ```
+    #[cfg(feature = "hash")]
+    pub fn thumbprint(&self) -> Result<Base64UrlStrNoPad, Error> {
+        let x = required_parameter("x", &self.x)?;
+        // required members in lexicographic order, without whitespace
+        let members = match self.kty.as_str() {
+            "EC" => {
+                let y = required_parameter("y", &self.y)?;
+                format!(
+                    r#"{{"crv":{},"kty":{},"x":"{x}","y":"{y}"}}"#,
+                    json_string(&self.crv),
+                    json_string(&self.kty)
+                )
+            }
+            "OKP" => format!(
+                r#"{{"crv":{},"kty":{},"x":"{x}"}}"#,
+                json_string(&self.crv),
+                json_string(&self.kty)
+            ),
+            kty => Err(Error::UnsupportedKeyType { kty: kty.to_string() })?,
+        };
+        Ok(Base64UrlStrNoPad::from(crate::hash::sha256(members).as_bytes()))
+    }
+
+    /// Check that the JWK has the expected key type and curve.
+    #[cfg(any(feature = "secp256k1", feature = "ed25519", feature = "x25519"))]
+    pub(crate) fn expect_key_type(&self, kty: &str, crv: &str, key_type: &'static str) -> Result<(), Error> {
+        if self.kty != kty || self.crv != crv {
+            Err(Error::UnexpectedKeyType {
+                kty: self.kty.clone(),
+                crv: self.crv.clone(),
+                key_type,
+            })?
+        }
+        Ok(())
+    }
+
+    /// Check that the public key parameters present in the JWK match the ones of its private key.
+    #[cfg(any(feature = "secp256k1", feature = "ed25519", feature = "x25519"))]
+    pub(crate) fn expect_public_jwk(&self, public_jwk: &Jwk) -> Result<(), Error> {
+        let matches = |param: &Option<Base64UrlStrNoPad>, expected: &Option<Base64UrlStrNoPad>| {
+            param.is_none() || param == expected
+        };
+        if !matches(&self.x, &public_jwk.x) || !matches(&self.y, &public_jwk.y) {
+            Err(Error::PublicKeyMismatch)?
+        }
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "hash", feature = "secp256k1", feature = "ed25519", feature = "x25519"))]
+pub(crate) fn required_parameter<'a>(
+    parameter: &'static str,
+    value: &'a Option<Base64UrlStrNoPad>,
+) -> Result<&'a Base64UrlStrNoPad, Error> {
+    value.as_ref().ok_or(Error::MissingParameter { parameter })
+}
+
+#[cfg(feature = "hash")]
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
 }
 
 pub trait EncodeJwk {
     fn encode_jwk(&self) -> Jwk;
 }
+
+pub trait DecodeJwk: Sized {
+    fn decode_jwk(jwk: &Jwk) -> Result<Self, Error>;
+}
@@ -0,0 +1,238 @@
+//! Compact [JWS](https://www.rfc-editor.org/rfc/rfc7515) signing and verification with
+//! `ES256K` ([RFC 8812](https://www.rfc-editor.org/rfc/rfc8812))
+//! and `EdDSA` ([RFC 8037](https://www.rfc-editor.org/rfc/rfc8037)).
+//!
+//! # Example
+//! ```
+//! use identus_apollo::crypto::ed25519::Ed25519PrivateKey;
+//! use identus_apollo::jwk::EncodeJwk;
+//! use identus_apollo::jws::{CompactJws, JwsHeader};
+//!
+//! let key = Ed25519PrivateKey::from_slice(&[7; 32]).unwrap();
+//! let jws = CompactJws::sign(JwsHeader::default(), b"hello world", &key).unwrap();
+//!
+//! let jws: CompactJws = jws.to_string().parse().unwrap();
+//! assert_eq!(jws.payload(), b"hello world");
+//! let jwk = key.to_public_key().encode_jwk();
+//! assert!(jws.verify_with_jwk(&jwk).is_ok());
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::base64::Base64UrlStrNoPad;
+use crate::crypto::Verifiable;
+use crate::crypto::ed25519::{Ed25519PrivateKey, Ed25519PublicKey};
+use crate::crypto::secp256k1::{Secp256k1PrivateKey, Secp256k1PublicKey};
+use crate::jwk::{self, DecodeJwk, Jwk};
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum Error {
+    #[display("jws is not made of three base64url encoded segments")]
+    MalformedJws,
+    #[display("unable to decode jws header")]
+    InvalidHeader { source: serde_json::Error },
+    #[display("jws header has critical parameters, which are not supported")]
+    UnsupportedCriticalHeader,
+    #[display("jws algorithm {header_alg} does not match the {key_alg} key")]
+    AlgorithmMismatch {
+        header_alg: JwsAlgorithm,
+        key_alg: JwsAlgorithm,
+    },
+    #[display("jwk is not a valid key for jws algorithm {alg}")]
+    InvalidJwk { source: jwk::Error, alg: JwsAlgorithm },
+    #[display("jws signature verification failed")]
+    InvalidSignature,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, derive_more::Display, serde::Serialize, serde::Deserialize,
+)]
+pub enum JwsAlgorithm {
+    /// ECDSA using secp256k1 and SHA-256.
+    #[default]
+    #[display("ES256K")]
+    #[serde(rename = "ES256K")]
+    Es256k,
+    /// EdDSA using Ed25519.
+    #[display("EdDSA")]
+    #[serde(rename = "EdDSA")]
+    EdDsa,
+}
+
+/// The protected header of a JWS.
+///
+/// Other header parameters are ignored when parsing, except `crit` which is rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct JwsHeader {
+    /// The algorithm is set to the one of the signing key when signing.
+    pub alg: JwsAlgorithm,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub typ: Option<String>,
+}
+
+pub trait JwsSigner {
+    fn jws_algorithm(&self) -> JwsAlgorithm;
+
+    /// Sign the JWS signing input, returning the JWS signature.
+    fn sign_jws(&self, signing_input: &[u8]) -> Vec<u8>;
+}
+
+pub trait JwsVerifier: Verifiable {
+    fn jws_algorithm(&self) -> JwsAlgorithm;
+
+    /// Verify the JWS signature of the JWS signing input.
+    fn verify_jws(&self, signing_input: &[u8], signature: &[u8]) -> bool;
+}
+
+/// A JWS in the compact serialization `header.payload.signature`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactJws {
+    header: JwsHeader,
+    payload: Vec<u8>,
+    signature: Vec<u8>,
+    /// The encoded header and payload, kept as received since the signature is over these exact bytes.
+    signing_input: String,
+}
+
+impl CompactJws {
+    /// Sign the payload with the key, setting the algorithm of the header to the one of the key.
+    pub fn sign(mut header: JwsHeader, payload: &[u8], signer: &impl JwsSigner) -> Result<Self, Error> {
+        header.alg = signer.jws_algorithm();
+        let encoded_header = serde_json::to_vec(&header).map_err(|e| Error::InvalidHeader { source: e })?;
+        let signing_input = format!(
+            "{}.{}",
+            Base64UrlStrNoPad::from(encoded_header),
+            Base64UrlStrNoPad::from(payload)
+        );
+        let signature = signer.sign_jws(signing_input.as_bytes());
+        Ok(Self {
+            header,
+            payload: payload.to_vec(),
+            signature,
+            signing_input,
+        })
+    }
+
+    pub fn header(&self) -> &JwsHeader {
+        &self.header
+    }
+
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// Verify the signature with the key, which must match the algorithm of the header.
+    pub fn verify(&self, verifier: &impl JwsVerifier) -> Result<(), Error> {
+        let key_alg = verifier.jws_algorithm();
+        if self.header.alg != key_alg {
+            Err(Error::AlgorithmMismatch {
+                header_alg: self.header.alg,
+                key_alg,
+            })?
+        }
+        if !verifier.verify_jws(self.signing_input.as_bytes(), &self.signature) {
+            Err(Error::InvalidSignature)?
+        }
+        Ok(())
+    }
+
+    /// Verify the signature with a public JWK, decoded as the key type of the header algorithm.
+    pub fn verify_with_jwk(&self, jwk: &Jwk) -> Result<(), Error> {
+        let alg = self.header.alg;
+        let invalid_jwk = |e| Error::InvalidJwk { source: e, alg };
+        match alg {
+            JwsAlgorithm::Es256k => self.verify(&Secp256k1PublicKey::decode_jwk(jwk).map_err(invalid_jwk)?),
+            JwsAlgorithm::EdDsa => self.verify(&Ed25519PublicKey::decode_jwk(jwk).map_err(invalid_jwk)?),
+        }
+    }
+}
+
+impl Display for CompactJws {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.signing_input, Base64UrlStrNoPad::from(&self.signature))
+    }
+}
+
+impl FromStr for CompactJws {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((signing_input, encoded_signature)) = s.rsplit_once('.') else {
+            Err(Error::MalformedJws)?
+        };
+        let Some((encoded_header, encoded_payload)) = signing_input.split_once('.') else {
+            Err(Error::MalformedJws)?
+        };
+        let decode = |segment: &str| {
+            Base64UrlStrNoPad::from_str(segment)
+                .map(|i| i.to_bytes())
+                .map_err(|_| Error::MalformedJws)
+        };
+        let header = serde_json::from_slice::<serde_json::Value>(&decode(encoded_header)?)
+            .map_err(|e| Error::InvalidHeader { source: e })?;
+        if header.get("crit").is_some() {
+            Err(Error::UnsupportedCriticalHeader)?
+        }
+        let header = serde_json::from_value(header).map_err(|e| Error::InvalidHeader { source: e })?;
+        Ok(Self {
+            header,
+            payload: decode(encoded_payload)?,
+            signature: decode(encoded_signature)?,
+            signing_input: signing_input.to_string(),
+        })
+    }
+}
+
+impl JwsSigner for Secp256k1PrivateKey {
+    fn jws_algorithm(&self) -> JwsAlgorithm {
+        JwsAlgorithm::Es256k
+    }
+
+    /// Sign with the 64-byte `R || S` signature used by JWS instead of DER.
+    fn sign_jws(&self, signing_input: &[u8]) -> Vec<u8> {
+        let signature = k256::ecdsa::Signature::from_der(&self.sign(signing_input))
+            .unwrap_or_else(|_| unreachable!("secp256k1 signature should be a valid DER signature"));
+        signature.to_bytes().to_vec()
+    }
+}
+
+impl JwsVerifier for Secp256k1PublicKey {
+    fn jws_algorithm(&self) -> JwsAlgorithm {
+        JwsAlgorithm::Es256k
+    }
+
+    /// Verify the 64-byte `R || S` signature used by JWS, transcoded to DER.
+    fn verify_jws(&self, signing_input: &[u8], signature: &[u8]) -> bool {
+        let Ok(signature) = k256::ecdsa::Signature::from_slice(signature) else {
+            return false;
+        };
+        self.verify(signing_input, signature.to_der().as_bytes())
+    }
+}
+
+impl JwsSigner for Ed25519PrivateKey {
+    fn jws_algorithm(&self) -> JwsAlgorithm {
+        JwsAlgorithm::EdDsa
+    }
+
+    fn sign_jws(&self, signing_input: &[u8]) -> Vec<u8> {
+        self.sign(signing_input)
+    }
+}
+
+impl JwsVerifier for Ed25519PublicKey {
+    fn jws_algorithm(&self) -> JwsAlgorithm {
+        JwsAlgorithm::EdDsa
+    }
+
+    fn verify_jws(&self, signing_input: &[u8], signature: &[u8]) -> bool {
+        self.verify(signing_input, signature)
+    }
+}
@@ -2,6 +2,9 @@
 
 pub mod crypto;
 
+#[cfg(feature = "derivation")]
+pub mod derivation;
+
 #[cfg(feature = "hash")]
 pub mod hash;
 
@@ -13,3 +16,9 @@ pub mod base64;
 
 #[cfg(feature = "jwk")]
 pub mod jwk;
+
+#[cfg(feature = "jws")]
+pub mod jws;
+
+#[cfg(feature = "multibase")]
+pub mod multibase;
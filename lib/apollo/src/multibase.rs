@@ -0,0 +1,208 @@
+use std::str::FromStr;
+
+const BASE58BTC_PREFIX: char = 'z';
+const BASE32_PREFIX: char = 'b';
+const BASE32_UPPER_PREFIX: char = 'B';
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum Error {
+    #[display("multibase string '{value}' uses an unsupported base")]
+    UnsupportedBase { value: String },
+    #[display("unable to base58 decode multibase string '{value}'")]
+    Base58Decoding { source: bs58::decode::Error, value: String },
+    #[display("unable to base32 decode multibase string '{value}'")]
+    Base32Decoding {
+        source: data_encoding::DecodeError,
+        value: String,
+    },
+    #[display("multikey has a missing or malformed multicodec prefix")]
+    InvalidMulticodec,
+    #[display("multicodec 0x{code:x} is not a supported public key type")]
+    UnsupportedMulticodec { code: u64 },
+}
+
+/// A base58btc multibase string, prefixed with `z`.
+///
+/// # Example
+/// ```
+/// use identus_apollo::multibase::MultibaseStr;
+///
+/// let b = b"hello world";
+/// let mb = MultibaseStr::from(b);
+/// assert!(mb.to_string() == "zStV1DL6CwTryKyV");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Into, derive_more::AsRef)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MultibaseStr(String);
+
+impl MultibaseStr {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bs58::decode(&self.0[BASE58BTC_PREFIX.len_utf8()..])
+            .into_vec()
+            .unwrap_or_else(|_| unreachable!("{} should be a valid base58btc string", std::any::type_name::<Self>()))
+    }
+}
+
+impl<B: AsRef<[u8]>> From<B> for MultibaseStr {
+    fn from(value: B) -> Self {
+        Self(format!(
+            "{BASE58BTC_PREFIX}{}",
+            bs58::encode(value.as_ref()).into_string()
+        ))
+    }
+}
+
+/// # Example
+/// ```
+/// use std::str::FromStr;
+///
+/// use identus_apollo::multibase::MultibaseStr;
+///
+/// let mb = MultibaseStr::from_str("zStV1DL6CwTryKyV").unwrap();
+/// assert_eq!(mb, MultibaseStr::from(b"hello world"));
+///
+/// let mb = MultibaseStr::from_str("maGVsbG8gd29ybGQ");
+/// assert!(mb.is_err());
+/// ```
+impl FromStr for MultibaseStr {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(encoded) = s.strip_prefix(BASE58BTC_PREFIX) else {
+            Err(Error::UnsupportedBase { value: s.to_string() })?
+        };
+        let bytes = bs58::decode(encoded).into_vec().map_err(|e| Error::Base58Decoding {
+            source: e,
+            value: s.to_string(),
+        })?;
+        Ok(bytes.into())
+    }
+}
+
+/// Decode a multibase string encoded in base58btc (`z`), or in lowercase (`b`) or uppercase (`B`)
+/// base32 without padding, the bases content identifiers are usually encoded with.
+///
+/// # Example
+/// ```
+/// use identus_apollo::multibase;
+///
+/// assert_eq!(
+///     multibase::decode("zStV1DL6CwTryKyV").unwrap(),
+///     b"hello world"
+/// );
+/// assert_eq!(
+///     multibase::decode("bnbswy3dpeb3w64tmmq").unwrap(),
+///     b"hello world"
+/// );
+/// assert_eq!(
+///     multibase::decode("BNBSWY3DPEB3W64TMMQ").unwrap(),
+///     b"hello world"
+/// );
+/// assert!(multibase::decode("maGVsbG8gd29ybGQ").is_err());
+/// ```
+pub fn decode(value: &str) -> Result<Vec<u8>, Error> {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(BASE58BTC_PREFIX) => Ok(MultibaseStr::from_str(value)?.to_bytes()),
+        Some(BASE32_PREFIX | BASE32_UPPER_PREFIX) => decode_base32(&chars.as_str().to_ascii_uppercase(), value),
+        _ => Err(Error::UnsupportedBase {
+            value: value.to_string(),
+        }),
+    }
+}
+
+fn decode_base32(encoded: &str, value: &str) -> Result<Vec<u8>, Error> {
+    data_encoding::BASE32_NOPAD
+        .decode(encoded.as_bytes())
+        .map_err(|e| Error::Base32Decoding {
+            source: e,
+            value: value.to_string(),
+        })
+}
+
+/// Multicodec of the supported public key types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Multicodec {
+    Secp256k1Pub,
+    Ed25519Pub,
+    X25519Pub,
+}
+
+impl Multicodec {
+    pub const fn code(&self) -> u64 {
+        match self {
+            Self::Secp256k1Pub => 0xe7,
+            Self::Ed25519Pub => 0xed,
+            Self::X25519Pub => 0xec,
+        }
+    }
+
+    pub fn from_code(code: u64) -> Option<Self> {
+        [Self::Secp256k1Pub, Self::Ed25519Pub, Self::X25519Pub]
+            .into_iter()
+            .find(|i| i.code() == code)
+    }
+}
+
+/// A public key prefixed with the varint of its multicodec, as used by `publicKeyMultibase`.
+///
+/// # Example
+/// ```
+/// use identus_apollo::multibase::{Multicodec, Multikey};
+///
+/// let multikey = Multikey {
+///     codec: Multicodec::Ed25519Pub,
+///     key: vec![0; 32],
+/// };
+/// let encoded = multikey.encode();
+/// assert!(encoded.to_string().starts_with("z6Mk"));
+/// assert_eq!(Multikey::decode(&encoded).unwrap(), multikey);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Multikey {
+    pub codec: Multicodec,
+    pub key: Vec<u8>,
+}
+
+impl Multikey {
+    pub fn encode(&self) -> MultibaseStr {
+        let mut bytes = encode_varint(self.codec.code());
+        bytes.extend_from_slice(&self.key);
+        MultibaseStr::from(bytes)
+    }
+
+    pub fn decode(value: &MultibaseStr) -> Result<Self, Error> {
+        let bytes = value.to_bytes();
+        let (code, key) = decode_varint(&bytes).ok_or(Error::InvalidMulticodec)?;
+        let codec = Multicodec::from_code(code).ok_or(Error::UnsupportedMulticodec { code })?;
+        Ok(Self {
+            codec,
+            key: key.to_vec(),
+        })
+    }
+}
+
+pub trait EncodeMultikey {
+    fn encode_multikey(&self) -> Multikey;
+}
+
+fn encode_varint(mut value: u64) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(2);
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+    bytes
+}
+
+fn decode_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().take(9).enumerate() {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, &bytes[i + 1..]));
+        }
+    }
+    None
+}
@@ -0,0 +1,203 @@
+#![cfg(all(feature = "derivation", feature = "hex"))]
+
+//! The BIP39, BIP32 and SLIP-0010 vectors in `vectors/derivation.json` are taken from their specifications.
+//! The Identus DID key vectors derive keys of the BIP39 test mnemonic along the Identus SDK key paths,
+//! and were checked against an independent BIP32 and SLIP-0010 implementation.
+
+use identus_apollo::crypto::EncodeVec;
+use identus_apollo::crypto::ed25519::Ed25519PrivateKey;
+use identus_apollo::crypto::secp256k1::Secp256k1PrivateKey;
+use identus_apollo::derivation::{self, ChildNumber, DerivationPath, DidKeyUsage, Error, Seed};
+use identus_apollo::hex::HexStr;
+use serde_json::Value;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn vectors(section: &str) -> Vec<Value> {
+    let vectors: Value = serde_json::from_str(include_str!("vectors/derivation.json")).unwrap();
+    vectors[section].as_array().unwrap().clone()
+}
+
+fn field<'a>(vector: &'a Value, name: &str) -> &'a str {
+    vector[name].as_str().unwrap()
+}
+
+fn hex_field(vector: &Value, name: &str) -> Vec<u8> {
+    field(vector, name).parse::<HexStr>().unwrap().to_bytes()
+}
+
+fn path_field(vector: &Value) -> DerivationPath {
+    field(vector, "path").parse().unwrap()
+}
+
+fn key_usage(name: &str) -> DidKeyUsage {
+    match name {
+        "master" => DidKeyUsage::Master,
+        "issuing" => DidKeyUsage::Issuing,
+        "key_agreement" => DidKeyUsage::KeyAgreement,
+        "authentication" => DidKeyUsage::Authentication,
+        "revocation" => DidKeyUsage::Revocation,
+        "capability_invocation" => DidKeyUsage::CapabilityInvocation,
+        "capability_delegation" => DidKeyUsage::CapabilityDelegation,
+        "vdr" => DidKeyUsage::Vdr,
+        _ => panic!("unknown key usage '{name}'"),
+    }
+}
+
+fn assert_secp256k1_key(key: &Secp256k1PrivateKey, vector: &Value) {
+    assert_eq!(
+        key,
+        &Secp256k1PrivateKey::from_slice(&hex_field(vector, "private_key")).unwrap()
+    );
+    assert_eq!(key.to_public_key().encode_vec(), hex_field(vector, "public_key"));
+}
+
+fn assert_ed25519_key(key: &Ed25519PrivateKey, vector: &Value) {
+    assert_eq!(
+        key,
+        &Ed25519PrivateKey::from_slice(&hex_field(vector, "private_key")).unwrap()
+    );
+    assert_eq!(key.to_public_key().encode_vec(), hex_field(vector, "public_key"));
+}
+
+// ---------------------------------------------------------------------------
+// Derivation path
+// ---------------------------------------------------------------------------
+
+#[test]
+fn derivation_path_parse_and_display() {
+    let path: DerivationPath = "m/29'/29h/0/1'".parse().unwrap();
+    assert_eq!(
+        path.children(),
+        &[
+            ChildNumber::hardened(29).unwrap(),
+            ChildNumber::hardened(29).unwrap(),
+            ChildNumber::normal(0).unwrap(),
+            ChildNumber::hardened(1).unwrap(),
+        ]
+    );
+    assert_eq!(path.to_string(), "m/29'/29'/0/1'");
+
+    let root: DerivationPath = "m".parse().unwrap();
+    assert!(root.children().is_empty());
+    assert_eq!(root.to_string(), "m");
+}
+
+#[test]
+fn derivation_path_parse_rejects_invalid_path() {
+    for path in [
+        "",
+        "29'/0'",
+        "m/",
+        "m//0",
+        "m/x",
+        "m/-1",
+        "m/+1",
+        "m/0''",
+        "m/2147483648",
+    ] {
+        assert!(
+            path.parse::<DerivationPath>().is_err(),
+            "path '{path}' should be rejected"
+        );
+    }
+}
+
+#[test]
+fn child_number_rejects_index_out_of_range() {
+    assert!(ChildNumber::normal((1 << 31) - 1).is_ok());
+    assert!(matches!(
+        ChildNumber::hardened(1 << 31),
+        Err(Error::InvalidChildIndex { index: 2147483648 })
+    ));
+}
+
+#[test]
+fn identus_did_key_path() {
+    let path = DerivationPath::identus_did_key(2, DidKeyUsage::Authentication, 5).unwrap();
+    assert_eq!(path.to_string(), "m/29'/29'/2'/4'/5'");
+    assert!(path.children().iter().all(|i| i.is_hardened()));
+}
+
+// ---------------------------------------------------------------------------
+// BIP39
+// ---------------------------------------------------------------------------
+
+#[test]
+fn bip39_seed_from_mnemonic() {
+    for vector in vectors("bip39") {
+        let seed = Seed::from_mnemonic(field(&vector, "mnemonic"), field(&vector, "passphrase")).unwrap();
+        assert_eq!(seed.as_bytes(), hex_field(&vector, "seed"));
+    }
+}
+
+#[test]
+fn bip39_rejects_invalid_mnemonic() {
+    let bad_checksum =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+    let unknown_word =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon neoprism";
+    for mnemonic in [bad_checksum, unknown_word] {
+        assert!(matches!(
+            Seed::from_mnemonic(mnemonic, ""),
+            Err(Error::InvalidMnemonic { .. })
+        ));
+    }
+}
+
+#[test]
+fn seed_debug_is_redacted() {
+    let seed = Seed::from([7; 64]);
+    assert_eq!(format!("{seed:?}"), "Seed(..)");
+}
+
+// ---------------------------------------------------------------------------
+// BIP32 / SLIP-0010
+// ---------------------------------------------------------------------------
+
+#[test]
+fn bip32_secp256k1_derivation() {
+    for vector in vectors("bip32_secp256k1") {
+        let key = derivation::derive_secp256k1(&hex_field(&vector, "seed"), &path_field(&vector)).unwrap();
+        assert_secp256k1_key(&key, &vector);
+    }
+}
+
+#[test]
+fn slip10_ed25519_derivation() {
+    for vector in vectors("slip10_ed25519") {
+        let key = derivation::derive_ed25519(&hex_field(&vector, "seed"), &path_field(&vector)).unwrap();
+        assert_ed25519_key(&key, &vector);
+    }
+}
+
+#[test]
+fn slip10_ed25519_rejects_non_hardened_child() {
+    let path: DerivationPath = "m/0'/1".parse().unwrap();
+    let result = derivation::derive_ed25519(&[0; 16], &path);
+    assert!(matches!(result, Err(Error::NonHardenedEd25519Derivation { index: 1 })));
+}
+
+// ---------------------------------------------------------------------------
+// Identus DID keys
+// ---------------------------------------------------------------------------
+
+#[test]
+fn identus_did_key_derivation() {
+    for vector in vectors("identus_did_keys") {
+        let seed = Seed::from_mnemonic(field(&vector, "mnemonic"), field(&vector, "passphrase")).unwrap();
+        let did_index = vector["did_index"].as_u64().unwrap() as u32;
+        let key_index = vector["key_index"].as_u64().unwrap() as u32;
+        let path =
+            DerivationPath::identus_did_key(did_index, key_usage(field(&vector, "key_usage")), key_index).unwrap();
+        assert_eq!(path, path_field(&vector));
+
+        match field(&vector, "curve") {
+            "secp256k1" => assert_secp256k1_key(&seed.derive_secp256k1(&path).unwrap(), &vector),
+            "ed25519" => assert_ed25519_key(&seed.derive_ed25519(&path).unwrap(), &vector),
+            curve => panic!("unknown curve '{curve}'"),
+        }
+    }
+}
@@ -1,9 +1,10 @@
 #![cfg(feature = "ed25519")]
 
 use ed25519_dalek::Signer;
-use identus_apollo::crypto::ed25519::Ed25519PublicKey;
+use identus_apollo::crypto::ed25519::{Ed25519PrivateKey, Ed25519PublicKey};
 use identus_apollo::crypto::{EncodeArray, EncodeVec, Error, Verifiable};
 use identus_apollo::jwk::EncodeJwk;
+use identus_apollo::multibase::{EncodeMultikey, Multicodec, Multikey};
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -24,6 +25,10 @@ fn sample_public_key() -> Ed25519PublicKey {
     Ed25519PublicKey::from_slice(signing.verifying_key().as_bytes()).unwrap()
 }
 
+fn sample_private_key() -> Ed25519PrivateKey {
+    Ed25519PrivateKey::from_slice(sample_signing_key().as_bytes()).unwrap()
+}
+
 // ---------------------------------------------------------------------------
 // Ed25519PublicKey::from_slice — invalid key bytes (covers line 17 `?` path)
 // ---------------------------------------------------------------------------
@@ -197,3 +202,90 @@ fn public_key_debug_contains_type_name() {
     let debug = format!("{pk:?}");
     assert!(debug.contains("Ed25519PublicKey"), "debug should contain type name");
 }
+
+// ---------------------------------------------------------------------------
+// EncodeMultikey for Ed25519PublicKey
+// ---------------------------------------------------------------------------
+
+#[test]
+fn encode_multikey_roundtrip() {
+    let pk = sample_public_key();
+    let multikey = pk.encode_multikey();
+    assert_eq!(multikey.codec, Multicodec::Ed25519Pub);
+    assert_eq!(multikey.key, pk.encode_vec());
+    assert_eq!(Multikey::decode(&multikey.encode()).unwrap(), multikey);
+}
+
+// ---------------------------------------------------------------------------
+// Ed25519PrivateKey
+// ---------------------------------------------------------------------------
+
+#[test]
+fn private_key_to_public_key_matches_signing_key() {
+    assert_eq!(sample_private_key().to_public_key(), sample_public_key());
+}
+
+#[test]
+fn private_key_from_slice_wrong_size_returns_invalid_key_size() {
+    let err = Ed25519PrivateKey::from_slice(&[0u8; 33]).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            Error::InvalidKeySize {
+                expected: 32,
+                actual: 33,
+                ..
+            }
+        ),
+        "expected InvalidKeySize, got: {err:?}"
+    );
+}
+
+#[test]
+fn private_key_sign_verifies_with_public_key() {
+    let sk = sample_private_key();
+    let signature = sk.sign(b"message");
+    assert_eq!(signature.len(), 64);
+    assert_eq!(signature, sample_signing_key().sign(b"message").to_bytes());
+    assert!(sk.to_public_key().verify(b"message", &signature));
+    assert!(!sk.to_public_key().verify(b"other message", &signature));
+}
+
+#[test]
+fn private_key_equality() {
+    assert_eq!(sample_private_key(), sample_private_key());
+    assert_ne!(
+        sample_private_key(),
+        Ed25519PrivateKey::from_slice(&[0xAB; 32]).unwrap()
+    );
+}
+
+#[test]
+fn private_key_debug_does_not_leak_key() {
+    let debug = format!("{:?}", sample_private_key());
+    assert_eq!(debug, "Ed25519PrivateKey(..)");
+}
+
+#[cfg(feature = "x25519")]
+#[test]
+fn to_x25519_keys_form_a_key_pair() {
+    let sk = sample_private_key();
+    let x25519_sk = sk.to_x25519_private_key();
+    assert_eq!(x25519_sk.to_public_key(), sk.to_public_key().to_x25519_public_key());
+}
+
+#[cfg(feature = "x25519")]
+#[test]
+fn to_x25519_keys_agree_on_shared_secret() {
+    let alice = sample_private_key();
+    let bob = Ed25519PrivateKey::from_slice(&[0xAB; 32]).unwrap();
+    let alice_secret = alice
+        .to_x25519_private_key()
+        .diffie_hellman(&bob.to_public_key().to_x25519_public_key())
+        .unwrap();
+    let bob_secret = bob
+        .to_x25519_private_key()
+        .diffie_hellman(&alice.to_public_key().to_x25519_public_key())
+        .unwrap();
+    assert_eq!(alice_secret.as_bytes(), bob_secret.as_bytes());
+}
@@ -0,0 +1,174 @@
+#![cfg(all(feature = "secp256k1", feature = "ed25519", feature = "x25519", feature = "hash"))]
+
+use identus_apollo::crypto::ed25519::{Ed25519PrivateKey, Ed25519PublicKey};
+use identus_apollo::crypto::secp256k1::{Secp256k1PrivateKey, Secp256k1PublicKey};
+use identus_apollo::crypto::x25519::{X25519PrivateKey, X25519PublicKey};
+use identus_apollo::jwk::{DecodeJwk, EncodeJwk, Error, Jwk};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// The private key of RFC 8037 Appendix A.1.
+fn rfc8037_jwk() -> Jwk {
+    Jwk {
+        kty: "OKP".to_string(),
+        crv: "Ed25519".to_string(),
+        x: Some("11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo".parse().unwrap()),
+        y: None,
+        d: Some("nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A".parse().unwrap()),
+    }
+}
+
+fn sample_secp256k1_private_key() -> Secp256k1PrivateKey {
+    let bytes: Vec<u8> = (1..=32).collect();
+    Secp256k1PrivateKey::from_slice(&bytes).unwrap()
+}
+
+// ---------------------------------------------------------------------------
+// DecodeJwk
+// ---------------------------------------------------------------------------
+
+#[test]
+fn decode_jwk_roundtrip_public_keys() {
+    let secp256k1 = sample_secp256k1_private_key().to_public_key();
+    assert_eq!(
+        Secp256k1PublicKey::decode_jwk(&secp256k1.encode_jwk()).unwrap(),
+        secp256k1
+    );
+
+    let ed25519 = Ed25519PrivateKey::from_slice(&[7; 32]).unwrap().to_public_key();
+    assert_eq!(Ed25519PublicKey::decode_jwk(&ed25519.encode_jwk()).unwrap(), ed25519);
+
+    let x25519 = X25519PrivateKey::from_slice(&[7; 32]).unwrap().to_public_key();
+    assert_eq!(X25519PublicKey::decode_jwk(&x25519.encode_jwk()).unwrap(), x25519);
+}
+
+#[test]
+fn decode_jwk_roundtrip_private_keys() {
+    let secp256k1 = sample_secp256k1_private_key();
+    let jwk = secp256k1.encode_jwk();
+    assert!(jwk.is_private());
+    assert_eq!(jwk.to_public_jwk(), secp256k1.to_public_key().encode_jwk());
+    assert_eq!(Secp256k1PrivateKey::decode_jwk(&jwk).unwrap(), secp256k1);
+
+    let ed25519 = Ed25519PrivateKey::from_slice(&[7; 32]).unwrap();
+    assert_eq!(Ed25519PrivateKey::decode_jwk(&ed25519.encode_jwk()).unwrap(), ed25519);
+
+    let x25519 = X25519PrivateKey::from_slice(&[7; 32]).unwrap();
+    assert_eq!(X25519PrivateKey::decode_jwk(&x25519.encode_jwk()).unwrap(), x25519);
+}
+
+#[test]
+fn decode_jwk_rfc8037_private_key() {
+    let key = Ed25519PrivateKey::decode_jwk(&rfc8037_jwk()).unwrap();
+    assert_eq!(key.to_public_key().encode_jwk(), rfc8037_jwk().to_public_jwk());
+}
+
+#[test]
+fn decode_jwk_rejects_other_key_type() {
+    let jwk = sample_secp256k1_private_key().to_public_key().encode_jwk();
+    assert!(matches!(
+        Ed25519PublicKey::decode_jwk(&jwk),
+        Err(Error::UnexpectedKeyType { .. })
+    ));
+
+    let jwk = Ed25519PrivateKey::from_slice(&[7; 32])
+        .unwrap()
+        .to_public_key()
+        .encode_jwk();
+    assert!(matches!(
+        X25519PublicKey::decode_jwk(&jwk),
+        Err(Error::UnexpectedKeyType { .. })
+    ));
+}
+
+#[test]
+fn decode_jwk_rejects_missing_parameters() {
+    let mut jwk = sample_secp256k1_private_key().to_public_key().encode_jwk();
+    jwk.y = None;
+    assert!(matches!(
+        Secp256k1PublicKey::decode_jwk(&jwk),
+        Err(Error::MissingParameter { parameter: "y" })
+    ));
+
+    let jwk = rfc8037_jwk().to_public_jwk();
+    assert!(matches!(
+        Ed25519PrivateKey::decode_jwk(&jwk),
+        Err(Error::MissingParameter { parameter: "d" })
+    ));
+}
+
+#[test]
+fn decode_jwk_rejects_invalid_key() {
+    let mut jwk = sample_secp256k1_private_key().to_public_key().encode_jwk();
+    jwk.y = jwk.x.clone();
+    assert!(matches!(
+        Secp256k1PublicKey::decode_jwk(&jwk),
+        Err(Error::InvalidKey { .. })
+    ));
+}
+
+#[test]
+fn decode_jwk_rejects_private_key_with_other_public_key() {
+    let mut jwk = rfc8037_jwk();
+    jwk.x = Ed25519PrivateKey::from_slice(&[7; 32])
+        .unwrap()
+        .to_public_key()
+        .encode_jwk()
+        .x;
+    assert!(matches!(
+        Ed25519PrivateKey::decode_jwk(&jwk),
+        Err(Error::PublicKeyMismatch)
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Thumbprint
+// ---------------------------------------------------------------------------
+
+#[test]
+fn thumbprint_rfc8037_ed25519() {
+    let thumbprint = rfc8037_jwk().thumbprint().unwrap();
+    assert_eq!(thumbprint.to_string(), "kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k");
+}
+
+#[test]
+fn thumbprint_secp256k1() {
+    let private_jwk = sample_secp256k1_private_key().encode_jwk();
+    let public_jwk = private_jwk.to_public_jwk();
+    let thumbprint = public_jwk.thumbprint().unwrap();
+    assert_eq!(thumbprint.to_string(), "sHVsPsw-yovOh9b_K7fFCws-lXIyEPLYkQerigODPfg");
+    assert_eq!(private_jwk.thumbprint().unwrap(), thumbprint);
+}
+
+#[test]
+fn thumbprint_rejects_unsupported_key_type() {
+    let jwk = Jwk {
+        kty: "RSA".to_string(),
+        ..rfc8037_jwk()
+    };
+    assert!(matches!(jwk.thumbprint(), Err(Error::UnsupportedKeyType { .. })));
+}
+
+// ---------------------------------------------------------------------------
+// Private parameter
+// ---------------------------------------------------------------------------
+
+#[test]
+fn debug_does_not_show_private_key() {
+    let debug = format!("{:?}", rfc8037_jwk());
+    assert!(!debug.contains("nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_omits_absent_private_key() {
+    let public_json = serde_json::to_value(rfc8037_jwk().to_public_jwk()).unwrap();
+    assert!(public_json.get("d").is_none());
+
+    let private_json = serde_json::to_value(rfc8037_jwk()).unwrap();
+    assert_eq!(private_json["d"], "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A");
+    let jwk: Jwk = serde_json::from_value(private_json).unwrap();
+    assert_eq!(jwk, rfc8037_jwk());
+}
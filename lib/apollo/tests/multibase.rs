@@ -0,0 +1,117 @@
+#![cfg(feature = "multibase")]
+
+use std::str::FromStr;
+
+use identus_apollo::multibase::{self, Error, MultibaseStr, Multicodec, Multikey};
+
+// ---------------------------------------------------------------------------
+// MultibaseStr
+// ---------------------------------------------------------------------------
+
+#[test]
+fn multibase_encodes_base58btc_with_prefix() {
+    let mb = MultibaseStr::from(b"hello world");
+    assert_eq!(mb.to_string(), "zStV1DL6CwTryKyV");
+    assert_eq!(mb.to_bytes(), b"hello world");
+}
+
+#[test]
+fn multibase_keeps_leading_zeros() {
+    let mb = MultibaseStr::from([0u8, 0, 1]);
+    assert_eq!(mb.to_string(), "z112");
+    assert_eq!(mb.to_bytes(), vec![0, 0, 1]);
+}
+
+#[test]
+fn multibase_from_str_roundtrip() {
+    let mb = MultibaseStr::from_str("zStV1DL6CwTryKyV").unwrap();
+    assert_eq!(mb, MultibaseStr::from(b"hello world"));
+}
+
+#[test]
+fn multibase_from_str_rejects_other_bases() {
+    let err = MultibaseStr::from_str("maGVsbG8gd29ybGQ").unwrap_err();
+    assert!(matches!(err, Error::UnsupportedBase { .. }));
+    let err = MultibaseStr::from_str("").unwrap_err();
+    assert!(matches!(err, Error::UnsupportedBase { .. }));
+}
+
+#[test]
+fn multibase_from_str_rejects_invalid_base58() {
+    // '0' is not in the base58 alphabet
+    let err = MultibaseStr::from_str("z0OIl").unwrap_err();
+    assert!(matches!(err, Error::Base58Decoding { .. }));
+}
+
+#[test]
+fn multibase_decode_supports_base58btc_and_base32() {
+    assert_eq!(multibase::decode("zStV1DL6CwTryKyV").unwrap(), b"hello world");
+    assert_eq!(multibase::decode("bnbswy3dpeb3w64tmmq").unwrap(), b"hello world");
+    assert_eq!(multibase::decode("BNBSWY3DPEB3W64TMMQ").unwrap(), b"hello world");
+    assert_eq!(multibase::decode("b").unwrap(), b"");
+}
+
+#[test]
+fn multibase_decode_rejects_invalid_input() {
+    let err = multibase::decode("maGVsbG8gd29ybGQ").unwrap_err();
+    assert!(matches!(err, Error::UnsupportedBase { .. }));
+    let err = multibase::decode("").unwrap_err();
+    assert!(matches!(err, Error::UnsupportedBase { .. }));
+    // '0' is neither in the base32 alphabet nor in the base58 alphabet
+    let err = multibase::decode("b0000").unwrap_err();
+    assert!(matches!(err, Error::Base32Decoding { .. }));
+    let err = multibase::decode("z0OIl").unwrap_err();
+    assert!(matches!(err, Error::Base58Decoding { .. }));
+}
+
+// ---------------------------------------------------------------------------
+// Multikey
+// ---------------------------------------------------------------------------
+
+#[test]
+fn multikey_has_well_known_prefixes() {
+    let cases = [
+        (Multicodec::Ed25519Pub, vec![0xaa; 32], "z6Mk"),
+        (Multicodec::X25519Pub, vec![0xaa; 32], "z6LS"),
+        (Multicodec::Secp256k1Pub, [vec![0x02], vec![0xaa; 32]].concat(), "zQ3s"),
+    ];
+    for (codec, key, prefix) in cases {
+        let encoded = Multikey { codec, key }.encode().to_string();
+        assert!(
+            encoded.starts_with(prefix),
+            "{codec:?} multikey {encoded} should start with {prefix}"
+        );
+    }
+}
+
+#[test]
+fn multikey_decode_roundtrip() {
+    for codec in [Multicodec::Ed25519Pub, Multicodec::X25519Pub, Multicodec::Secp256k1Pub] {
+        let multikey = Multikey {
+            codec,
+            key: vec![7; 33],
+        };
+        assert_eq!(Multikey::decode(&multikey.encode()).unwrap(), multikey);
+    }
+}
+
+#[test]
+fn multikey_decode_rejects_unsupported_codec() {
+    // 0x12 is the sha2-256 multihash code
+    let err = Multikey::decode(&MultibaseStr::from([0x12, 0x20, 0x00])).unwrap_err();
+    assert!(matches!(err, Error::UnsupportedMulticodec { code: 0x12 }));
+}
+
+#[test]
+fn multikey_decode_rejects_truncated_varint() {
+    let err = Multikey::decode(&MultibaseStr::from([0xed])).unwrap_err();
+    assert!(matches!(err, Error::InvalidMulticodec));
+}
+
+#[test]
+fn multicodec_code_roundtrip() {
+    for codec in [Multicodec::Ed25519Pub, Multicodec::X25519Pub, Multicodec::Secp256k1Pub] {
+        assert_eq!(Multicodec::from_code(codec.code()), Some(codec));
+    }
+    assert_eq!(Multicodec::from_code(0x12), None);
+}
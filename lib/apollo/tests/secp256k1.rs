@@ -6,6 +6,7 @@ use identus_apollo::crypto::secp256k1::{Secp256k1PrivateKey, Secp256k1PublicKey}
 use identus_apollo::crypto::{EncodeArray, EncodeVec, Verifiable};
 use identus_apollo::hex::HexStr;
 use identus_apollo::jwk::EncodeJwk;
+use identus_apollo::multibase::{EncodeMultikey, Multicodec, Multikey};
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -442,3 +443,16 @@ fn verify_high_s_signature_wrong_message_returns_false() {
     // so it should traverse vanilla → normalized → transcoded paths and return false.
     assert!(!result, "high-S signature against wrong message should fail");
 }
+
+// ---------------------------------------------------------------------------
+// EncodeMultikey for Secp256k1PublicKey
+// ---------------------------------------------------------------------------
+
+#[test]
+fn encode_multikey_roundtrip() {
+    let pk = sample_public_key();
+    let multikey = pk.encode_multikey();
+    assert_eq!(multikey.codec, Multicodec::Secp256k1Pub);
+    assert_eq!(multikey.key, pk.encode_compressed().to_vec());
+    assert_eq!(Multikey::decode(&multikey.encode()).unwrap(), multikey);
+}
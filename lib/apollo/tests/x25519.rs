@@ -1,8 +1,9 @@
 #![cfg(feature = "x25519")]
 
-use identus_apollo::crypto::x25519::X25519PublicKey;
-use identus_apollo::crypto::{EncodeArray, EncodeVec};
+use identus_apollo::crypto::x25519::{X25519PrivateKey, X25519PublicKey};
+use identus_apollo::crypto::{EncodeArray, EncodeVec, Error};
 use identus_apollo::jwk::EncodeJwk;
+use identus_apollo::multibase::{EncodeMultikey, Multicodec, Multikey};
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -175,3 +176,79 @@ fn public_keys_from_different_bytes_are_not_equal() {
     let pk2 = X25519PublicKey::from_slice(&[0x02; 32]).unwrap();
     assert_ne!(pk1, pk2);
 }
+
+// ---------------------------------------------------------------------------
+// EncodeMultikey for X25519PublicKey
+// ---------------------------------------------------------------------------
+
+#[test]
+fn encode_multikey_roundtrip() {
+    let pk = sample_public_key();
+    let multikey = pk.encode_multikey();
+    assert_eq!(multikey.codec, Multicodec::X25519Pub);
+    assert_eq!(multikey.key, pk.encode_vec());
+    assert_eq!(Multikey::decode(&multikey.encode()).unwrap(), multikey);
+}
+
+// ---------------------------------------------------------------------------
+// X25519PrivateKey
+// ---------------------------------------------------------------------------
+
+#[test]
+fn private_key_from_slice_wrong_size_returns_invalid_key_size() {
+    let err = X25519PrivateKey::from_slice(&[0u8; 31]).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            Error::InvalidKeySize {
+                expected: 32,
+                actual: 31,
+                ..
+            }
+        ),
+        "expected InvalidKeySize, got: {err:?}"
+    );
+}
+
+#[test]
+fn private_key_to_public_key_matches_dalek() {
+    let sk = X25519PrivateKey::from_slice(&[0x11; 32]).unwrap();
+    let expected = x25519_dalek::PublicKey::from(&x25519_dalek::StaticSecret::from([0x11; 32]));
+    assert_eq!(sk.to_public_key().encode_array(), expected.to_bytes());
+}
+
+#[test]
+fn diffie_hellman_agrees_on_shared_secret() {
+    let alice = X25519PrivateKey::from_slice(&[0x11; 32]).unwrap();
+    let bob = X25519PrivateKey::from_slice(&[0x22; 32]).unwrap();
+    let alice_secret = alice.diffie_hellman(&bob.to_public_key()).unwrap();
+    let bob_secret = bob.diffie_hellman(&alice.to_public_key()).unwrap();
+    assert_eq!(alice_secret.as_bytes(), bob_secret.as_bytes());
+    assert_ne!(alice_secret.as_bytes(), &[0u8; 32]);
+}
+
+#[test]
+fn diffie_hellman_rejects_low_order_public_key() {
+    let sk = X25519PrivateKey::from_slice(&[0x11; 32]).unwrap();
+    let low_order = X25519PublicKey::from_slice(&[0u8; 32]).unwrap();
+    let err = sk.diffie_hellman(&low_order).unwrap_err();
+    assert!(matches!(err, Error::NonContributoryKeyAgreement), "got: {err:?}");
+}
+
+#[test]
+fn private_key_equality() {
+    let sk1 = X25519PrivateKey::from_slice(&[0x11; 32]).unwrap();
+    let sk2 = X25519PrivateKey::from_slice(&[0x11; 32]).unwrap();
+    let sk3 = X25519PrivateKey::from_slice(&[0x22; 32]).unwrap();
+    assert_eq!(sk1, sk2);
+    assert_ne!(sk1, sk3);
+}
+
+#[test]
+fn private_key_and_shared_secret_debug_do_not_leak_bytes() {
+    let sk = X25519PrivateKey::from_slice(&[0x11; 32]).unwrap();
+    assert_eq!(format!("{sk:?}"), "X25519PrivateKey(..)");
+    let other = X25519PrivateKey::from_slice(&[0x22; 32]).unwrap();
+    let shared_secret = sk.diffie_hellman(&other.to_public_key()).unwrap();
+    assert_eq!(format!("{shared_secret:?}"), "X25519SharedSecret(..)");
+}
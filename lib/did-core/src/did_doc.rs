@@ -3,6 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::{Did, Uri};
 
+/// JSON-LD context of DID documents.
+pub const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+const JWS_2020_CONTEXT: &str = "https://w3id.org/security/suites/jws-2020/v1";
+const MULTIKEY_CONTEXT: &str = "https://w3id.org/security/multikey/v1";
+const ED25519_2020_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2020/v1";
+const X25519_2020_CONTEXT: &str = "https://w3id.org/security/suites/x25519-2020/v1";
+const SECP256K1_2019_CONTEXT: &str = "https://w3id.org/security/suites/secp256k1-2019/v1";
+const LINKED_DOMAINS_CONTEXT: &str = "https://identity.foundation/.well-known/did-configuration/v1";
+const DIDCOMM_MESSAGING_CONTEXT: &str = "https://didcomm.org/messaging/contexts/v2";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[cfg_attr(
@@ -25,6 +35,82 @@ pub struct DidDocument {
     pub service: Option<Vec<Service>>,
 }
 
+impl DidDocument {
+    /// Complete the `@context` for the JSON-LD representation.
+    ///
+    /// The DID context is placed first, followed by the contexts defining every known verification method
+    /// and service type used in the document. Contexts already present are kept in their order.
+    pub fn with_json_ld_context(mut self) -> Self {
+        let relationship_methods = [
+            &self.authentication,
+            &self.assertion_method,
+            &self.key_agreement,
+            &self.capability_invocation,
+            &self.capability_delegation,
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|i| match i {
+            VerificationMethodOrRef::Embedded(vm) => Some(vm),
+            VerificationMethodOrRef::Ref(_) => None,
+        });
+        let vm_contexts = self
+            .verification_method
+            .iter()
+            .chain(relationship_methods)
+            .filter_map(|vm| verification_method_context(&vm.r#type, false));
+        let service_contexts = self
+            .service
+            .iter()
+            .flatten()
+            .flat_map(|s| match &s.r#type {
+                ServiceType::Str(t) => vec![t.as_str()],
+                ServiceType::List(ts) => ts.iter().map(String::as_str).collect(),
+            })
+            .filter_map(service_context);
+
+        let mut context = vec![DID_CONTEXT.to_string()];
+        for i in self
+            .context
+            .iter()
+            .map(String::as_str)
+            .chain(vm_contexts)
+            .chain(service_contexts)
+        {
+            if !context.iter().any(|c| c == i) {
+                context.push(i.to_string());
+            }
+        }
+        self.context = context;
+        self
+    }
+}
+
+/// JSON-LD context defining a verification method type.
+///
+/// With `legacy_json_web_key_2020`, `JsonWebKey2020` has no context, as in the documents of the legacy PRISM node.
+pub fn verification_method_context(r#type: &str, legacy_json_web_key_2020: bool) -> Option<&'static str> {
+    match r#type {
+        "JsonWebKey2020" if legacy_json_web_key_2020 => None,
+        "JsonWebKey2020" => Some(JWS_2020_CONTEXT),
+        "Multikey" => Some(MULTIKEY_CONTEXT),
+        "Ed25519VerificationKey2020" => Some(ED25519_2020_CONTEXT),
+        "X25519KeyAgreementKey2020" => Some(X25519_2020_CONTEXT),
+        "EcdsaSecp256k1VerificationKey2019" => Some(SECP256K1_2019_CONTEXT),
+        _ => None,
+    }
+}
+
+/// JSON-LD context defining a service type.
+fn service_context(r#type: &str) -> Option<&'static str> {
+    match r#type {
+        "LinkedDomains" => Some(LINKED_DOMAINS_CONTEXT),
+        "DIDCommMessaging" => Some(DIDCOMM_MESSAGING_CONTEXT),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[cfg_attr(
@@ -37,8 +123,27 @@ pub struct VerificationMethod {
     pub id: String,
     pub r#type: String,
     pub controller: String,
-    #[cfg_attr(feature = "ts-types", ts(type = "Record<string, any> | null"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts-types", ts(type = "Record<string, any>", optional))]
     pub public_key_jwk: Option<Jwk>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts-types", ts(optional))]
+    pub public_key_multibase: Option<String>,
+}
+
+/// How public keys are represented as verification methods in a DID document.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum VerificationMethodEncoding {
+    /// `JsonWebKey2020` with `publicKeyJwk`.
+    #[default]
+    Jwk,
+    /// `Multikey` with `publicKeyMultibase`.
+    Multikey,
+    /// The type specific to the key: `EcdsaSecp256k1VerificationKey2019` with `publicKeyJwk`,
+    /// or `Ed25519VerificationKey2020` and `X25519KeyAgreementKey2020` with `publicKeyMultibase`.
+    KeyType,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
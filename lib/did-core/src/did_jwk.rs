@@ -0,0 +1,114 @@
+//! Resolver of the [did:jwk](https://github.com/quartzjer/did-jwk/blob/main/spec.md) method.
+
+use std::str::FromStr;
+
+use identus_apollo::base64::{self, Base64UrlStrNoPad};
+use identus_apollo::crypto::ed25519::Ed25519PublicKey;
+use identus_apollo::crypto::secp256k1::Secp256k1PublicKey;
+use identus_apollo::crypto::x25519::X25519PublicKey;
+use identus_apollo::jwk::{self, DecodeJwk, Jwk};
+
+use crate::key_document::single_key_document;
+use crate::{
+    Did, DidDocument, DidOps, DidResolutionErrorCode, DidResolver, ResolutionOptions, ResolutionResult,
+    VerificationKey, VerificationMethodEncoding,
+};
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum DidJwkError {
+    #[display("DID method '{method}' is not 'jwk'")]
+    UnexpectedMethod {
+        #[error(not(source))]
+        method: String,
+    },
+    #[display("did:jwk identifier is not base64url encoded")]
+    InvalidEncoding { source: base64::Error },
+    #[display("did:jwk identifier is not a JSON web key")]
+    InvalidJwk { source: serde_json::Error },
+    #[display("did:jwk identifier must not contain a private key")]
+    PrivateKey,
+    #[display("jwk with kty '{kty}' and crv '{crv}' is not a supported key type")]
+    UnsupportedKeyType { kty: String, crv: String },
+    #[display("did:jwk identifier does not contain a valid public key")]
+    InvalidPublicKey { source: jwk::Error },
+}
+
+impl DidJwkError {
+    pub fn error_code(&self) -> DidResolutionErrorCode {
+        match self {
+            Self::UnexpectedMethod { .. } => DidResolutionErrorCode::MethodNotSupported,
+            Self::InvalidEncoding { .. } | Self::InvalidJwk { .. } | Self::PrivateKey => {
+                DidResolutionErrorCode::InvalidDid
+            }
+            Self::UnsupportedKeyType { .. } => DidResolutionErrorCode::UnsupportedPublicKeyType,
+            Self::InvalidPublicKey { .. } => DidResolutionErrorCode::InvalidPublicKey,
+        }
+    }
+}
+
+/// Resolves did:jwk DIDs locally, since the DID document is derived from the JWK in the DID.
+///
+/// Supports secp256k1, Ed25519 and X25519 keys. The verification method `#0` is a `JsonWebKey2020`
+/// unless another encoding is requested.
+///
+/// # Example
+/// ```
+/// use identus_did_core::DidJwkResolver;
+///
+/// // {"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}
+/// let did = "did:jwk:eyJrdHkiOiJPS1AiLCJjcnYiOiJFZDI1NTE5IiwieCI6IjExcVlBWUt4Q3JmVlNfN1R5V1FIT2c3aGN2UGFwaU1scndJYWFQY0hVUm8ifQ"
+///     .parse()
+///     .unwrap();
+/// let document = DidJwkResolver.resolve_document(&did, Default::default()).unwrap();
+/// assert_eq!(document.verification_method[0].id, format!("{did}#0"));
+/// assert_eq!(document.verification_method[0].r#type, "JsonWebKey2020");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DidJwkResolver;
+
+impl DidJwkResolver {
+    pub const METHOD: &str = "jwk";
+
+    /// Build the DID document of a did:jwk DID, with the verification method in the given encoding if any.
+    pub fn resolve_document(
+        &self,
+        did: &Did,
+        encoding: Option<VerificationMethodEncoding>,
+    ) -> Result<DidDocument, DidJwkError> {
+        if did.method() != Self::METHOD {
+            Err(DidJwkError::UnexpectedMethod {
+                method: did.method().to_string(),
+            })?
+        }
+        let encoded =
+            Base64UrlStrNoPad::from_str(did.method_id()).map_err(|e| DidJwkError::InvalidEncoding { source: e })?;
+        let jwk: Jwk =
+            serde_json::from_slice(&encoded.to_bytes()).map_err(|e| DidJwkError::InvalidJwk { source: e })?;
+        if jwk.is_private() {
+            Err(DidJwkError::PrivateKey)?
+        }
+        let invalid_key = |e| DidJwkError::InvalidPublicKey { source: e };
+        let key = match (jwk.kty.as_str(), jwk.crv.as_str()) {
+            ("EC", "secp256k1") => VerificationKey::from(Secp256k1PublicKey::decode_jwk(&jwk).map_err(invalid_key)?),
+            ("OKP", "Ed25519") => VerificationKey::from(Ed25519PublicKey::decode_jwk(&jwk).map_err(invalid_key)?),
+            ("OKP", "X25519") => VerificationKey::from(X25519PublicKey::decode_jwk(&jwk).map_err(invalid_key)?),
+            (kty, crv) => Err(DidJwkError::UnsupportedKeyType {
+                kty: kty.to_string(),
+                crv: crv.to_string(),
+            })?,
+        };
+        let encoding = encoding.unwrap_or(VerificationMethodEncoding::Jwk);
+        Ok(single_key_document(did, "0", &key, encoding))
+    }
+}
+
+#[async_trait::async_trait]
+impl DidResolver for DidJwkResolver {
+    async fn resolve(&self, did: &Did, options: &ResolutionOptions) -> ResolutionResult {
+        match self.resolve_document(did, options.verification_method_encoding) {
+            Ok(document) => ResolutionResult::success(document),
+            Err(DidJwkError::UnexpectedMethod { method }) => ResolutionResult::method_not_supported(&method),
+            Err(e) => ResolutionResult::error(e.error_code(), "Invalid DID", e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Resolver of the [did:key](https://w3c-ccg.github.io/did-key-spec/) method.
+
+use std::str::FromStr;
+
+use identus_apollo::crypto::ed25519::Ed25519PublicKey;
+use identus_apollo::crypto::secp256k1::Secp256k1PublicKey;
+use identus_apollo::crypto::x25519::X25519PublicKey;
+use identus_apollo::multibase::{self, MultibaseStr, Multicodec, Multikey};
+
+use crate::key_document::single_key_document;
+use crate::{
+    Did, DidDocument, DidOps, DidResolutionErrorCode, DidResolver, ResolutionOptions, ResolutionResult,
+    VerificationKey, VerificationMethodEncoding,
+};
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum DidKeyError {
+    #[display("DID method '{method}' is not 'key'")]
+    UnexpectedMethod {
+        #[error(not(source))]
+        method: String,
+    },
+    #[display("did:key identifier is not a multibase encoded multikey")]
+    InvalidMultikey { source: multibase::Error },
+    #[display("did:key identifier does not contain a valid public key")]
+    InvalidPublicKey { source: identus_apollo::crypto::Error },
+}
+
+impl DidKeyError {
+    pub fn error_code(&self) -> DidResolutionErrorCode {
+        match self {
+            Self::UnexpectedMethod { .. } => DidResolutionErrorCode::MethodNotSupported,
+            Self::InvalidMultikey {
+                source: multibase::Error::UnsupportedMulticodec { .. },
+            } => DidResolutionErrorCode::UnsupportedPublicKeyType,
+            Self::InvalidMultikey { .. } => DidResolutionErrorCode::InvalidDid,
+            Self::InvalidPublicKey {
+                source: identus_apollo::crypto::Error::InvalidKeySize { .. },
+            } => DidResolutionErrorCode::InvalidPublicKeyLength,
+            Self::InvalidPublicKey { .. } => DidResolutionErrorCode::InvalidPublicKey,
+        }
+    }
+}
+
+/// Resolves did:key DIDs locally, since the DID document is derived from the public key in the DID.
+///
+/// Supports secp256k1, Ed25519 and X25519 keys. The verification method is a `Multikey`
+/// unless another encoding is requested. No X25519 key agreement key is derived from Ed25519 keys.
+///
+/// # Example
+/// ```
+/// use identus_did_core::DidKeyResolver;
+///
+/// let did = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK".parse().unwrap();
+/// let document = DidKeyResolver.resolve_document(&did, Default::default()).unwrap();
+/// assert_eq!(
+///     document.verification_method[0].id,
+///     "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DidKeyResolver;
+
+impl DidKeyResolver {
+    pub const METHOD: &str = "key";
+
+    /// Build the DID document of a did:key DID, with the verification method in the given encoding if any.
+    pub fn resolve_document(
+        &self,
+        did: &Did,
+        encoding: Option<VerificationMethodEncoding>,
+    ) -> Result<DidDocument, DidKeyError> {
+        if did.method() != Self::METHOD {
+            Err(DidKeyError::UnexpectedMethod {
+                method: did.method().to_string(),
+            })?
+        }
+        let multibase =
+            MultibaseStr::from_str(did.method_id()).map_err(|e| DidKeyError::InvalidMultikey { source: e })?;
+        let multikey = Multikey::decode(&multibase).map_err(|e| DidKeyError::InvalidMultikey { source: e })?;
+        let invalid_key = |e| DidKeyError::InvalidPublicKey { source: e };
+        let key = match multikey.codec {
+            Multicodec::Secp256k1Pub => {
+                VerificationKey::from(Secp256k1PublicKey::from_slice(&multikey.key).map_err(invalid_key)?)
+            }
+            Multicodec::Ed25519Pub => {
+                VerificationKey::from(Ed25519PublicKey::from_slice(&multikey.key).map_err(invalid_key)?)
+            }
+            Multicodec::X25519Pub => {
+                VerificationKey::from(X25519PublicKey::from_slice(&multikey.key).map_err(invalid_key)?)
+            }
+        };
+        let encoding = encoding.unwrap_or(VerificationMethodEncoding::Multikey);
+        Ok(single_key_document(did, did.method_id(), &key, encoding))
+    }
+}
+
+#[async_trait::async_trait]
+impl DidResolver for DidKeyResolver {
+    async fn resolve(&self, did: &Did, options: &ResolutionOptions) -> ResolutionResult {
+        match self.resolve_document(did, options.verification_method_encoding) {
+            Ok(document) => ResolutionResult::success(document),
+            Err(DidKeyError::UnexpectedMethod { method }) => ResolutionResult::method_not_supported(&method),
+            Err(e) => ResolutionResult::error(e.error_code(), "Invalid DID", e.to_string()),
+        }
+    }
+}
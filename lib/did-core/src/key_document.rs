@@ -0,0 +1,33 @@
+//! DID documents of the DID methods made of a single public key, such as did:key and did:jwk.
+
+use crate::{DID_CONTEXT, Did, DidDocument, VerificationKey, VerificationMethodEncoding, VerificationMethodOrRef};
+
+/// The DID document with the key as its only verification method, identified by the fragment.
+///
+/// X25519 keys are used for key agreement, and signing keys for every other verification relationship.
+pub(crate) fn single_key_document(
+    did: &Did,
+    fragment: &str,
+    key: &VerificationKey,
+    encoding: VerificationMethodEncoding,
+) -> DidDocument {
+    let vm_id = format!("{did}#{fragment}");
+    let verification_method = key.to_verification_method(vm_id.clone(), did, encoding);
+    let (signing, key_agreement) = match key {
+        VerificationKey::X25519(_) => (None, Some(vec![VerificationMethodOrRef::Ref(vm_id)])),
+        _ => (Some(vec![VerificationMethodOrRef::Ref(vm_id)]), None),
+    };
+    DidDocument {
+        context: vec![DID_CONTEXT.to_string()],
+        id: did.clone(),
+        also_known_as: None,
+        verification_method: vec![verification_method],
+        authentication: signing.clone(),
+        assertion_method: signing.clone(),
+        key_agreement,
+        capability_invocation: signing.clone(),
+        capability_delegation: signing,
+        service: None,
+    }
+    .with_json_ld_context()
+}
@@ -1,11 +1,27 @@
 mod did;
 mod did_doc;
+#[cfg(feature = "did-jwk")]
+mod did_jwk;
+#[cfg(feature = "did-key")]
+mod did_key;
 mod error;
+#[cfg(any(feature = "did-key", feature = "did-jwk"))]
+mod key_document;
 mod resolution;
+mod router;
 pub mod uri;
+#[cfg(feature = "verification-key")]
+mod verification_key;
 
 pub use did::*;
 pub use did_doc::*;
+#[cfg(feature = "did-jwk")]
+pub use did_jwk::*;
+#[cfg(feature = "did-key")]
+pub use did_key::*;
 pub use error::*;
 pub use resolution::*;
+pub use router::*;
 pub use uri::*;
+#[cfg(feature = "verification-key")]
+pub use verification_key::*;
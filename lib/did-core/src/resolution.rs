@@ -1,7 +1,8 @@
 use chrono::{DateTime, Utc};
+use identus_apollo::jwk::Jwk;
 use serde::{Deserialize, Serialize};
 
-use crate::{Did, DidDocument};
+use crate::{Did, DidDocument, VerificationMethodEncoding};
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
@@ -11,6 +12,10 @@ pub struct ResolutionOptions {
     pub expand_relative_urls: Option<bool>,
     pub version_id: Option<String>,
     pub version_time: Option<DateTime<Utc>>,
+    /// Representation of the verification methods in the DID document, defaulting to the one configured by the resolver.
+    pub verification_method_encoding: Option<VerificationMethodEncoding>,
+    /// Whether to list the keys controlling the DID that are not part of the DID document in the document metadata.
+    pub include_operation_keys: Option<bool>,
 }
 
 #[async_trait::async_trait]
@@ -66,6 +71,31 @@ impl ResolutionResult {
             did_document: Default::default(),
         }
     }
+
+    pub fn error(r#type: DidResolutionErrorCode, title: &str, detail: String) -> Self {
+        let error = DidResolutionError {
+            r#type,
+            title: Some(title.to_string()),
+            detail: Some(detail),
+        };
+
+        ResolutionResult {
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: None,
+                error: Some(error),
+            },
+            did_document_metadata: Default::default(),
+            did_document: Default::default(),
+        }
+    }
+
+    pub fn method_not_supported(method: &str) -> Self {
+        Self::error(
+            DidResolutionErrorCode::MethodNotSupported,
+            "Method Not Supported",
+            format!("DID method '{method}' is not supported"),
+        )
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -77,6 +107,20 @@ pub struct DidDocumentMetadata {
     pub deactivated: Option<bool>,
     pub canonical_id: Option<Did>,
     pub version_id: Option<String>,
+    /// Keys controlling the DID that are not part of the DID document, listed when requested by the resolution options.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_keys: Option<Vec<OperationKey>>,
+}
+
+/// A key authorizing operations on the DID, such as a PRISM master or VDR key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct OperationKey {
+    pub id: String,
+    /// The operations the key authorizes, e.g. `master` or `vdr`.
+    pub purpose: String,
+    pub public_key_jwk: Jwk,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
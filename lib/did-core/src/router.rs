@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{Did, DidOps, DidResolver, ResolutionOptions, ResolutionResult};
+
+/// A resolver dispatching each DID to the resolver registered for its method.
+///
+/// DIDs of a method without a registered resolver get a `MethodNotSupported` error.
+///
+/// # Example
+/// ```
+/// use identus_did_core::{
+///     Did, DidResolver, DidResolverRouter, ResolutionOptions, ResolutionResult,
+/// };
+///
+/// struct ExampleResolver;
+///
+/// #[async_trait::async_trait]
+/// impl DidResolver for ExampleResolver {
+///     async fn resolve(&self, _did: &Did, _options: &ResolutionOptions) -> ResolutionResult {
+///         ResolutionResult::deactivated()
+///     }
+/// }
+///
+/// let router = DidResolverRouter::new().with_resolver("example", ExampleResolver);
+/// assert_eq!(router.methods().collect::<Vec<_>>(), ["example"]);
+/// ```
+#[derive(Clone, Default)]
+pub struct DidResolverRouter {
+    resolvers: HashMap<String, Arc<dyn DidResolver + Send + Sync>>,
+}
+
+impl DidResolverRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the resolver of a DID method, replacing any resolver previously registered for it.
+    pub fn with_resolver(mut self, method: &str, resolver: impl DidResolver + Send + Sync + 'static) -> Self {
+        self.resolvers.insert(method.to_string(), Arc::new(resolver));
+        self
+    }
+
+    /// The DID methods with a registered resolver.
+    pub fn methods(&self) -> impl Iterator<Item = &str> {
+        self.resolvers.keys().map(String::as_str)
+    }
+}
+
+#[async_trait::async_trait]
+impl DidResolver for DidResolverRouter {
+    async fn resolve(&self, did: &Did, options: &ResolutionOptions) -> ResolutionResult {
+        match self.resolvers.get(did.method()) {
+            Some(resolver) => resolver.resolve(did, options).await,
+            None => ResolutionResult::method_not_supported(did.method()),
+        }
+    }
+}
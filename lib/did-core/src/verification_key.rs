@@ -0,0 +1,63 @@
+//! Verification methods of the public keys supported by Identus DIDs.
+
+use identus_apollo::crypto::ed25519::Ed25519PublicKey;
+use identus_apollo::crypto::secp256k1::Secp256k1PublicKey;
+use identus_apollo::crypto::x25519::X25519PublicKey;
+use identus_apollo::jwk::{EncodeJwk, Jwk};
+use identus_apollo::multibase::{EncodeMultikey, Multikey};
+
+use crate::{Did, VerificationMethod, VerificationMethodEncoding};
+
+/// A public key that can be represented as a verification method.
+#[derive(Debug, Clone, derive_more::From)]
+pub enum VerificationKey {
+    Secp256k1(Secp256k1PublicKey),
+    Ed25519(Ed25519PublicKey),
+    X25519(X25519PublicKey),
+}
+
+impl VerificationKey {
+    fn encode_jwk(&self) -> Jwk {
+        match self {
+            Self::Secp256k1(pk) => pk.encode_jwk(),
+            Self::Ed25519(pk) => pk.encode_jwk(),
+            Self::X25519(pk) => pk.encode_jwk(),
+        }
+    }
+
+    fn encode_multikey(&self) -> Multikey {
+        match self {
+            Self::Secp256k1(pk) => pk.encode_multikey(),
+            Self::Ed25519(pk) => pk.encode_multikey(),
+            Self::X25519(pk) => pk.encode_multikey(),
+        }
+    }
+
+    /// Represent the key as a verification method of the given encoding.
+    pub fn to_verification_method(
+        &self,
+        id: String,
+        did: &Did,
+        encoding: VerificationMethodEncoding,
+    ) -> VerificationMethod {
+        let multibase = || Some(self.encode_multikey().encode().to_string());
+        let (r#type, public_key_jwk, public_key_multibase) = match (encoding, self) {
+            (VerificationMethodEncoding::Jwk, _) => ("JsonWebKey2020", Some(self.encode_jwk()), None),
+            (VerificationMethodEncoding::Multikey, _) => ("Multikey", None, multibase()),
+            (VerificationMethodEncoding::KeyType, Self::Secp256k1(_)) => {
+                ("EcdsaSecp256k1VerificationKey2019", Some(self.encode_jwk()), None)
+            }
+            (VerificationMethodEncoding::KeyType, Self::Ed25519(_)) => {
+                ("Ed25519VerificationKey2020", None, multibase())
+            }
+            (VerificationMethodEncoding::KeyType, Self::X25519(_)) => ("X25519KeyAgreementKey2020", None, multibase()),
+        };
+        VerificationMethod {
+            id,
+            r#type: r#type.to_string(),
+            controller: did.to_string(),
+            public_key_jwk,
+            public_key_multibase,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use identus_did_core::{
+    Did, DidDocument, Service, ServiceEndpoint, ServiceType, StringOrMap, VerificationMethod, VerificationMethodOrRef,
+    verification_method_context,
+};
+
+fn sample_did() -> Did {
+    "did:example:123456789abcdefghi".parse().unwrap()
+}
+
+fn sample_did_document(did: &Did) -> DidDocument {
+    DidDocument {
+        context: vec![],
+        id: did.clone(),
+        also_known_as: None,
+        verification_method: vec![],
+        authentication: None,
+        assertion_method: None,
+        key_agreement: None,
+        capability_invocation: None,
+        capability_delegation: None,
+        service: None,
+    }
+}
+
+fn sample_verification_method(did: &Did, id: &str, r#type: &str) -> VerificationMethod {
+    VerificationMethod {
+        id: format!("{did}#{id}"),
+        r#type: r#type.to_string(),
+        controller: did.to_string(),
+        public_key_jwk: None,
+        public_key_multibase: None,
+    }
+}
+
+fn sample_service(did: &Did, r#type: ServiceType) -> Service {
+    Service {
+        id: format!("{did}#service-1"),
+        r#type,
+        service_endpoint: ServiceEndpoint::StrOrMap(StringOrMap::Str("https://example.com".to_string())),
+    }
+}
+
+// ------------------------------------------------------------------
+// JSON-LD context
+// ------------------------------------------------------------------
+
+#[test]
+fn json_ld_context_adds_did_context_first() {
+    let did = sample_did();
+    let mut doc = sample_did_document(&did);
+    doc.context = vec!["https://example.com/custom/v1".to_string()];
+
+    let doc = doc.with_json_ld_context();
+    assert_eq!(
+        doc.context,
+        vec!["https://www.w3.org/ns/did/v1", "https://example.com/custom/v1"]
+    );
+}
+
+#[test]
+fn json_ld_context_covers_verification_method_types() {
+    let did = sample_did();
+    let mut doc = sample_did_document(&did);
+    doc.verification_method = vec![
+        sample_verification_method(&did, "key-1", "JsonWebKey2020"),
+        sample_verification_method(&did, "key-2", "Multikey"),
+        sample_verification_method(&did, "key-3", "Multikey"),
+    ];
+    doc.key_agreement = Some(vec![VerificationMethodOrRef::Embedded(sample_verification_method(
+        &did,
+        "key-4",
+        "X25519KeyAgreementKey2020",
+    ))]);
+
+    let doc = doc.with_json_ld_context();
+    assert_eq!(
+        doc.context,
+        vec![
+            "https://www.w3.org/ns/did/v1",
+            "https://w3id.org/security/suites/jws-2020/v1",
+            "https://w3id.org/security/multikey/v1",
+            "https://w3id.org/security/suites/x25519-2020/v1",
+        ]
+    );
+}
+
+#[test]
+fn json_ld_context_covers_service_types() {
+    let did = sample_did();
+    let mut doc = sample_did_document(&did);
+    doc.service = Some(vec![
+        sample_service(&did, ServiceType::Str("LinkedDomains".to_string())),
+        sample_service(
+            &did,
+            ServiceType::List(vec!["DIDCommMessaging".to_string(), "LinkedDomains".to_string()]),
+        ),
+    ]);
+
+    let doc = doc.with_json_ld_context();
+    assert_eq!(
+        doc.context,
+        vec![
+            "https://www.w3.org/ns/did/v1",
+            "https://identity.foundation/.well-known/did-configuration/v1",
+            "https://didcomm.org/messaging/contexts/v2",
+        ]
+    );
+}
+
+#[test]
+fn json_ld_context_does_not_duplicate_existing_contexts() {
+    let did = sample_did();
+    let mut doc = sample_did_document(&did);
+    doc.context = vec![
+        "https://www.w3.org/ns/did/v1".to_string(),
+        "https://w3id.org/security/multikey/v1".to_string(),
+    ];
+    doc.verification_method = vec![sample_verification_method(&did, "key-1", "Multikey")];
+
+    let doc = doc.with_json_ld_context();
+    assert_eq!(
+        doc.context,
+        vec!["https://www.w3.org/ns/did/v1", "https://w3id.org/security/multikey/v1"]
+    );
+}
+
+#[test]
+fn verification_method_context_omits_json_web_key_2020_for_legacy_documents() {
+    assert_eq!(
+        verification_method_context("JsonWebKey2020", false),
+        Some("https://w3id.org/security/suites/jws-2020/v1")
+    );
+    assert_eq!(verification_method_context("JsonWebKey2020", true), None);
+    assert_eq!(
+        verification_method_context("Multikey", true),
+        Some("https://w3id.org/security/multikey/v1")
+    );
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "did-jwk")]
+
+use identus_did_core::{
+    Did, DidJwkError, DidJwkResolver, DidResolutionErrorCode, DidResolver, ResolutionOptions,
+    VerificationMethodEncoding,
+};
+
+// {"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}
+const ED25519_DID: &str = "did:jwk:eyJrdHkiOiJPS1AiLCJjcnYiOiJFZDI1NTE5IiwieCI6IjExcVlBWUt4Q3JmVlNfN1R5V1FIT2c3aGN2UGFwaU1scndJYWFQY0hVUm8ifQ";
+// {"kty":"OKP","crv":"X25519","use":"enc","x":"3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"}
+const X25519_DID: &str = "did:jwk:eyJrdHkiOiJPS1AiLCJjcnYiOiJYMjU1MTkiLCJ1c2UiOiJlbmMiLCJ4IjoiM3A3YmZYdDl3YlRUVzJIQzdPUTFOei1EUThoYmVHZE5yZngtRkctSUswOCJ9";
+// {"kty":"EC","crv":"P-256","x":"acbIQiuMs3i8_uszEjJ2tpTtRM4EU3yz91PH6CdH2V0","y":"_KcyLj9vWMptnmKtm46GqDz8wf74I5LKgrl2GzH3nSE"}
+const P256_DID: &str = "did:jwk:eyJrdHkiOiJFQyIsImNydiI6IlAtMjU2IiwieCI6ImFjYklRaXVNczNpOF91c3pFakoydHBUdFJNNEVVM3l6OTFQSDZDZEgyVjAiLCJ5IjoiX0tjeUxqOXZXTXB0bm1LdG00NkdxRHo4d2Y3NEk1TEtncmwyR3pIM25TRSJ9";
+// {"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo","d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"}
+const PRIVATE_DID: &str = "did:jwk:eyJrdHkiOiJPS1AiLCJjcnYiOiJFZDI1NTE5IiwieCI6IjExcVlBWUt4Q3JmVlNfN1R5V1FIT2c3aGN2UGFwaU1scndJYWFQY0hVUm8iLCJkIjoibldHeG5lXzlXbUM2aEVyMGt1d3N4RVJKeFdsN01ta1pjRHVzQXh5dWYyQSJ9";
+
+fn did(s: &str) -> Did {
+    s.parse().unwrap()
+}
+
+// ------------------------------------------------------------------
+// Document
+// ------------------------------------------------------------------
+
+#[test]
+fn resolve_ed25519_did_jwk() {
+    let document = DidJwkResolver.resolve_document(&did(ED25519_DID), None).unwrap();
+    let vm_id = format!("{ED25519_DID}#0");
+
+    assert_eq!(document.id.to_string(), ED25519_DID);
+    let vm = &document.verification_method[0];
+    assert_eq!(vm.id, vm_id);
+    assert_eq!(vm.r#type, "JsonWebKey2020");
+    assert_eq!(vm.controller, ED25519_DID);
+    let jwk = vm.public_key_jwk.as_ref().unwrap();
+    assert_eq!((jwk.kty.as_str(), jwk.crv.as_str()), ("OKP", "Ed25519"));
+    assert_eq!(
+        jwk.x.as_ref().unwrap().to_string(),
+        "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    );
+
+    assert_eq!(document.assertion_method.as_ref().unwrap().len(), 1);
+    assert_eq!(document.authentication.as_ref().unwrap().len(), 1);
+    assert!(document.key_agreement.is_none());
+}
+
+#[test]
+fn resolve_did_jwk_with_multikey_encoding() {
+    let document = DidJwkResolver
+        .resolve_document(&did(ED25519_DID), Some(VerificationMethodEncoding::Multikey))
+        .unwrap();
+    let vm = &document.verification_method[0];
+    assert_eq!(vm.r#type, "Multikey");
+    assert_eq!(
+        vm.public_key_multibase.as_deref(),
+        Some("z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw")
+    );
+}
+
+#[test]
+fn resolve_x25519_did_jwk_for_key_agreement() {
+    let document = DidJwkResolver.resolve_document(&did(X25519_DID), None).unwrap();
+    assert_eq!(document.key_agreement.as_ref().unwrap().len(), 1);
+    assert!(document.authentication.is_none());
+    assert!(document.assertion_method.is_none());
+}
+
+// ------------------------------------------------------------------
+// Errors
+// ------------------------------------------------------------------
+
+#[test]
+fn resolve_did_jwk_rejects_invalid_identifier() {
+    let result = DidJwkResolver.resolve_document(&did("did:jwk:not.base64"), None);
+    assert!(matches!(result, Err(DidJwkError::InvalidEncoding { .. })));
+
+    // "hello world"
+    let result = DidJwkResolver.resolve_document(&did("did:jwk:aGVsbG8gd29ybGQ"), None);
+    assert!(matches!(result, Err(DidJwkError::InvalidJwk { .. })));
+
+    let result = DidJwkResolver.resolve_document(&did(PRIVATE_DID), None);
+    assert!(matches!(result, Err(DidJwkError::PrivateKey)));
+}
+
+#[test]
+fn resolve_did_jwk_rejects_unsupported_key_type() {
+    let result = DidJwkResolver.resolve_document(&did(P256_DID), None);
+    let err = result.unwrap_err();
+    assert!(matches!(err, DidJwkError::UnsupportedKeyType { .. }));
+    assert!(matches!(
+        err.error_code(),
+        DidResolutionErrorCode::UnsupportedPublicKeyType
+    ));
+}
+
+#[tokio::test]
+async fn did_jwk_resolver_reports_errors() {
+    let options = ResolutionOptions::default();
+
+    let result = DidJwkResolver.resolve(&did(X25519_DID), &options).await;
+    assert!(result.did_document.is_some());
+
+    let result = DidJwkResolver.resolve(&did(P256_DID), &options).await;
+    assert!(result.did_document.is_none());
+    let error = result.did_resolution_metadata.error.unwrap();
+    assert!(matches!(error.r#type, DidResolutionErrorCode::UnsupportedPublicKeyType));
+
+    let result = DidJwkResolver
+        .resolve(
+            &did("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"),
+            &options,
+        )
+        .await;
+    let error = result.did_resolution_metadata.error.unwrap();
+    assert!(matches!(error.r#type, DidResolutionErrorCode::MethodNotSupported));
+}
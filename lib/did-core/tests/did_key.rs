@@ -0,0 +1,114 @@
+#![cfg(feature = "did-key")]
+
+use identus_did_core::{
+    Did, DidKeyError, DidKeyResolver, DidResolutionErrorCode, DidResolver, ResolutionOptions,
+    VerificationMethodEncoding, VerificationMethodOrRef,
+};
+
+const ED25519_DID: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+const SECP256K1_DID: &str = "did:key:zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme";
+const X25519_DID: &str = "did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F";
+
+fn did(s: &str) -> Did {
+    s.parse().unwrap()
+}
+
+fn reference(vm: &VerificationMethodOrRef) -> &str {
+    match vm {
+        VerificationMethodOrRef::Ref(id) => id,
+        VerificationMethodOrRef::Embedded(vm) => &vm.id,
+    }
+}
+
+// ------------------------------------------------------------------
+// Document
+// ------------------------------------------------------------------
+
+#[test]
+fn resolve_ed25519_did_key() {
+    let document = DidKeyResolver.resolve_document(&did(ED25519_DID), None).unwrap();
+    let vm_id = format!("{ED25519_DID}#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK");
+
+    assert_eq!(document.id.to_string(), ED25519_DID);
+    assert_eq!(document.verification_method.len(), 1);
+    let vm = &document.verification_method[0];
+    assert_eq!(vm.id, vm_id);
+    assert_eq!(vm.r#type, "Multikey");
+    assert_eq!(vm.controller, ED25519_DID);
+    assert_eq!(
+        vm.public_key_multibase.as_deref(),
+        Some("z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK")
+    );
+
+    for relationship in [
+        &document.authentication,
+        &document.assertion_method,
+        &document.capability_invocation,
+        &document.capability_delegation,
+    ] {
+        let refs: Vec<_> = relationship.as_ref().unwrap().iter().map(reference).collect();
+        assert_eq!(refs, [vm_id.as_str()]);
+    }
+    assert!(document.key_agreement.is_none());
+}
+
+#[test]
+fn resolve_secp256k1_did_key() {
+    let document = DidKeyResolver.resolve_document(&did(SECP256K1_DID), None).unwrap();
+    assert_eq!(document.verification_method[0].r#type, "Multikey");
+    assert!(document.assertion_method.is_some());
+    assert!(document.key_agreement.is_none());
+
+    let document = DidKeyResolver
+        .resolve_document(&did(SECP256K1_DID), Some(VerificationMethodEncoding::Jwk))
+        .unwrap();
+    let vm = &document.verification_method[0];
+    assert_eq!(vm.r#type, "JsonWebKey2020");
+    let jwk = vm.public_key_jwk.as_ref().unwrap();
+    assert_eq!((jwk.kty.as_str(), jwk.crv.as_str()), ("EC", "secp256k1"));
+}
+
+#[test]
+fn resolve_x25519_did_key_for_key_agreement() {
+    let document = DidKeyResolver
+        .resolve_document(&did(X25519_DID), Some(VerificationMethodEncoding::KeyType))
+        .unwrap();
+    assert_eq!(document.verification_method[0].r#type, "X25519KeyAgreementKey2020");
+    assert_eq!(document.key_agreement.as_ref().unwrap().len(), 1);
+    assert!(document.authentication.is_none());
+    assert!(document.assertion_method.is_none());
+}
+
+// ------------------------------------------------------------------
+// Errors
+// ------------------------------------------------------------------
+
+#[test]
+fn resolve_did_key_rejects_invalid_identifier() {
+    let result = DidKeyResolver.resolve_document(&did("did:key:abc"), None);
+    assert!(matches!(result, Err(DidKeyError::InvalidMultikey { .. })));
+
+    let result = DidKeyResolver.resolve_document(
+        &did("did:example:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"),
+        None,
+    );
+    assert!(matches!(result, Err(DidKeyError::UnexpectedMethod { .. })));
+}
+
+#[tokio::test]
+async fn did_key_resolver_reports_errors() {
+    let options = ResolutionOptions::default();
+
+    let result = DidKeyResolver.resolve(&did(ED25519_DID), &options).await;
+    assert!(result.did_document.is_some());
+    assert!(result.did_resolution_metadata.error.is_none());
+
+    let result = DidKeyResolver.resolve(&did("did:key:abc"), &options).await;
+    assert!(result.did_document.is_none());
+    let error = result.did_resolution_metadata.error.unwrap();
+    assert!(matches!(error.r#type, DidResolutionErrorCode::InvalidDid));
+
+    let result = DidKeyResolver.resolve(&did("did:example:123"), &options).await;
+    let error = result.did_resolution_metadata.error.unwrap();
+    assert!(matches!(error.r#type, DidResolutionErrorCode::MethodNotSupported));
+}
@@ -1,7 +1,8 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
+use identus_apollo::jwk::Jwk;
 use identus_did_core::{
     Did, DidDocument, DidDocumentMetadata, DidResolutionError, DidResolutionErrorCode, DidResolutionMetadata,
-    ResolutionOptions, ResolutionResult,
+    OperationKey, ResolutionOptions, ResolutionResult, VerificationMethodEncoding,
 };
 
 fn sample_did() -> Did {
@@ -47,11 +48,17 @@ fn resolution_options_construction() {
         expand_relative_urls: Some(true),
         version_id: Some("v1".to_string()),
         version_time: Some(dt),
+        verification_method_encoding: Some(VerificationMethodEncoding::Multikey),
+        include_operation_keys: Some(true),
     };
     assert_eq!(opts.accept.as_deref(), Some("application/did+ld+json"));
     assert_eq!(opts.expand_relative_urls, Some(true));
     assert_eq!(opts.version_id.as_deref(), Some("v1"));
     assert_eq!(opts.version_time, Some(dt));
+    assert_eq!(
+        opts.verification_method_encoding,
+        Some(VerificationMethodEncoding::Multikey)
+    );
 }
 
 #[test]
@@ -61,6 +68,8 @@ fn resolution_options_serialization_roundtrip() {
         expand_relative_urls: Some(false),
         version_id: None,
         version_time: None,
+        verification_method_encoding: None,
+        include_operation_keys: None,
     };
     let json = serde_json::to_string(&opts).unwrap();
     // Verify camelCase serialization
@@ -73,6 +82,19 @@ fn resolution_options_serialization_roundtrip() {
     assert_eq!(opts.expand_relative_urls, deserialized.expand_relative_urls);
 }
 
+#[test]
+fn resolution_options_verification_method_encoding_deserialization() {
+    let json = r#"{"verificationMethodEncoding":"key-type"}"#;
+    let opts: ResolutionOptions = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        opts.verification_method_encoding,
+        Some(VerificationMethodEncoding::KeyType)
+    );
+
+    let json = r#"{"verificationMethodEncoding":"unknown"}"#;
+    assert!(serde_json::from_str::<ResolutionOptions>(json).is_err());
+}
+
 // ------------------------------------------------------------------
 // ResolutionResult::success
 // ------------------------------------------------------------------
@@ -215,6 +237,7 @@ fn did_document_metadata_construction() {
         deactivated: Some(false),
         canonical_id: Some(did.clone()),
         version_id: Some("v42".to_string()),
+        operation_keys: None,
     };
     assert_eq!(meta.created, Some(created));
     assert_eq!(meta.updated, Some(updated));
@@ -231,6 +254,7 @@ fn did_document_metadata_serialization_camel_case() {
         deactivated: Some(true),
         canonical_id: None,
         version_id: Some("v1".to_string()),
+        operation_keys: None,
     };
     let json = serde_json::to_string(&meta).unwrap();
     assert!(json.contains("versionId"), "expected camelCase: {json}");
@@ -241,6 +265,34 @@ fn did_document_metadata_serialization_camel_case() {
     assert_eq!(back.version_id.as_deref(), Some("v1"));
 }
 
+#[test]
+fn did_document_metadata_operation_keys_omitted_when_not_requested() {
+    let json = serde_json::to_value(DidDocumentMetadata::default()).unwrap();
+    assert!(json.get("operationKeys").is_none());
+
+    let meta = DidDocumentMetadata {
+        operation_keys: Some(vec![OperationKey {
+            id: "did:example:123#master-0".to_string(),
+            purpose: "master".to_string(),
+            public_key_jwk: Jwk {
+                kty: "EC".to_string(),
+                crv: "secp256k1".to_string(),
+                x: None,
+                y: None,
+                d: None,
+            },
+        }]),
+        ..Default::default()
+    };
+    let json = serde_json::to_value(&meta).unwrap();
+    assert_eq!(json["operationKeys"][0]["id"], "did:example:123#master-0");
+    assert_eq!(json["operationKeys"][0]["purpose"], "master");
+    assert_eq!(json["operationKeys"][0]["publicKeyJwk"]["crv"], "secp256k1");
+
+    let back: DidDocumentMetadata = serde_json::from_value(json).unwrap();
+    assert_eq!(back.operation_keys.map(|i| i.len()), Some(1));
+}
+
 // ------------------------------------------------------------------
 // DidResolutionMetadata
 // ------------------------------------------------------------------
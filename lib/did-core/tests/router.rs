@@ -0,0 +1,73 @@
+use identus_did_core::{
+    Did, DidDocument, DidResolutionErrorCode, DidResolver, DidResolverRouter, ResolutionOptions, ResolutionResult,
+};
+
+struct StaticResolver;
+
+#[async_trait::async_trait]
+impl DidResolver for StaticResolver {
+    async fn resolve(&self, did: &Did, _options: &ResolutionOptions) -> ResolutionResult {
+        ResolutionResult::success(DidDocument {
+            context: vec!["https://www.w3.org/ns/did/v1".to_string()],
+            id: did.clone(),
+            also_known_as: None,
+            verification_method: vec![],
+            authentication: None,
+            assertion_method: None,
+            key_agreement: None,
+            capability_invocation: None,
+            capability_delegation: None,
+            service: None,
+        })
+    }
+}
+
+struct DeactivatedResolver;
+
+#[async_trait::async_trait]
+impl DidResolver for DeactivatedResolver {
+    async fn resolve(&self, _did: &Did, _options: &ResolutionOptions) -> ResolutionResult {
+        ResolutionResult::deactivated()
+    }
+}
+
+fn router() -> DidResolverRouter {
+    DidResolverRouter::new()
+        .with_resolver("example", StaticResolver)
+        .with_resolver("other", DeactivatedResolver)
+}
+
+// ------------------------------------------------------------------
+// Dispatch
+// ------------------------------------------------------------------
+
+#[tokio::test]
+async fn router_dispatches_by_method() {
+    let router = router();
+
+    let did: Did = "did:example:123".parse().unwrap();
+    let result = router.resolve(&did, &ResolutionOptions::default()).await;
+    assert_eq!(result.did_document.unwrap().id.to_string(), did.to_string());
+
+    let did: Did = "did:other:123".parse().unwrap();
+    let result = router.resolve(&did, &ResolutionOptions::default()).await;
+    assert_eq!(result.did_document_metadata.deactivated, Some(true));
+}
+
+#[tokio::test]
+async fn router_rejects_unknown_method() {
+    let did: Did = "did:unknown:123".parse().unwrap();
+    let result = router().resolve(&did, &ResolutionOptions::default()).await;
+    assert!(result.did_document.is_none());
+    let error = result.did_resolution_metadata.error.unwrap();
+    assert!(matches!(error.r#type, DidResolutionErrorCode::MethodNotSupported));
+    assert!(error.detail.unwrap().contains("unknown"));
+}
+
+#[test]
+fn router_replaces_resolver_of_same_method() {
+    let router = router().with_resolver("example", DeactivatedResolver);
+    let mut methods: Vec<_> = router.methods().collect();
+    methods.sort();
+    assert_eq!(methods, ["example", "other"]);
+}
@@ -4,8 +4,9 @@ use std::time::Duration;
 
 use blockfrost::{BlockfrostAPI, BlockfrostError};
 use blockfrost_openapi::models::{BlockContent, TxContent, TxMetadataLabelJsonInner};
+use chrono::{DateTime, Utc};
 use futures::{StreamExt, TryStreamExt};
-use identus_did_prism::dlt::{DltCursor, PublishedPrismObject};
+use identus_did_prism::dlt::{DltChainTip, DltCursor, PublishedPrismObject};
 use identus_did_prism::location;
 use tokio::sync::{mpsc, watch};
 use tokio::task::JoinHandle;
@@ -136,12 +137,7 @@ mod models {
                 tx_idx,
             })?;
 
-        let prism_object = metadata_map.parse_prism_object(&block.block, tx_idx)?;
-
-        Ok(PublishedPrismObject {
-            block_metadata,
-            prism_object,
-        })
+        metadata_map.parse_published_prism_object(block_metadata, &block.block, tx_idx)
     }
 }
 
@@ -158,6 +154,8 @@ pub struct BlockfrostSource<Store: DltCursorRepo + Send + 'static> {
     api_key: String,
     base_url: String,
     sync_cursor_tx: watch::Sender<Option<DltCursor>>,
+    chain_tip_tx: watch::Sender<Option<DltChainTip>>,
+    last_error_tx: watch::Sender<Option<DateTime<Utc>>>,
     from_page: u32,
     config: BlockfrostConfig,
 }
@@ -176,11 +174,15 @@ impl<E, Store: DltCursorRepo<Error = E> + Send + 'static> BlockfrostSource<Store
 
     pub fn new(store: Store, api_key: &str, base_url: &str, from_page: u32, config: BlockfrostConfig) -> Self {
         let (cursor_tx, _) = watch::channel::<Option<DltCursor>>(None);
+        let (chain_tip_tx, _) = watch::channel::<Option<DltChainTip>>(None);
+        let (last_error_tx, _) = watch::channel::<Option<DateTime<Utc>>>(None);
         Self {
             store,
             api_key: api_key.to_string(),
             base_url: base_url.to_string(),
             sync_cursor_tx: cursor_tx,
+            chain_tip_tx,
+            last_error_tx,
             config,
             from_page,
         }
@@ -188,10 +190,22 @@ impl<E, Store: DltCursorRepo<Error = E> + Send + 'static> BlockfrostSource<Store
 }
 
 impl<E, Store: DltCursorRepo<Error = E> + Send + 'static> DltSource for BlockfrostSource<Store> {
+    fn source_name(&self) -> &'static str {
+        "blockfrost"
+    }
+
     fn sync_cursor(&self) -> watch::Receiver<Option<DltCursor>> {
         self.sync_cursor_tx.subscribe()
     }
 
+    fn chain_tip(&self) -> watch::Receiver<Option<DltChainTip>> {
+        self.chain_tip_tx.subscribe()
+    }
+
+    fn last_error(&self) -> watch::Receiver<Option<DateTime<Utc>>> {
+        self.last_error_tx.subscribe()
+    }
+
     fn into_stream(self) -> Result<mpsc::Receiver<PublishedPrismObject>, String> {
         let (event_tx, rx) = mpsc::channel::<PublishedPrismObject>(1024);
 
@@ -200,6 +214,8 @@ impl<E, Store: DltCursorRepo<Error = E> + Send + 'static> DltSource for Blockfro
             api_key: self.api_key,
             base_url: self.base_url,
             sync_cursor_tx: self.sync_cursor_tx,
+            chain_tip_tx: self.chain_tip_tx,
+            last_error_tx: self.last_error_tx,
             event_tx,
             from_page: self.from_page,
             config: self.config,
@@ -216,6 +232,8 @@ struct BlockfrostStreamWorker {
     api_key: String,
     base_url: String,
     sync_cursor_tx: watch::Sender<Option<DltCursor>>,
+    chain_tip_tx: watch::Sender<Option<DltChainTip>>,
+    last_error_tx: watch::Sender<Option<DateTime<Utc>>>,
     event_tx: mpsc::Sender<PublishedPrismObject>,
     from_page: u32,
     config: BlockfrostConfig,
@@ -229,6 +247,8 @@ impl BlockfrostStreamWorker {
             let base_url = self.base_url;
             let event_tx = self.event_tx;
             let sync_cursor_tx = self.sync_cursor_tx;
+            let chain_tip_tx = self.chain_tip_tx;
+            let last_error_tx = self.last_error_tx;
 
             loop {
                 tracing::info!("starting blockfrost stream worker");
@@ -241,6 +261,7 @@ impl BlockfrostStreamWorker {
                     api.clone(),
                     event_tx.clone(),
                     sync_cursor_tx.clone(),
+                    chain_tip_tx.clone(),
                     self.from_page,
                     &self.config,
                 )
@@ -251,6 +272,7 @@ impl BlockfrostStreamWorker {
                     tracing::error!("{}", report);
                 }
 
+                let _ = last_error_tx.send(Some(Utc::now()));
                 tracing::error!(
                     "blockfrost pipeline terminated, restarting in {}s",
                     RESTART_DELAY.as_secs()
@@ -275,10 +297,19 @@ impl BlockfrostStreamWorker {
         let _ = sync_cursor_tx.send(Some(cursor));
     }
 
+    fn emit_chain_tip(block_time: BlockTimeProjection, chain_tip_tx: &watch::Sender<Option<DltChainTip>>) {
+        let chain_tip = DltChainTip {
+            slot: block_time.slot_no as u64,
+            cbt: block_time.time,
+        };
+        let _ = chain_tip_tx.send(Some(chain_tip));
+    }
+
     async fn stream_loop(
         api: Arc<BlockfrostAPI>,
         event_tx: mpsc::Sender<PublishedPrismObject>,
         sync_cursor_tx: watch::Sender<Option<DltCursor>>,
+        chain_tip_tx: watch::Sender<Option<DltChainTip>>,
         from_page: u32,
         config: &BlockfrostConfig,
     ) -> Result<(), DltError> {
@@ -292,8 +323,13 @@ impl BlockfrostStreamWorker {
             .unwrap_or(from_page);
 
         loop {
+            let latest_block = Self::fetch_latest_block(&api).await?;
+            if let Ok(block_time) = BlockTimeProjection::try_from(&latest_block) {
+                Self::emit_chain_tip(block_time, &chain_tip_tx);
+            }
+
             let Some(last_confirmed_block) =
-                Self::fetch_latest_confirmed_block(&api, config.confirmation_blocks).await?
+                Self::fetch_latest_confirmed_block(&api, &latest_block, config.confirmation_blocks).await?
             else {
                 tokio::time::sleep(config.poll_interval).await;
                 continue;
@@ -390,14 +426,18 @@ impl BlockfrostStreamWorker {
         Ok(())
     }
 
+    async fn fetch_latest_block(api: &BlockfrostAPI) -> Result<BlockContent, DltError> {
+        api.blocks_latest().await.map_err(|e| DltError::Connection {
+            source: e.into(),
+            location: location!(),
+        })
+    }
+
     async fn fetch_latest_confirmed_block(
         api: &BlockfrostAPI,
+        latest_block: &BlockContent,
         confirmation_blocks: u16,
     ) -> Result<Option<BlockContent>, DltError> {
-        let latest_block = api.blocks_latest().await.map_err(|e| DltError::Connection {
-            source: e.into(),
-            location: location!(),
-        })?;
         let latest_confirmed_block_no = latest_block
             .height
             .and_then(|h| h.checked_sub(confirmation_blocks as i32))
@@ -10,6 +10,7 @@ pub mod metadata_map {
     use std::str::FromStr;
 
     use identus_apollo::hex::HexStr;
+    use identus_did_prism::dlt::{BlockMetadata, PublishedPrismObject};
     use identus_did_prism::proto::MessageExt;
     use identus_did_prism::proto::prism::PrismObject;
     use serde::{Deserialize, Serialize};
@@ -32,6 +33,31 @@ pub mod metadata_map {
             block_hash: &str,
             tx_idx: Option<usize>,
         ) -> Result<PrismObject, MetadataReadError> {
+            let bytes = self.prism_object_bytes(block_hash, tx_idx)?;
+            PrismObject::decode(bytes.as_slice()).map_err(|e| MetadataReadError::PrismBlockProtoDecode {
+                source: e,
+                block_hash: Some(block_hash.to_string()),
+                tx_idx,
+            })
+        }
+
+        /// Parse the byte groups and decode the PRISM object, keeping the published bytes alongside.
+        pub fn parse_published_prism_object(
+            self,
+            block_metadata: BlockMetadata,
+            block_hash: &str,
+            tx_idx: Option<usize>,
+        ) -> Result<PublishedPrismObject, MetadataReadError> {
+            let bytes = self.prism_object_bytes(block_hash, tx_idx)?;
+            PublishedPrismObject::decode(block_metadata, bytes).map_err(|e| MetadataReadError::PrismBlockProtoDecode {
+                source: e,
+                block_hash: Some(block_hash.to_string()),
+                tx_idx,
+            })
+        }
+
+        /// Concatenate the hex-encoded byte groups into the encoded PRISM object.
+        fn prism_object_bytes(self, block_hash: &str, tx_idx: Option<usize>) -> Result<Vec<u8>, MetadataReadError> {
             let byte_group = self
                 .c
                 .into_iter()
@@ -55,12 +81,7 @@ pub mod metadata_map {
             for mut b in byte_group.into_iter() {
                 bytes.append(&mut b);
             }
-
-            PrismObject::decode(bytes.as_slice()).map_err(|e| MetadataReadError::PrismBlockProtoDecode {
-                source: e,
-                block_hash: Some(block_hash.to_string()),
-                tx_idx,
-            })
+            Ok(bytes)
         }
     }
 }
@@ -120,6 +141,7 @@ mod tests {
 
     #[cfg(any(feature = "blockfrost", feature = "dbsync"))]
     mod metadata_map_tests {
+        use identus_did_prism::dlt::{BlockMetadata, BlockNo, SlotNo, TxId};
         use identus_did_prism::proto::MessageExt;
         use identus_did_prism::proto::prism::{PrismBlock, PrismObject};
 
@@ -193,6 +215,28 @@ mod tests {
             assert_eq!(result, obj);
         }
 
+        #[test]
+        fn metadata_map_parse_published_keeps_published_bytes() {
+            let obj = minimal_prism_object();
+            let byte_groups = encode_object_as_byte_groups(&obj);
+            let block_metadata = BlockMetadata {
+                slot_number: SlotNo::from(1),
+                block_number: BlockNo::from(1),
+                cbt: chrono::DateTime::UNIX_EPOCH,
+                absn: 0,
+                tx_id: TxId::from(identus_apollo::hash::sha256([1])),
+            };
+
+            let meta = metadata_map::MetadataMapJson { c: byte_groups, v: 1 };
+
+            let published = meta
+                .parse_published_prism_object(block_metadata.clone(), "abc123", None)
+                .unwrap();
+            assert_eq!(published.block_metadata, block_metadata);
+            assert_eq!(published.prism_object, obj);
+            assert_eq!(published.prism_object_bytes, obj.encode_to_vec());
+        }
+
         #[test]
         fn metadata_map_parse_missing_0x_prefix_returns_error() {
             let meta = metadata_map::MetadataMapJson {
@@ -2,8 +2,9 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::mpsc::RecvTimeoutError;
 
+use chrono::{DateTime, Utc};
 use identus_apollo::hex::HexStr;
-use identus_did_prism::dlt::{DltCursor, NetworkIdentifier, PublishedPrismObject};
+use identus_did_prism::dlt::{DltChainTip, DltCursor, NetworkIdentifier, PublishedPrismObject};
 use identus_did_prism::location;
 use oura::model::{Event, EventData};
 use oura::pipelining::{SourceProvider, StageReceiver};
@@ -22,8 +23,6 @@ mod models {
 
     use chrono::{DateTime, Utc};
     use identus_did_prism::dlt::{BlockMetadata, BlockNo, PublishedPrismObject, SlotNo, TxId};
-    use identus_did_prism::prelude::*;
-    use identus_did_prism::proto::prism::PrismObject;
     use oura::model::{EventContext, MetadataRecord};
 
     use crate::dlt::error::MetadataReadError;
@@ -129,16 +128,10 @@ mod models {
             bytes.append(&mut b);
         }
 
-        let prism_object =
-            PrismObject::decode(bytes.as_slice()).map_err(|e| MetadataReadError::PrismBlockProtoDecode {
-                source: e,
-                block_hash,
-                tx_idx,
-            })?;
-
-        Ok(PublishedPrismObject {
-            block_metadata,
-            prism_object,
+        PublishedPrismObject::decode(block_metadata, bytes).map_err(|e| MetadataReadError::PrismBlockProtoDecode {
+            source: e,
+            block_hash,
+            tx_idx,
         })
     }
 }
@@ -161,6 +154,8 @@ pub struct OuraN2NSource<Store: DltCursorRepo + Send + 'static> {
     with_utils: WithUtils<Config>,
     store: Store,
     sync_cursor_tx: watch::Sender<Option<DltCursor>>,
+    chain_tip_tx: watch::Sender<Option<DltChainTip>>,
+    last_error_tx: watch::Sender<Option<DateTime<Utc>>>,
 }
 
 impl<E, Store: DltCursorRepo<Error = E> + Send + 'static> OuraN2NSource<Store> {
@@ -231,19 +226,36 @@ impl<E, Store: DltCursorRepo<Error = E> + Send + 'static> OuraN2NSource<Store> {
         let utils = Utils::new(chain_wellknown_info(chain));
         let with_utils = WithUtils::new(config, Arc::new(utils));
         let (sync_cursor_tx, _) = watch::channel::<Option<DltCursor>>(None);
+        let (chain_tip_tx, _) = watch::channel::<Option<DltChainTip>>(None);
+        let (last_error_tx, _) = watch::channel::<Option<DateTime<Utc>>>(None);
         Self {
             with_utils,
             store,
             sync_cursor_tx,
+            chain_tip_tx,
+            last_error_tx,
         }
     }
 }
 
 impl<Store: DltCursorRepo + Send> DltSource for OuraN2NSource<Store> {
+    fn source_name(&self) -> &'static str {
+        "oura"
+    }
+
     fn sync_cursor(&self) -> watch::Receiver<Option<DltCursor>> {
         self.sync_cursor_tx.subscribe()
     }
 
+    /// Oura does not expose the tip it receives from the relay, so no chain tip is ever reported.
+    fn chain_tip(&self) -> watch::Receiver<Option<DltChainTip>> {
+        self.chain_tip_tx.subscribe()
+    }
+
+    fn last_error(&self) -> watch::Receiver<Option<DateTime<Utc>>> {
+        self.last_error_tx.subscribe()
+    }
+
     fn into_stream(self) -> Result<mpsc::Receiver<PublishedPrismObject>, String> {
         let (event_tx, rx) = tokio::sync::mpsc::channel::<PublishedPrismObject>(1024);
 
@@ -251,6 +263,7 @@ impl<Store: DltCursorRepo + Send> DltSource for OuraN2NSource<Store> {
         let stream_worker = OuraStreamWorker {
             with_utils: self.with_utils,
             sync_cursor_tx: self.sync_cursor_tx,
+            last_error_tx: self.last_error_tx,
             event_tx,
         };
 
@@ -264,6 +277,7 @@ impl<Store: DltCursorRepo + Send> DltSource for OuraN2NSource<Store> {
 struct OuraStreamWorker {
     with_utils: WithUtils<Config>,
     sync_cursor_tx: watch::Sender<Option<DltCursor>>,
+    last_error_tx: watch::Sender<Option<DateTime<Utc>>>,
     event_tx: mpsc::Sender<PublishedPrismObject>,
 }
 
@@ -275,8 +289,11 @@ impl OuraStreamWorker {
             loop {
                 let with_utils = self.build_with_util();
                 tracing::info!("starting oura stream worker");
-                let (handle, oura_rx) = with_utils.bootstrap().map_err(|e| DltError::InitSource {
-                    source: e.to_string().into(),
+                let (handle, oura_rx) = with_utils.bootstrap().map_err(|e| {
+                    let _ = self.last_error_tx.send(Some(Utc::now()));
+                    DltError::InitSource {
+                        source: e.to_string().into(),
+                    }
                 })?;
 
                 // When the stream loop terminates with recv timeout,
@@ -290,6 +307,7 @@ impl OuraStreamWorker {
                     }
                 };
 
+                let _ = self.last_error_tx.send(Some(Utc::now()));
                 tracing::error!("oura pipeline terminated, restarting in {}s", RESTART_DELAY.as_secs());
                 std::thread::sleep(RESTART_DELAY);
             }
@@ -979,6 +997,7 @@ mod tests {
         OuraStreamWorker {
             with_utils: temp_source.with_utils.clone(),
             sync_cursor_tx: cursor_tx,
+            last_error_tx: watch::channel(None).0,
             event_tx,
         }
     }
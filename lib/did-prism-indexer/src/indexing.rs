@@ -1,12 +1,14 @@
 use identus_apollo::hash::Sha256Digest;
 use identus_apollo::hex::HexStr;
 use identus_did_prism::did::CanonicalPrismDid;
-use identus_did_prism::dlt::OperationMetadata;
+use identus_did_prism::dlt::{OperationMetadata, PublishedPrismObject};
 use identus_did_prism::prelude::*;
 use identus_did_prism::proto::prism::prism_operation::Operation;
+use tokio::sync::broadcast;
+use tracing::Instrument;
 
-use crate::DltSource;
 use crate::repo::{IndexedOperation, IndexedOperationRepo, RawOperationRepo};
+use crate::{DltSource, metrics};
 
 enum IntermediateIndexedOperation {
     Ssi {
@@ -24,6 +26,32 @@ enum IntermediateIndexedOperation {
 
 /// Run indexer loop until no more operation to index
 pub async fn run_indexer_loop<Repo>(repo: &Repo) -> anyhow::Result<()>
+where
+    Repo: RawOperationRepo + IndexedOperationRepo + ?Sized,
+    <Repo as RawOperationRepo>::Error: Send + Sync + 'static,
+    <Repo as IndexedOperationRepo>::Error: Send + Sync + 'static,
+{
+    index_operations(repo, None).await
+}
+
+/// Run indexer loop until no more operation to index,
+/// publishing the DID of every indexed SSI or VDR operation to `indexed_did_tx`.
+pub async fn run_indexer_loop_with_notifier<Repo>(
+    repo: &Repo,
+    indexed_did_tx: &broadcast::Sender<CanonicalPrismDid>,
+) -> anyhow::Result<()>
+where
+    Repo: RawOperationRepo + IndexedOperationRepo + ?Sized,
+    <Repo as RawOperationRepo>::Error: Send + Sync + 'static,
+    <Repo as IndexedOperationRepo>::Error: Send + Sync + 'static,
+{
+    index_operations(repo, Some(indexed_did_tx)).await
+}
+
+async fn index_operations<Repo>(
+    repo: &Repo,
+    indexed_did_tx: Option<&broadcast::Sender<CanonicalPrismDid>>,
+) -> anyhow::Result<()>
 where
     Repo: RawOperationRepo + IndexedOperationRepo + ?Sized,
     <Repo as RawOperationRepo>::Error: Send + Sync + 'static,
@@ -40,6 +68,7 @@ where
             let raw_operation_id = record.id;
             let meta = record.metadata;
             let signed_operation = record.signed_operation;
+            let operation_type = metrics::operation_type_label(&signed_operation);
             let intermediate_indexed_op = index_from_signed_operation(signed_operation);
             let indexed_op = match intermediate_indexed_op {
                 Ok(IntermediateIndexedOperation::Ssi { did }) => IndexedOperation::Ssi { raw_operation_id, did },
@@ -78,7 +107,16 @@ where
                     IndexedOperation::Ignored { raw_operation_id }
                 }
             };
+            let indexed_did = match &indexed_op {
+                IndexedOperation::Ssi { did, .. } | IndexedOperation::Vdr { did, .. } => Some(did.clone()),
+                IndexedOperation::Ignored { .. } => None,
+            };
             repo.insert_indexed_operations(vec![indexed_op]).await?;
+            metrics::record_operation_indexed(operation_type, indexed_did.is_none());
+            if let (Some(tx), Some(did)) = (indexed_did_tx, indexed_did) {
+                // Sending only fails when there are no subscribers
+                let _ = tx.send(did);
+            }
         }
     }
 }
@@ -91,40 +129,73 @@ where
     <Repo as RawOperationRepo>::Error: Send + Sync + 'static,
     <Repo as IndexedOperationRepo>::Error: Send + Sync + 'static,
 {
+    let source_name = source.source_name();
+    let cursor_reporter = tokio::spawn(metrics::report_cursor(source.sync_cursor(), source.chain_tip()));
     let mut rx = source.into_stream().expect("Unable to create a DLT source");
 
     while let Some(published_prism_object) = rx.recv().await {
-        let block = published_prism_object.prism_object.block_content;
-        let block_metadata = published_prism_object.block_metadata;
-        let signed_operations = block.map(|i| i.operations).unwrap_or_default();
-
-        let mut insert_batch = Vec::with_capacity(signed_operations.len());
-        for (idx, signed_operation) in signed_operations.into_iter().enumerate() {
-            let has_operation = signed_operation
-                .operation
-                .as_ref()
-                .and_then(|i| i.operation.as_ref())
-                .is_some();
-
-            if !has_operation {
-                continue;
-            }
+        metrics::record_object_received(source_name);
+        let block_metadata = &published_prism_object.block_metadata;
+        let span = tracing::info_span!(
+            "dlt.object",
+            source = source_name,
+            slot = %block_metadata.slot_number,
+            tx_id = %block_metadata.tx_id,
+        );
+        persist_prism_object(repo, published_prism_object)
+            .instrument(span)
+            .await;
+    }
+    cursor_reporter.abort();
+    Ok(())
+}
 
-            insert_batch.push((
-                OperationMetadata {
-                    block_metadata: block_metadata.clone(),
-                    osn: idx as u32,
-                },
-                signed_operation,
-            ));
-        }
+async fn persist_prism_object<Repo>(repo: &Repo, published_prism_object: PublishedPrismObject)
+where
+    Repo: RawOperationRepo + ?Sized,
+{
+    let block = published_prism_object.prism_object.block_content;
+    let block_metadata = published_prism_object.block_metadata;
+    let tx_id = block_metadata.tx_id.clone();
+    let signed_operations = block.map(|i| i.operations).unwrap_or_default();
+
+    let mut insert_batch = Vec::with_capacity(signed_operations.len());
+    for (idx, signed_operation) in signed_operations.into_iter().enumerate() {
+        let has_operation = signed_operation
+            .operation
+            .as_ref()
+            .and_then(|i| i.operation.as_ref())
+            .is_some();
 
-        let insert_result = repo.insert_raw_operations(insert_batch).await;
-        if let Err(e) = insert_result {
-            tracing::error!("Failed to insert operation into database: {:?}", e);
+        if !has_operation {
+            continue;
         }
+
+        insert_batch.push((
+            OperationMetadata {
+                block_metadata: block_metadata.clone(),
+                osn: idx as u32,
+            },
+            signed_operation,
+        ));
+    }
+
+    if insert_batch.is_empty() {
+        return;
+    }
+
+    // Keep the PrismObject as published so it can be served as evidence of the operations
+    let insert_result = repo
+        .insert_prism_object_bytes(&tx_id, published_prism_object.prism_object_bytes)
+        .await;
+    if let Err(e) = insert_result {
+        tracing::error!("Failed to insert prism object into database: {:?}", e);
+    }
+
+    let insert_result = repo.insert_raw_operations(insert_batch).await;
+    if let Err(e) = insert_result {
+        tracing::error!("Failed to insert operation into database: {:?}", e);
     }
-    Ok(())
 }
 
 /// Returns DID that create a root operation and its operation hash
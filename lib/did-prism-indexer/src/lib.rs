@@ -3,16 +3,24 @@
     feature(error_reporter)
 )]
 
-use identus_did_prism::dlt::{DltCursor, PublishedPrismObject};
+use chrono::{DateTime, Utc};
+use identus_did_prism::dlt::{DltChainTip, DltCursor, PublishedPrismObject};
 use tokio::sync::{mpsc, watch};
 
 pub mod dlt;
 mod indexing;
+mod metrics;
 pub mod repo;
 
-pub use indexing::{run_indexer_loop, run_sync_loop};
+pub use indexing::{run_indexer_loop, run_indexer_loop_with_notifier, run_sync_loop};
 
 pub trait DltSource {
+    /// Short name of the source, used to label metrics.
+    fn source_name(&self) -> &'static str;
     fn sync_cursor(&self) -> watch::Receiver<Option<DltCursor>>;
+    /// Latest chain tip observed by the source.
+    fn chain_tip(&self) -> watch::Receiver<Option<DltChainTip>>;
+    /// Time of the most recent error that interrupted the source stream.
+    fn last_error(&self) -> watch::Receiver<Option<DateTime<Utc>>>;
     fn into_stream(self) -> Result<mpsc::Receiver<PublishedPrismObject>, String>;
 }
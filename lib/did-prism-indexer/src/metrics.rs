@@ -0,0 +1,108 @@
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use identus_did_prism::dlt::{DltChainTip, DltCursor};
+use identus_did_prism::prelude::*;
+use identus_did_prism::proto::prism::prism_operation::Operation;
+use prometheus::{IntCounterVec, IntGauge, register_int_counter_vec, register_int_gauge};
+use tokio::sync::watch;
+
+const CURSOR_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+static DLT_OBJECTS_RECEIVED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "neoprism_dlt_objects_received_total",
+        "Number of PRISM objects received from the DLT source",
+        &["source"]
+    )
+    .expect("metric neoprism_dlt_objects_received_total must be registered")
+});
+
+static INDEXER_OPERATIONS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "neoprism_indexer_operations_total",
+        "Number of operations processed by the indexer",
+        &["operation_type", "outcome"]
+    )
+    .expect("metric neoprism_indexer_operations_total must be registered")
+});
+
+static DLT_CURSOR_SLOT: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!("neoprism_dlt_cursor_slot", "Slot of the current DLT sync cursor")
+        .expect("metric neoprism_dlt_cursor_slot must be registered")
+});
+
+static DLT_CHAIN_TIP_SLOT: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "neoprism_dlt_chain_tip_slot",
+        "Slot of the chain tip observed by the DLT source"
+    )
+    .expect("metric neoprism_dlt_chain_tip_slot must be registered")
+});
+
+static INDEXER_LAG_SLOTS: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "neoprism_indexer_lag_slots",
+        "Number of slots between the DLT sync cursor and the observed chain tip"
+    )
+    .expect("metric neoprism_indexer_lag_slots must be registered")
+});
+
+static INDEXER_LAG_SECONDS: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "neoprism_indexer_lag_seconds",
+        "Seconds elapsed between the block time of the DLT sync cursor and now"
+    )
+    .expect("metric neoprism_indexer_lag_seconds must be registered")
+});
+
+pub(crate) fn record_object_received(source: &str) {
+    DLT_OBJECTS_RECEIVED.with_label_values(&[source]).inc();
+}
+
+pub(crate) fn record_operation_indexed(operation_type: &str, ignored: bool) {
+    let outcome = if ignored { "ignored" } else { "indexed" };
+    INDEXER_OPERATIONS.with_label_values(&[operation_type, outcome]).inc();
+}
+
+fn record_cursor(cursor: &DltCursor, chain_tip: Option<&DltChainTip>) {
+    DLT_CURSOR_SLOT.set(cursor.slot.try_into().unwrap_or(i64::MAX));
+    if let Some(cbt) = cursor.cbt {
+        let lag = chrono::Utc::now().signed_duration_since(cbt).num_seconds();
+        INDEXER_LAG_SECONDS.set(lag.max(0));
+    }
+    if let Some(chain_tip) = chain_tip {
+        DLT_CHAIN_TIP_SLOT.set(chain_tip.slot.try_into().unwrap_or(i64::MAX));
+        let lag = chain_tip.slot.saturating_sub(cursor.slot);
+        INDEXER_LAG_SLOTS.set(lag.try_into().unwrap_or(i64::MAX));
+    }
+}
+
+/// Periodically refresh the cursor gauges so the lag keeps growing when the source stalls.
+pub(crate) async fn report_cursor(
+    cursor_rx: watch::Receiver<Option<DltCursor>>,
+    chain_tip_rx: watch::Receiver<Option<DltChainTip>>,
+) {
+    let mut interval = tokio::time::interval(CURSOR_REPORT_INTERVAL);
+    loop {
+        interval.tick().await;
+        let chain_tip = chain_tip_rx.borrow().clone();
+        if let Some(cursor) = cursor_rx.borrow().as_ref() {
+            record_cursor(cursor, chain_tip.as_ref());
+        }
+    }
+}
+
+pub(crate) fn operation_type_label(signed_operation: &SignedPrismOperation) -> &'static str {
+    match signed_operation.operation.as_ref().and_then(|i| i.operation.as_ref()) {
+        Some(Operation::CreateDid(_)) => "create_did",
+        Some(Operation::UpdateDid(_)) => "update_did",
+        Some(Operation::DeactivateDid(_)) => "deactivate_did",
+        Some(Operation::ProtocolVersionUpdate(_)) => "protocol_version_update",
+        Some(Operation::CreateStorageEntry(_)) => "create_storage_entry",
+        Some(Operation::UpdateStorageEntry(_)) => "update_storage_entry",
+        Some(Operation::DeactivateStorageEntry(_)) => "deactivate_storage_entry",
+        Some(_) => "unsupported",
+        None => "empty",
+    }
+}
@@ -23,6 +23,27 @@ pub struct RawOperationRecord {
     pub signed_operation: SignedPrismOperation,
 }
 
+/// Position of an operation on the ledger.
+///
+/// Positions are ordered by block number, PrismBlock sequence number and operation sequence number,
+/// which is the order operations are applied in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperationPosition {
+    pub block_number: BlockNo,
+    pub absn: u32,
+    pub osn: u32,
+}
+
+impl From<&OperationMetadata> for OperationPosition {
+    fn from(value: &OperationMetadata) -> Self {
+        Self {
+            block_number: value.block_metadata.block_number,
+            absn: value.block_metadata.absn,
+            osn: value.osn,
+        }
+    }
+}
+
 pub enum IndexedOperation {
     Ssi {
         raw_operation_id: RawOperationId,
@@ -58,6 +79,21 @@ pub trait RawOperationRepo {
 
     async fn get_raw_operations_by_did(&self, did: &CanonicalPrismDid) -> Result<Vec<RawOperationRecord>, Self::Error>;
 
+    /// Fetch a page of the raw operations of a DID, in ledger order.
+    async fn get_raw_operations_by_did_page(
+        &self,
+        did: &CanonicalPrismDid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Paginated<RawOperationRecord>, Self::Error>;
+
+    /// Fetch the raw operations of several DIDs in a single query.
+    /// Each record is returned together with the DID it belongs to.
+    async fn get_raw_operations_by_dids(
+        &self,
+        dids: &[CanonicalPrismDid],
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error>;
+
     async fn get_raw_operation_vdr_by_operation_hash(
         &self,
         operation_hash: &Sha256Digest,
@@ -68,15 +104,30 @@ pub trait RawOperationRepo {
         tx_id: &TxId,
     ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error>;
 
+    /// Fetch the PrismObject of a transaction exactly as it was published in the transaction metadata.
+    async fn get_prism_object_bytes(&self, tx_id: &TxId) -> Result<Option<Vec<u8>>, Self::Error>;
+
     async fn get_raw_operation_by_operation_id(
         &self,
         operation_id: &OperationId,
     ) -> Result<Option<(RawOperationRecord, CanonicalPrismDid)>, Self::Error>;
 
+    /// Fetch up to `limit` indexed operations positioned after `position`, in ledger order.
+    /// Each record is returned together with the DID it belongs to.
+    async fn get_raw_operations_after(
+        &self,
+        position: &OperationPosition,
+        limit: u32,
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error>;
+
     async fn insert_raw_operations(
         &self,
         operations: Vec<(OperationMetadata, SignedPrismOperation)>,
     ) -> Result<(), Self::Error>;
+
+    /// Store the PrismObject of a transaction exactly as it was published in the transaction metadata.
+    /// Storing the object of a transaction that is already stored has no effect.
+    async fn insert_prism_object_bytes(&self, tx_id: &TxId, prism_object_bytes: Vec<u8>) -> Result<(), Self::Error>;
 }
 
 #[async_trait::async_trait]
@@ -124,6 +175,22 @@ where
         self.as_ref().get_raw_operations_by_did(did).await
     }
 
+    async fn get_raw_operations_by_did_page(
+        &self,
+        did: &CanonicalPrismDid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Paginated<RawOperationRecord>, Self::Error> {
+        self.as_ref().get_raw_operations_by_did_page(did, page, page_size).await
+    }
+
+    async fn get_raw_operations_by_dids(
+        &self,
+        dids: &[CanonicalPrismDid],
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        self.as_ref().get_raw_operations_by_dids(dids).await
+    }
+
     async fn get_raw_operation_vdr_by_operation_hash(
         &self,
         operation_hash: &Sha256Digest,
@@ -140,6 +207,10 @@ where
         self.as_ref().get_raw_operations_by_tx_id(tx_id).await
     }
 
+    async fn get_prism_object_bytes(&self, tx_id: &TxId) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.as_ref().get_prism_object_bytes(tx_id).await
+    }
+
     async fn get_raw_operation_by_operation_id(
         &self,
         operation_id: &OperationId,
@@ -147,12 +218,24 @@ where
         self.as_ref().get_raw_operation_by_operation_id(operation_id).await
     }
 
+    async fn get_raw_operations_after(
+        &self,
+        position: &OperationPosition,
+        limit: u32,
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        self.as_ref().get_raw_operations_after(position, limit).await
+    }
+
     async fn insert_raw_operations(
         &self,
         operations: Vec<(OperationMetadata, SignedPrismOperation)>,
     ) -> Result<(), Self::Error> {
         self.as_ref().insert_raw_operations(operations).await
     }
+
+    async fn insert_prism_object_bytes(&self, tx_id: &TxId, prism_object_bytes: Vec<u8>) -> Result<(), Self::Error> {
+        self.as_ref().insert_prism_object_bytes(tx_id, prism_object_bytes).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1,20 +1,22 @@
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use chrono::{DateTime, Utc};
 use identus_apollo::crypto::secp256k1::Secp256k1PrivateKey;
 use identus_apollo::hash::Sha256Digest;
 use identus_did_prism::did::{CanonicalPrismDid, PrismDidOps};
 use identus_did_prism::dlt::{
-    BlockMetadata, BlockNo, DltCursor, OperationMetadata, PublishedPrismObject, SlotNo, TxId,
+    BlockMetadata, BlockNo, DltChainTip, DltCursor, OperationMetadata, PublishedPrismObject, SlotNo, TxId,
 };
 use identus_did_prism::prelude::*;
 use identus_did_prism::proto;
 use identus_did_prism::proto::prism::{PrismBlock, PrismObject};
+use identus_did_prism::utils::paging::Paginated;
 use identus_did_prism_indexer::repo::{
-    IndexedOperation, IndexedOperationRepo, RawOperationId, RawOperationRecord, RawOperationRepo,
+    IndexedOperation, IndexedOperationRepo, OperationPosition, RawOperationId, RawOperationRecord, RawOperationRepo,
 };
-use identus_did_prism_indexer::{DltSource, run_indexer_loop, run_sync_loop};
-use tokio::sync::{mpsc, watch};
+use identus_did_prism_indexer::{DltSource, run_indexer_loop, run_indexer_loop_with_notifier, run_sync_loop};
+use tokio::sync::{broadcast, mpsc, watch};
 use uuid::Uuid;
 
 /// Generate a unique UUID from a monotonic counter (avoids needing uuid/v4 feature).
@@ -36,6 +38,7 @@ const VDR_KEY_NAME: &str = "vdr-0";
 struct InMemoryRepo {
     raw_operations: Mutex<Vec<RawOperationRecord>>,
     indexed: Mutex<Vec<IndexedOperation>>,
+    prism_objects: Mutex<Vec<(TxId, Vec<u8>)>>,
 }
 
 impl InMemoryRepo {
@@ -43,6 +46,7 @@ impl InMemoryRepo {
         Self {
             raw_operations: Mutex::new(vec![]),
             indexed: Mutex::new(vec![]),
+            prism_objects: Mutex::new(vec![]),
         }
     }
 
@@ -162,6 +166,27 @@ impl RawOperationRepo for InMemoryRepo {
         Ok(vec![])
     }
 
+    async fn get_raw_operations_by_did_page(
+        &self,
+        _did: &CanonicalPrismDid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Paginated<RawOperationRecord>, Self::Error> {
+        Ok(Paginated {
+            items: vec![],
+            current_page: page,
+            page_size,
+            total_items: 0,
+        })
+    }
+
+    async fn get_raw_operations_by_dids(
+        &self,
+        _dids: &[CanonicalPrismDid],
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        Ok(vec![])
+    }
+
     async fn get_raw_operation_vdr_by_operation_hash(
         &self,
         operation_hash: &Sha256Digest,
@@ -193,6 +218,14 @@ impl RawOperationRepo for InMemoryRepo {
         Ok(vec![])
     }
 
+    async fn get_prism_object_bytes(&self, tx_id: &TxId) -> Result<Option<Vec<u8>>, Self::Error> {
+        let prism_objects = self.prism_objects.lock().unwrap();
+        Ok(prism_objects
+            .iter()
+            .find(|(id, _)| id == tx_id)
+            .map(|(_, bytes)| bytes.clone()))
+    }
+
     async fn get_raw_operation_by_operation_id(
         &self,
         _operation_id: &identus_did_prism::did::operation::OperationId,
@@ -200,6 +233,14 @@ impl RawOperationRepo for InMemoryRepo {
         Ok(None)
     }
 
+    async fn get_raw_operations_after(
+        &self,
+        _position: &OperationPosition,
+        _limit: u32,
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        Ok(vec![])
+    }
+
     async fn insert_raw_operations(
         &self,
         operations: Vec<(OperationMetadata, SignedPrismOperation)>,
@@ -209,6 +250,14 @@ impl RawOperationRepo for InMemoryRepo {
         }
         Ok(())
     }
+
+    async fn insert_prism_object_bytes(&self, tx_id: &TxId, prism_object_bytes: Vec<u8>) -> Result<(), Self::Error> {
+        let mut prism_objects = self.prism_objects.lock().unwrap();
+        if !prism_objects.iter().any(|(id, _)| id == tx_id) {
+            prism_objects.push((tx_id.clone(), prism_object_bytes));
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -525,6 +574,42 @@ async fn index_multiple_independent_vdr_entries() {
     );
 }
 
+#[tokio::test]
+async fn index_loop_notifies_indexed_dids() {
+    let repo = InMemoryRepo::new();
+    let (create_did_op, _, did, _, vdr_sk) = create_did_with_vdr_key();
+    let (create_storage_op, _) = new_create_storage_op(&did, &vdr_sk, 0, vec![1, 2, 3]);
+    let empty_signed_op = SignedPrismOperation {
+        signed_with: "master-0".to_string(),
+        signature: vec![],
+        operation: None.into(),
+        special_fields: Default::default(),
+    };
+    repo.insert(test_utils::dummy_metadata(0), create_did_op);
+    repo.insert(test_utils::dummy_metadata(1), create_storage_op);
+    repo.insert(test_utils::dummy_metadata(2), empty_signed_op);
+
+    let (tx, mut rx) = broadcast::channel(16);
+    run_indexer_loop_with_notifier(&repo, &tx).await.unwrap();
+
+    // SSI and VDR operations notify their DID, ignored operations do not
+    assert_eq!(rx.try_recv().unwrap(), did);
+    assert_eq!(rx.try_recv().unwrap(), did);
+    assert!(rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn index_loop_without_subscribers_succeeds() {
+    let repo = InMemoryRepo::new();
+    let (create_did_op, _, _, _, _) = create_did_with_vdr_key();
+    repo.insert(test_utils::dummy_metadata(0), create_did_op);
+
+    let (tx, rx) = broadcast::channel(16);
+    drop(rx);
+    run_indexer_loop_with_notifier(&repo, &tx).await.unwrap();
+    assert_eq!(repo.indexed_ops().len(), 1);
+}
+
 // ---------------------------------------------------------------------------
 // Tests for index_from_operation branches
 // ---------------------------------------------------------------------------
@@ -685,10 +770,22 @@ struct MockDltSource {
 }
 
 impl DltSource for MockDltSource {
+    fn source_name(&self) -> &'static str {
+        "mock"
+    }
+
     fn sync_cursor(&self) -> watch::Receiver<Option<DltCursor>> {
         self.cursor_rx.clone()
     }
 
+    fn chain_tip(&self) -> watch::Receiver<Option<DltChainTip>> {
+        watch::channel(None).1
+    }
+
+    fn last_error(&self) -> watch::Receiver<Option<DateTime<Utc>>> {
+        watch::channel(None).1
+    }
+
     fn into_stream(self) -> Result<mpsc::Receiver<PublishedPrismObject>, String> {
         self.rx.ok_or_else(|| "no receiver configured".to_string())
     }
@@ -705,16 +802,18 @@ fn mock_dlt_source_with_channel() -> (MockDltSource, mpsc::Sender<PublishedPrism
 }
 
 fn make_published_object(block_metadata: BlockMetadata, operations: Vec<SignedPrismOperation>) -> PublishedPrismObject {
+    let prism_object = PrismObject {
+        block_content: Some(PrismBlock {
+            operations,
+            special_fields: Default::default(),
+        })
+        .into(),
+        special_fields: Default::default(),
+    };
     PublishedPrismObject {
         block_metadata,
-        prism_object: PrismObject {
-            block_content: Some(PrismBlock {
-                operations,
-                special_fields: Default::default(),
-            })
-            .into(),
-            special_fields: Default::default(),
-        },
+        prism_object_bytes: prism_object.encode_to_vec(),
+        prism_object,
     }
 }
 
@@ -726,6 +825,7 @@ async fn sync_loop_inserts_valid_operations() {
 
     let meta = test_utils::dummy_metadata(0).block_metadata;
     let obj = make_published_object(meta.clone(), vec![create_did_op]);
+    let prism_object_bytes = obj.prism_object_bytes.clone();
     tx.send(obj).await.unwrap();
     drop(tx); // Close the source
 
@@ -736,6 +836,10 @@ async fn sync_loop_inserts_valid_operations() {
     assert_eq!(unindexed.len(), 1);
     assert_eq!(unindexed[0].metadata.osn, 0);
     assert_eq!(unindexed[0].metadata.block_metadata.tx_id, meta.tx_id);
+
+    // The PrismObject is stored as published
+    let stored = repo.get_prism_object_bytes(&meta.tx_id).await.unwrap();
+    assert_eq!(stored, Some(prism_object_bytes));
 }
 
 #[tokio::test]
@@ -785,11 +889,12 @@ async fn sync_loop_handles_none_block_content() {
 
     let meta = test_utils::dummy_metadata(0).block_metadata;
     let obj = PublishedPrismObject {
-        block_metadata: meta,
+        block_metadata: meta.clone(),
         prism_object: PrismObject {
             block_content: None.into(),
             special_fields: Default::default(),
         },
+        prism_object_bytes: vec![],
     };
     tx.send(obj).await.unwrap();
     drop(tx);
@@ -799,6 +904,7 @@ async fn sync_loop_handles_none_block_content() {
     // No operations should be inserted
     let unindexed = repo.raw_operations.lock().unwrap();
     assert!(unindexed.is_empty());
+    assert!(repo.prism_objects.lock().unwrap().is_empty());
 }
 
 #[tokio::test]
@@ -891,6 +997,27 @@ impl RawOperationRepo for FailingInsertRepo {
         Ok(vec![])
     }
 
+    async fn get_raw_operations_by_did_page(
+        &self,
+        _did: &CanonicalPrismDid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Paginated<RawOperationRecord>, Self::Error> {
+        Ok(Paginated {
+            items: vec![],
+            current_page: page,
+            page_size,
+            total_items: 0,
+        })
+    }
+
+    async fn get_raw_operations_by_dids(
+        &self,
+        _dids: &[CanonicalPrismDid],
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        Ok(vec![])
+    }
+
     async fn get_raw_operation_vdr_by_operation_hash(
         &self,
         _operation_hash: &Sha256Digest,
@@ -905,6 +1032,10 @@ impl RawOperationRepo for FailingInsertRepo {
         Ok(vec![])
     }
 
+    async fn get_prism_object_bytes(&self, _tx_id: &TxId) -> Result<Option<Vec<u8>>, Self::Error> {
+        Ok(None)
+    }
+
     async fn get_raw_operation_by_operation_id(
         &self,
         _operation_id: &identus_did_prism::did::operation::OperationId,
@@ -912,6 +1043,14 @@ impl RawOperationRepo for FailingInsertRepo {
         Ok(None)
     }
 
+    async fn get_raw_operations_after(
+        &self,
+        _position: &OperationPosition,
+        _limit: u32,
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        Ok(vec![])
+    }
+
     async fn insert_raw_operations(
         &self,
         operations: Vec<(OperationMetadata, SignedPrismOperation)>,
@@ -924,6 +1063,10 @@ impl RawOperationRepo for FailingInsertRepo {
         self.inserted.lock().unwrap().extend(operations);
         Ok(())
     }
+
+    async fn insert_prism_object_bytes(&self, _tx_id: &TxId, _prism_object_bytes: Vec<u8>) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
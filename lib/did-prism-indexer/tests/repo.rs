@@ -8,8 +8,8 @@ use identus_did_prism::dlt::{BlockMetadata, BlockNo, DltCursor, OperationMetadat
 use identus_did_prism::prelude::*;
 use identus_did_prism::utils::paging::Paginated;
 use identus_did_prism_indexer::repo::{
-    DltCursorRepo, IndexedOperation, IndexedOperationRepo, IndexerStateRepo, RawOperationId, RawOperationRecord,
-    RawOperationRepo,
+    DltCursorRepo, IndexedOperation, IndexedOperationRepo, IndexerStateRepo, OperationPosition, RawOperationId,
+    RawOperationRecord, RawOperationRepo,
 };
 use uuid::Uuid;
 
@@ -35,9 +35,12 @@ struct MockError;
 struct MockRawRepo {
     unindexed_result: Mutex<Vec<RawOperationRecord>>,
     by_did_result: Mutex<Vec<RawOperationRecord>>,
+    by_dids_result: Mutex<Vec<(RawOperationRecord, CanonicalPrismDid)>>,
     vdr_by_hash_result: Mutex<Option<RawOperationRecord>>,
     by_tx_id_result: Mutex<Vec<(RawOperationRecord, CanonicalPrismDid)>>,
+    prism_object_bytes: Mutex<Vec<(TxId, Vec<u8>)>>,
     by_op_id_result: Mutex<Option<(RawOperationRecord, CanonicalPrismDid)>>,
+    after_result: Mutex<Vec<(RawOperationRecord, CanonicalPrismDid)>>,
     inserted: Mutex<Vec<(OperationMetadata, SignedPrismOperation)>>,
 }
 
@@ -46,9 +49,12 @@ impl MockRawRepo {
         Self {
             unindexed_result: Mutex::new(vec![]),
             by_did_result: Mutex::new(vec![]),
+            by_dids_result: Mutex::new(vec![]),
             vdr_by_hash_result: Mutex::new(None),
             by_tx_id_result: Mutex::new(vec![]),
+            prism_object_bytes: Mutex::new(vec![]),
             by_op_id_result: Mutex::new(None),
+            after_result: Mutex::new(vec![]),
             inserted: Mutex::new(vec![]),
         }
     }
@@ -69,6 +75,32 @@ impl RawOperationRepo for MockRawRepo {
         Ok(self.by_did_result.lock().unwrap().clone())
     }
 
+    async fn get_raw_operations_by_did_page(
+        &self,
+        _did: &CanonicalPrismDid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Paginated<RawOperationRecord>, Self::Error> {
+        let items = self.by_did_result.lock().unwrap().clone();
+        Ok(Paginated {
+            total_items: items.len() as u32,
+            items: items
+                .into_iter()
+                .skip((page * page_size) as usize)
+                .take(page_size as usize)
+                .collect(),
+            current_page: page,
+            page_size,
+        })
+    }
+
+    async fn get_raw_operations_by_dids(
+        &self,
+        _dids: &[CanonicalPrismDid],
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        Ok(self.by_dids_result.lock().unwrap().clone())
+    }
+
     async fn get_raw_operation_vdr_by_operation_hash(
         &self,
         _operation_hash: &Sha256Digest,
@@ -83,6 +115,14 @@ impl RawOperationRepo for MockRawRepo {
         Ok(self.by_tx_id_result.lock().unwrap().clone())
     }
 
+    async fn get_prism_object_bytes(&self, tx_id: &TxId) -> Result<Option<Vec<u8>>, Self::Error> {
+        let stored = self.prism_object_bytes.lock().unwrap();
+        Ok(stored
+            .iter()
+            .find(|(id, _)| id == tx_id)
+            .map(|(_, bytes)| bytes.clone()))
+    }
+
     async fn get_raw_operation_by_operation_id(
         &self,
         _operation_id: &OperationId,
@@ -90,6 +130,14 @@ impl RawOperationRepo for MockRawRepo {
         Ok(self.by_op_id_result.lock().unwrap().clone())
     }
 
+    async fn get_raw_operations_after(
+        &self,
+        _position: &OperationPosition,
+        _limit: u32,
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        Ok(self.after_result.lock().unwrap().clone())
+    }
+
     async fn insert_raw_operations(
         &self,
         operations: Vec<(OperationMetadata, SignedPrismOperation)>,
@@ -97,6 +145,14 @@ impl RawOperationRepo for MockRawRepo {
         self.inserted.lock().unwrap().extend(operations);
         Ok(())
     }
+
+    async fn insert_prism_object_bytes(&self, tx_id: &TxId, prism_object_bytes: Vec<u8>) -> Result<(), Self::Error> {
+        self.prism_object_bytes
+            .lock()
+            .unwrap()
+            .push((tx_id.clone(), prism_object_bytes));
+        Ok(())
+    }
 }
 
 struct MockIndexedRepo {
@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use identus_did_prism::dlt::{BlockMetadata, BlockNo, PublishedPrismObject, SlotNo, TxId};
+use identus_did_prism::proto::MessageExt;
 use identus_did_prism::proto::prism::PrismObject;
 use identus_did_prism_submitter::DltSink;
 pub use sink::InMemoryDltSink;
@@ -30,6 +31,7 @@ pub fn create_ledger() -> (InMemoryDltSource, Arc<dyn DltSink + Send + Sync>) {
                     cbt: Utc::now(),
                     absn: 0, // In-memory blocks contain a single PrismObject per block
                 },
+                prism_object_bytes: prism_object.encode_to_vec(),
                 prism_object,
             };
             if let Err(e) = block_tx.send(published_prism_object).await {
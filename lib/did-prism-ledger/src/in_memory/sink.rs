@@ -23,6 +23,10 @@ impl InMemoryDltSink {
 
 #[async_trait::async_trait]
 impl DltSink for InMemoryDltSink {
+    fn sink_name(&self) -> &'static str {
+        "in-memory"
+    }
+
     async fn publish_operations(&self, operations: Vec<SignedPrismOperation>) -> Result<TxId, String> {
         let prism_object = PrismObject {
             block_content: Some(PrismBlock {
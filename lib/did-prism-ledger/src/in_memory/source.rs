@@ -1,35 +1,62 @@
-use identus_did_prism::dlt::{DltCursor, PublishedPrismObject};
+use chrono::{DateTime, Utc};
+use identus_did_prism::dlt::{DltChainTip, DltCursor, PublishedPrismObject};
 use identus_did_prism_indexer::DltSource;
 use tokio::sync::{mpsc, watch};
 
 pub struct InMemoryDltSource {
     block_rx: mpsc::Receiver<PublishedPrismObject>,
     sync_cursor_tx: watch::Sender<Option<DltCursor>>,
+    chain_tip_tx: watch::Sender<Option<DltChainTip>>,
+    last_error_tx: watch::Sender<Option<DateTime<Utc>>>,
 }
 
 impl InMemoryDltSource {
     pub fn new(block_rx: mpsc::Receiver<PublishedPrismObject>) -> Self {
         let (sync_cursor_tx, _) = watch::channel::<Option<DltCursor>>(None);
+        let (chain_tip_tx, _) = watch::channel::<Option<DltChainTip>>(None);
+        let (last_error_tx, _) = watch::channel::<Option<DateTime<Utc>>>(None);
         Self {
             block_rx,
             sync_cursor_tx,
+            chain_tip_tx,
+            last_error_tx,
         }
     }
 }
 
 impl DltSource for InMemoryDltSource {
+    fn source_name(&self) -> &'static str {
+        "in-memory"
+    }
+
     fn sync_cursor(&self) -> watch::Receiver<Option<DltCursor>> {
         self.sync_cursor_tx.subscribe()
     }
 
+    fn chain_tip(&self) -> watch::Receiver<Option<DltChainTip>> {
+        self.chain_tip_tx.subscribe()
+    }
+
+    fn last_error(&self) -> watch::Receiver<Option<DateTime<Utc>>> {
+        self.last_error_tx.subscribe()
+    }
+
     fn into_stream(self) -> Result<mpsc::Receiver<PublishedPrismObject>, String> {
         let (event_tx, event_rx) = mpsc::channel::<PublishedPrismObject>(1024);
         let sync_cursor_tx = self.sync_cursor_tx;
+        let chain_tip_tx = self.chain_tip_tx;
 
         tokio::spawn(async move {
             let mut block_rx = self.block_rx;
 
             while let Some(published_object) = block_rx.recv().await {
+                // Every published block is immediately the tip of the in-memory ledger
+                let chain_tip = DltChainTip {
+                    slot: published_object.block_metadata.slot_number.into(),
+                    cbt: published_object.block_metadata.cbt,
+                };
+                let _ = chain_tip_tx.send(Some(chain_tip));
+
                 // Update cursor based on the block metadata
                 let cursor = DltCursor {
                     slot: published_object.block_metadata.slot_number.into(),
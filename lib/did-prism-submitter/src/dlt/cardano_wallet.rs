@@ -60,6 +60,10 @@ impl CardanoWalletSink {
 
 #[async_trait::async_trait]
 impl DltSink for CardanoWalletSink {
+    fn sink_name(&self) -> &'static str {
+        "cardano-wallet"
+    }
+
     async fn publish_operations(&self, operations: Vec<SignedPrismOperation>) -> Result<TxId, String> {
         let prism_object = PrismObject {
             block_content: Some(PrismBlock {
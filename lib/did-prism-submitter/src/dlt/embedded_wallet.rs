@@ -100,6 +100,10 @@ impl EmbeddedWalletSink {
 
 #[async_trait::async_trait]
 impl DltSink for EmbeddedWalletSink {
+    fn sink_name(&self) -> &'static str {
+        "embedded-wallet"
+    }
+
     async fn publish_operations(&self, operations: Vec<SignedPrismOperation>) -> Result<TxId, String> {
         let prism_object = PrismObject {
             block_content: Some(PrismBlock {
@@ -0,0 +1,75 @@
+use std::sync::LazyLock;
+use std::time::Instant;
+
+use identus_did_prism::dlt::TxId;
+use identus_did_prism::prelude::SignedPrismOperation;
+use prometheus::{HistogramVec, register_histogram_vec};
+
+use crate::DltSink;
+
+static SUBMISSION_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "neoprism_submission_duration_seconds",
+        "Duration of operation submission to the DLT sink",
+        &["sink", "outcome"]
+    )
+    .expect("metric neoprism_submission_duration_seconds must be registered")
+});
+
+/// A DLT sink wrapper that records the duration of every submission.
+#[derive(Debug, Clone)]
+pub struct InstrumentedDltSink<T> {
+    inner: T,
+}
+
+impl<T: DltSink> InstrumentedDltSink<T> {
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: DltSink + Send + Sync> DltSink for InstrumentedDltSink<T> {
+    fn sink_name(&self) -> &'static str {
+        self.inner.sink_name()
+    }
+
+    async fn publish_operations(&self, operations: Vec<SignedPrismOperation>) -> Result<TxId, String> {
+        let started_at = Instant::now();
+        let result = self.inner.publish_operations(operations).await;
+        let outcome = if result.is_ok() { "success" } else { "error" };
+        SUBMISSION_DURATION
+            .with_label_values(&[self.sink_name(), outcome])
+            .observe(started_at.elapsed().as_secs_f64());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FailingSink;
+
+    #[async_trait::async_trait]
+    impl DltSink for FailingSink {
+        fn sink_name(&self) -> &'static str {
+            "failing"
+        }
+
+        async fn publish_operations(&self, _: Vec<SignedPrismOperation>) -> Result<TxId, String> {
+            Err("unavailable".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn publish_operations_records_submission_duration() {
+        let sink = InstrumentedDltSink::new(FailingSink);
+        assert!(sink.publish_operations(vec![]).await.is_err());
+
+        let count = SUBMISSION_DURATION
+            .with_label_values(&["failing", "error"])
+            .get_sample_count();
+        assert_eq!(count, 1);
+    }
+}
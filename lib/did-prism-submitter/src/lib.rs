@@ -1,14 +1,32 @@
+use std::sync::Arc;
+
 use identus_did_prism::dlt::TxId;
 use identus_did_prism::prelude::SignedPrismOperation;
 
 pub mod dlt;
+mod instrumented;
 
 #[cfg(feature = "cardano-wallet")]
 pub use dlt::cardano_wallet::CardanoWalletSink;
 #[cfg(feature = "embedded-wallet")]
 pub use dlt::embedded_wallet::EmbeddedWalletSink;
+pub use instrumented::InstrumentedDltSink;
 
 #[async_trait::async_trait]
 pub trait DltSink {
+    /// Short name of the sink, used to label metrics.
+    fn sink_name(&self) -> &'static str;
+
     async fn publish_operations(&self, operations: Vec<SignedPrismOperation>) -> Result<TxId, String>;
 }
+
+#[async_trait::async_trait]
+impl<T: DltSink + Send + Sync + ?Sized> DltSink for Arc<T> {
+    fn sink_name(&self) -> &'static str {
+        self.as_ref().sink_name()
+    }
+
+    async fn publish_operations(&self, operations: Vec<SignedPrismOperation>) -> Result<TxId, String> {
+        self.as_ref().publish_operations(operations).await
+    }
+}
@@ -1,16 +1,25 @@
 use identus_apollo::hex::HexStr;
 use identus_apollo::jwk::EncodeJwk;
 use identus_did_core::{
-    Did, DidDocument, DidDocumentMetadata, ResolutionResult, Service, ServiceEndpoint, ServiceType, StringOrMap,
-    VerificationMethod, VerificationMethodOrRef,
+    DID_CONTEXT, Did, DidDocument, DidDocumentMetadata, OperationKey, ResolutionResult, Service, ServiceEndpoint,
+    ServiceType, StringOrMap, VerificationKey, VerificationMethod, VerificationMethodEncoding, VerificationMethodOrRef,
+    verification_method_context,
 };
 
-use crate::did::operation::KeyUsage;
+use crate::did::operation::{KeyUsage, NonOperationPublicKey};
 use crate::did::{DidState, PrismDid, PrismDidOps, operation};
 
 impl DidState {
     pub fn to_resolution_result(&self, did: &PrismDid) -> ResolutionResult {
-        let did_document = self.to_did_document(&did.to_did());
+        self.to_resolution_result_with_encoding(did, VerificationMethodEncoding::default())
+    }
+
+    pub fn to_resolution_result_with_encoding(
+        &self,
+        did: &PrismDid,
+        encoding: VerificationMethodEncoding,
+    ) -> ResolutionResult {
+        let did_document = self.to_did_document_with_encoding(&did.to_did(), encoding);
         let canonical_id = match did {
             PrismDid::LongForm(did) if self.is_published => Some(did.clone().into_canonical().to_did()),
             _ => None,
@@ -22,6 +31,7 @@ impl DidState {
             deactivated: Some(self.is_deactivated()),
             canonical_id,
             version_id: Some(HexStr::from(self.last_operation_hash.as_bytes()).to_string()),
+            operation_keys: None,
         };
 
         let mut result = if self.is_deactivated() {
@@ -34,9 +44,13 @@ impl DidState {
     }
 
     pub fn to_did_document(&self, did: &Did) -> DidDocument {
-        let mut context = vec!["https://www.w3.org/ns/did/v1".to_string()];
-        context.extend(self.context.clone());
+        self.to_did_document_with_encoding(did, VerificationMethodEncoding::default())
+    }
 
+    /// Build the DID document, representing the public keys as verification methods of the given encoding.
+    ///
+    /// The JSON-LD contexts defining the verification method types are added after the DID context.
+    pub fn to_did_document_with_encoding(&self, did: &Did, encoding: VerificationMethodEncoding) -> DidDocument {
         let get_relationship = |usage: KeyUsage| -> Vec<VerificationMethodOrRef> {
             self.public_keys
                 .iter()
@@ -57,8 +71,21 @@ impl DidState {
                 ];
                 W3C_KEY_TYPES.iter().any(|usage| usage == &k.data.usage())
             })
-            .flat_map(|k| transform_key_jwk(did, k))
-            .collect();
+            .flat_map(|k| transform_key(did, k, encoding))
+            .collect::<Vec<_>>();
+
+        let mut context = vec![DID_CONTEXT.to_string()];
+        // `JsonWebKey2020` has no context so that documents keep the representation of the legacy PRISM node
+        for vm_context in verification_method
+            .iter()
+            .filter_map(|vm| verification_method_context(&vm.r#type, true))
+        {
+            if !context.iter().chain(&self.context).any(|i| i == vm_context) {
+                context.push(vm_context.to_string());
+            }
+        }
+        context.extend(self.context.clone());
+
         DidDocument {
             context,
             id: did.clone(),
@@ -74,22 +101,43 @@ impl DidState {
     }
 }
 
-fn transform_key_jwk(did: &Did, key: &operation::PublicKey) -> Option<VerificationMethod> {
-    match &key.data {
-        operation::PublicKeyData::Master { .. } => None,
-        operation::PublicKeyData::Vdr { .. } => None,
-        operation::PublicKeyData::Other { data, .. } => {
-            let jwk = data.encode_jwk();
-            Some(VerificationMethod {
-                id: format!("{}#{}", did, key.id),
-                r#type: "JsonWebKey2020".to_string(),
-                controller: did.to_string(),
-                public_key_jwk: Some(jwk),
+impl DidState {
+    /// List the master and VDR keys, which are not part of the DID document.
+    pub fn to_operation_keys(&self, did: &Did) -> Vec<OperationKey> {
+        self.public_keys
+            .iter()
+            .filter_map(|k| {
+                let (purpose, data) = match &k.data {
+                    operation::PublicKeyData::Master { data } => ("master", data),
+                    operation::PublicKeyData::Vdr { data } => ("vdr", data),
+                    operation::PublicKeyData::Other { .. } => return None,
+                };
+                Some(OperationKey {
+                    id: format!("{}#{}", did, k.id),
+                    purpose: purpose.to_string(),
+                    public_key_jwk: data.encode_jwk(),
+                })
             })
-        }
+            .collect()
     }
 }
 
+fn transform_key(
+    did: &Did,
+    key: &operation::PublicKey,
+    encoding: VerificationMethodEncoding,
+) -> Option<VerificationMethod> {
+    let operation::PublicKeyData::Other { data, .. } = &key.data else {
+        return None;
+    };
+    let verification_key = match data {
+        NonOperationPublicKey::Secp256k1(pk) => VerificationKey::from(pk.clone()),
+        NonOperationPublicKey::Ed25519(pk) => VerificationKey::from(pk.clone()),
+        NonOperationPublicKey::X25519(pk) => VerificationKey::from(pk.clone()),
+    };
+    Some(verification_key.to_verification_method(format!("{}#{}", did, key.id), did, encoding))
+}
+
 fn transform_service(did: &Did, service: &operation::Service) -> Service {
     let r#type = match &service.r#type {
         operation::ServiceType::One(name) => ServiceType::Str(name.to_string()),
@@ -0,0 +1,174 @@
+//! Verification of compact JWS, such as JWT credentials, signed with the keys of a PRISM DID.
+//!
+//! The signing key is the one referenced by the `kid` header as a DID URL `did:prism:<suffix>#<key-id>`.
+//! The key is checked against the DID state at the issuance time of the JWS, so a credential stays valid
+//! after its issuing key is revoked, while one issued with an already revoked key does not.
+
+use chrono::{DateTime, Utc};
+use identus_apollo::jws::{self, CompactJws};
+
+use crate::did::error::PublicKeyIdError;
+use crate::did::operation::{KeyUsage, NonOperationPublicKey, OperationParameters, PublicKeyData, PublicKeyId};
+use crate::did::{CanonicalPrismDid, DidState, PrismDid, PrismDidOps, PublicKeyHistory};
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum Error {
+    #[display("jws header has no 'kid' parameter")]
+    MissingKid,
+    #[display("jws 'kid' {kid} is not a PRISM DID URL with a key id fragment")]
+    InvalidKid {
+        #[error(not(source))]
+        kid: String,
+    },
+    #[display("jws 'kid' {kid} has an invalid PRISM DID")]
+    InvalidKidDid { source: crate::did::Error, kid: String },
+    #[display("jws 'kid' {kid} has an invalid key id")]
+    InvalidKidKeyId { source: PublicKeyIdError, kid: String },
+    #[display("jws payload is not a JSON object")]
+    InvalidPayload { source: serde_json::Error },
+    #[display("jws payload has neither 'iat' nor 'nbf' claim")]
+    MissingIssuanceTime,
+    #[display("jws payload claim '{claim}' is not a valid numeric date")]
+    InvalidIssuanceTime { claim: &'static str },
+    #[display("jws is signed by {did} but the DID state is of {expected_did}")]
+    DidMismatch {
+        did: CanonicalPrismDid,
+        expected_did: CanonicalPrismDid,
+    },
+    #[display("public key {id} does not exist in the DID")]
+    KeyNotFound {
+        #[error(not(source))]
+        id: PublicKeyId,
+    },
+    #[display("public key {id} is not active at {time}")]
+    KeyNotActive { id: PublicKeyId, time: DateTime<Utc> },
+    #[display("public key {id} has usage {usage:?} but {expected:?} is required")]
+    UnexpectedKeyUsage {
+        id: PublicKeyId,
+        usage: KeyUsage,
+        expected: KeyUsage,
+    },
+    #[display("public key {id} cannot be used to verify a jws")]
+    UnsupportedKey {
+        #[error(not(source))]
+        id: PublicKeyId,
+    },
+    #[display("jws signature verification failed")]
+    InvalidSignature { source: jws::Error },
+}
+
+/// The signing key of a JWS, referenced by its `kid` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JwsKid {
+    pub did: PrismDid,
+    pub key_id: PublicKeyId,
+}
+
+impl JwsKid {
+    pub fn from_jws(jws: &CompactJws) -> Result<Self, Error> {
+        let kid = jws.header().kid.as_deref().ok_or(Error::MissingKid)?;
+        let Some((did, key_id)) = kid.split_once('#') else {
+            Err(Error::InvalidKid { kid: kid.to_string() })?
+        };
+        let did = did.parse::<PrismDid>().map_err(|e| Error::InvalidKidDid {
+            source: e,
+            kid: kid.to_string(),
+        })?;
+        let key_id =
+            PublicKeyId::parse(key_id, OperationParameters::v1().max_id_size).map_err(|e| Error::InvalidKidKeyId {
+                source: e,
+                kid: kid.to_string(),
+            })?;
+        Ok(Self { did, key_id })
+    }
+}
+
+/// The result of a successful verification.
+#[derive(Debug, Clone)]
+pub struct VerifiedJws {
+    pub kid: JwsKid,
+    pub issuance_time: DateTime<Utc>,
+    /// The signing key, with the operations that added and possibly later revoked it.
+    pub public_key: PublicKeyHistory,
+}
+
+/// The issuance time of a JWS payload, from the `iat` claim or else the `nbf` claim.
+pub fn issuance_time(jws: &CompactJws) -> Result<DateTime<Utc>, Error> {
+    let payload = serde_json::from_slice::<serde_json::Map<String, serde_json::Value>>(jws.payload())
+        .map_err(|e| Error::InvalidPayload { source: e })?;
+    let Some((claim, value)) = ["iat", "nbf"]
+        .into_iter()
+        .find_map(|claim| payload.get(claim).map(|value| (claim, value)))
+    else {
+        Err(Error::MissingIssuanceTime)?
+    };
+    // NumericDate is the number of seconds since the epoch, possibly with a fraction
+    value
+        .as_f64()
+        .and_then(|seconds| {
+            let whole = seconds.floor();
+            DateTime::from_timestamp(whole as i64, ((seconds - whole) * 1e9) as u32)
+        })
+        .ok_or(Error::InvalidIssuanceTime { claim })
+}
+
+/// Verify the JWS signature with the `kid` key of the DID state, which must have the expected usage
+/// and be active at the issuance time of the JWS.
+///
+/// `IssuingKey` is the usage of the `assertionMethod` relationship expected for credentials.
+pub fn verify_jws(jws: &CompactJws, state: &DidState, expected_usage: KeyUsage) -> Result<VerifiedJws, Error> {
+    let kid = JwsKid::from_jws(jws)?;
+    let did = kid.did.clone().into_canonical();
+    if did != state.did {
+        Err(Error::DidMismatch {
+            did,
+            expected_did: state.did.clone(),
+        })?
+    }
+
+    let time = issuance_time(jws)?;
+    let history = state
+        .public_key_history(&kid.key_id)
+        .ok_or_else(|| Error::KeyNotFound { id: kid.key_id.clone() })?;
+    let public_key = &history.public_key;
+    let usage = public_key.data.usage();
+    if usage != expected_usage {
+        Err(Error::UnexpectedKeyUsage {
+            id: public_key.id.clone(),
+            usage,
+            expected: expected_usage,
+        })?
+    }
+    if !history.is_active_at(&time) {
+        Err(Error::KeyNotActive {
+            id: public_key.id.clone(),
+            time,
+        })?
+    }
+
+    let result = match &public_key.data {
+        PublicKeyData::Master { data }
+        | PublicKeyData::Vdr { data }
+        | PublicKeyData::Other {
+            data: NonOperationPublicKey::Secp256k1(data),
+            ..
+        } => jws.verify(data),
+        PublicKeyData::Other {
+            data: NonOperationPublicKey::Ed25519(data),
+            ..
+        } => jws.verify(data),
+        PublicKeyData::Other {
+            data: NonOperationPublicKey::X25519(_),
+            ..
+        } => Err(Error::UnsupportedKey {
+            id: public_key.id.clone(),
+        })?,
+    };
+    result.map_err(|e| Error::InvalidSignature { source: e })?;
+
+    Ok(VerifiedJws {
+        kid,
+        issuance_time: time,
+        public_key: history.clone(),
+    })
+}
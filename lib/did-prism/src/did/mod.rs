@@ -11,8 +11,9 @@ use identus_apollo::hex::HexStr;
 use identus_did_core::Did;
 use regex::Regex;
 
-use self::operation::{PublicKey, Service};
+use self::operation::{PublicKey, PublicKeyId, Service};
 use crate::did::operation::StorageData;
+use crate::dlt::OperationMetadata;
 use crate::prelude::*;
 use crate::proto::node_api;
 use crate::proto::prism::PrismOperation;
@@ -20,6 +21,7 @@ use crate::proto::prism::prism_operation::Operation;
 
 pub mod did_doc;
 pub mod error;
+pub mod jws;
 pub mod operation;
 
 pub use error::Error;
@@ -40,6 +42,10 @@ pub enum PrismDid {
     LongForm(LongFormPrismDid),
 }
 
+impl PrismDid {
+    pub const METHOD: &str = "prism";
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, derive_more::Debug, derive_more::Display)]
 #[display("did:{}:{}", self.method(), self.suffix_hex())]
 #[debug("did:{}:{}", self.method(), self.suffix_hex())]
@@ -60,7 +66,7 @@ pub trait PrismDidOps: std::fmt::Display {
     fn suffix(&self) -> &Sha256Digest;
 
     fn method(&self) -> &'static str {
-        "prism"
+        PrismDid::METHOD
     }
 
     fn suffix_hex(&self) -> HexStr {
@@ -222,6 +228,8 @@ pub struct DidState {
     pub context: Vec<String>,
     pub last_operation_hash: Rc<Sha256Digest>,
     pub public_keys: Vec<PublicKey>,
+    /// All public keys ever added to the DID, including the revoked ones, in the order they were added.
+    pub public_key_history: Vec<PublicKeyHistory>,
     pub services: Vec<Service>,
     pub storage: Vec<StorageState>,
     pub created_at: DateTime<Utc>,
@@ -233,6 +241,30 @@ impl DidState {
     pub fn is_deactivated(&self) -> bool {
         self.public_keys.is_empty()
     }
+
+    pub fn public_key_history(&self, id: &PublicKeyId) -> Option<&PublicKeyHistory> {
+        self.public_key_history.iter().find(|i| &i.public_key.id == id)
+    }
+}
+
+/// A public key together with the operations that added and revoked it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicKeyHistory {
+    pub public_key: PublicKey,
+    pub added_at: OperationMetadata,
+    pub revoked_at: Option<OperationMetadata>,
+}
+
+impl PublicKeyHistory {
+    /// Whether the key was added at or before the given time and not yet revoked at that time.
+    pub fn is_active_at(&self, time: &DateTime<Utc>) -> bool {
+        let added = self.added_at.block_metadata.cbt <= *time;
+        let revoked = self
+            .revoked_at
+            .as_ref()
+            .is_some_and(|revoked_at| revoked_at.block_metadata.cbt <= *time);
+        added && !revoked
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -8,6 +8,7 @@ use identus_apollo::crypto::secp256k1::Secp256k1PublicKey;
 use identus_apollo::crypto::x25519::X25519PublicKey;
 use identus_apollo::hash::Sha256Digest;
 use identus_apollo::jwk::EncodeJwk;
+use identus_apollo::multibase::{EncodeMultikey, Multikey};
 use identus_did_core::uri::{is_uri, is_uri_fragment};
 use regex::Regex;
 
@@ -461,6 +462,16 @@ impl EncodeJwk for NonOperationPublicKey {
     }
 }
 
+impl EncodeMultikey for NonOperationPublicKey {
+    fn encode_multikey(&self) -> Multikey {
+        match self {
+            NonOperationPublicKey::Secp256k1(pk) => pk.encode_multikey(),
+            NonOperationPublicKey::Ed25519(pk) => pk.encode_multikey(),
+            NonOperationPublicKey::X25519(pk) => pk.encode_multikey(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PublicKeyData {
     Master {
@@ -6,6 +6,7 @@ use identus_apollo::hex::HexStr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum::VariantArray;
 
+use crate::proto::MessageExt;
 use crate::proto::prism::PrismObject;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -16,6 +17,13 @@ pub struct DltCursor {
     pub blockfrost_page: Option<u32>,
 }
 
+/// The most recent block observed on the chain by a DLT source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DltChainTip {
+    pub slot: u64,
+    pub cbt: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BlockMetadata {
     /// Cardano slot number
@@ -58,6 +66,20 @@ impl OperationMetadata {
 pub struct PublishedPrismObject {
     pub block_metadata: BlockMetadata,
     pub prism_object: PrismObject,
+    /// The PrismObject exactly as carried in the transaction metadata, with its byte groups concatenated.
+    pub prism_object_bytes: Vec<u8>,
+}
+
+impl PublishedPrismObject {
+    /// Decode the PrismObject carried in a transaction, keeping the published bytes alongside.
+    pub fn decode(block_metadata: BlockMetadata, prism_object_bytes: Vec<u8>) -> protobuf::Result<Self> {
+        let prism_object = PrismObject::decode(&prism_object_bytes)?;
+        Ok(Self {
+            block_metadata,
+            prism_object,
+            prism_object_bytes,
+        })
+    }
 }
 
 #[derive(
@@ -16,6 +16,8 @@ pub mod proto {
 
     include!(concat!(env!("OUT_DIR"), "/generated/mod.rs"));
 
+    pub mod json;
+
     pub trait MessageExt: Sized {
         fn encode_to_vec(&self) -> Vec<u8>;
         fn decode(bytes: &[u8]) -> protobuf::Result<Self>;
@@ -0,0 +1,288 @@
+//! Canonical JSON rendering of PRISM protobuf messages.
+//!
+//! Field names follow the protobuf definitions in snake case, bytes are rendered as
+//! hex strings and public keys are additionally decoded to JWK when the curve is supported.
+//! Hashes derived from the messages (`operation_hash`, `operation_id`) are included
+//! so the output can be matched against the indexer API.
+
+use identus_apollo::hex::HexStr;
+use identus_apollo::jwk::EncodeJwk;
+use serde_json::{Value, json};
+
+use crate::did::operation::NonOperationPublicKey;
+use crate::proto::prism::prism_operation::Operation;
+use crate::proto::prism::{PrismBlock, PrismObject, PrismOperation, SignedPrismOperation};
+use crate::proto::prism_ssi::public_key::Key_data;
+use crate::proto::prism_ssi::update_didaction::Action;
+use crate::proto::prism_ssi::{
+    KeyUsage, ProtoCreateDID, ProtoDeactivateDID, ProtoUpdateDID, PublicKey, Service, UpdateDIDAction,
+};
+use crate::proto::prism_storage::proto_create_storage_entry::Data as ProtoCreateStorageData;
+use crate::proto::prism_storage::proto_update_storage_entry::Data as ProtoUpdateStorageData;
+use crate::proto::prism_storage::{
+    ProtoCreateStorageEntry, ProtoDeactivateStorageEntry, ProtoUpdateStorageEntry, StatusListEntry,
+};
+use crate::proto::prism_version::{ProtoProtocolVersionUpdate, ProtocolVersionInfo};
+
+/// Render a protobuf message as canonical JSON.
+///
+/// # Examples
+/// ```
+/// use identus_did_prism::proto::json::ProtoJson;
+/// use identus_did_prism::proto::prism::SignedPrismOperation;
+///
+/// let signed_operation = SignedPrismOperation {
+///     signed_with: "master-0".to_string(),
+///     signature: vec![0xde, 0xad],
+///     ..Default::default()
+/// };
+/// let json = signed_operation.to_json();
+/// assert_eq!(json["signed_with"], "master-0");
+/// assert_eq!(json["signature"], "dead");
+/// assert!(json["operation"].is_null());
+/// ```
+pub trait ProtoJson {
+    fn to_json(&self) -> Value;
+}
+
+impl<T: ProtoJson> ProtoJson for protobuf::MessageField<T> {
+    fn to_json(&self) -> Value {
+        self.as_ref().map(|i| i.to_json()).unwrap_or(Value::Null)
+    }
+}
+
+impl<T: ProtoJson> ProtoJson for Vec<T> {
+    fn to_json(&self) -> Value {
+        Value::Array(self.iter().map(|i| i.to_json()).collect())
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    HexStr::from(bytes).to_string()
+}
+
+impl ProtoJson for PrismObject {
+    fn to_json(&self) -> Value {
+        json!({
+            "block_content": self.block_content.to_json(),
+        })
+    }
+}
+
+impl ProtoJson for PrismBlock {
+    fn to_json(&self) -> Value {
+        json!({
+            "operations": self.operations.to_json(),
+        })
+    }
+}
+
+impl ProtoJson for SignedPrismOperation {
+    fn to_json(&self) -> Value {
+        json!({
+            "operation_id": self.operation_id().to_string(),
+            "signed_with": self.signed_with,
+            "signature": hex(&self.signature),
+            "operation": self.operation.to_json(),
+        })
+    }
+}
+
+impl ProtoJson for PrismOperation {
+    fn to_json(&self) -> Value {
+        let (operation_type, operation) = match &self.operation {
+            Some(Operation::CreateDid(op)) => ("create_did", op.to_json()),
+            Some(Operation::UpdateDid(op)) => ("update_did", op.to_json()),
+            Some(Operation::DeactivateDid(op)) => ("deactivate_did", op.to_json()),
+            Some(Operation::ProtocolVersionUpdate(op)) => ("protocol_version_update", op.to_json()),
+            Some(Operation::CreateStorageEntry(op)) => ("create_storage_entry", op.to_json()),
+            Some(Operation::UpdateStorageEntry(op)) => ("update_storage_entry", op.to_json()),
+            Some(Operation::DeactivateStorageEntry(op)) => ("deactivate_storage_entry", op.to_json()),
+            None => return json!({ "operation_hash": hex(self.operation_hash().as_bytes()) }),
+        };
+        json!({
+            "operation_hash": hex(self.operation_hash().as_bytes()),
+            (operation_type): operation,
+        })
+    }
+}
+
+impl ProtoJson for ProtoCreateDID {
+    fn to_json(&self) -> Value {
+        let did_data = self.did_data.as_ref().map(|did_data| {
+            json!({
+                "public_keys": did_data.public_keys.to_json(),
+                "services": did_data.services.to_json(),
+                "context": did_data.context,
+            })
+        });
+        json!({ "did_data": did_data })
+    }
+}
+
+impl ProtoJson for ProtoUpdateDID {
+    fn to_json(&self) -> Value {
+        json!({
+            "previous_operation_hash": hex(&self.previous_operation_hash),
+            "id": self.id,
+            "actions": self.actions.to_json(),
+        })
+    }
+}
+
+impl ProtoJson for ProtoDeactivateDID {
+    fn to_json(&self) -> Value {
+        json!({
+            "previous_operation_hash": hex(&self.previous_operation_hash),
+            "id": self.id,
+        })
+    }
+}
+
+impl ProtoJson for UpdateDIDAction {
+    fn to_json(&self) -> Value {
+        match &self.action {
+            Some(Action::AddKey(action)) => json!({ "add_key": { "key": action.key.to_json() } }),
+            Some(Action::RemoveKey(action)) => json!({ "remove_key": { "key_id": action.keyId } }),
+            Some(Action::AddService(action)) => json!({ "add_service": { "service": action.service.to_json() } }),
+            Some(Action::RemoveService(action)) => json!({ "remove_service": { "service_id": action.serviceId } }),
+            Some(Action::UpdateService(action)) => json!({
+                "update_service": {
+                    "service_id": action.serviceId,
+                    "type": action.type_,
+                    "service_endpoints": action.service_endpoints,
+                }
+            }),
+            Some(Action::PatchContext(action)) => json!({ "patch_context": { "context": action.context } }),
+            None => json!({}),
+        }
+    }
+}
+
+impl ProtoJson for PublicKey {
+    fn to_json(&self) -> Value {
+        let usage = match self.usage.enum_value() {
+            Ok(usage) => Value::from(key_usage_name(usage)),
+            Err(value) => Value::from(value),
+        };
+        let key_data = match &self.key_data {
+            Some(Key_data::EcKeyData(k)) => json!({
+                "ec_key_data": { "curve": k.curve, "x": hex(&k.x), "y": hex(&k.y) }
+            }),
+            Some(Key_data::CompressedEcKeyData(k)) => json!({
+                "compressed_ec_key_data": { "curve": k.curve, "data": hex(&k.data) }
+            }),
+            None => Value::Null,
+        };
+        // Keys with an unsupported curve or invalid data are kept as raw key data only
+        let jwk = self
+            .key_data
+            .as_ref()
+            .and_then(|key_data| NonOperationPublicKey::parse(key_data).ok().flatten())
+            .map(|pk| serde_json::to_value(pk.encode_jwk()).expect("JWK must serialize to JSON"));
+        json!({
+            "id": self.id,
+            "usage": usage,
+            "key_data": key_data,
+            "jwk": jwk,
+        })
+    }
+}
+
+fn key_usage_name(usage: KeyUsage) -> &'static str {
+    match usage {
+        KeyUsage::UNKNOWN_KEY => "UNKNOWN_KEY",
+        KeyUsage::MASTER_KEY => "MASTER_KEY",
+        KeyUsage::ISSUING_KEY => "ISSUING_KEY",
+        KeyUsage::KEY_AGREEMENT_KEY => "KEY_AGREEMENT_KEY",
+        KeyUsage::AUTHENTICATION_KEY => "AUTHENTICATION_KEY",
+        KeyUsage::REVOCATION_KEY => "REVOCATION_KEY",
+        KeyUsage::CAPABILITY_INVOCATION_KEY => "CAPABILITY_INVOCATION_KEY",
+        KeyUsage::CAPABILITY_DELEGATION_KEY => "CAPABILITY_DELEGATION_KEY",
+        KeyUsage::VDR_KEY => "VDR_KEY",
+    }
+}
+
+impl ProtoJson for Service {
+    fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "type": self.type_,
+            "service_endpoint": self.service_endpoint,
+        })
+    }
+}
+
+impl ProtoJson for ProtoProtocolVersionUpdate {
+    fn to_json(&self) -> Value {
+        json!({
+            "proposer_did": self.proposer_did,
+            "version": self.version.to_json(),
+        })
+    }
+}
+
+impl ProtoJson for ProtocolVersionInfo {
+    fn to_json(&self) -> Value {
+        let protocol_version = self.protocol_version.as_ref().map(|v| {
+            json!({
+                "major_version": v.major_version,
+                "minor_version": v.minor_version,
+            })
+        });
+        json!({
+            "version_name": self.version_name,
+            "effective_since": self.effective_since,
+            "protocol_version": protocol_version,
+        })
+    }
+}
+
+impl ProtoJson for ProtoCreateStorageEntry {
+    fn to_json(&self) -> Value {
+        let data = match &self.data {
+            Some(ProtoCreateStorageData::Bytes(bytes)) => json!({ "bytes": hex(bytes) }),
+            Some(ProtoCreateStorageData::Ipfs(cid)) => json!({ "ipfs": cid }),
+            Some(ProtoCreateStorageData::StatusListEntry(entry)) => json!({ "status_list_entry": entry.to_json() }),
+            None => Value::Null,
+        };
+        json!({
+            "did_prism_hash": hex(&self.did_prism_hash),
+            "nonce": hex(&self.nonce),
+            "data": data,
+        })
+    }
+}
+
+impl ProtoJson for ProtoUpdateStorageEntry {
+    fn to_json(&self) -> Value {
+        let data = match &self.data {
+            Some(ProtoUpdateStorageData::Bytes(bytes)) => json!({ "bytes": hex(bytes) }),
+            Some(ProtoUpdateStorageData::Ipfs(cid)) => json!({ "ipfs": cid }),
+            Some(ProtoUpdateStorageData::StatusListEntry(entry)) => json!({ "status_list_entry": entry.to_json() }),
+            None => Value::Null,
+        };
+        json!({
+            "previous_event_hash": hex(&self.previous_event_hash),
+            "data": data,
+        })
+    }
+}
+
+impl ProtoJson for ProtoDeactivateStorageEntry {
+    fn to_json(&self) -> Value {
+        json!({
+            "previous_event_hash": hex(&self.previous_event_hash),
+        })
+    }
+}
+
+impl ProtoJson for StatusListEntry {
+    fn to_json(&self) -> Value {
+        json!({
+            "state": self.state,
+            "name": self.name,
+            "details": self.details,
+        })
+    }
+}
@@ -10,7 +10,7 @@ use protobuf::SpecialFields;
 
 use self::v1::V1Processor;
 use crate::did::operation::{PublicKey, PublicKeyId, Service, ServiceEndpoint, ServiceId, ServiceType, StorageData};
-use crate::did::{CanonicalPrismDid, DidState, StorageState};
+use crate::did::{CanonicalPrismDid, DidState, PublicKeyHistory, StorageState};
 use crate::dlt::{BlockMetadata, OperationMetadata, TxId};
 use crate::prelude::*;
 use crate::proto::prism::prism_operation::Operation;
@@ -19,13 +19,13 @@ use crate::proto::prism_storage::{ProtoCreateStorageEntry, ProtoDeactivateStorag
 use crate::proto::prism_version::ProtoProtocolVersionUpdate;
 
 pub mod error;
+pub mod proof;
 pub mod resolver;
 mod v1;
 
 #[derive(Debug, Clone)]
 struct Revocable<T> {
     inner: T,
-    #[allow(unused)]
     added_at: OperationMetadata,
     revoked_at: Option<OperationMetadata>,
 }
@@ -304,6 +304,19 @@ impl DidStateRc {
             (created_at.unwrap_or_default(), updated_at.unwrap_or_default())
         };
 
+        let mut public_key_history: Vec<PublicKeyHistory> = self
+            .public_keys
+            .iter()
+            .map(|(_, i)| PublicKeyHistory {
+                public_key: i.get().clone(),
+                added_at: i.added_at.clone(),
+                revoked_at: i.revoked_at.clone(),
+            })
+            .collect();
+        public_key_history.sort_by(|a, b| {
+            OperationMetadata::compare_time_asc(&a.added_at, &b.added_at)
+                .then_with(|| a.public_key.id.as_str().cmp(b.public_key.id.as_str()))
+        });
         let public_keys: Vec<PublicKey> = self
             .public_keys
             .into_iter()
@@ -334,6 +347,7 @@ impl DidStateRc {
             context,
             last_operation_hash,
             public_keys,
+            public_key_history,
             services,
             storage,
             created_at,
@@ -0,0 +1,220 @@
+//! Self-contained proofs of the state of a PRISM DID.
+//!
+//! A resolution proof bundles every signed operation of a DID with the Cardano transaction publishing it
+//! and the PRISM object carried in the metadata of that transaction, exactly as it was published. It can be
+//! archived as evidence of what a DID looked like at some point in time, and later verified offline by
+//! replaying the operations with the protocol rules.
+//!
+//! Verification checks the operations against the PRISM objects in the proof, not against the ledger.
+//! To avoid trusting the node that produced the proof, a verifier also compares each PRISM object with
+//! the metadata of its transaction on chain, which any Cardano explorer or node can provide.
+//!
+//! A node only has the PRISM objects of the transactions it synced after it started storing them. The
+//! PRISM object of an older transaction is left out of the proof, and the proof cannot be verified until
+//! the node syncs that transaction again.
+//!
+//! A proof shows that the operations it contains resolve to a DID state. It cannot show that no operation
+//! was left out, so it is at most as recent as the index of the node that produced it.
+
+use chrono::{DateTime, Utc};
+use identus_apollo::hex::HexStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::did::operation::SignedPrismOperationHexStr;
+use crate::did::{CanonicalPrismDid, DidState, PrismDid};
+use crate::dlt::{BlockMetadata, BlockNo, OperationMetadata, SlotNo, TxId};
+use crate::proto::MessageExt;
+use crate::proto::prism::{PrismObject, SignedPrismOperation};
+use crate::protocol::resolver;
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum Error {
+    #[display("prism object of transaction {tx_id} is not available")]
+    PrismObjectUnavailable {
+        #[error(not(source))]
+        tx_id: TxId,
+    },
+    #[display("prism object of transaction {tx_id} cannot be decoded")]
+    InvalidPrismObject { source: protobuf::Error, tx_id: TxId },
+    #[display("operation {osn} is not published in the prism object of transaction {tx_id}")]
+    OperationNotPublished { tx_id: TxId, osn: u32 },
+    #[display("operation {osn} differs from the one published in the prism object of transaction {tx_id}")]
+    OperationMismatch { tx_id: TxId, osn: u32 },
+    #[display("operations do not create DID {did}")]
+    DidNotCreated {
+        #[error(not(source))]
+        did: CanonicalPrismDid,
+    },
+    #[display("operations resolve to DID {resolved_did} instead of {did}")]
+    DidMismatch {
+        did: CanonicalPrismDid,
+        resolved_did: CanonicalPrismDid,
+    },
+}
+
+/// Evidence of the state of a PRISM DID that can be verified offline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ResolutionProof {
+    /// The DID the proof is about, in canonical form.
+    #[serde(serialize_with = "serialize_did", deserialize_with = "deserialize_did")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, example = "did:prism:0000000000000000000000000000000000000000000000000000000000000000"))]
+    pub did: CanonicalPrismDid,
+    /// The transactions publishing operations of the DID, in ledger order.
+    pub transactions: Vec<TransactionProof>,
+}
+
+/// A Cardano transaction publishing operations of a DID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TransactionProof {
+    pub tx_id: TxId,
+    pub slot_number: SlotNo,
+    pub block_number: BlockNo,
+    pub block_timestamp: DateTime<Utc>,
+    /// PrismBlock sequence number of the transaction within the Cardano block.
+    pub absn: u32,
+    /// The PrismObject protobuf message exactly as carried in the transaction metadata, hex-encoded.
+    /// Absent when the node producing the proof has not stored the PrismObject of the transaction.
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    pub prism_object: Option<HexStr>,
+    /// The operations of the DID published in the transaction.
+    pub operations: Vec<OperationProof>,
+}
+
+/// A signed operation together with its position in the PrismObject of its transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct OperationProof {
+    /// Operation sequence number, the index of the operation in the PrismBlock.
+    pub osn: u32,
+    pub signed_operation_data: SignedPrismOperationHexStr,
+}
+
+impl ResolutionProof {
+    /// Build a proof from the operations of a DID and the PRISM objects of the transactions publishing them.
+    ///
+    /// Operations of the same transaction are grouped together, and `prism_object_bytes` is called once per
+    /// transaction. It must return the PrismObject exactly as published in the transaction metadata,
+    /// or `None` if it is not available.
+    pub fn new<F>(
+        did: CanonicalPrismDid,
+        mut operations: Vec<(OperationMetadata, SignedPrismOperation)>,
+        mut prism_object_bytes: F,
+    ) -> Self
+    where
+        F: FnMut(&BlockMetadata) -> Option<Vec<u8>>,
+    {
+        operations.sort_by(|a, b| OperationMetadata::compare_time_asc(&a.0, &b.0));
+        let mut transactions: Vec<TransactionProof> = vec![];
+        for (metadata, signed_operation) in operations {
+            let block_metadata = metadata.block_metadata;
+            let operation = OperationProof {
+                osn: metadata.osn,
+                signed_operation_data: signed_operation.into(),
+            };
+            match transactions.last_mut() {
+                Some(tx) if tx.tx_id == block_metadata.tx_id => tx.operations.push(operation),
+                _ => transactions.push(TransactionProof {
+                    prism_object: prism_object_bytes(&block_metadata).map(HexStr::from),
+                    tx_id: block_metadata.tx_id,
+                    slot_number: block_metadata.slot_number,
+                    block_number: block_metadata.block_number,
+                    block_timestamp: block_metadata.cbt,
+                    absn: block_metadata.absn,
+                    operations: vec![operation],
+                }),
+            }
+        }
+        Self { did, transactions }
+    }
+
+    /// Check that every operation is published in the PRISM object of its transaction
+    /// and re-derive the DID state from the operations.
+    pub fn verify(&self) -> Result<DidState, Error> {
+        let mut operations = vec![];
+        for tx in &self.transactions {
+            operations.extend(tx.verify()?);
+        }
+        let Some(state) = resolver::resolve_published(operations).0 else {
+            Err(Error::DidNotCreated { did: self.did.clone() })?
+        };
+        if state.did != self.did {
+            Err(Error::DidMismatch {
+                did: self.did.clone(),
+                resolved_did: state.did.clone(),
+            })?
+        }
+        Ok(state)
+    }
+}
+
+impl TransactionProof {
+    fn verify(&self) -> Result<Vec<(OperationMetadata, SignedPrismOperation)>, Error> {
+        let Some(prism_object_bytes) = &self.prism_object else {
+            Err(Error::PrismObjectUnavailable {
+                tx_id: self.tx_id.clone(),
+            })?
+        };
+        let prism_object =
+            PrismObject::decode(&prism_object_bytes.to_bytes()).map_err(|e| Error::InvalidPrismObject {
+                source: e,
+                tx_id: self.tx_id.clone(),
+            })?;
+        let published_operations = prism_object
+            .block_content
+            .into_option()
+            .map(|block| block.operations)
+            .unwrap_or_default();
+
+        let block_metadata = BlockMetadata {
+            slot_number: self.slot_number,
+            block_number: self.block_number,
+            cbt: self.block_timestamp,
+            tx_id: self.tx_id.clone(),
+            absn: self.absn,
+        };
+        self.operations
+            .iter()
+            .map(|operation| {
+                let signed_operation: SignedPrismOperation = operation.signed_operation_data.clone().into();
+                let Some(published_operation) = published_operations.get(operation.osn as usize) else {
+                    Err(Error::OperationNotPublished {
+                        tx_id: self.tx_id.clone(),
+                        osn: operation.osn,
+                    })?
+                };
+                if *published_operation != signed_operation {
+                    Err(Error::OperationMismatch {
+                        tx_id: self.tx_id.clone(),
+                        osn: operation.osn,
+                    })?
+                }
+                let metadata = OperationMetadata {
+                    block_metadata: block_metadata.clone(),
+                    osn: operation.osn,
+                };
+                Ok((metadata, signed_operation))
+            })
+            .collect()
+    }
+}
+
+fn serialize_did<S>(did: &CanonicalPrismDid, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&did.to_string())
+}
+
+fn deserialize_did<'de, D>(deserializer: D) -> Result<CanonicalPrismDid, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let did = String::deserialize(deserializer)?;
+    match did.parse::<PrismDid>() {
+        Ok(PrismDid::Canonical(did)) => Ok(did),
+        Ok(PrismDid::LongForm(_)) => Err(serde::de::Error::custom("value is not a canonical PRISM DID")),
+        Err(e) => Err(serde::de::Error::custom(format!("value is not a valid PRISM DID: {e}"))),
+    }
+}
@@ -2,11 +2,14 @@ use std::rc::Rc;
 use std::str::FromStr;
 
 use chrono::{DateTime, TimeZone, Utc};
+use identus_apollo::crypto::ed25519::Ed25519PublicKey;
 use identus_apollo::crypto::secp256k1::Secp256k1PrivateKey;
+use identus_apollo::crypto::x25519::X25519PublicKey;
 use identus_apollo::hash::Sha256Digest;
 use identus_apollo::hex::HexStr;
 use identus_did_core::{
-    Did, ServiceEndpoint as CoreServiceEndpoint, ServiceType as CoreServiceType, StringOrMap, VerificationMethodOrRef,
+    Did, ServiceEndpoint as CoreServiceEndpoint, ServiceType as CoreServiceType, StringOrMap,
+    VerificationMethodEncoding, VerificationMethodOrRef,
 };
 use identus_did_prism::did::operation::{
     KeyUsage, NonOperationPublicKey, PublicKey, PublicKeyData, PublicKeyId, Service, ServiceEndpoint,
@@ -133,6 +136,50 @@ fn make_revocation_key(id: &str) -> PublicKey {
     }
 }
 
+fn make_curve_key_proto(
+    id: &str,
+    usage: proto::prism_ssi::KeyUsage,
+    curve: &str,
+    data: &[u8],
+) -> proto::prism_ssi::PublicKey {
+    proto::prism_ssi::PublicKey {
+        id: id.to_string(),
+        usage: usage.into(),
+        key_data: Some(proto::prism_ssi::public_key::Key_data::CompressedEcKeyData(
+            proto::prism_ssi::CompressedECKeyData {
+                curve: curve.to_string(),
+                data: data.to_vec(),
+                special_fields: Default::default(),
+            },
+        )),
+        special_fields: Default::default(),
+    }
+}
+
+fn make_ed25519_auth_key(id: &str) -> PublicKey {
+    let pk_bytes = [42u8; 32];
+    PublicKey {
+        id: PublicKeyId::parse(id, 50).unwrap(),
+        data: PublicKeyData::Other {
+            data: NonOperationPublicKey::Ed25519(Ed25519PublicKey::from_slice(&pk_bytes).unwrap()),
+            usage: KeyUsage::AuthenticationKey,
+        },
+        orig: make_curve_key_proto(id, proto::prism_ssi::KeyUsage::AUTHENTICATION_KEY, "Ed25519", &pk_bytes),
+    }
+}
+
+fn make_x25519_key_agreement_key(id: &str) -> PublicKey {
+    let pk_bytes = [99u8; 32];
+    PublicKey {
+        id: PublicKeyId::parse(id, 50).unwrap(),
+        data: PublicKeyData::Other {
+            data: NonOperationPublicKey::X25519(X25519PublicKey::from_slice(&pk_bytes).unwrap()),
+            usage: KeyUsage::KeyAgreementKey,
+        },
+        orig: make_curve_key_proto(id, proto::prism_ssi::KeyUsage::KEY_AGREEMENT_KEY, "X25519", &pk_bytes),
+    }
+}
+
 fn make_uri_service(id: &str, type_name: &str, uri: &str) -> Service {
     let orig = proto::prism_ssi::Service {
         id: id.to_string(),
@@ -193,6 +240,7 @@ fn base_state() -> DidState {
         context: vec![],
         last_operation_hash: make_operation_hash(),
         public_keys: vec![],
+        public_key_history: vec![],
         services: vec![],
         storage: vec![],
         created_at: make_timestamp(),
@@ -471,6 +519,143 @@ fn to_did_document_also_known_as_is_none() {
     assert!(doc.also_known_as.is_none());
 }
 
+// ---------- verification method encoding tests ----------
+
+#[test]
+fn to_did_document_jwk_encoding_adds_no_context() {
+    let mut state = base_state();
+    state.public_keys = vec![make_auth_key("auth-0"), make_ed25519_auth_key("auth-1")];
+
+    let doc = state.to_did_document_with_encoding(&make_did(), VerificationMethodEncoding::Jwk);
+    assert_eq!(doc.context, vec!["https://www.w3.org/ns/did/v1"]);
+    assert!(doc.verification_method.iter().all(|vm| vm.r#type == "JsonWebKey2020"));
+    assert!(
+        doc.verification_method
+            .iter()
+            .all(|vm| vm.public_key_multibase.is_none())
+    );
+}
+
+#[test]
+fn to_did_document_multikey_encoding() {
+    let mut state = base_state();
+    state.public_keys = vec![
+        make_auth_key("auth-0"),
+        make_ed25519_auth_key("auth-1"),
+        make_x25519_key_agreement_key("ka-0"),
+    ];
+
+    let did = make_did();
+    let doc = state.to_did_document_with_encoding(&did, VerificationMethodEncoding::Multikey);
+    assert_eq!(
+        doc.context,
+        vec!["https://www.w3.org/ns/did/v1", "https://w3id.org/security/multikey/v1"]
+    );
+    let prefixes = ["zQ3s", "z6Mk", "z6LS"];
+    assert_eq!(doc.verification_method.len(), prefixes.len());
+    for (vm, prefix) in doc.verification_method.iter().zip(prefixes) {
+        assert_eq!(vm.r#type, "Multikey");
+        assert_eq!(vm.controller, did.to_string());
+        assert!(vm.public_key_jwk.is_none());
+        let multibase = vm.public_key_multibase.as_ref().unwrap();
+        assert!(multibase.starts_with(prefix), "{multibase} should start with {prefix}");
+    }
+}
+
+#[test]
+fn to_did_document_key_type_encoding() {
+    let mut state = base_state();
+    state.public_keys = vec![
+        make_auth_key("auth-0"),
+        make_ed25519_auth_key("auth-1"),
+        make_x25519_key_agreement_key("ka-0"),
+    ];
+
+    let doc = state.to_did_document_with_encoding(&make_did(), VerificationMethodEncoding::KeyType);
+    assert_eq!(
+        doc.context,
+        vec![
+            "https://www.w3.org/ns/did/v1",
+            "https://w3id.org/security/suites/secp256k1-2019/v1",
+            "https://w3id.org/security/suites/ed25519-2020/v1",
+            "https://w3id.org/security/suites/x25519-2020/v1",
+        ]
+    );
+
+    let secp256k1 = &doc.verification_method[0];
+    assert_eq!(secp256k1.r#type, "EcdsaSecp256k1VerificationKey2019");
+    assert!(secp256k1.public_key_jwk.is_some());
+    assert!(secp256k1.public_key_multibase.is_none());
+
+    let ed25519 = &doc.verification_method[1];
+    assert_eq!(ed25519.r#type, "Ed25519VerificationKey2020");
+    assert!(ed25519.public_key_jwk.is_none());
+    assert!(ed25519.public_key_multibase.as_ref().unwrap().starts_with("z6Mk"));
+
+    let x25519 = &doc.verification_method[2];
+    assert_eq!(x25519.r#type, "X25519KeyAgreementKey2020");
+    assert!(x25519.public_key_multibase.as_ref().unwrap().starts_with("z6LS"));
+}
+
+#[test]
+fn to_did_document_encoding_context_not_duplicated() {
+    let mut state = base_state();
+    state.context = vec!["https://w3id.org/security/multikey/v1".to_string()];
+    state.public_keys = vec![make_auth_key("auth-0"), make_issuing_key("issue-0")];
+
+    let doc = state.to_did_document_with_encoding(&make_did(), VerificationMethodEncoding::Multikey);
+    assert_eq!(
+        doc.context,
+        vec!["https://www.w3.org/ns/did/v1", "https://w3id.org/security/multikey/v1"]
+    );
+}
+
+#[test]
+fn to_did_document_encoding_no_keys_adds_no_context() {
+    let state = base_state();
+    let doc = state.to_did_document_with_encoding(&make_did(), VerificationMethodEncoding::Multikey);
+    assert_eq!(doc.context, vec!["https://www.w3.org/ns/did/v1"]);
+}
+
+#[test]
+fn to_resolution_result_with_encoding_applies_to_document() {
+    let mut state = base_state();
+    state.public_keys = vec![make_auth_key("auth-0")];
+    let did = PrismDid::Canonical(make_canonical_did());
+
+    let result = state.to_resolution_result_with_encoding(&did, VerificationMethodEncoding::Multikey);
+    let doc = result.did_document.unwrap();
+    assert_eq!(doc.verification_method[0].r#type, "Multikey");
+}
+
+// ---------- to_operation_keys tests ----------
+
+#[test]
+fn to_operation_keys_lists_master_and_vdr_keys() {
+    let mut state = base_state();
+    state.public_keys = vec![
+        make_master_key("master-0"),
+        make_auth_key("auth-0"),
+        make_vdr_key("vdr-0"),
+    ];
+    let did = make_did();
+
+    let keys = state.to_operation_keys(&did);
+    assert_eq!(keys.len(), 2);
+    assert_eq!(keys[0].id, format!("{did}#master-0"));
+    assert_eq!(keys[0].purpose, "master");
+    assert_eq!(keys[0].public_key_jwk.crv, "secp256k1");
+    assert_eq!(keys[1].id, format!("{did}#vdr-0"));
+    assert_eq!(keys[1].purpose, "vdr");
+}
+
+#[test]
+fn to_operation_keys_empty_without_operation_keys() {
+    let mut state = base_state();
+    state.public_keys = vec![make_auth_key("auth-0")];
+    assert!(state.to_operation_keys(&make_did()).is_empty());
+}
+
 // ---------- to_resolution_result tests ----------
 
 #[test]
@@ -637,6 +822,7 @@ fn storage_state_with_bytes_converts_to_did_data() {
         context: vec![],
         last_operation_hash: make_operation_hash(),
         public_keys: vec![],
+        public_key_history: vec![],
         services: vec![],
         storage: vec![storage],
         created_at: make_timestamp(),
@@ -0,0 +1,273 @@
+use chrono::{DateTime, TimeZone, Utc};
+use identus_apollo::crypto::EncodeVec;
+use identus_apollo::crypto::ed25519::Ed25519PrivateKey;
+use identus_apollo::crypto::secp256k1::Secp256k1PrivateKey;
+use identus_apollo::jws::{CompactJws, JwsHeader, JwsSigner};
+use identus_did_prism::did::jws::{self, Error};
+use identus_did_prism::did::operation::KeyUsage;
+use identus_did_prism::did::{CanonicalPrismDid, DidState, PrismDidOps};
+use identus_did_prism::dlt::{BlockMetadata, OperationMetadata, TxId};
+use identus_did_prism::proto;
+use identus_did_prism::proto::prism::prism_operation::Operation;
+use identus_did_prism::protocol::resolver;
+
+mod test_utils;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+const ISSUING_KEY: [u8; 32] = [2; 32];
+const ED25519_ISSUING_KEY: [u8; 32] = [3; 32];
+
+fn time(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, day, 0, 0, 0).unwrap()
+}
+
+fn metadata(block_number: u64, cbt: DateTime<Utc>) -> OperationMetadata {
+    OperationMetadata {
+        block_metadata: BlockMetadata {
+            slot_number: block_number.into(),
+            block_number: block_number.into(),
+            cbt,
+            absn: 0,
+            tx_id: TxId::from(identus_apollo::hash::sha256(block_number.to_be_bytes())),
+        },
+        osn: 0,
+    }
+}
+
+fn ed25519_public_key(id: &str, sk: &Ed25519PrivateKey) -> proto::prism_ssi::PublicKey {
+    proto::prism_ssi::PublicKey {
+        id: id.to_string(),
+        usage: proto::prism_ssi::KeyUsage::ISSUING_KEY.into(),
+        key_data: Some(proto::prism_ssi::public_key::Key_data::CompressedEcKeyData(
+            proto::prism_ssi::CompressedECKeyData {
+                curve: "Ed25519".to_string(),
+                data: sk.to_public_key().encode_vec().into(),
+                special_fields: Default::default(),
+            },
+        )),
+        special_fields: Default::default(),
+    }
+}
+
+/// A DID created on day 10 with the issuing keys `iss-0` and `iss-1`, where `iss-0` is revoked on day 20.
+fn did_state_with_revoked_key() -> DidState {
+    let issuing_sk = Secp256k1PrivateKey::from_slice(&ISSUING_KEY).unwrap();
+    let ed25519_sk = Ed25519PrivateKey::from_slice(&ED25519_ISSUING_KEY).unwrap();
+    let (create_did_op, create_did_op_hash, master_sk) =
+        test_utils::new_create_did_operation(Some(test_utils::CreateDidOptions {
+            public_keys: Some(vec![
+                test_utils::new_public_key("iss-0", proto::prism_ssi::KeyUsage::ISSUING_KEY, &issuing_sk),
+                ed25519_public_key("iss-1", &ed25519_sk),
+            ]),
+            ..Default::default()
+        }));
+    let did = CanonicalPrismDid::from_operation(create_did_op.operation.as_ref().unwrap()).unwrap();
+    let (revoke_op, _) = test_utils::new_signed_operation(
+        "master-0",
+        &master_sk,
+        Operation::UpdateDid(proto::prism_ssi::ProtoUpdateDID {
+            previous_operation_hash: create_did_op_hash.to_vec(),
+            id: did.suffix_hex().to_string(),
+            actions: vec![proto::prism_ssi::UpdateDIDAction {
+                action: Some(proto::prism_ssi::update_didaction::Action::RemoveKey(
+                    proto::prism_ssi::RemoveKeyAction {
+                        keyId: "iss-0".to_string(),
+                        special_fields: Default::default(),
+                    },
+                )),
+                special_fields: Default::default(),
+            }],
+            special_fields: Default::default(),
+        }),
+    );
+
+    let operations = vec![
+        (metadata(1, time(10)), create_did_op),
+        (metadata(2, time(20)), revoke_op),
+    ];
+    resolver::resolve_published(operations).0.unwrap()
+}
+
+fn sign(state: &DidState, key_id: &str, payload: serde_json::Value, signer: &impl JwsSigner) -> CompactJws {
+    let header = JwsHeader {
+        kid: Some(format!("{}#{key_id}", state.did)),
+        typ: Some("JWT".to_string()),
+        ..Default::default()
+    };
+    CompactJws::sign(header, payload.to_string().as_bytes(), signer).unwrap()
+}
+
+fn issued_on(day: u32) -> serde_json::Value {
+    serde_json::json!({ "iat": time(day).timestamp() })
+}
+
+fn issuing_sk() -> Secp256k1PrivateKey {
+    Secp256k1PrivateKey::from_slice(&ISSUING_KEY).unwrap()
+}
+
+// ---------------------------------------------------------------------------
+// Key history
+// ---------------------------------------------------------------------------
+
+#[test]
+fn did_state_keeps_revoked_key_history() {
+    let state = did_state_with_revoked_key();
+    assert_eq!(state.public_keys.len(), 2);
+
+    let ids: Vec<_> = state
+        .public_key_history
+        .iter()
+        .map(|i| i.public_key.id.as_str())
+        .collect();
+    assert_eq!(ids, ["iss-0", "iss-1", "master-0"]);
+
+    let revoked = &state.public_key_history[0];
+    assert_eq!(revoked.added_at.block_metadata.cbt, time(10));
+    assert_eq!(revoked.revoked_at.as_ref().unwrap().block_metadata.cbt, time(20));
+    assert!(!revoked.is_active_at(&time(5)));
+    assert!(revoked.is_active_at(&time(10)));
+    assert!(revoked.is_active_at(&time(15)));
+    assert!(!revoked.is_active_at(&time(20)));
+}
+
+// ---------------------------------------------------------------------------
+// Verification
+// ---------------------------------------------------------------------------
+
+#[test]
+fn verify_jws_issued_while_key_active() {
+    let state = did_state_with_revoked_key();
+    let jws = sign(&state, "iss-0", issued_on(15), &issuing_sk());
+    let verified = jws::verify_jws(&jws, &state, KeyUsage::IssuingKey).unwrap();
+    assert_eq!(verified.kid.key_id.as_str(), "iss-0");
+    assert_eq!(verified.issuance_time, time(15));
+    assert!(verified.public_key.revoked_at.is_some());
+}
+
+#[test]
+fn verify_jws_with_ed25519_key() {
+    let state = did_state_with_revoked_key();
+    let sk = Ed25519PrivateKey::from_slice(&ED25519_ISSUING_KEY).unwrap();
+    let jws = sign(&state, "iss-1", issued_on(25), &sk);
+    assert!(jws::verify_jws(&jws, &state, KeyUsage::IssuingKey).is_ok());
+}
+
+#[test]
+fn verify_jws_falls_back_to_nbf() {
+    let state = did_state_with_revoked_key();
+    let payload = serde_json::json!({ "nbf": time(12).timestamp() });
+    let jws = sign(&state, "iss-0", payload, &issuing_sk());
+    let verified = jws::verify_jws(&jws, &state, KeyUsage::IssuingKey).unwrap();
+    assert_eq!(verified.issuance_time, time(12));
+}
+
+#[test]
+fn verify_jws_rejects_key_not_active() {
+    let state = did_state_with_revoked_key();
+    for day in [5, 25] {
+        let jws = sign(&state, "iss-0", issued_on(day), &issuing_sk());
+        let result = jws::verify_jws(&jws, &state, KeyUsage::IssuingKey);
+        assert!(matches!(result, Err(Error::KeyNotActive { .. })), "issued on day {day}");
+    }
+}
+
+#[test]
+fn verify_jws_rejects_unexpected_key_usage() {
+    let state = did_state_with_revoked_key();
+    let master_sk = Secp256k1PrivateKey::from_slice(&[1; 32]).unwrap();
+    let jws = sign(&state, "master-0", issued_on(15), &master_sk);
+    let result = jws::verify_jws(&jws, &state, KeyUsage::IssuingKey);
+    assert!(matches!(
+        result,
+        Err(Error::UnexpectedKeyUsage {
+            usage: KeyUsage::MasterKey,
+            expected: KeyUsage::IssuingKey,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn verify_jws_rejects_invalid_signature() {
+    let state = did_state_with_revoked_key();
+    let other_sk = Secp256k1PrivateKey::from_slice(&[9; 32]).unwrap();
+    let jws = sign(&state, "iss-0", issued_on(15), &other_sk);
+    let result = jws::verify_jws(&jws, &state, KeyUsage::IssuingKey);
+    assert!(matches!(result, Err(Error::InvalidSignature { .. })));
+}
+
+#[test]
+fn verify_jws_rejects_unknown_key() {
+    let state = did_state_with_revoked_key();
+    let jws = sign(&state, "iss-9", issued_on(15), &issuing_sk());
+    let result = jws::verify_jws(&jws, &state, KeyUsage::IssuingKey);
+    assert!(matches!(result, Err(Error::KeyNotFound { .. })));
+}
+
+#[test]
+fn verify_jws_rejects_other_did() {
+    let state = did_state_with_revoked_key();
+    let header = JwsHeader {
+        kid: Some(format!("did:prism:{}#iss-0", "0".repeat(64))),
+        ..Default::default()
+    };
+    let jws = CompactJws::sign(header, issued_on(15).to_string().as_bytes(), &issuing_sk()).unwrap();
+    let result = jws::verify_jws(&jws, &state, KeyUsage::IssuingKey);
+    assert!(matches!(result, Err(Error::DidMismatch { .. })));
+}
+
+// ---------------------------------------------------------------------------
+// Kid and claims
+// ---------------------------------------------------------------------------
+
+#[test]
+fn kid_must_be_prism_did_url() {
+    let sk = issuing_sk();
+    let jws = CompactJws::sign(JwsHeader::default(), b"{}", &sk).unwrap();
+    assert!(matches!(jws::JwsKid::from_jws(&jws), Err(Error::MissingKid)));
+
+    let kid_error = |kid: String| {
+        let header = JwsHeader {
+            kid: Some(kid),
+            ..Default::default()
+        };
+        let jws = CompactJws::sign(header, b"{}", &sk).unwrap();
+        jws::JwsKid::from_jws(&jws).unwrap_err()
+    };
+    assert!(matches!(
+        kid_error("did:prism:abc".to_string()),
+        Error::InvalidKid { .. }
+    ));
+    assert!(matches!(
+        kid_error("did:example:123#key-1".to_string()),
+        Error::InvalidKidDid { .. }
+    ));
+    assert!(matches!(
+        kid_error(format!("did:prism:{}#", "0".repeat(64))),
+        Error::InvalidKidKeyId { .. }
+    ));
+}
+
+#[test]
+fn issuance_time_requires_numeric_date() {
+    let sk = issuing_sk();
+    let sign_payload = |payload: &[u8]| CompactJws::sign(JwsHeader::default(), payload, &sk).unwrap();
+
+    let jws = sign_payload(br#"{"iat":1736899200.5}"#);
+    assert_eq!(
+        jws::issuance_time(&jws).unwrap(),
+        time(15) + chrono::Duration::milliseconds(500)
+    );
+
+    let result = jws::issuance_time(&sign_payload(br#"{"iss":"did:prism:test"}"#));
+    assert!(matches!(result, Err(Error::MissingIssuanceTime)));
+
+    let result = jws::issuance_time(&sign_payload(br#"{"iat":"yesterday"}"#));
+    assert!(matches!(result, Err(Error::InvalidIssuanceTime { claim: "iat" })));
+
+    let result = jws::issuance_time(&sign_payload(b"not json"));
+    assert!(matches!(result, Err(Error::InvalidPayload { .. })));
+}
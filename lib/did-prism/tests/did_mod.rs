@@ -344,6 +344,7 @@ fn did_state_is_deactivated_true_when_no_public_keys() {
         context: vec![],
         last_operation_hash: Rc::new(zeros_hash()),
         public_keys: vec![],
+        public_key_history: vec![],
         services: vec![],
         storage: vec![],
         created_at: Utc::now(),
@@ -372,6 +373,7 @@ fn did_state_into_did_data_conversion() {
         context: vec!["https://www.w3.org/ns/did/v1".to_string()],
         last_operation_hash: Rc::new(zeros_hash()),
         public_keys: vec![],
+        public_key_history: vec![],
         services: vec![],
         storage: vec![],
         created_at: Utc::now(),
@@ -392,6 +394,7 @@ fn did_state_into_did_data_strips_did_prefix() {
         context: vec![],
         last_operation_hash: Rc::new(zeros_hash()),
         public_keys: vec![],
+        public_key_history: vec![],
         services: vec![],
         storage: vec![],
         created_at: Utc::now(),
@@ -542,6 +545,7 @@ fn did_state_into_did_data_filters_non_bytes_storage() {
         context: vec![],
         last_operation_hash: Rc::new(zeros_hash()),
         public_keys: vec![],
+        public_key_history: vec![],
         services: vec![],
         storage: vec![bytes_storage, ipfs_storage],
         created_at: Utc::now(),
@@ -6,7 +6,8 @@ use identus_apollo::hash::sha256;
 use identus_did_prism::dlt::{
     BlockMetadata, BlockNo, DltCursor, NetworkIdentifier, OperationMetadata, PublishedPrismObject, SlotNo, TxId,
 };
-use identus_did_prism::proto::prism::PrismObject;
+use identus_did_prism::proto::MessageExt;
+use identus_did_prism::proto::prism::{PrismBlock, PrismObject};
 
 // ── DltCursor ──────────────────────────────────────────────────────────────
 
@@ -193,16 +194,32 @@ fn published_prism_object_construction() {
     let published = PublishedPrismObject {
         block_metadata: bm.clone(),
         prism_object: prism_object.clone(),
+        prism_object_bytes: vec![],
     };
     assert_eq!(published.block_metadata, bm);
     assert_eq!(published.prism_object, prism_object);
 }
 
+#[test]
+fn published_prism_object_decode_keeps_bytes() {
+    let prism_object = PrismObject {
+        block_content: Some(PrismBlock::default()).into(),
+        ..Default::default()
+    };
+    let bytes = prism_object.encode_to_vec();
+    let published = PublishedPrismObject::decode(sample_block_metadata(10, 0), bytes.clone()).unwrap();
+    assert_eq!(published.prism_object, prism_object);
+    assert_eq!(published.prism_object_bytes, bytes);
+
+    assert!(PublishedPrismObject::decode(sample_block_metadata(10, 0), vec![0xff, 0xff]).is_err());
+}
+
 #[test]
 fn published_prism_object_clone() {
     let published = PublishedPrismObject {
         block_metadata: sample_block_metadata(1, 0),
         prism_object: PrismObject::default(),
+        prism_object_bytes: vec![],
     };
     let cloned = published.clone();
     assert_eq!(published.block_metadata, cloned.block_metadata);
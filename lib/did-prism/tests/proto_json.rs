@@ -0,0 +1,155 @@
+//! Tests for `lib/did-prism/src/proto/json.rs`
+//!
+//! Covers: `ProtoJson` rendering of `PrismObject`, `SignedPrismOperation` and `PrismOperation` variants
+
+use identus_apollo::hex::HexStr;
+use identus_did_prism::proto;
+use identus_did_prism::proto::json::ProtoJson;
+use identus_did_prism::proto::prism::prism_operation::Operation;
+
+mod test_utils;
+
+#[test]
+fn signed_create_did_operation_renders_hashes_and_jwk() {
+    let (signed_op, operation_hash, _) = test_utils::new_create_did_operation(None);
+
+    let json = signed_op.to_json();
+
+    assert_eq!(json["operation_id"], signed_op.operation_id().to_string());
+    assert_eq!(json["signed_with"], "master-0");
+    assert_eq!(json["signature"], HexStr::from(&signed_op.signature).to_string());
+    assert_eq!(
+        json["operation"]["operation_hash"],
+        HexStr::from(operation_hash.as_bytes()).to_string()
+    );
+
+    let public_key = &json["operation"]["create_did"]["did_data"]["public_keys"][0];
+    assert_eq!(public_key["id"], "master-0");
+    assert_eq!(public_key["usage"], "MASTER_KEY");
+    assert_eq!(public_key["key_data"]["compressed_ec_key_data"]["curve"], "secp256k1");
+    assert_eq!(public_key["jwk"]["kty"], "EC");
+    assert_eq!(public_key["jwk"]["crv"], "secp256k1");
+}
+
+#[test]
+fn public_key_with_unsupported_curve_has_no_jwk() {
+    let public_key = proto::prism_ssi::PublicKey {
+        id: "key-1".to_string(),
+        usage: proto::prism_ssi::KeyUsage::ISSUING_KEY.into(),
+        key_data: Some(proto::prism_ssi::public_key::Key_data::CompressedEcKeyData(
+            proto::prism_ssi::CompressedECKeyData {
+                curve: "P-256".to_string(),
+                data: vec![1, 2, 3],
+                special_fields: Default::default(),
+            },
+        )),
+        special_fields: Default::default(),
+    };
+
+    let json = public_key.to_json();
+
+    assert_eq!(json["usage"], "ISSUING_KEY");
+    assert_eq!(json["key_data"]["compressed_ec_key_data"]["data"], "010203");
+    assert!(json["jwk"].is_null());
+}
+
+#[test]
+fn update_did_operation_renders_actions() {
+    let (_, create_hash, master_sk) = test_utils::new_create_did_operation(None);
+    let (signed_op, _) = test_utils::new_signed_operation(
+        "master-0",
+        &master_sk,
+        Operation::UpdateDid(proto::prism_ssi::ProtoUpdateDID {
+            previous_operation_hash: create_hash.to_vec(),
+            id: "did-suffix".to_string(),
+            actions: vec![test_utils::add_service_action("service-1")],
+            special_fields: Default::default(),
+        }),
+    );
+
+    let json = signed_op.to_json();
+    let update_did = &json["operation"]["update_did"];
+
+    assert_eq!(
+        update_did["previous_operation_hash"],
+        HexStr::from(create_hash.as_bytes()).to_string()
+    );
+    assert_eq!(update_did["id"], "did-suffix");
+    assert_eq!(update_did["actions"][0]["add_service"]["service"]["id"], "service-1");
+    assert_eq!(
+        update_did["actions"][0]["add_service"]["service"]["type"],
+        "LinkedDomains"
+    );
+}
+
+#[test]
+fn storage_operations_render_data() {
+    let (_, _, master_sk) = test_utils::new_create_did_operation(None);
+    let (create_op, _) = test_utils::new_signed_operation(
+        "vdr-0",
+        &master_sk,
+        Operation::CreateStorageEntry(proto::prism_storage::ProtoCreateStorageEntry {
+            did_prism_hash: vec![0xab; 32],
+            nonce: vec![1],
+            data: Some(proto::prism_storage::proto_create_storage_entry::Data::Bytes(vec![
+                0xca, 0xfe,
+            ])),
+            special_fields: Default::default(),
+        }),
+    );
+    let (update_op, _) = test_utils::new_signed_operation(
+        "vdr-0",
+        &master_sk,
+        Operation::UpdateStorageEntry(proto::prism_storage::ProtoUpdateStorageEntry {
+            previous_event_hash: vec![0xcd; 32],
+            data: Some(proto::prism_storage::proto_update_storage_entry::Data::Ipfs(
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+            )),
+            special_fields: Default::default(),
+        }),
+    );
+
+    let create_json = create_op.to_json();
+    let create_entry = &create_json["operation"]["create_storage_entry"];
+    assert_eq!(create_entry["did_prism_hash"], "ab".repeat(32));
+    assert_eq!(create_entry["nonce"], "01");
+    assert_eq!(create_entry["data"]["bytes"], "cafe");
+
+    let update_json = update_op.to_json();
+    let update_entry = &update_json["operation"]["update_storage_entry"];
+    assert_eq!(update_entry["previous_event_hash"], "cd".repeat(32));
+    assert_eq!(
+        update_entry["data"]["ipfs"],
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+    );
+}
+
+#[test]
+fn prism_object_renders_block_operations() {
+    let (signed_op, _, _) = test_utils::new_create_did_operation(None);
+    let prism_object = proto::prism::PrismObject {
+        block_content: Some(proto::prism::PrismBlock {
+            operations: vec![signed_op.clone()],
+            special_fields: Default::default(),
+        })
+        .into(),
+        special_fields: Default::default(),
+    };
+
+    let json = prism_object.to_json();
+
+    let operations = json["block_content"]["operations"].as_array().unwrap();
+    assert_eq!(operations.len(), 1);
+    assert_eq!(operations[0], signed_op.to_json());
+}
+
+#[test]
+fn empty_messages_render_null_fields() {
+    let prism_object = proto::prism::PrismObject::default();
+    assert!(prism_object.to_json()["block_content"].is_null());
+
+    let operation = proto::prism::PrismOperation::default();
+    let json = operation.to_json();
+    assert!(json["operation_hash"].is_string());
+    assert_eq!(json.as_object().unwrap().len(), 1);
+}
@@ -0,0 +1,202 @@
+use chrono::{DateTime, TimeZone, Utc};
+use identus_apollo::hex::HexStr;
+use identus_did_prism::did::CanonicalPrismDid;
+use identus_did_prism::dlt::{BlockMetadata, OperationMetadata, TxId};
+use identus_did_prism::proto;
+use identus_did_prism::proto::MessageExt;
+use identus_did_prism::proto::prism::prism_operation::Operation;
+use identus_did_prism::protocol::proof::{Error, ResolutionProof};
+use identus_did_prism::protocol::resolver;
+
+mod test_utils;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn time(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, day, 0, 0, 0).unwrap()
+}
+
+fn metadata(block_number: u64, osn: u32) -> OperationMetadata {
+    OperationMetadata {
+        block_metadata: BlockMetadata {
+            slot_number: block_number.into(),
+            block_number: block_number.into(),
+            cbt: time(block_number as u32),
+            absn: 0,
+            tx_id: TxId::from(identus_apollo::hash::sha256(block_number.to_be_bytes())),
+        },
+        osn,
+    }
+}
+
+fn prism_object(operations: Vec<proto::prism::SignedPrismOperation>) -> proto::prism::PrismObject {
+    proto::prism::PrismObject {
+        block_content: Some(proto::prism::PrismBlock {
+            operations,
+            special_fields: Default::default(),
+        })
+        .into(),
+        special_fields: Default::default(),
+    }
+}
+
+/// A DID created in block 1, next to the creation of another DID, and updated in block 2.
+///
+/// Returns the DID, all operations published in each block and the operations of the DID.
+#[allow(clippy::type_complexity)]
+fn published_did() -> (
+    CanonicalPrismDid,
+    Vec<Vec<proto::prism::SignedPrismOperation>>,
+    Vec<(OperationMetadata, proto::prism::SignedPrismOperation)>,
+) {
+    let (other_create_op, _, _) = test_utils::new_create_did_operation(Some(test_utils::CreateDidOptions {
+        contexts: Some(vec!["https://www.w3.org/ns/did/v1".to_string()]),
+        ..Default::default()
+    }));
+    let (create_op, create_op_hash, master_sk) = test_utils::new_create_did_operation(None);
+    let did = CanonicalPrismDid::from_operation(create_op.operation.as_ref().unwrap()).unwrap();
+    let (update_op, _) = test_utils::new_signed_operation(
+        "master-0",
+        &master_sk,
+        Operation::UpdateDid(proto::prism_ssi::ProtoUpdateDID {
+            previous_operation_hash: create_op_hash.to_vec(),
+            id: did.suffix_hex().to_string(),
+            actions: vec![test_utils::add_service_action("service-0")],
+            special_fields: Default::default(),
+        }),
+    );
+
+    let blocks = vec![vec![other_create_op, create_op.clone()], vec![update_op.clone()]];
+    let operations = vec![(metadata(1, 1), create_op), (metadata(2, 0), update_op)];
+    (did, blocks, operations)
+}
+
+fn new_proof(
+    did: CanonicalPrismDid,
+    blocks: &[Vec<proto::prism::SignedPrismOperation>],
+    operations: Vec<(OperationMetadata, proto::prism::SignedPrismOperation)>,
+) -> ResolutionProof {
+    ResolutionProof::new(did, operations, |block_metadata| {
+        let block_number = block_metadata.block_number.inner() as usize;
+        Some(prism_object(blocks[block_number - 1].clone()).encode_to_vec())
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Verification
+// ---------------------------------------------------------------------------
+
+#[test]
+fn proof_verifies_to_resolved_did_state() {
+    let (did, blocks, operations) = published_did();
+    let expected = resolver::resolve_published(operations.clone()).0.unwrap();
+    let proof = new_proof(did.clone(), &blocks, operations);
+    assert_eq!(proof.transactions.len(), 2);
+    assert_eq!(proof.transactions[0].operations[0].osn, 1);
+
+    let state = proof.verify().unwrap();
+    assert_eq!(state.did, did);
+    assert_eq!(state.last_operation_hash, expected.last_operation_hash);
+    assert_eq!(state.services.len(), 1);
+    assert_eq!(state.created_at, time(1));
+    assert_eq!(state.updated_at, time(2));
+}
+
+#[test]
+fn proof_survives_json_roundtrip() {
+    let (did, blocks, operations) = published_did();
+    let proof = new_proof(did.clone(), &blocks, operations);
+
+    let json = serde_json::to_value(&proof).unwrap();
+    assert_eq!(json["did"], did.to_string());
+    assert_eq!(
+        json["transactions"][0]["prism_object"],
+        HexStr::from(prism_object(blocks[0].clone()).encode_to_vec()).to_string()
+    );
+
+    let proof: ResolutionProof = serde_json::from_value(json).unwrap();
+    assert_eq!(proof.verify().unwrap().did, did);
+}
+
+#[test]
+fn proof_keeps_published_prism_object_bytes() {
+    let (did, blocks, operations) = published_did();
+    // An unknown field (number 100) that a re-encoded PrismObject would not reproduce byte for byte
+    let mut published_bytes = prism_object(blocks[0].clone()).encode_to_vec();
+    published_bytes.extend([0xa0, 0x06, 0x01]);
+    let proof = ResolutionProof::new(did.clone(), operations, |block_metadata| {
+        if block_metadata.block_number.inner() == 1 {
+            Some(published_bytes.clone())
+        } else {
+            Some(prism_object(blocks[1].clone()).encode_to_vec())
+        }
+    });
+
+    assert_eq!(
+        proof.transactions[0].prism_object.as_ref().unwrap().to_bytes(),
+        published_bytes
+    );
+    assert_eq!(proof.verify().unwrap().did, did);
+}
+
+#[test]
+fn proof_rejects_operation_not_in_prism_object() {
+    let (did, blocks, operations) = published_did();
+    let mut proof = new_proof(did, &blocks, operations);
+    proof.transactions[1].operations[0].osn = 1;
+    assert!(matches!(
+        proof.verify(),
+        Err(Error::OperationNotPublished { osn: 1, .. })
+    ));
+}
+
+#[test]
+fn proof_rejects_operation_differing_from_prism_object() {
+    let (did, blocks, operations) = published_did();
+    let mut proof = new_proof(did, &blocks, operations);
+    proof.transactions[0].operations[0].osn = 0;
+    assert!(matches!(proof.verify(), Err(Error::OperationMismatch { osn: 0, .. })));
+}
+
+#[test]
+fn proof_rejects_invalid_prism_object() {
+    let (did, blocks, operations) = published_did();
+    let mut proof = new_proof(did, &blocks, operations);
+    proof.transactions[0].prism_object = Some(HexStr::from([0xff, 0xff]));
+    assert!(matches!(proof.verify(), Err(Error::InvalidPrismObject { .. })));
+}
+
+#[test]
+fn proof_rejects_unavailable_prism_object() {
+    let (did, blocks, operations) = published_did();
+    let proof = ResolutionProof::new(did, operations, |block_metadata| {
+        let block_number = block_metadata.block_number.inner() as usize;
+        (block_number == 2).then(|| prism_object(blocks[1].clone()).encode_to_vec())
+    });
+    assert!(proof.transactions[0].prism_object.is_none());
+    assert!(matches!(
+        proof.verify(),
+        Err(Error::PrismObjectUnavailable { tx_id }) if tx_id == proof.transactions[0].tx_id
+    ));
+
+    let json = serde_json::to_value(&proof).unwrap();
+    assert!(json["transactions"][0]["prism_object"].is_null());
+}
+
+#[test]
+fn proof_rejects_operations_of_another_did() {
+    let (did, blocks, _) = published_did();
+    let other_create_op = blocks[0][0].clone();
+    let proof = new_proof(did, &blocks, vec![(metadata(1, 0), other_create_op)]);
+    assert!(matches!(proof.verify(), Err(Error::DidMismatch { .. })));
+}
+
+#[test]
+fn proof_without_operations_does_not_create_did() {
+    let (did, blocks, _) = published_did();
+    let proof = new_proof(did, &blocks, vec![]);
+    assert!(proof.transactions.is_empty());
+    assert!(matches!(proof.verify(), Err(Error::DidNotCreated { .. })));
+}
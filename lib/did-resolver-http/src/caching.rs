@@ -0,0 +1,125 @@
+//! HTTP caching of DID resolution responses.
+//!
+//! Responses of successfully resolved DIDs carry an `ETag` derived from the `versionId`
+//! document metadata and a `Last-Modified` header derived from the `updated` metadata.
+//! Conditional requests using `If-None-Match` or `If-Modified-Since` are answered with
+//! `304 Not Modified` when the DID document has not changed.
+
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use identus_did_core::{Did, ResolutionResult};
+
+use crate::DidResolverStateDyn;
+
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+/// Decides the `Cache-Control` header of DID resolution responses.
+pub trait ResolutionCachePolicy {
+    /// Returns the `Cache-Control` header for the resolution result of `did`, or `None` to omit it.
+    fn cache_control(&self, did: &Did, result: &ResolutionResult) -> Option<HeaderValue>;
+}
+
+/// Caching headers of a single response.
+pub struct CacheHeaders {
+    etag: Option<String>,
+    last_modified: Option<DateTime<Utc>>,
+    cache_control: Option<HeaderValue>,
+}
+
+impl CacheHeaders {
+    pub(crate) fn new(state: &DidResolverStateDyn, did: &Did, result: &ResolutionResult) -> Self {
+        let cache_control = state
+            .cache_policy
+            .as_ref()
+            .and_then(|policy| policy.cache_control(did, result));
+        if result.did_resolution_metadata.error.is_some() {
+            return Self {
+                etag: None,
+                last_modified: None,
+                cache_control,
+            };
+        }
+
+        let metadata = &result.did_document_metadata;
+        // The update time is part of the tag so that a long-form DID gets a new tag once it is published,
+        // even though its version stays the same.
+        let etag = metadata.version_id.as_ref().map(|version_id| match metadata.updated {
+            Some(updated) => format!("W/\"{version_id}-{}\"", updated.timestamp()),
+            None => format!("W/\"{version_id}\""),
+        });
+        Self {
+            etag,
+            last_modified: metadata.updated,
+            cache_control,
+        }
+    }
+
+    /// Caching headers of a resource other than a DID resolution result, identified by the entity tag `etag`.
+    pub fn for_resource(
+        etag: String,
+        last_modified: Option<DateTime<Utc>>,
+        cache_control: Option<HeaderValue>,
+    ) -> Self {
+        Self {
+            etag: Some(etag),
+            last_modified,
+            cache_control,
+        }
+    }
+
+    /// Answer with `304 Not Modified` if the request preconditions match, otherwise build the full response.
+    pub fn respond(self, request_headers: &HeaderMap, response: impl FnOnce() -> Response) -> Response {
+        if self.is_not_modified(request_headers) {
+            self.apply(StatusCode::NOT_MODIFIED.into_response())
+        } else {
+            self.apply(response())
+        }
+    }
+
+    fn is_not_modified(&self, request_headers: &HeaderMap) -> bool {
+        // If-Modified-Since is ignored when If-None-Match is present (RFC 9110, section 13.1.3).
+        if let Some(if_none_match) = request_headers.get(header::IF_NONE_MATCH) {
+            let Some(etag) = self.etag.as_deref() else {
+                return false;
+            };
+            return if_none_match
+                .to_str()
+                .map(|i| i.split(',').any(|tag| etag_matches(tag.trim(), etag)))
+                .unwrap_or(false);
+        }
+
+        let if_modified_since = request_headers
+            .get(header::IF_MODIFIED_SINCE)
+            .and_then(|i| i.to_str().ok())
+            .and_then(|i| DateTime::parse_from_rfc2822(i).ok());
+        match (self.last_modified, if_modified_since) {
+            (Some(last_modified), Some(since)) => last_modified.timestamp() <= since.timestamp(),
+            _ => false,
+        }
+    }
+
+    fn apply(self, mut response: Response) -> Response {
+        let headers = response.headers_mut();
+        if let Some(etag) = self.etag.and_then(|i| HeaderValue::from_str(&i).ok()) {
+            headers.insert(header::ETAG, etag);
+        }
+        if let Some(last_modified) = self
+            .last_modified
+            .and_then(|i| HeaderValue::from_str(&i.format(HTTP_DATE_FORMAT).to_string()).ok())
+        {
+            headers.insert(header::LAST_MODIFIED, last_modified);
+        }
+        if let Some(cache_control) = self.cache_control {
+            headers.insert(header::CACHE_CONTROL, cache_control);
+        }
+        // The representation depends on the Accept header.
+        headers.insert(header::VARY, HeaderValue::from_static("accept"));
+        response
+    }
+}
+
+/// Weak comparison of entity tags (RFC 9110, section 8.8.3.2).
+fn etag_matches(candidate: &str, etag: &str) -> bool {
+    candidate == "*" || candidate.trim_start_matches("W/") == etag.trim_start_matches("W/")
+}
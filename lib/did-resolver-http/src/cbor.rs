@@ -0,0 +1,49 @@
+//! Deterministic CBOR encoding following the
+//! [core deterministic encoding requirements](https://www.rfc-editor.org/rfc/rfc8949#name-core-deterministic-encoding).
+
+use ciborium::Value;
+use serde::Serialize;
+
+#[derive(Debug, derive_more::Display, derive_more::Error, derive_more::From)]
+pub(crate) enum CborEncodingError {
+    #[display("unable to convert value to cbor")]
+    Value { source: ciborium::value::Error },
+    #[display("unable to write cbor")]
+    Write {
+        source: ciborium::ser::Error<std::io::Error>,
+    },
+}
+
+/// Encode a value as deterministic CBOR.
+///
+/// Integers and lengths take their shortest form, only definite lengths are used,
+/// and map entries are sorted by the bytewise lexicographic order of their encoded keys.
+pub(crate) fn to_deterministic_cbor(value: &impl Serialize) -> Result<Vec<u8>, CborEncodingError> {
+    let value = sort_map_keys(Value::serialized(value)?)?;
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&value, &mut bytes)?;
+    Ok(bytes)
+}
+
+fn sort_map_keys(value: Value) -> Result<Value, CborEncodingError> {
+    match value {
+        Value::Array(items) => Ok(Value::Array(
+            items.into_iter().map(sort_map_keys).collect::<Result<_, _>>()?,
+        )),
+        Value::Map(entries) => {
+            let mut entries = entries
+                .into_iter()
+                .map(|(k, v)| -> Result<_, CborEncodingError> {
+                    let k = sort_map_keys(k)?;
+                    let mut encoded_key = Vec::new();
+                    ciborium::into_writer(&k, &mut encoded_key)?;
+                    Ok((encoded_key, k, sort_map_keys(v)?))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Ok(Value::Map(entries.into_iter().map(|(_, k, v)| (k, v)).collect()))
+        }
+        Value::Tag(tag, inner) => Ok(Value::Tag(tag, Box::new(sort_map_keys(*inner)?))),
+        value => Ok(value),
+    }
+}
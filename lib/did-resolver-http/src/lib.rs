@@ -1,18 +1,44 @@
-use std::collections::HashSet;
 use std::marker::PhantomData;
 use std::str::FromStr;
 use std::sync::Arc;
 
-use axum::extract::{Path, State};
+use axum::extract::rejection::QueryRejection;
+use axum::extract::{Path, Query, State};
 use axum::http::{HeaderMap, StatusCode, header};
 use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use axum::{Json, Router};
-use identus_did_core::{Did, DidDocument, DidResolutionErrorCode, DidResolver, ResolutionResult};
+pub use caching::{CacheHeaders, ResolutionCachePolicy};
+use identus_did_core::{Did, DidDocument, DidResolutionErrorCode, DidResolver, ResolutionOptions, ResolutionResult};
+use negotiation::{
+    MEDIA_TYPE_DID, MEDIA_TYPE_DID_CBOR, MEDIA_TYPE_DID_LD_JSON, MEDIA_TYPE_DID_RESOLUTION, MEDIA_TYPE_JSON,
+};
+#[cfg(feature = "prism-proxy")]
+pub use proxy::{PrismProxyError, PrismProxyResolver};
+pub use universal_resolver::{
+    UNIVERSAL_RESOLVER_IDENTIFIERS_PATH, UNIVERSAL_RESOLVER_PROPERTIES_PATH, UniversalResolverDriverOptions,
+    universal_resolver_driver_binding,
+};
+
+mod caching;
+mod cbor;
+mod negotiation;
+#[cfg(feature = "prism-proxy")]
+mod proxy;
+mod universal_resolver;
 
 #[cfg(feature = "openapi")]
 const PLACEHOLDER_RESOLVER_PATH: &str = "/placeholder-did-resolver";
 
+/// Representations of the DID resolver endpoint, in order of preference.
+const DID_RESOLVER_MEDIA_TYPES: &[&str] = &[
+    MEDIA_TYPE_DID,
+    MEDIA_TYPE_DID_LD_JSON,
+    MEDIA_TYPE_DID_CBOR,
+    MEDIA_TYPE_DID_RESOLUTION,
+    MEDIA_TYPE_JSON,
+];
+
 pub struct DidResolverHttpBinding {
     pub router: Router<DidResolverStateDyn>,
     #[cfg(feature = "openapi")]
@@ -22,6 +48,7 @@ pub struct DidResolverHttpBinding {
 #[derive(Clone)]
 pub struct DidResolverStateDyn {
     pub resolver: Arc<dyn DidResolver + Send + Sync>,
+    pub cache_policy: Option<Arc<dyn ResolutionCachePolicy + Send + Sync>>,
 }
 
 #[derive(Default)]
@@ -63,17 +90,20 @@ pub fn did_resolver_http_binding(path: &str, options: HttpBindingOptions) -> Did
     utoipa::path(
         get,
         summary = "Resolve DID",
-        description = "This endpoint is fully compliant with the W3C DID Resolution specification. It returns a DID Resolution Result object, including metadata and the resolved DID Document, following the standard resolution process.\n\nOptional resolution options may be provided as query parameters, but are not yet supported in this implementation.",
+        description = "This endpoint is fully compliant with the W3C DID Resolution specification. It returns a DID Resolution Result object, including metadata and the resolved DID Document, following the standard resolution process.\n\nResolution options are provided as query parameters. Invalid options are rejected with `400 Bad Request`, as are `versionId` and `versionTime` since resolving past versions is not supported.\n\nThe representation is negotiated from the `accept` resolution option or the `Accept` header, honoring quality values. Without either, the DID document is returned as `application/did`. `application/did+ld+json` completes the `@context` for the verification method and service types used in the document, and `application/did+cbor` is a deterministic CBOR encoding of the DID document. Unsupported representations are rejected with `406 Not Acceptable`.\n\nResolved DIDs carry `ETag` and `Last-Modified` headers. Conditional requests with `If-None-Match` or `If-Modified-Since` return `304 Not Modified` when the DID document has not changed.",
         path = PLACEHOLDER_RESOLVER_PATH,
         responses(
             (status = OK, description = "Successfully resolved the DID.",
                 content(
                     (DidDocument = "application/json"),
                     (DidDocument = "application/did"),
+                    (DidDocument = "application/did+ld+json"),
+                    (DidDocument = "application/did+cbor"),
                     (ResolutionResult = "application/did-resolution")
                 )
             ),
-            (status = BAD_REQUEST, description = "The provided DID is invalid.", body = ResolutionResult, content_type = "application/did-resolution"),
+            (status = NOT_MODIFIED, description = "The DID document has not changed since the version identified by the request preconditions."),
+            (status = BAD_REQUEST, description = "The provided DID or resolution options are invalid.", body = ResolutionResult, content_type = "application/did-resolution"),
             (status = NOT_FOUND, description = "The DID does not exist or not found.", body = ResolutionResult, content_type = "application/did-resolution"),
             (status = GONE, description = "The DID has been deactivated.", body = ResolutionResult, content_type = "application/did-resolution"),
             (status = NOT_ACCEPTABLE, description = "The requested representation is not supported.", body = ResolutionResult, content_type = "application/did-resolution"),
@@ -82,16 +112,27 @@ pub fn did_resolver_http_binding(path: &str, options: HttpBindingOptions) -> Did
         ),
         params(
             ("did" = String, Path, description = "The Decentralized Identifier (DID) to resolve.", example = "did:example:123456789abcdefghi"),
+            ("accept" = Option<String>, Query, description = "The requested representation. Takes precedence over the `Accept` header."),
+            ("verificationMethodEncoding" = Option<String>, Query, description = "Representation of the verification methods: `jwk`, `multikey` or `key-type`."),
+            ("includeOperationKeys" = Option<bool>, Query, description = "List the keys controlling the DID that are not part of the DID document, such as PRISM master and VDR keys, in the `operationKeys` document metadata."),
         ),
     )
 )]
-pub async fn did_resolver(state: State<DidResolverStateDyn>, Path(did): Path<String>, headers: HeaderMap) -> Response {
+pub async fn did_resolver(
+    state: State<DidResolverStateDyn>,
+    Path(did): Path<String>,
+    query: Result<Query<ResolutionOptions>, QueryRejection>,
+    headers: HeaderMap,
+) -> Response {
     let resolver = &state.resolver;
-    let accept = headers
-        .get(header::ACCEPT)
-        .and_then(|i| i.to_str().ok())
-        .map(|i| i.trim())
-        .map(|i| i.split(',').collect::<HashSet<_>>());
+    let options = match resolution_options(query) {
+        Ok(options) => options,
+        Err(result) => return ResolverResponse::<ApplicationDidResolution>::from(result).into_response(),
+    };
+    let accept = options
+        .accept
+        .as_deref()
+        .or_else(|| headers.get(header::ACCEPT).and_then(|i| i.to_str().ok()));
 
     let parsed_did = match Did::from_str(&did) {
         Ok(did) => did,
@@ -101,29 +142,55 @@ pub async fn did_resolver(state: State<DidResolverStateDyn>, Path(did): Path<Str
         }
     };
 
-    // TODO: support resolution options
-    let options = Default::default();
+    let Some(media_type) = negotiation::negotiate(accept, DID_RESOLVER_MEDIA_TYPES) else {
+        let result = ResolutionResult::error(
+            DidResolutionErrorCode::RepresentationNotSupported,
+            "Representation Not Supported",
+            format!(
+                "none of the requested representations are supported: {}",
+                accept.unwrap_or_default()
+            ),
+        );
+        return ResolverResponse::<ApplicationDidResolution>::from(result).into_response();
+    };
+
     let result = resolver.resolve(&parsed_did, &options).await;
 
-    match accept {
+    CacheHeaders::new(&state, &parsed_did, &result).respond(&headers, || match media_type {
         _ if result.did_resolution_metadata.error.is_some()
             || result.did_document_metadata.deactivated == Some(true) =>
         {
             ResolverResponse::<ApplicationDidResolution>::from(result).into_response()
         }
-        Some(c) if c.contains("application/json") => ResolverResponse::<ApplicationJson>::from(result).into_response(),
-        Some(c) if c.contains("application/did") => ResolverResponse::<ApplicationDid>::from(result).into_response(),
-        Some(c) if c.contains("application/did-resolution") => {
-            ResolverResponse::<ApplicationDidResolution>::from(result).into_response()
-        }
+        MEDIA_TYPE_JSON => ResolverResponse::<ApplicationJson>::from(result).into_response(),
+        MEDIA_TYPE_DID_LD_JSON => ResolverResponse::<ApplicationDidLdJson>::from(result).into_response(),
+        MEDIA_TYPE_DID_CBOR => ResolverResponse::<ApplicationDidCbor>::from(result).into_response(),
+        MEDIA_TYPE_DID_RESOLUTION => ResolverResponse::<ApplicationDidResolution>::from(result).into_response(),
         _ => ResolverResponse::<ApplicationDid>::from(result).into_response(),
+    })
+}
+
+/// Resolution options from the query of a request, rejecting the options that are not supported.
+fn resolution_options(
+    query: Result<Query<ResolutionOptions>, QueryRejection>,
+) -> Result<ResolutionOptions, ResolutionResult> {
+    let invalid_options =
+        |detail: String| ResolutionResult::error(DidResolutionErrorCode::InvalidOptions, "Invalid Options", detail);
+    let Query(options) = query.map_err(|e| invalid_options(e.body_text()))?;
+    if options.version_id.is_some() || options.version_time.is_some() {
+        return Err(invalid_options(
+            "resolving a version of the DID document with versionId or versionTime is not supported".to_string(),
+        ));
     }
+    Ok(options)
 }
 
 struct ResolverResponse<Format>(ResolutionResult, PhantomData<Format>);
 
 struct ApplicationDidResolution;
 struct ApplicationDid;
+struct ApplicationDidLdJson;
+struct ApplicationDidCbor;
 struct ApplicationJson;
 
 impl<T> From<ResolutionResult> for ResolverResponse<T> {
@@ -136,7 +203,7 @@ impl IntoResponse for ResolverResponse<ApplicationDidResolution> {
     fn into_response(self) -> Response {
         (
             status_code_from_resolution_result(&self.0),
-            [(header::CONTENT_TYPE, "application/did-resolution")],
+            [(header::CONTENT_TYPE, MEDIA_TYPE_DID_RESOLUTION)],
             Json(self.0),
         )
             .into_response()
@@ -147,13 +214,44 @@ impl IntoResponse for ResolverResponse<ApplicationDid> {
     fn into_response(self) -> Response {
         (
             status_code_from_resolution_result(&self.0),
-            [(header::CONTENT_TYPE, "application/did")],
+            [(header::CONTENT_TYPE, MEDIA_TYPE_DID)],
             Json(self.0.did_document),
         )
             .into_response()
     }
 }
 
+impl IntoResponse for ResolverResponse<ApplicationDidLdJson> {
+    fn into_response(self) -> Response {
+        let status_code = status_code_from_resolution_result(&self.0);
+        let did_document = self.0.did_document.map(DidDocument::with_json_ld_context);
+        (
+            status_code,
+            [(header::CONTENT_TYPE, MEDIA_TYPE_DID_LD_JSON)],
+            Json(did_document),
+        )
+            .into_response()
+    }
+}
+
+impl IntoResponse for ResolverResponse<ApplicationDidCbor> {
+    fn into_response(self) -> Response {
+        match cbor::to_deterministic_cbor(&self.0.did_document) {
+            Ok(body) => (
+                status_code_from_resolution_result(&self.0),
+                [(header::CONTENT_TYPE, MEDIA_TYPE_DID_CBOR)],
+                body,
+            )
+                .into_response(),
+            Err(e) => {
+                let result =
+                    ResolutionResult::error(DidResolutionErrorCode::InternalError, "Internal Error", e.to_string());
+                ResolverResponse::<ApplicationDidResolution>::from(result).into_response()
+            }
+        }
+    }
+}
+
 impl IntoResponse for ResolverResponse<ApplicationJson> {
     fn into_response(self) -> Response {
         (status_code_from_resolution_result(&self.0), Json(self.0.did_document)).into_response()
@@ -162,7 +260,17 @@ impl IntoResponse for ResolverResponse<ApplicationJson> {
 
 fn status_code_from_resolution_result(result: &ResolutionResult) -> StatusCode {
     let error_code = result.did_resolution_metadata.error.as_ref().map(|i| &i.r#type);
-    let mut status_code = match error_code {
+    let mut status_code = status_code_from_error_code(error_code);
+
+    if result.did_document_metadata.deactivated == Some(true) {
+        status_code = StatusCode::GONE;
+    }
+
+    status_code
+}
+
+fn status_code_from_error_code(error_code: Option<&DidResolutionErrorCode>) -> StatusCode {
+    match error_code {
         None => StatusCode::OK,
         Some(DidResolutionErrorCode::InvalidDid) => StatusCode::BAD_REQUEST,
         Some(DidResolutionErrorCode::InvalidDidUrl) => StatusCode::BAD_REQUEST,
@@ -172,11 +280,5 @@ fn status_code_from_resolution_result(result: &ResolutionResult) -> StatusCode {
         Some(DidResolutionErrorCode::MethodNotSupported) => StatusCode::NOT_IMPLEMENTED,
         Some(DidResolutionErrorCode::UnsupportedPublicKeyType) => StatusCode::NOT_IMPLEMENTED,
         Some(_) => StatusCode::INTERNAL_SERVER_ERROR,
-    };
-
-    if result.did_document_metadata.deactivated == Some(true) {
-        status_code = StatusCode::GONE;
     }
-
-    status_code
 }
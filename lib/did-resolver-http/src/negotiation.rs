@@ -0,0 +1,124 @@
+//! Media types of DID resolution responses and content negotiation from the
+//! [`Accept` header](https://www.rfc-editor.org/rfc/rfc9110#name-accept).
+
+use std::cmp::Reverse;
+
+pub(crate) const MEDIA_TYPE_DID_RESOLUTION: &str = "application/did-resolution";
+pub(crate) const MEDIA_TYPE_LD_JSON_DID_RESOLUTION: &str =
+    "application/ld+json;profile=\"https://w3id.org/did-resolution\"";
+pub(crate) const MEDIA_TYPE_DID_URL_DEREFERENCING: &str = "application/did-url-dereferencing";
+pub(crate) const MEDIA_TYPE_DID_LD_JSON: &str = "application/did+ld+json";
+pub(crate) const MEDIA_TYPE_DID_JSON: &str = "application/did+json";
+pub(crate) const MEDIA_TYPE_DID_CBOR: &str = "application/did+cbor";
+pub(crate) const MEDIA_TYPE_DID: &str = "application/did";
+pub(crate) const MEDIA_TYPE_LD_JSON: &str = "application/ld+json";
+pub(crate) const MEDIA_TYPE_JSON: &str = "application/json";
+
+/// A media range of the `Accept` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MediaRange<'a> {
+    media_type: &'a str,
+    /// Quality value in thousandths.
+    quality: u16,
+}
+
+impl<'a> MediaRange<'a> {
+    /// Parse a media range, or return `None` if it is malformed.
+    fn parse(value: &'a str) -> Option<Self> {
+        let mut parts = value.split(';').map(str::trim);
+        let media_type = parts.next().filter(|i| i.split_once('/').is_some())?;
+        let mut quality = 1000;
+        for param in parts {
+            if let Some((name, value)) = param.split_once('=')
+                && name.trim().eq_ignore_ascii_case("q")
+            {
+                quality = parse_quality(value.trim())?;
+            }
+        }
+        Some(Self { media_type, quality })
+    }
+
+    /// How specifically the range matches the media type: 2 for the exact type, 1 for `type/*` and 0 for `*/*`.
+    fn specificity(&self, media_type: &str) -> Option<u8> {
+        if self.media_type.eq_ignore_ascii_case(media_type) {
+            return Some(2);
+        }
+        let (range_type, range_subtype) = self.media_type.split_once('/')?;
+        let (r#type, _) = media_type.split_once('/')?;
+        match (range_type, range_subtype) {
+            ("*", "*") => Some(0),
+            (range_type, "*") if range_type.eq_ignore_ascii_case(r#type) => Some(1),
+            _ => None,
+        }
+    }
+}
+
+fn parse_quality(value: &str) -> Option<u16> {
+    let quality = value.parse::<f32>().ok().filter(|q| (0.0..=1.0).contains(q))?;
+    Some((quality * 1000.0).round() as u16)
+}
+
+/// Select the supported media type preferred by the client, or return `None` if none is acceptable.
+///
+/// A supported media type takes the quality of the most specific media range matching it.
+/// Ties are broken by the specificity of that range, then by the order of `supported`.
+/// Without an `Accept` header, the first supported media type is selected.
+pub(crate) fn negotiate<'a>(accept: Option<&str>, supported: &[&'a str]) -> Option<&'a str> {
+    let Some(accept) = accept.map(str::trim).filter(|i| !i.is_empty()) else {
+        return supported.first().copied();
+    };
+    let media_ranges = accept.split(',').filter_map(MediaRange::parse).collect::<Vec<_>>();
+    supported
+        .iter()
+        .enumerate()
+        .filter_map(|(index, media_type)| {
+            let (specificity, quality) = media_ranges
+                .iter()
+                .filter_map(|range| range.specificity(media_type).map(|s| (s, range.quality)))
+                .max_by_key(|(specificity, _)| *specificity)?;
+            (quality > 0).then_some(((quality, specificity, Reverse(index)), *media_type))
+        })
+        .max_by_key(|(rank, _)| *rank)
+        .map(|(_, media_type)| media_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUPPORTED: &[&str] = &[MEDIA_TYPE_DID, MEDIA_TYPE_DID_LD_JSON, MEDIA_TYPE_JSON];
+
+    #[test]
+    fn negotiate_without_accept_selects_first_supported() {
+        assert_eq!(negotiate(None, SUPPORTED), Some(MEDIA_TYPE_DID));
+        assert_eq!(negotiate(Some(" "), SUPPORTED), Some(MEDIA_TYPE_DID));
+    }
+
+    #[test]
+    fn negotiate_prefers_highest_quality() {
+        let accept = "application/did;q=0.5, application/json;q=0.8, application/did+ld+json;q=0.2";
+        assert_eq!(negotiate(Some(accept), SUPPORTED), Some(MEDIA_TYPE_JSON));
+    }
+
+    #[test]
+    fn negotiate_prefers_exact_match_over_wildcard() {
+        assert_eq!(
+            negotiate(Some("text/html, application/json, */*"), SUPPORTED),
+            Some(MEDIA_TYPE_JSON)
+        );
+        assert_eq!(negotiate(Some("application/*"), SUPPORTED), Some(MEDIA_TYPE_DID));
+    }
+
+    #[test]
+    fn negotiate_uses_most_specific_range_quality() {
+        let accept = "*/*;q=0.1, application/did;q=0";
+        assert_eq!(negotiate(Some(accept), SUPPORTED), Some(MEDIA_TYPE_DID_LD_JSON));
+    }
+
+    #[test]
+    fn negotiate_rejects_unacceptable_types() {
+        assert_eq!(negotiate(Some("text/html"), SUPPORTED), None);
+        assert_eq!(negotiate(Some("application/json;q=0"), SUPPORTED), None);
+        assert_eq!(negotiate(Some("application/json;q=2"), SUPPORTED), None);
+    }
+}
@@ -0,0 +1,278 @@
+//! Resolver of PRISM DIDs forwarding resolution to upstream NeoPRISM nodes.
+
+use chrono::{DateTime, Utc};
+use identus_did_core::{
+    Did, DidResolutionErrorCode, DidResolver, ResolutionOptions, ResolutionResult, VerificationMethodEncoding,
+};
+use identus_did_prism::did::operation::SignedPrismOperationHexStr;
+use identus_did_prism::did::{PrismDid, PrismDidOps};
+use identus_did_prism::dlt::{BlockMetadata, BlockNo, OperationMetadata, SlotNo, TxId};
+use identus_did_prism::proto::prism::{PrismOperation, SignedPrismOperation};
+use identus_did_prism::protocol::resolver;
+use reqwest::StatusCode;
+use reqwest::header::ACCEPT;
+use serde::Deserialize;
+
+use crate::negotiation::MEDIA_TYPE_DID_RESOLUTION;
+
+/// Number of operations fetched from the upstream per page, which is the maximum page size of NeoPRISM.
+const OPERATION_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of operation pages fetched from the upstream for one DID, bounding the work of a resolution.
+const MAX_OPERATION_PAGES: u32 = 100;
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum PrismProxyError {
+    #[display("unable to send request to upstream {url}")]
+    Request { source: reqwest::Error, url: String },
+    #[display("upstream {url} responded with status {status}")]
+    UnexpectedStatus { status: StatusCode, url: String },
+    #[display("unable to decode response from upstream {url}")]
+    InvalidResponse { source: reqwest::Error, url: String },
+    #[display(
+        "upstream {url} resolved the DID to version {upstream:?} but its operations replay to version {replayed:?}"
+    )]
+    VersionMismatch {
+        url: String,
+        upstream: Option<String>,
+        replayed: Option<String>,
+    },
+    #[display("upstream {url} returned more than {limit} pages of operations")]
+    TooManyOperations { url: String, limit: u32 },
+}
+
+/// A page of the DID operation history of a NeoPRISM node, with only the fields needed to replay the operations.
+#[derive(Debug, Deserialize)]
+struct DidOperationHistory {
+    operations: Vec<DidOperationEntry>,
+    total_items: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct DidOperationEntry {
+    signed_operation_data: SignedPrismOperationHexStr,
+    tx_id: TxId,
+    slot_number: SlotNo,
+    block_number: BlockNo,
+    block_timestamp: DateTime<Utc>,
+    absn: u32,
+    osn: u32,
+}
+
+impl From<DidOperationEntry> for (OperationMetadata, SignedPrismOperation) {
+    fn from(entry: DidOperationEntry) -> Self {
+        let metadata = OperationMetadata {
+            block_metadata: BlockMetadata {
+                slot_number: entry.slot_number,
+                block_number: entry.block_number,
+                cbt: entry.block_timestamp,
+                tx_id: entry.tx_id,
+                absn: entry.absn,
+            },
+            osn: entry.osn,
+        };
+        (metadata, entry.signed_operation_data.into())
+    }
+}
+
+/// Resolves PRISM DIDs through upstream NeoPRISM nodes, without a local index of the Cardano blockchain.
+///
+/// The signed operations of the DID are fetched from the upstream with their block metadata and replayed
+/// locally, and the upstream answer is only accepted if it resolves to the same version of the DID. This
+/// checks that the answer is consistent with validly signed operations, but it does not authenticate it:
+/// the upstream supplies both the version and the operations, so it can withhold operations or make up
+/// their block metadata. Only use upstreams that are trusted to follow the chain.
+///
+/// Upstreams are tried in order until one returns a verifiable answer.
+#[derive(Debug, Clone)]
+pub struct PrismProxyResolver {
+    client: reqwest::Client,
+    upstream_urls: Vec<String>,
+    verification_method_encoding: VerificationMethodEncoding,
+}
+
+impl PrismProxyResolver {
+    /// Create a resolver forwarding to the NeoPRISM nodes at the given base URLs, e.g. `https://neoprism.patlo.dev`.
+    pub fn new(client: reqwest::Client, upstream_urls: Vec<String>) -> Self {
+        Self {
+            client,
+            upstream_urls: upstream_urls
+                .into_iter()
+                .map(|url| url.trim_end_matches('/').to_string())
+                .collect(),
+            verification_method_encoding: VerificationMethodEncoding::default(),
+        }
+    }
+
+    /// Set the verification method encoding used when the resolution options do not request one.
+    pub fn with_verification_method_encoding(self, verification_method_encoding: VerificationMethodEncoding) -> Self {
+        Self {
+            verification_method_encoding,
+            ..self
+        }
+    }
+
+    async fn resolve_from_upstream(
+        &self,
+        upstream_url: &str,
+        did: &PrismDid,
+        unpublished_operation: Option<&PrismOperation>,
+        options: &ResolutionOptions,
+    ) -> Result<ResolutionResult, PrismProxyError> {
+        let upstream_result = self.fetch_resolution_result(upstream_url, did).await?;
+        let operations = self.fetch_operations(upstream_url, did).await?;
+
+        let result = self.replay(did, operations, unpublished_operation, options);
+        let upstream_version = upstream_result.did_document_metadata.version_id;
+        let replayed_version = result.did_document_metadata.version_id.clone();
+        if upstream_version != replayed_version {
+            Err(PrismProxyError::VersionMismatch {
+                url: upstream_url.to_string(),
+                upstream: upstream_version,
+                replayed: replayed_version,
+            })?
+        }
+        Ok(result)
+    }
+
+    async fn fetch_resolution_result(
+        &self,
+        upstream_url: &str,
+        did: &PrismDid,
+    ) -> Result<ResolutionResult, PrismProxyError> {
+        let url = format!("{upstream_url}/api/dids/{did}");
+        let response = self
+            .client
+            .get(&url)
+            .header(ACCEPT, MEDIA_TYPE_DID_RESOLUTION)
+            .send()
+            .await
+            .map_err(|e| PrismProxyError::Request {
+                source: e,
+                url: url.clone(),
+            })?;
+        // Errors are also answered with a resolution result, such as a DID that is not found.
+        response
+            .json()
+            .await
+            .map_err(|e| PrismProxyError::InvalidResponse { source: e, url })
+    }
+
+    async fn fetch_operations(
+        &self,
+        upstream_url: &str,
+        did: &PrismDid,
+    ) -> Result<Vec<(OperationMetadata, SignedPrismOperation)>, PrismProxyError> {
+        let url = format!("{upstream_url}/api/dids/{}/operations", did.clone().into_canonical());
+        let mut operations = vec![];
+        for page in 0..MAX_OPERATION_PAGES {
+            let response = self
+                .client
+                .get(&url)
+                .query(&[("page", page), ("page_size", OPERATION_PAGE_SIZE)])
+                .send()
+                .await
+                .map_err(|e| PrismProxyError::Request {
+                    source: e,
+                    url: url.clone(),
+                })?;
+            // A DID without any operation is not found, unless it is a long-form DID
+            if response.status() == StatusCode::NOT_FOUND {
+                return Ok(operations);
+            }
+            if !response.status().is_success() {
+                Err(PrismProxyError::UnexpectedStatus {
+                    status: response.status(),
+                    url: url.clone(),
+                })?
+            }
+            let history: DidOperationHistory = response.json().await.map_err(|e| PrismProxyError::InvalidResponse {
+                source: e,
+                url: url.clone(),
+            })?;
+            let is_last_page = history.operations.is_empty()
+                || operations.len() + history.operations.len() >= history.total_items as usize;
+            operations.extend(history.operations.into_iter().map(Into::into));
+            if is_last_page {
+                return Ok(operations);
+            }
+        }
+        Err(PrismProxyError::TooManyOperations {
+            url,
+            limit: MAX_OPERATION_PAGES,
+        })
+    }
+
+    /// Resolve the DID from its operations the same way a NeoPRISM node does.
+    fn replay(
+        &self,
+        did: &PrismDid,
+        operations: Vec<(OperationMetadata, SignedPrismOperation)>,
+        unpublished_operation: Option<&PrismOperation>,
+        options: &ResolutionOptions,
+    ) -> ResolutionResult {
+        let state = match (operations.is_empty(), unpublished_operation) {
+            (false, _) => resolver::resolve_published(operations).0,
+            (true, Some(operation)) => match resolver::resolve_unpublished(operation.clone()) {
+                Ok(state) => Some(state),
+                Err(e) => return invalid_did(e.to_string()),
+            },
+            (true, None) => None,
+        };
+        let Some(state) = state else {
+            return ResolutionResult::error(
+                DidResolutionErrorCode::NotFound,
+                "DID Not Found",
+                "did is not found".to_string(),
+            );
+        };
+        let encoding = options
+            .verification_method_encoding
+            .unwrap_or(self.verification_method_encoding);
+        let mut result = state.to_resolution_result_with_encoding(did, encoding);
+        if options.include_operation_keys == Some(true) {
+            result.did_document_metadata.operation_keys = Some(state.to_operation_keys(&did.to_did()));
+        }
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl DidResolver for PrismProxyResolver {
+    async fn resolve(&self, did: &Did, options: &ResolutionOptions) -> ResolutionResult {
+        let prism_did = match did.to_string().parse::<PrismDid>() {
+            Ok(prism_did) => prism_did,
+            Err(e) => return invalid_did(e.to_string()),
+        };
+        let unpublished_operation = match &prism_did {
+            PrismDid::Canonical(_) => None,
+            PrismDid::LongForm(long_form_did) => match long_form_did.operation() {
+                Ok(operation) => Some(operation),
+                Err(e) => return invalid_did(e.to_string()),
+            },
+        };
+
+        let mut last_error = None;
+        for upstream_url in &self.upstream_urls {
+            match self
+                .resolve_from_upstream(upstream_url, &prism_did, unpublished_operation.as_ref(), options)
+                .await
+            {
+                Ok(result) => return result,
+                Err(e) => {
+                    tracing::warn!(error = %e, "unable to resolve {prism_did} from upstream");
+                    last_error = Some(e);
+                }
+            }
+        }
+        let detail = match last_error {
+            Some(e) => format!("no upstream returned a verifiable resolution: {e}"),
+            None => "no upstream is configured".to_string(),
+        };
+        ResolutionResult::error(DidResolutionErrorCode::InternalError, "Internal Error", detail)
+    }
+}
+
+fn invalid_did(detail: String) -> ResolutionResult {
+    ResolutionResult::error(DidResolutionErrorCode::InvalidDid, "Invalid DID", detail)
+}
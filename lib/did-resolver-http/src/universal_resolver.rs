@@ -0,0 +1,380 @@
+//! Binding that follows the [DIF Universal Resolver driver contract](https://github.com/decentralized-identity/universal-resolver/blob/main/docs/driver-development.md).
+//!
+//! The driver exposes `/1.0/identifiers/{identifier}` for both DID resolution and DID URL
+//! dereferencing, and `/1.0/properties` for driver-specific properties.
+
+use std::str::FromStr;
+
+use axum::extract::rejection::QueryRejection;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use identus_did_core::{
+    Did, DidDocument, DidDocumentMetadata, DidResolutionError, DidResolutionErrorCode, DidResolutionMetadata, DidUrl,
+    DidUrlOps, ResolutionOptions, ResolutionResult, ServiceEndpoint, StringOrMap,
+};
+use serde::Serialize;
+
+use crate::caching::CacheHeaders;
+use crate::negotiation::{
+    self, MEDIA_TYPE_DID, MEDIA_TYPE_DID_CBOR, MEDIA_TYPE_DID_JSON, MEDIA_TYPE_DID_LD_JSON, MEDIA_TYPE_DID_RESOLUTION,
+    MEDIA_TYPE_DID_URL_DEREFERENCING, MEDIA_TYPE_JSON, MEDIA_TYPE_LD_JSON, MEDIA_TYPE_LD_JSON_DID_RESOLUTION,
+};
+use crate::{
+    DidResolverHttpBinding, DidResolverStateDyn, cbor, resolution_options, status_code_from_error_code,
+    status_code_from_resolution_result,
+};
+
+/// Path of the DID resolution and DID URL dereferencing endpoint.
+pub const UNIVERSAL_RESOLVER_IDENTIFIERS_PATH: &str = "/1.0/identifiers/{identifier}";
+/// Path of the driver properties endpoint.
+pub const UNIVERSAL_RESOLVER_PROPERTIES_PATH: &str = "/1.0/properties";
+
+// DID URLs may contain unescaped `/` path segments, so the route captures the remainder of the path.
+const IDENTIFIERS_ROUTE: &str = "/1.0/identifiers/{*identifier}";
+
+/// Media types offered by the driver, the first one being the default.
+const UNIVERSAL_RESOLVER_MEDIA_TYPES: &[&str] = &[
+    MEDIA_TYPE_DID_RESOLUTION,
+    MEDIA_TYPE_DID_URL_DEREFERENCING,
+    MEDIA_TYPE_DID_LD_JSON,
+    MEDIA_TYPE_DID_JSON,
+    MEDIA_TYPE_DID_CBOR,
+    MEDIA_TYPE_DID,
+    MEDIA_TYPE_LD_JSON,
+    MEDIA_TYPE_JSON,
+];
+
+#[derive(Default)]
+pub struct UniversalResolverDriverOptions {
+    pub openapi_tags: Option<Vec<String>>,
+    /// Driver-specific properties returned by the properties endpoint.
+    pub properties: serde_json::Map<String, serde_json::Value>,
+}
+
+pub fn universal_resolver_driver_binding(options: UniversalResolverDriverOptions) -> DidResolverHttpBinding {
+    let driver_properties = serde_json::Value::Object(options.properties);
+    let router = Router::new().route(IDENTIFIERS_ROUTE, get(identifiers)).route(
+        UNIVERSAL_RESOLVER_PROPERTIES_PATH,
+        get(move || properties(driver_properties.clone())),
+    );
+
+    #[cfg(feature = "openapi")]
+    let openapi = {
+        #[derive(utoipa::OpenApi)]
+        #[openapi(paths(identifiers, properties))]
+        struct OpenApiDoc;
+
+        let mut openapi = <OpenApiDoc as utoipa::OpenApi>::openapi();
+        for path_item in openapi.paths.paths.values_mut() {
+            if let Some(operation) = path_item.get.as_mut() {
+                operation.tags = options.openapi_tags.clone();
+            }
+        }
+        openapi
+    };
+
+    DidResolverHttpBinding {
+        router,
+        #[cfg(feature = "openapi")]
+        openapi,
+    }
+}
+
+/// Representation requested by the client, negotiated from the `accept` option or header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Representation {
+    /// The full resolution (or dereferencing) result including metadata.
+    Result(&'static str),
+    /// Only the DID document (or dereferenced resource).
+    Content(&'static str),
+}
+
+impl Representation {
+    /// Negotiate the representation, defaulting to the resolution result.
+    fn negotiate(accept: Option<&str>) -> Option<Self> {
+        let media_type = negotiation::negotiate(accept, UNIVERSAL_RESOLVER_MEDIA_TYPES)?;
+        let representation = match media_type {
+            MEDIA_TYPE_LD_JSON if accept.is_some_and(requests_resolution_profile) => {
+                Self::Result(MEDIA_TYPE_LD_JSON_DID_RESOLUTION)
+            }
+            MEDIA_TYPE_DID_RESOLUTION | MEDIA_TYPE_DID_URL_DEREFERENCING => Self::Result(media_type),
+            _ => Self::Content(media_type),
+        };
+        Some(representation)
+    }
+}
+
+/// Whether the `Accept` header requests `application/ld+json` with the DID resolution profile.
+fn requests_resolution_profile(accept: &str) -> bool {
+    accept.split(',').any(|media_range| {
+        let mut parts = media_range.split(';').map(str::trim);
+        parts.next().is_some_and(|i| i.eq_ignore_ascii_case(MEDIA_TYPE_LD_JSON))
+            && parts.any(|p| p.starts_with("profile=") && p.contains("did-resolution"))
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DereferencingResult {
+    dereferencing_metadata: DidResolutionMetadata,
+    content_stream: Option<serde_json::Value>,
+    content_metadata: DidDocumentMetadata,
+}
+
+#[cfg_attr(
+    feature = "openapi",
+    utoipa::path(
+        get,
+        summary = "Resolve DID or dereference DID URL (Universal Resolver driver)",
+        description = "Resolves a DID or dereferences a DID URL following the DIF Universal Resolver driver contract.\n\nThe representation is negotiated from the `accept` resolution option or the `Accept` header, honoring quality values. Without either, the DID Resolution Result is returned. `application/did+cbor` is a deterministic CBOR encoding of the DID document. DID URLs with a fragment dereference to the matching verification method or service. DID URLs with a `service` query redirect to the service endpoint. Resolving past versions with `versionId` or `versionTime` is not supported and rejected as invalid options.\n\nResolved DIDs carry `ETag` and `Last-Modified` headers. Conditional requests with `If-None-Match` or `If-Modified-Since` return `304 Not Modified` when the DID document has not changed.",
+        path = UNIVERSAL_RESOLVER_IDENTIFIERS_PATH,
+        responses(
+            (status = OK, description = "Successfully resolved the DID or dereferenced the DID URL.",
+                content(
+                    (ResolutionResult = "application/did-resolution"),
+                    (ResolutionResult = "application/ld+json;profile=\"https://w3id.org/did-resolution\""),
+                    (identus_did_core::DidDocument = "application/did+ld+json"),
+                    (identus_did_core::DidDocument = "application/did+json"),
+                    (identus_did_core::DidDocument = "application/did+cbor"),
+                    (identus_did_core::DidDocument = "application/did"),
+                )
+            ),
+            (status = SEE_OTHER, description = "The DID URL was dereferenced to a service endpoint."),
+            (status = NOT_MODIFIED, description = "The DID document has not changed since the version identified by the request preconditions."),
+            (status = BAD_REQUEST, description = "The provided DID, DID URL or resolution options are invalid.", body = ResolutionResult, content_type = "application/did-resolution"),
+            (status = NOT_FOUND, description = "The DID or the dereferenced resource does not exist.", body = ResolutionResult, content_type = "application/did-resolution"),
+            (status = NOT_ACCEPTABLE, description = "The requested representation is not supported.", body = ResolutionResult, content_type = "application/did-resolution"),
+            (status = GONE, description = "The DID has been deactivated.", body = ResolutionResult, content_type = "application/did-resolution"),
+            (status = INTERNAL_SERVER_ERROR, description = "An unexpected error occurred during resolution.", body = ResolutionResult, content_type = "application/did-resolution"),
+            (status = NOT_IMPLEMENTED, description = "The DID method is not supported.", body = ResolutionResult, content_type = "application/did-resolution"),
+        ),
+        params(
+            ("identifier" = String, Path, description = "The DID or DID URL to resolve.", example = "did:prism:5a5d2f3e4c1b0a9f8e7d6c5b4a3f2e1d0c9b8a7f6e5d4c3b2a1f0e9d8c7b6a5f"),
+            ("accept" = Option<String>, Query, description = "The requested representation. Takes precedence over the `Accept` header."),
+            ("verificationMethodEncoding" = Option<String>, Query, description = "Representation of the verification methods: `jwk`, `multikey` or `key-type`."),
+            ("includeOperationKeys" = Option<bool>, Query, description = "List the keys controlling the DID that are not part of the DID document, such as PRISM master and VDR keys, in the `operationKeys` document metadata."),
+        ),
+    )
+)]
+pub async fn identifiers(
+    State(state): State<DidResolverStateDyn>,
+    Path(identifier): Path<String>,
+    query: Result<Query<ResolutionOptions>, QueryRejection>,
+    headers: HeaderMap,
+) -> Response {
+    let mut options = match resolution_options(query) {
+        Ok(options) => options,
+        Err(result) => return result_response(result, MEDIA_TYPE_DID_RESOLUTION),
+    };
+
+    let accept = options.accept.clone().or_else(|| {
+        headers
+            .get(header::ACCEPT)
+            .and_then(|i| i.to_str().ok())
+            .map(String::from)
+    });
+    let Some(representation) = Representation::negotiate(accept.as_deref()) else {
+        let result = ResolutionResult::error(
+            DidResolutionErrorCode::RepresentationNotSupported,
+            "Representation Not Supported",
+            format!(
+                "none of the requested representations are supported: {}",
+                accept.unwrap_or_default()
+            ),
+        );
+        return result_response(result, MEDIA_TYPE_DID_RESOLUTION);
+    };
+    if let Representation::Content(media_type) = representation {
+        options.accept = Some(media_type.to_string());
+    }
+
+    if let Ok(did) = Did::from_str(&identifier) {
+        let result = state.resolver.resolve(&did, &options).await;
+        return CacheHeaders::new(&state, &did, &result)
+            .respond(&headers, || resolution_response(result, representation));
+    }
+
+    match DidUrl::from_str(&identifier) {
+        Ok(did_url) => {
+            let did = did_url.to_did();
+            let result = state.resolver.resolve(&did, &options).await;
+            CacheHeaders::new(&state, &did, &result).respond(&headers, || dereference(did_url, result, representation))
+        }
+        Err(e) => result_response(ResolutionResult::invalid_did(e), MEDIA_TYPE_DID_RESOLUTION),
+    }
+}
+
+#[cfg_attr(
+    feature = "openapi",
+    utoipa::path(
+        get,
+        summary = "Driver properties (Universal Resolver driver)",
+        path = UNIVERSAL_RESOLVER_PROPERTIES_PATH,
+        responses(
+            (status = OK, description = "Driver-specific properties.", body = Object, content_type = "application/json"),
+        ),
+    )
+)]
+async fn properties(driver_properties: serde_json::Value) -> Json<serde_json::Value> {
+    Json(driver_properties)
+}
+
+fn resolution_response(mut result: ResolutionResult, representation: Representation) -> Response {
+    let is_error = result.did_resolution_metadata.error.is_some();
+    let is_deactivated = result.did_document_metadata.deactivated == Some(true);
+    match representation {
+        _ if is_error || is_deactivated => result_response(result, MEDIA_TYPE_DID_RESOLUTION),
+        Representation::Result(media_type) => result_response(result, media_type),
+        Representation::Content(media_type) => {
+            result.did_resolution_metadata.content_type = Some(media_type.to_string());
+            let did_document = match media_type {
+                MEDIA_TYPE_DID_LD_JSON | MEDIA_TYPE_LD_JSON => {
+                    result.did_document.map(DidDocument::with_json_ld_context)
+                }
+                _ => result.did_document,
+            };
+            content_response(StatusCode::OK, did_document, media_type)
+        }
+    }
+}
+
+fn dereference(did_url: DidUrl, result: ResolutionResult, representation: Representation) -> Response {
+    if result.did_resolution_metadata.error.is_some() || result.did_document_metadata.deactivated == Some(true) {
+        return result_response(result, MEDIA_TYPE_DID_RESOLUTION);
+    }
+    let Some(did_document) = result.did_document else {
+        return dereferencing_error_response(DidResolutionErrorCode::NotFound, "DID document not found".to_string());
+    };
+
+    if did_url.path().is_some() {
+        return dereferencing_error_response(
+            DidResolutionErrorCode::NotFound,
+            "DID URL path dereferencing is not supported".to_string(),
+        );
+    }
+
+    if let Some(service_id) = did_url.query().and_then(|q| query_param(q, "service")) {
+        let relative_ref = did_url.query().and_then(|q| query_param(q, "relativeRef"));
+        let endpoint = did_document
+            .service
+            .iter()
+            .flatten()
+            .find(|s| fragment_of(&s.id) == service_id)
+            .and_then(|s| match &s.service_endpoint {
+                ServiceEndpoint::StrOrMap(StringOrMap::Str(uri)) => Some(uri.clone()),
+                ServiceEndpoint::List(uris) => uris.iter().find_map(|i| match i {
+                    StringOrMap::Str(uri) => Some(uri.clone()),
+                    StringOrMap::Map(_) => None,
+                }),
+                ServiceEndpoint::StrOrMap(StringOrMap::Map(_)) => None,
+            });
+        return match endpoint {
+            Some(endpoint) => redirect_response(&format!("{endpoint}{}", relative_ref.unwrap_or_default())),
+            None => dereferencing_error_response(
+                DidResolutionErrorCode::NotFound,
+                format!("service {service_id} does not exist or has no URI endpoint"),
+            ),
+        };
+    }
+
+    let Some(fragment) = did_url.fragment() else {
+        return dereferencing_error_response(
+            DidResolutionErrorCode::InvalidDidUrl,
+            "DID URL must contain a fragment or a service query".to_string(),
+        );
+    };
+    let verification_method = did_document
+        .verification_method
+        .iter()
+        .find(|i| fragment_of(&i.id) == fragment)
+        .map(serde_json::to_value);
+    let service = did_document
+        .service
+        .iter()
+        .flatten()
+        .find(|i| fragment_of(&i.id) == fragment)
+        .map(serde_json::to_value);
+    let content = match verification_method.or(service) {
+        Some(Ok(content)) => content,
+        Some(Err(e)) => return dereferencing_error_response(DidResolutionErrorCode::InternalError, e.to_string()),
+        None => {
+            return dereferencing_error_response(
+                DidResolutionErrorCode::NotFound,
+                format!("resource #{fragment} does not exist in the DID document"),
+            );
+        }
+    };
+
+    match representation {
+        Representation::Result(_) => {
+            let dereferencing_result = DereferencingResult {
+                dereferencing_metadata: DidResolutionMetadata {
+                    content_type: Some(MEDIA_TYPE_DID_JSON.to_string()),
+                    error: None,
+                },
+                content_stream: Some(content),
+                content_metadata: result.did_document_metadata,
+            };
+            content_response(StatusCode::OK, dereferencing_result, MEDIA_TYPE_DID_URL_DEREFERENCING)
+        }
+        Representation::Content(media_type) => content_response(StatusCode::OK, content, media_type),
+    }
+}
+
+/// Returns the fragment of a DID URL or relative reference, or the input if it has none.
+fn fragment_of(id: &str) -> &str {
+    id.rsplit_once('#').map(|(_, fragment)| fragment).unwrap_or(id)
+}
+
+/// Returns the percent-decoded value of a query parameter.
+fn query_param(query: &str, key: &str) -> Option<String> {
+    form_urlencoded::parse(query.as_bytes())
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.into_owned())
+}
+
+fn result_response(result: ResolutionResult, media_type: &'static str) -> Response {
+    let status_code = status_code_from_resolution_result(&result);
+    content_response(status_code, result, media_type)
+}
+
+fn dereferencing_error_response(code: DidResolutionErrorCode, detail: String) -> Response {
+    let status_code = status_code_from_error_code(Some(&code));
+    let dereferencing_result = DereferencingResult {
+        dereferencing_metadata: DidResolutionMetadata {
+            content_type: None,
+            error: Some(DidResolutionError {
+                r#type: code,
+                title: None,
+                detail: Some(detail),
+            }),
+        },
+        content_stream: None,
+        content_metadata: Default::default(),
+    };
+    content_response(status_code, dereferencing_result, MEDIA_TYPE_DID_URL_DEREFERENCING)
+}
+
+fn content_response(status_code: StatusCode, body: impl Serialize, media_type: &'static str) -> Response {
+    let content_type = [(header::CONTENT_TYPE, HeaderValue::from_static(media_type))];
+    if media_type != MEDIA_TYPE_DID_CBOR {
+        return (status_code, content_type, Json(body)).into_response();
+    }
+    match cbor::to_deterministic_cbor(&body) {
+        Ok(body) => (status_code, content_type, body).into_response(),
+        Err(e) => result_response(
+            ResolutionResult::error(DidResolutionErrorCode::InternalError, "Internal Error", e.to_string()),
+            MEDIA_TYPE_DID_RESOLUTION,
+        ),
+    }
+}
+
+fn redirect_response(location: &str) -> Response {
+    match HeaderValue::from_str(location) {
+        Ok(location) => (StatusCode::SEE_OTHER, [(header::LOCATION, location)]).into_response(),
+        Err(e) => dereferencing_error_response(DidResolutionErrorCode::InternalError, e.to_string()),
+    }
+}
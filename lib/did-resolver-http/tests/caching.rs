@@ -0,0 +1,326 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{HeaderMap, HeaderValue, Request, StatusCode, header};
+use axum::response::IntoResponse;
+use chrono::{TimeZone, Utc};
+use identus_did_core::{
+    Did, DidDocument, DidDocumentMetadata, DidResolutionError, DidResolutionErrorCode, DidResolutionMetadata,
+    DidResolver, ResolutionOptions, ResolutionResult,
+};
+use identus_did_resolver_http::{
+    CacheHeaders, DidResolverStateDyn, ResolutionCachePolicy, UniversalResolverDriverOptions,
+    did_resolver_http_binding, universal_resolver_driver_binding,
+};
+use tower::ServiceExt;
+
+// ---------------------------------------------------------------------------
+// Mocks
+// ---------------------------------------------------------------------------
+
+struct MockResolver {
+    result: ResolutionResult,
+}
+
+#[async_trait::async_trait]
+impl DidResolver for MockResolver {
+    async fn resolve(&self, _did: &Did, _options: &ResolutionOptions) -> ResolutionResult {
+        self.result.clone()
+    }
+}
+
+/// Uses a different policy for deactivated DIDs and omits the header for errors.
+struct MockCachePolicy;
+
+impl ResolutionCachePolicy for MockCachePolicy {
+    fn cache_control(&self, _did: &Did, result: &ResolutionResult) -> Option<HeaderValue> {
+        if result.did_resolution_metadata.error.is_some() {
+            None
+        } else if result.did_document_metadata.deactivated == Some(true) {
+            Some(HeaderValue::from_static("public, max-age=86400"))
+        } else {
+            Some(HeaderValue::from_static("public, max-age=60"))
+        }
+    }
+}
+
+fn resolved() -> ResolutionResult {
+    let mut result = ResolutionResult::success(DidDocument {
+        context: vec!["https://www.w3.org/ns/did/v1".to_string()],
+        id: Did::from_str("did:example:123").unwrap(),
+        also_known_as: None,
+        verification_method: vec![],
+        authentication: None,
+        assertion_method: None,
+        key_agreement: None,
+        capability_invocation: None,
+        capability_delegation: None,
+        service: None,
+    });
+    result.did_document_metadata = DidDocumentMetadata {
+        created: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+        updated: Some(Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap()),
+        deactivated: Some(false),
+        canonical_id: None,
+        version_id: Some("abc123".to_string()),
+        operation_keys: None,
+    };
+    result
+}
+
+fn deactivated() -> ResolutionResult {
+    let mut result = ResolutionResult::deactivated();
+    result.did_document_metadata = DidDocumentMetadata {
+        deactivated: Some(true),
+        ..resolved().did_document_metadata
+    };
+    result
+}
+
+fn not_found() -> ResolutionResult {
+    ResolutionResult {
+        did_document: None,
+        did_resolution_metadata: DidResolutionMetadata {
+            content_type: None,
+            error: Some(DidResolutionError {
+                r#type: DidResolutionErrorCode::NotFound,
+                title: None,
+                detail: None,
+            }),
+        },
+        did_document_metadata: Default::default(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn make_state(result: ResolutionResult) -> DidResolverStateDyn {
+    DidResolverStateDyn {
+        resolver: Arc::new(MockResolver { result }),
+        cache_policy: Some(Arc::new(MockCachePolicy)),
+    }
+}
+
+fn make_app(result: ResolutionResult) -> axum::Router {
+    let state = make_state(result);
+    did_resolver_http_binding("/did/{did}", Default::default())
+        .router
+        .merge(universal_resolver_driver_binding(UniversalResolverDriverOptions::default()).router)
+        .with_state(state)
+}
+
+async fn send_request(
+    app: axum::Router,
+    path: &str,
+    headers: &[(header::HeaderName, &str)],
+) -> axum::response::Response {
+    let mut builder = Request::builder().uri(path).method("GET");
+    for (name, value) in headers {
+        builder = builder.header(name, *value);
+    }
+    app.oneshot(builder.body(Body::empty()).unwrap()).await.unwrap()
+}
+
+fn header_value(response: &axum::response::Response, name: header::HeaderName) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+}
+
+// ---------------------------------------------------------------------------
+// Tests: response headers
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn resolved_did_has_validators_and_cache_control() {
+    let response = send_request(make_app(resolved()), "/did/did:example:123", &[]).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        header_value(&response, header::ETAG).as_deref(),
+        Some("W/\"abc123-1717243200\"")
+    );
+    assert_eq!(
+        header_value(&response, header::LAST_MODIFIED).as_deref(),
+        Some("Sat, 01 Jun 2024 12:00:00 GMT")
+    );
+    assert_eq!(
+        header_value(&response, header::CACHE_CONTROL).as_deref(),
+        Some("public, max-age=60")
+    );
+    assert_eq!(header_value(&response, header::VARY).as_deref(), Some("accept"));
+}
+
+#[tokio::test]
+async fn deactivated_did_uses_deactivated_policy() {
+    let response = send_request(make_app(deactivated()), "/did/did:example:123", &[]).await;
+
+    assert_eq!(response.status(), StatusCode::GONE);
+    assert!(header_value(&response, header::ETAG).is_some());
+    assert_eq!(
+        header_value(&response, header::CACHE_CONTROL).as_deref(),
+        Some("public, max-age=86400")
+    );
+}
+
+#[tokio::test]
+async fn resolution_error_has_no_validators() {
+    let response = send_request(make_app(not_found()), "/did/did:example:123", &[]).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert!(header_value(&response, header::ETAG).is_none());
+    assert!(header_value(&response, header::LAST_MODIFIED).is_none());
+    assert!(header_value(&response, header::CACHE_CONTROL).is_none());
+}
+
+#[tokio::test]
+async fn no_cache_control_without_policy() {
+    let state = DidResolverStateDyn {
+        cache_policy: None,
+        ..make_state(resolved())
+    };
+    let app = did_resolver_http_binding("/did/{did}", Default::default())
+        .router
+        .with_state(state);
+    let response = send_request(app, "/did/did:example:123", &[]).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(header_value(&response, header::ETAG).is_some());
+    assert!(header_value(&response, header::CACHE_CONTROL).is_none());
+}
+
+// ---------------------------------------------------------------------------
+// Tests: conditional requests
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn if_none_match_with_current_etag_returns_304() {
+    let response = send_request(
+        make_app(resolved()),
+        "/did/did:example:123",
+        &[(header::IF_NONE_MATCH, "\"other\", W/\"abc123-1717243200\"")],
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(
+        header_value(&response, header::ETAG).as_deref(),
+        Some("W/\"abc123-1717243200\"")
+    );
+    assert_eq!(
+        header_value(&response, header::CACHE_CONTROL).as_deref(),
+        Some("public, max-age=60")
+    );
+}
+
+#[tokio::test]
+async fn if_none_match_with_stale_etag_returns_200() {
+    let response = send_request(
+        make_app(resolved()),
+        "/did/did:example:123",
+        &[(header::IF_NONE_MATCH, "W/\"abc123-0\"")],
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn if_modified_since_after_update_returns_304() {
+    let response = send_request(
+        make_app(resolved()),
+        "/did/did:example:123",
+        &[(header::IF_MODIFIED_SINCE, "Sat, 01 Jun 2024 12:00:00 GMT")],
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn if_modified_since_before_update_returns_200() {
+    let response = send_request(
+        make_app(resolved()),
+        "/did/did:example:123",
+        &[(header::IF_MODIFIED_SINCE, "Fri, 31 May 2024 12:00:00 GMT")],
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn if_none_match_takes_precedence_over_if_modified_since() {
+    let response = send_request(
+        make_app(resolved()),
+        "/did/did:example:123",
+        &[
+            (header::IF_NONE_MATCH, "W/\"abc123-0\""),
+            (header::IF_MODIFIED_SINCE, "Sat, 01 Jun 2024 12:00:00 GMT"),
+        ],
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn universal_resolver_supports_conditional_requests() {
+    let response = send_request(
+        make_app(resolved()),
+        "/1.0/identifiers/did:example:123",
+        &[(header::IF_NONE_MATCH, "W/\"abc123-1717243200\"")],
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(
+        header_value(&response, header::CACHE_CONTROL).as_deref(),
+        Some("public, max-age=60")
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Tests: other resources
+// ---------------------------------------------------------------------------
+
+fn resource_headers() -> CacheHeaders {
+    CacheHeaders::for_resource(
+        "\"abc123\"".to_string(),
+        Some(Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap()),
+        Some(HeaderValue::from_static("public, max-age=60")),
+    )
+}
+
+#[test]
+fn resource_with_current_etag_returns_304() {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static("W/\"abc123\""));
+    let response = resource_headers().respond(&headers, || StatusCode::OK.into_response());
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(header_value(&response, header::ETAG).as_deref(), Some("\"abc123\""));
+    assert_eq!(
+        header_value(&response, header::LAST_MODIFIED).as_deref(),
+        Some("Sat, 01 Jun 2024 12:00:00 GMT")
+    );
+    assert_eq!(
+        header_value(&response, header::CACHE_CONTROL).as_deref(),
+        Some("public, max-age=60")
+    );
+}
+
+#[test]
+fn resource_with_stale_etag_returns_full_response() {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static("\"other\""));
+    let response = resource_headers().respond(&headers, || StatusCode::OK.into_response());
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(header_value(&response, header::ETAG).as_deref(), Some("\"abc123\""));
+}
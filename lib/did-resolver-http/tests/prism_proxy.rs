@@ -0,0 +1,322 @@
+#![cfg(feature = "prism-proxy")]
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{TimeZone, Utc};
+use identus_apollo::crypto::secp256k1::Secp256k1PrivateKey;
+use identus_apollo::hash::sha256;
+use identus_did_core::{Did, DidResolutionErrorCode, DidResolver, ResolutionOptions, ResolutionResult};
+use identus_did_prism::did::operation::SignedPrismOperationHexStr;
+use identus_did_prism::did::{CanonicalPrismDid, LongFormPrismDid, PrismDid, PrismDidOps};
+use identus_did_prism::dlt::{BlockMetadata, OperationMetadata, TxId};
+use identus_did_prism::prelude::MessageExt;
+use identus_did_prism::proto;
+use identus_did_prism::proto::prism::prism_operation::Operation;
+use identus_did_prism::protocol::resolver;
+use identus_did_resolver_http::PrismProxyResolver;
+use serde::Deserialize;
+
+// ---------------------------------------------------------------------------
+// Fake upstream
+// ---------------------------------------------------------------------------
+
+const MASTER_KEY: [u8; 32] = [1; 32];
+
+#[derive(Clone, Default)]
+struct Upstream {
+    operations: Arc<Vec<(OperationMetadata, proto::prism::SignedPrismOperation)>>,
+    /// Answer resolution with this version instead of the one of the operations.
+    version_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PageQuery {
+    page: usize,
+}
+
+async fn resolve(State(upstream): State<Upstream>, Path(did): Path<String>) -> Response {
+    let did: PrismDid = did.parse().unwrap();
+    let mut result = match resolver::resolve_published(upstream.operations.to_vec()).0 {
+        Some(state) => state.to_resolution_result(&did),
+        None => match &did {
+            PrismDid::LongForm(did) => {
+                let state = resolver::resolve_unpublished(did.operation().unwrap()).unwrap();
+                state.to_resolution_result(&PrismDid::LongForm(did.clone()))
+            }
+            PrismDid::Canonical(_) => ResolutionResult::error(
+                DidResolutionErrorCode::NotFound,
+                "DID Not Found",
+                "did is not found".to_string(),
+            ),
+        },
+    };
+    if let Some(version_id) = upstream.version_id {
+        result.did_document_metadata.version_id = Some(version_id);
+    }
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/did-resolution")],
+        Json(result),
+    )
+        .into_response()
+}
+
+/// Serve one operation per page so the proxy has to go through all pages.
+async fn operations(State(upstream): State<Upstream>, Query(query): Query<PageQuery>) -> Response {
+    if upstream.operations.is_empty() {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let operations: Vec<_> = upstream
+        .operations
+        .iter()
+        .skip(query.page)
+        .take(1)
+        .map(|(metadata, operation)| {
+            serde_json::json!({
+                "signed_operation_data": SignedPrismOperationHexStr::from(operation.clone()),
+                "tx_id": metadata.block_metadata.tx_id,
+                "slot_number": metadata.block_metadata.slot_number,
+                "block_number": metadata.block_metadata.block_number,
+                "block_timestamp": metadata.block_metadata.cbt,
+                "absn": metadata.block_metadata.absn,
+                "osn": metadata.osn,
+                "accepted": true,
+            })
+        })
+        .collect();
+    Json(serde_json::json!({
+        "operations": operations,
+        "current_page": query.page,
+        "page_size": 1,
+        "total_items": upstream.operations.len(),
+    }))
+    .into_response()
+}
+
+async fn serve(upstream: Upstream) -> String {
+    let router = Router::new()
+        .route("/api/dids/{did}", get(resolve))
+        .route("/api/dids/{did}/operations", get(operations))
+        .with_state(upstream);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+    url
+}
+
+// ---------------------------------------------------------------------------
+// Operations
+// ---------------------------------------------------------------------------
+
+fn metadata(block_number: u64) -> OperationMetadata {
+    OperationMetadata {
+        block_metadata: BlockMetadata {
+            slot_number: block_number.into(),
+            block_number: block_number.into(),
+            cbt: Utc.with_ymd_and_hms(2025, 1, block_number as u32, 0, 0, 0).unwrap(),
+            absn: 0,
+            tx_id: TxId::from(sha256(block_number.to_be_bytes())),
+        },
+        osn: 0,
+    }
+}
+
+fn sign(operation: Operation) -> proto::prism::SignedPrismOperation {
+    let sk = Secp256k1PrivateKey::from_slice(&MASTER_KEY).unwrap();
+    let operation = proto::prism::PrismOperation {
+        operation: Some(operation),
+        special_fields: Default::default(),
+    };
+    proto::prism::SignedPrismOperation {
+        signed_with: "master-0".to_string(),
+        signature: sk.sign(&operation.encode_to_vec()),
+        operation: Some(operation).into(),
+        special_fields: Default::default(),
+    }
+}
+
+fn create_did_operation() -> Operation {
+    let pk = Secp256k1PrivateKey::from_slice(&MASTER_KEY).unwrap().to_public_key();
+    Operation::CreateDid(proto::prism_ssi::ProtoCreateDID {
+        did_data: Some(proto::prism_ssi::proto_create_did::DIDCreationData {
+            public_keys: vec![proto::prism_ssi::PublicKey {
+                id: "master-0".to_string(),
+                usage: proto::prism_ssi::KeyUsage::MASTER_KEY.into(),
+                key_data: Some(proto::prism_ssi::public_key::Key_data::CompressedEcKeyData(
+                    proto::prism_ssi::CompressedECKeyData {
+                        curve: "secp256k1".to_string(),
+                        data: pk.encode_compressed().into(),
+                        special_fields: Default::default(),
+                    },
+                )),
+                special_fields: Default::default(),
+            }],
+            services: vec![],
+            context: vec![],
+            special_fields: Default::default(),
+        })
+        .into(),
+        special_fields: Default::default(),
+    })
+}
+
+/// The operations of a DID created in block 1 and deactivated in block 2.
+fn deactivated_did_operations() -> (
+    CanonicalPrismDid,
+    Vec<(OperationMetadata, proto::prism::SignedPrismOperation)>,
+) {
+    let create_did_op = sign(create_did_operation());
+    let create_operation = create_did_op.operation.as_ref().unwrap();
+    let did = CanonicalPrismDid::from_operation(create_operation).unwrap();
+    let deactivate_did_op = sign(Operation::DeactivateDid(proto::prism_ssi::ProtoDeactivateDID {
+        previous_operation_hash: create_operation.operation_hash().to_vec(),
+        id: did.suffix_hex().to_string(),
+        special_fields: Default::default(),
+    }));
+    (
+        did,
+        vec![(metadata(1), create_did_op), (metadata(2), deactivate_did_op)],
+    )
+}
+
+fn resolver(upstream_urls: Vec<String>) -> PrismProxyResolver {
+    PrismProxyResolver::new(reqwest::Client::new(), upstream_urls)
+}
+
+fn error_code(result: &ResolutionResult) -> Option<&DidResolutionErrorCode> {
+    result.did_resolution_metadata.error.as_ref().map(|e| &e.r#type)
+}
+
+// ---------------------------------------------------------------------------
+// Resolution
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn proxy_replays_upstream_operations() {
+    let (did, operations) = deactivated_did_operations();
+    let url = serve(Upstream {
+        operations: Arc::new(operations),
+        ..Default::default()
+    })
+    .await;
+
+    let result = resolver(vec![url])
+        .resolve(&did.to_did(), &ResolutionOptions::default())
+        .await;
+    assert!(error_code(&result).is_none());
+    assert_eq!(result.did_document_metadata.deactivated, Some(true));
+    assert_eq!(
+        result.did_document_metadata.created,
+        Some(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+    );
+    assert_eq!(
+        result.did_document_metadata.updated,
+        Some(Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap())
+    );
+}
+
+#[tokio::test]
+async fn proxy_resolves_unpublished_long_form_did() {
+    let url = serve(Upstream::default()).await;
+    let operation = sign(create_did_operation()).operation.unwrap();
+    let did = LongFormPrismDid::from_operation(&operation).unwrap();
+
+    let result = resolver(vec![url])
+        .resolve(&did.to_did(), &ResolutionOptions::default())
+        .await;
+    assert!(error_code(&result).is_none());
+    assert_eq!(result.did_document.unwrap().id.to_string(), did.to_string());
+}
+
+#[tokio::test]
+async fn proxy_reports_unknown_did_not_found() {
+    let url = serve(Upstream::default()).await;
+    let (did, _) = deactivated_did_operations();
+
+    let result = resolver(vec![url])
+        .resolve(&did.to_did(), &ResolutionOptions::default())
+        .await;
+    assert!(matches!(error_code(&result), Some(DidResolutionErrorCode::NotFound)));
+}
+
+// ---------------------------------------------------------------------------
+// Verification
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn proxy_rejects_upstream_answer_not_matching_operations() {
+    let (did, operations) = deactivated_did_operations();
+    let operations = Arc::new(operations);
+    let dishonest_url = serve(Upstream {
+        operations: operations.clone(),
+        version_id: Some("00".repeat(32)),
+    })
+    .await;
+
+    let result = resolver(vec![dishonest_url.clone()])
+        .resolve(&did.to_did(), &ResolutionOptions::default())
+        .await;
+    assert!(matches!(
+        error_code(&result),
+        Some(DidResolutionErrorCode::InternalError)
+    ));
+    assert!(result.did_document_metadata.deactivated.is_none());
+
+    let honest_url = serve(Upstream {
+        operations,
+        ..Default::default()
+    })
+    .await;
+    let result = resolver(vec![dishonest_url, honest_url])
+        .resolve(&did.to_did(), &ResolutionOptions::default())
+        .await;
+    assert!(error_code(&result).is_none());
+    assert_eq!(result.did_document_metadata.deactivated, Some(true));
+}
+
+#[tokio::test]
+async fn proxy_rejects_upstream_with_too_many_operation_pages() {
+    let (did, operations) = deactivated_did_operations();
+    // One operation per page, so the proxy stops before the last page
+    let operations = operations.iter().cycle().take(101).cloned().collect();
+    let url = serve(Upstream {
+        operations: Arc::new(operations),
+        ..Default::default()
+    })
+    .await;
+
+    let result = resolver(vec![url])
+        .resolve(&did.to_did(), &ResolutionOptions::default())
+        .await;
+    assert!(matches!(
+        error_code(&result),
+        Some(DidResolutionErrorCode::InternalError)
+    ));
+}
+
+#[tokio::test]
+async fn proxy_falls_back_on_unreachable_upstream() {
+    let (did, operations) = deactivated_did_operations();
+    let url = serve(Upstream {
+        operations: Arc::new(operations),
+        ..Default::default()
+    })
+    .await;
+
+    let result = resolver(vec!["http://127.0.0.1:1".to_string(), format!("{url}/")])
+        .resolve(&did.to_did(), &ResolutionOptions::default())
+        .await;
+    assert!(error_code(&result).is_none());
+}
+
+#[tokio::test]
+async fn proxy_rejects_invalid_did() {
+    let did: Did = "did:prism:abc".parse().unwrap();
+    let result = resolver(vec![]).resolve(&did, &ResolutionOptions::default()).await;
+    assert!(matches!(error_code(&result), Some(DidResolutionErrorCode::InvalidDid)));
+}
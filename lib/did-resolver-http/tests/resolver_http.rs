@@ -1,12 +1,13 @@
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use axum::body::Body;
 use axum::http::{Request, StatusCode, header};
 use http_body_util::BodyExt;
 use identus_did_core::{
     Did, DidDocument, DidDocumentMetadata, DidResolutionError, DidResolutionErrorCode, DidResolutionMetadata,
-    DidResolver, ResolutionResult,
+    DidResolver, ResolutionOptions, ResolutionResult, Service, ServiceEndpoint, ServiceType, StringOrMap,
+    VerificationMethod, VerificationMethodEncoding,
 };
 use identus_did_resolver_http::{DidResolverStateDyn, did_resolver_http_binding};
 use tower::ServiceExt;
@@ -62,6 +63,33 @@ impl MockResolver {
         })
     }
 
+    fn with_keys_and_services() -> Self {
+        Self::new(|did| {
+            ResolutionResult::success(DidDocument {
+                context: vec!["https://www.w3.org/ns/did/v1".to_string()],
+                id: did.clone(),
+                also_known_as: None,
+                verification_method: vec![VerificationMethod {
+                    id: format!("{did}#key-1"),
+                    r#type: "Multikey".to_string(),
+                    controller: did.to_string(),
+                    public_key_jwk: None,
+                    public_key_multibase: Some("z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK".to_string()),
+                }],
+                authentication: None,
+                assertion_method: None,
+                key_agreement: None,
+                capability_invocation: None,
+                capability_delegation: None,
+                service: Some(vec![Service {
+                    id: format!("{did}#domain-1"),
+                    r#type: ServiceType::Str("LinkedDomains".to_string()),
+                    service_endpoint: ServiceEndpoint::StrOrMap(StringOrMap::Str("https://example.com".to_string())),
+                }]),
+            })
+        })
+    }
+
     fn not_found() -> Self {
         Self::new(|_| ResolutionResult {
             did_document: None,
@@ -200,14 +228,29 @@ impl DidResolver for MockResolver {
     }
 }
 
+/// A resolver that records the options it receives and resolves like [`MockResolver::success`].
+#[derive(Clone, Default)]
+struct RecordingResolver {
+    options: Arc<Mutex<Vec<ResolutionOptions>>>,
+}
+
+#[async_trait::async_trait]
+impl DidResolver for RecordingResolver {
+    async fn resolve(&self, did: &Did, options: &ResolutionOptions) -> ResolutionResult {
+        self.options.lock().unwrap().push(options.clone());
+        MockResolver::success().resolve(did, options).await
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
-fn make_app(resolver: MockResolver) -> axum::Router {
+fn make_app(resolver: impl DidResolver + Send + Sync + 'static) -> axum::Router {
     let binding = did_resolver_http_binding("/did/{did}", Default::default());
     let state = DidResolverStateDyn {
         resolver: Arc::new(resolver),
+        cache_policy: None,
     };
     binding.router.with_state(state)
 }
@@ -220,6 +263,11 @@ async fn send_request(app: axum::Router, did: &str, accept: Option<&str>) -> (St
 }
 
 async fn send_request_raw(app: axum::Router, path: &str, accept: Option<&str>) -> (StatusCode, String, String) {
+    let (status, content_type, body) = send_request_bytes(app, path, accept).await;
+    (status, content_type, String::from_utf8_lossy(&body).to_string())
+}
+
+async fn send_request_bytes(app: axum::Router, path: &str, accept: Option<&str>) -> (StatusCode, String, Vec<u8>) {
     let mut builder = Request::builder().uri(path).method("GET");
     if let Some(accept) = accept {
         builder = builder.header(header::ACCEPT, accept);
@@ -234,8 +282,7 @@ async fn send_request_raw(app: axum::Router, path: &str, accept: Option<&str>) -
         .unwrap_or("")
         .to_string();
     let body = response.into_body().collect().await.unwrap().to_bytes();
-    let body_str = String::from_utf8_lossy(&body).to_string();
-    (status, content_type, body_str)
+    (status, content_type, body.to_vec())
 }
 
 // ---------------------------------------------------------------------------
@@ -293,9 +340,6 @@ async fn resolve_success_accept_application_did_resolution() {
 
 #[tokio::test]
 async fn resolve_success_accept_multiple_includes_json() {
-    // NOTE: There is a known bug where the Accept header values are not trimmed
-    // after splitting on comma. So "application/json" with a leading space won't
-    // match. This test uses no-space commas to verify the intended behavior works.
     let app = make_app(MockResolver::success());
     let (status, content_type, _body) =
         send_request(app, "did:example:123", Some("text/html,application/json,*/*")).await;
@@ -489,6 +533,102 @@ async fn accept_wildcard_returns_application_did() {
     assert_eq!(content_type, "application/did");
 }
 
+#[tokio::test]
+async fn accept_with_whitespace_and_quality_values_selects_preferred() {
+    let app = make_app(MockResolver::success());
+    let accept = "application/did;q=0.5, application/did-resolution;q=0.9, */*;q=0.1";
+    let (status, content_type, _body) = send_request(app, "did:example:123", Some(accept)).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did-resolution");
+}
+
+#[tokio::test]
+async fn accept_prefers_exact_match_over_wildcard() {
+    let app = make_app(MockResolver::success());
+    let (status, content_type, _body) =
+        send_request(app, "did:example:123", Some("text/html, application/did+ld+json, */*")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+ld+json");
+}
+
+#[tokio::test]
+async fn accept_unsupported_returns_representation_not_supported() {
+    let app = make_app(MockResolver::success());
+    let (status, content_type, body) = send_request(app, "did:example:123", Some("text/html")).await;
+
+    assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+    assert_eq!(content_type, "application/did-resolution");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let error = &json["didResolutionMetadata"]["error"];
+    assert_eq!(error["type"], "https://www.w3.org/ns/did#REPRESENTATION_NOT_SUPPORTED");
+}
+
+#[tokio::test]
+async fn accept_with_zero_quality_returns_representation_not_supported() {
+    let app = make_app(MockResolver::success());
+    let (status, _content_type, _body) =
+        send_request(app, "did:example:123", Some("application/did;q=0, */*;q=0")).await;
+
+    assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+}
+
+#[tokio::test]
+async fn accept_did_ld_json_completes_context() {
+    let app = make_app(MockResolver::with_keys_and_services());
+    let (status, content_type, body) = send_request(app, "did:example:123", Some("application/did+ld+json")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+ld+json");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(
+        json["@context"],
+        serde_json::json!([
+            "https://www.w3.org/ns/did/v1",
+            "https://w3id.org/security/multikey/v1",
+            "https://identity.foundation/.well-known/did-configuration/v1",
+        ])
+    );
+}
+
+#[tokio::test]
+async fn accept_did_cbor_returns_deterministic_did_document() {
+    let app = make_app(MockResolver::with_keys_and_services());
+    let path = "/did/did%3Aexample%3A123";
+    let (status, content_type, body) = send_request_bytes(app.clone(), path, Some("application/did+cbor")).await;
+    let (_, _, body_again) = send_request_bytes(app, path, Some("application/did+cbor")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+cbor");
+    assert_eq!(body, body_again);
+
+    let value: ciborium::Value = ciborium::from_reader(body.as_slice()).unwrap();
+    let keys = value
+        .as_map()
+        .unwrap()
+        .iter()
+        .map(|(k, _)| k.as_text().unwrap().to_string())
+        .collect::<Vec<_>>();
+    // map keys are sorted by length first, then bytewise
+    let mut sorted_keys = keys.clone();
+    sorted_keys.sort_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+    assert_eq!(keys, sorted_keys);
+
+    let document: DidDocument = ciborium::from_reader(body.as_slice()).unwrap();
+    assert_eq!(document.id.to_string(), "did:example:123");
+    assert_eq!(document.verification_method.len(), 1);
+}
+
+#[tokio::test]
+async fn accept_did_cbor_with_not_found_returns_did_resolution() {
+    let app = make_app(MockResolver::not_found());
+    let (status, content_type, _body) = send_request(app, "did:example:missing", Some("application/did+cbor")).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(content_type, "application/did-resolution");
+}
+
 #[tokio::test]
 async fn no_accept_header_with_deactivated_returns_gone_with_did_resolution() {
     let app = make_app(MockResolver::deactivated());
@@ -511,6 +651,7 @@ async fn binding_creates_functional_router() {
     let binding = did_resolver_http_binding("/resolve/{did}", Default::default());
     let state = DidResolverStateDyn {
         resolver: Arc::new(MockResolver::success()),
+        cache_policy: None,
     };
     let app = binding.router.with_state(state);
 
@@ -574,3 +715,68 @@ async fn resolve_invalid_public_key_returns_internal_error_via_catch_all() {
     let error = &json["didResolutionMetadata"]["error"];
     assert_eq!(error["type"], "https://w3id.org/security#INVALID_PUBLIC_KEY");
 }
+
+// ---------------------------------------------------------------------------
+// Tests: resolution options
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn resolution_options_are_passed_to_resolver() {
+    let resolver = RecordingResolver::default();
+    let app = make_app(resolver.clone());
+    let path = "/did/did%3Aexample%3A123?verificationMethodEncoding=multikey&includeOperationKeys=true";
+    let (status, content_type, _body) = send_request_raw(app, path, None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did");
+    let options = resolver.options.lock().unwrap();
+    assert_eq!(options.len(), 1);
+    assert_eq!(
+        options[0].verification_method_encoding,
+        Some(VerificationMethodEncoding::Multikey)
+    );
+    assert_eq!(options[0].include_operation_keys, Some(true));
+}
+
+#[tokio::test]
+async fn resolution_option_accept_takes_precedence_over_header() {
+    let app = make_app(MockResolver::success());
+    let path = "/did/did%3Aexample%3A123?accept=application%2Fdid-resolution";
+    let (status, content_type, _body) = send_request_raw(app, path, Some("application/did")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did-resolution");
+}
+
+#[tokio::test]
+async fn version_time_option_returns_invalid_options() {
+    let resolver = RecordingResolver::default();
+    let app = make_app(resolver.clone());
+    let path = "/did/did%3Aexample%3A123?versionTime=2024-01-01T00%3A00%3A00Z";
+    let (status, _content_type, body) = send_request_raw(app, path, None).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(
+        json["didResolutionMetadata"]["error"]["type"],
+        "https://www.w3.org/ns/did#INVALID_OPTIONS"
+    );
+    assert!(resolver.options.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn invalid_verification_method_encoding_returns_invalid_options() {
+    let resolver = RecordingResolver::default();
+    let app = make_app(resolver.clone());
+    let path = "/did/did%3Aexample%3A123?verificationMethodEncoding=unknown";
+    let (status, content_type, body) = send_request_raw(app, path, None).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(content_type, "application/did-resolution");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(
+        json["didResolutionMetadata"]["error"]["type"],
+        "https://www.w3.org/ns/did#INVALID_OPTIONS"
+    );
+    assert!(resolver.options.lock().unwrap().is_empty());
+}
@@ -0,0 +1,392 @@
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode, header};
+use http_body_util::BodyExt;
+use identus_did_core::{
+    Did, DidDocument, DidResolutionError, DidResolutionErrorCode, DidResolutionMetadata, DidResolver,
+    ResolutionOptions, ResolutionResult, Service, ServiceEndpoint, ServiceType, StringOrMap, VerificationMethod,
+    VerificationMethodEncoding,
+};
+use identus_did_resolver_http::{
+    DidResolverStateDyn, UniversalResolverDriverOptions, universal_resolver_driver_binding,
+};
+use tower::ServiceExt;
+
+// ---------------------------------------------------------------------------
+// Mock resolver
+// ---------------------------------------------------------------------------
+
+/// A mock resolver that returns a preset result and records the options it received.
+#[derive(Clone)]
+struct MockResolver {
+    result: ResolutionResult,
+    received_options: Arc<Mutex<Option<ResolutionOptions>>>,
+}
+
+impl MockResolver {
+    fn new(result: ResolutionResult) -> Self {
+        Self {
+            result,
+            received_options: Default::default(),
+        }
+    }
+
+    fn success() -> Self {
+        let did = Did::from_str("did:example:123").unwrap();
+        Self::new(ResolutionResult::success(DidDocument {
+            context: vec!["https://www.w3.org/ns/did/v1".to_string()],
+            id: did.clone(),
+            also_known_as: None,
+            verification_method: vec![VerificationMethod {
+                id: format!("{did}#key-1"),
+                r#type: "JsonWebKey2020".to_string(),
+                controller: did.to_string(),
+                public_key_jwk: None,
+                public_key_multibase: None,
+            }],
+            authentication: None,
+            assertion_method: None,
+            key_agreement: None,
+            capability_invocation: None,
+            capability_delegation: None,
+            service: Some(vec![Service {
+                id: format!("{did}#linked-domain"),
+                r#type: ServiceType::Str("LinkedDomains".to_string()),
+                service_endpoint: ServiceEndpoint::StrOrMap(StringOrMap::Str("https://example.com".to_string())),
+            }]),
+        }))
+    }
+
+    fn not_found() -> Self {
+        Self::new(ResolutionResult {
+            did_document: None,
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: None,
+                error: Some(DidResolutionError {
+                    r#type: DidResolutionErrorCode::NotFound,
+                    title: None,
+                    detail: None,
+                }),
+            },
+            did_document_metadata: Default::default(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl DidResolver for MockResolver {
+    async fn resolve(&self, _did: &Did, options: &ResolutionOptions) -> ResolutionResult {
+        *self.received_options.lock().unwrap() = Some(options.clone());
+        self.result.clone()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn make_app(resolver: MockResolver) -> axum::Router {
+    let mut properties = serde_json::Map::new();
+    properties.insert("network".to_string(), serde_json::json!("mainnet"));
+    let binding = universal_resolver_driver_binding(UniversalResolverDriverOptions {
+        properties,
+        ..Default::default()
+    });
+    let state = DidResolverStateDyn {
+        resolver: Arc::new(resolver),
+        cache_policy: None,
+    };
+    binding.router.with_state(state)
+}
+
+async fn send_request(app: axum::Router, path: &str, accept: Option<&str>) -> (StatusCode, String, String, String) {
+    let mut builder = Request::builder().uri(path).method("GET");
+    if let Some(accept) = accept {
+        builder = builder.header(header::ACCEPT, accept);
+    }
+    let request = builder.body(Body::empty()).unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let header_value = |name: header::HeaderName| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("")
+            .to_string()
+    };
+    let content_type = header_value(header::CONTENT_TYPE);
+    let location = header_value(header::LOCATION);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body_str = String::from_utf8_lossy(&body).to_string();
+    (status, content_type, body_str, location)
+}
+
+// ---------------------------------------------------------------------------
+// Tests: DID resolution
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn resolve_without_accept_returns_resolution_result() {
+    let app = make_app(MockResolver::success());
+    let (status, content_type, body, _) = send_request(app, "/1.0/identifiers/did:example:123", None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did-resolution");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["didDocument"]["id"], "did:example:123");
+}
+
+#[tokio::test]
+async fn resolve_with_ld_json_profile_returns_resolution_result() {
+    let app = make_app(MockResolver::success());
+    let accept = r#"application/ld+json;profile="https://w3id.org/did-resolution""#;
+    let (status, content_type, body, _) = send_request(app, "/1.0/identifiers/did:example:123", Some(accept)).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, accept);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert!(json.get("didResolutionMetadata").is_some());
+}
+
+#[tokio::test]
+async fn resolve_with_did_ld_json_returns_document() {
+    let app = make_app(MockResolver::success());
+    let (status, content_type, body, _) =
+        send_request(app, "/1.0/identifiers/did:example:123", Some("application/did+ld+json")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+ld+json");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["id"], "did:example:123");
+}
+
+#[tokio::test]
+async fn resolve_with_unsupported_accept_returns_406() {
+    let app = make_app(MockResolver::success());
+    let (status, _, body, _) = send_request(app, "/1.0/identifiers/did:example:123", Some("text/html")).await;
+
+    assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(
+        json["didResolutionMetadata"]["error"]["type"],
+        "https://www.w3.org/ns/did#REPRESENTATION_NOT_SUPPORTED"
+    );
+}
+
+#[tokio::test]
+async fn resolve_skips_representations_with_zero_quality() {
+    let app = make_app(MockResolver::success());
+    let accept = "application/did+ld+json;q=0, application/did+json";
+    let (status, content_type, _, _) = send_request(app, "/1.0/identifiers/did:example:123", Some(accept)).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+json");
+}
+
+#[tokio::test]
+async fn resolve_prefers_highest_quality_representation() {
+    let app = make_app(MockResolver::success());
+    let accept = "application/did-resolution;q=0.5, application/did+ld+json;q=0.9";
+    let (status, content_type, _, _) = send_request(app, "/1.0/identifiers/did:example:123", Some(accept)).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+ld+json");
+}
+
+#[tokio::test]
+async fn resolve_matches_media_types_case_insensitively() {
+    let app = make_app(MockResolver::success());
+    let (status, content_type, body, _) =
+        send_request(app, "/1.0/identifiers/did:example:123", Some("Application/DID+JSON")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+json");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["id"], "did:example:123");
+}
+
+#[tokio::test]
+async fn resolve_with_did_cbor_returns_document() {
+    let app = make_app(MockResolver::success());
+    let (status, content_type, _, _) =
+        send_request(app, "/1.0/identifiers/did:example:123", Some("application/did+cbor")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+cbor");
+}
+
+#[tokio::test]
+async fn resolve_accept_option_takes_precedence_over_header() {
+    let app = make_app(MockResolver::success());
+    let (status, content_type, _, _) = send_request(
+        app,
+        "/1.0/identifiers/did:example:123?accept=application%2Fdid%2Bjson",
+        Some("application/did-resolution"),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+json");
+}
+
+#[tokio::test]
+async fn resolve_passes_query_options_to_resolver() {
+    let resolver = MockResolver::success();
+    let received_options = resolver.received_options.clone();
+    let app = make_app(resolver);
+    let (status, _, _, _) = send_request(
+        app,
+        "/1.0/identifiers/did:example:123?verificationMethodEncoding=key-type&includeOperationKeys=true",
+        None,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let options = received_options.lock().unwrap().clone().unwrap();
+    assert_eq!(
+        options.verification_method_encoding,
+        Some(VerificationMethodEncoding::KeyType)
+    );
+    assert_eq!(options.include_operation_keys, Some(true));
+}
+
+#[tokio::test]
+async fn resolve_with_version_options_returns_invalid_options() {
+    for query in ["versionId=abc", "versionTime=2024-01-01T00:00:00Z"] {
+        let resolver = MockResolver::success();
+        let received_options = resolver.received_options.clone();
+        let app = make_app(resolver);
+        let (status, _, body, _) = send_request(app, &format!("/1.0/identifiers/did:example:123?{query}"), None).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(
+            json["didResolutionMetadata"]["error"]["type"],
+            "https://www.w3.org/ns/did#INVALID_OPTIONS"
+        );
+        assert!(received_options.lock().unwrap().is_none());
+    }
+}
+
+#[tokio::test]
+async fn resolve_with_invalid_options_returns_400() {
+    let app = make_app(MockResolver::success());
+    let (status, _, body, _) = send_request(app, "/1.0/identifiers/did:example:123?versionTime=yesterday", None).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(
+        json["didResolutionMetadata"]["error"]["type"],
+        "https://www.w3.org/ns/did#INVALID_OPTIONS"
+    );
+}
+
+#[tokio::test]
+async fn resolve_not_found_returns_404() {
+    let app = make_app(MockResolver::not_found());
+    let (status, content_type, _, _) = send_request(app, "/1.0/identifiers/did:example:123", None).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(content_type, "application/did-resolution");
+}
+
+#[tokio::test]
+async fn resolve_deactivated_returns_410() {
+    let app = make_app(MockResolver::new(ResolutionResult::deactivated()));
+    let (status, _, _, _) =
+        send_request(app, "/1.0/identifiers/did:example:123", Some("application/did+ld+json")).await;
+
+    assert_eq!(status, StatusCode::GONE);
+}
+
+#[tokio::test]
+async fn resolve_invalid_did_returns_400() {
+    let app = make_app(MockResolver::success());
+    let (status, _, _, _) = send_request(app, "/1.0/identifiers/not-a-did", None).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+// ---------------------------------------------------------------------------
+// Tests: DID URL dereferencing
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn dereference_fragment_returns_verification_method() {
+    let app = make_app(MockResolver::success());
+    let (status, content_type, body, _) = send_request(app, "/1.0/identifiers/did:example:123%23key-1", None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did-url-dereferencing");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["contentStream"]["id"], "did:example:123#key-1");
+}
+
+#[tokio::test]
+async fn dereference_fragment_with_content_accept_returns_resource() {
+    let app = make_app(MockResolver::success());
+    let (status, content_type, body, _) = send_request(
+        app,
+        "/1.0/identifiers/did:example:123%23linked-domain",
+        Some("application/did+json"),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "application/did+json");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["id"], "did:example:123#linked-domain");
+}
+
+#[tokio::test]
+async fn dereference_unknown_fragment_returns_404() {
+    let app = make_app(MockResolver::success());
+    let (status, _, _, _) = send_request(app, "/1.0/identifiers/did:example:123%23key-99", None).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn dereference_service_query_redirects_to_endpoint() {
+    let app = make_app(MockResolver::success());
+    let (status, _, _, location) = send_request(
+        app,
+        "/1.0/identifiers/did:example:123%3Fservice%3Dlinked-domain%26relativeRef%3D%2Fpath",
+        None,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::SEE_OTHER);
+    assert_eq!(location, "https://example.com/path");
+}
+
+#[tokio::test]
+async fn dereference_service_query_decodes_parameters() {
+    let app = make_app(MockResolver::success());
+    let (status, _, _, location) = send_request(
+        app,
+        "/1.0/identifiers/did:example:123%3Fservice%3Dlinked%252Ddomain%26relativeRef%3D%252Fpath%2523section",
+        None,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::SEE_OTHER);
+    assert_eq!(location, "https://example.com/path#section");
+}
+
+// ---------------------------------------------------------------------------
+// Tests: properties
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn properties_returns_driver_properties() {
+    let app = make_app(MockResolver::success());
+    let (status, _, body, _) = send_request(app, "/1.0/properties", None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["network"], "mainnet");
+}
@@ -0,0 +1,30 @@
+-- Webhook subscriptions and their pending or dead-lettered deliveries
+CREATE TABLE IF NOT EXISTS webhook_subscription (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    filter TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS webhook_delivery (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    subscription_id UUID NOT NULL,
+    payload TEXT NOT NULL,
+    status TEXT NOT NULL,
+    attempts INT4 NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL,
+    FOREIGN KEY (subscription_id) REFERENCES webhook_subscription (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS webhook_delivery_status_next_attempt_at ON webhook_delivery (status, next_attempt_at);
+
+-- Position of the last operation dispatched to the subscriptions, a single row
+CREATE TABLE IF NOT EXISTS webhook_dispatch_cursor (
+    id INT4 PRIMARY KEY CHECK (id = 1),
+    block_number INT8 NOT NULL,
+    absn INT4 NOT NULL,
+    osn INT4 NOT NULL
+);
@@ -0,0 +1,6 @@
+-- PrismObjects exactly as published in the transaction metadata, kept as evidence of the raw operations
+-- Transactions indexed before this migration keep their operations but have no stored PrismObject
+CREATE TABLE IF NOT EXISTS prism_object (
+    tx_hash BYTEA PRIMARY KEY,
+    data BYTEA NOT NULL
+);
@@ -0,0 +1,30 @@
+-- Webhook subscriptions and their pending or dead-lettered deliveries
+CREATE TABLE IF NOT EXISTS webhook_subscription (
+    id BLOB PRIMARY KEY DEFAULT (randomblob(16)),
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    filter TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS webhook_delivery (
+    id BLOB PRIMARY KEY DEFAULT (randomblob(16)),
+    subscription_id BLOB NOT NULL,
+    payload TEXT NOT NULL,
+    status TEXT NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TEXT NOT NULL,
+    last_error TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (subscription_id) REFERENCES webhook_subscription(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS webhook_delivery_status_next_attempt_at ON webhook_delivery (status, next_attempt_at);
+
+-- Position of the last operation dispatched to the subscriptions, a single row
+CREATE TABLE IF NOT EXISTS webhook_dispatch_cursor (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    block_number INTEGER NOT NULL,
+    absn INTEGER NOT NULL,
+    osn INTEGER NOT NULL
+);
@@ -0,0 +1,8 @@
+PRAGMA foreign_keys = ON;
+
+-- PrismObjects exactly as published in the transaction metadata, kept as evidence of the raw operations
+-- Transactions indexed before this migration keep their operations but have no stored PrismObject
+CREATE TABLE IF NOT EXISTS prism_object (
+    tx_hash BLOB PRIMARY KEY,
+    data BLOB NOT NULL
+);
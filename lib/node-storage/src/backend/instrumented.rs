@@ -0,0 +1,296 @@
+use std::sync::LazyLock;
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use identus_apollo::hash::Sha256Digest;
+use identus_did_prism::did::operation::OperationId;
+use identus_did_prism::dlt::{BlockNo, DltCursor, OperationMetadata, SlotNo, TxId};
+use identus_did_prism::prelude::*;
+use identus_did_prism::utils::paging::Paginated;
+use identus_did_prism_indexer::repo::{
+    DltCursorRepo, IndexedOperation, IndexedOperationRepo, IndexerStateRepo, OperationPosition, RawOperationRecord,
+    RawOperationRepo,
+};
+use prometheus::{HistogramVec, register_histogram_vec};
+use tracing::Instrument;
+use uuid::Uuid;
+
+use crate::webhook::{NewWebhookDelivery, NewWebhookSubscription, WebhookDelivery, WebhookRepo, WebhookSubscription};
+use crate::{Error, StorageBackend};
+
+static DB_QUERY_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "neoprism_db_query_duration_seconds",
+        "Duration of storage queries",
+        &["query", "outcome"]
+    )
+    .expect("metric neoprism_db_query_duration_seconds must be registered")
+});
+
+/// A storage backend wrapper that records the duration of every query and traces it in a span.
+#[derive(Debug, Clone)]
+pub struct InstrumentedDb<T> {
+    inner: T,
+}
+
+impl<T: StorageBackend> InstrumentedDb<T> {
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+async fn timed<R>(query: &'static str, fut: impl Future<Output = Result<R, Error>>) -> Result<R, Error> {
+    let started_at = Instant::now();
+    let result = fut
+        .instrument(tracing::info_span!("db.query", db.operation = query))
+        .await;
+    let outcome = if result.is_ok() { "success" } else { "error" };
+    DB_QUERY_DURATION
+        .with_label_values(&[query, outcome])
+        .observe(started_at.elapsed().as_secs_f64());
+    result
+}
+
+#[async_trait::async_trait]
+impl<T: StorageBackend> RawOperationRepo for InstrumentedDb<T> {
+    type Error = Error;
+
+    async fn get_raw_operations_unindexed(&self) -> Result<Vec<RawOperationRecord>, Self::Error> {
+        timed(
+            "get_raw_operations_unindexed",
+            self.inner.get_raw_operations_unindexed(),
+        )
+        .await
+    }
+
+    async fn get_raw_operations_by_did(&self, did: &CanonicalPrismDid) -> Result<Vec<RawOperationRecord>, Self::Error> {
+        timed("get_raw_operations_by_did", self.inner.get_raw_operations_by_did(did)).await
+    }
+
+    async fn get_raw_operations_by_did_page(
+        &self,
+        did: &CanonicalPrismDid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Paginated<RawOperationRecord>, Self::Error> {
+        timed(
+            "get_raw_operations_by_did_page",
+            self.inner.get_raw_operations_by_did_page(did, page, page_size),
+        )
+        .await
+    }
+
+    async fn get_raw_operations_by_dids(
+        &self,
+        dids: &[CanonicalPrismDid],
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        timed(
+            "get_raw_operations_by_dids",
+            self.inner.get_raw_operations_by_dids(dids),
+        )
+        .await
+    }
+
+    async fn get_raw_operation_vdr_by_operation_hash(
+        &self,
+        operation_hash: &Sha256Digest,
+    ) -> Result<Option<RawOperationRecord>, Self::Error> {
+        timed(
+            "get_raw_operation_vdr_by_operation_hash",
+            self.inner.get_raw_operation_vdr_by_operation_hash(operation_hash),
+        )
+        .await
+    }
+
+    async fn get_raw_operations_by_tx_id(
+        &self,
+        tx_id: &TxId,
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        timed(
+            "get_raw_operations_by_tx_id",
+            self.inner.get_raw_operations_by_tx_id(tx_id),
+        )
+        .await
+    }
+
+    async fn get_prism_object_bytes(&self, tx_id: &TxId) -> Result<Option<Vec<u8>>, Self::Error> {
+        timed("get_prism_object_bytes", self.inner.get_prism_object_bytes(tx_id)).await
+    }
+
+    async fn get_raw_operation_by_operation_id(
+        &self,
+        operation_id: &OperationId,
+    ) -> Result<Option<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        timed(
+            "get_raw_operation_by_operation_id",
+            self.inner.get_raw_operation_by_operation_id(operation_id),
+        )
+        .await
+    }
+
+    async fn get_raw_operations_after(
+        &self,
+        position: &OperationPosition,
+        limit: u32,
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        timed(
+            "get_raw_operations_after",
+            self.inner.get_raw_operations_after(position, limit),
+        )
+        .await
+    }
+
+    async fn insert_raw_operations(
+        &self,
+        operations: Vec<(OperationMetadata, SignedPrismOperation)>,
+    ) -> Result<(), Self::Error> {
+        timed("insert_raw_operations", self.inner.insert_raw_operations(operations)).await
+    }
+
+    async fn insert_prism_object_bytes(&self, tx_id: &TxId, prism_object_bytes: Vec<u8>) -> Result<(), Self::Error> {
+        timed(
+            "insert_prism_object_bytes",
+            self.inner.insert_prism_object_bytes(tx_id, prism_object_bytes),
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: StorageBackend> IndexedOperationRepo for InstrumentedDb<T> {
+    type Error = Error;
+
+    async fn insert_indexed_operations(&self, operations: Vec<IndexedOperation>) -> Result<(), Self::Error> {
+        timed(
+            "insert_indexed_operations",
+            self.inner.insert_indexed_operations(operations),
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: StorageBackend> IndexerStateRepo for InstrumentedDb<T> {
+    type Error = Error;
+
+    async fn get_last_indexed_block(&self) -> Result<Option<(SlotNo, BlockNo)>, Self::Error> {
+        timed("get_last_indexed_block", self.inner.get_last_indexed_block()).await
+    }
+
+    async fn get_all_dids(&self, page: u32, page_size: u32) -> Result<Paginated<CanonicalPrismDid>, Self::Error> {
+        timed("get_all_dids", self.inner.get_all_dids(page, page_size)).await
+    }
+
+    async fn get_did_by_vdr_entry(
+        &self,
+        operation_hash: &Sha256Digest,
+    ) -> Result<Option<CanonicalPrismDid>, Self::Error> {
+        timed("get_did_by_vdr_entry", self.inner.get_did_by_vdr_entry(operation_hash)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: StorageBackend> DltCursorRepo for InstrumentedDb<T> {
+    type Error = Error;
+
+    async fn set_cursor(&self, cursor: DltCursor) -> Result<(), Self::Error> {
+        timed("set_cursor", self.inner.set_cursor(cursor)).await
+    }
+
+    async fn get_cursor(&self) -> Result<Option<DltCursor>, Self::Error> {
+        timed("get_cursor", self.inner.get_cursor()).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: StorageBackend> WebhookRepo for InstrumentedDb<T> {
+    type Error = Error;
+
+    async fn insert_webhook_subscription(
+        &self,
+        subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, Self::Error> {
+        timed(
+            "insert_webhook_subscription",
+            self.inner.insert_webhook_subscription(subscription),
+        )
+        .await
+    }
+
+    async fn get_webhook_subscriptions(&self) -> Result<Vec<WebhookSubscription>, Self::Error> {
+        timed("get_webhook_subscriptions", self.inner.get_webhook_subscriptions()).await
+    }
+
+    async fn get_webhook_subscription(&self, id: &Uuid) -> Result<Option<WebhookSubscription>, Self::Error> {
+        timed("get_webhook_subscription", self.inner.get_webhook_subscription(id)).await
+    }
+
+    async fn delete_webhook_subscription(&self, id: &Uuid) -> Result<bool, Self::Error> {
+        timed(
+            "delete_webhook_subscription",
+            self.inner.delete_webhook_subscription(id),
+        )
+        .await
+    }
+
+    async fn insert_webhook_deliveries(&self, deliveries: Vec<NewWebhookDelivery>) -> Result<(), Self::Error> {
+        timed(
+            "insert_webhook_deliveries",
+            self.inner.insert_webhook_deliveries(deliveries),
+        )
+        .await
+    }
+
+    async fn get_webhook_deliveries_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, Self::Error> {
+        timed(
+            "get_webhook_deliveries_due",
+            self.inner.get_webhook_deliveries_due(now, limit),
+        )
+        .await
+    }
+
+    async fn get_webhook_deliveries_dead(&self, subscription_id: &Uuid) -> Result<Vec<WebhookDelivery>, Self::Error> {
+        timed(
+            "get_webhook_deliveries_dead",
+            self.inner.get_webhook_deliveries_dead(subscription_id),
+        )
+        .await
+    }
+
+    async fn delete_webhook_delivery(&self, id: &Uuid) -> Result<(), Self::Error> {
+        timed("delete_webhook_delivery", self.inner.delete_webhook_delivery(id)).await
+    }
+
+    async fn update_webhook_delivery_failed(
+        &self,
+        id: &Uuid,
+        error: String,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), Self::Error> {
+        timed(
+            "update_webhook_delivery_failed",
+            self.inner.update_webhook_delivery_failed(id, error, next_attempt_at),
+        )
+        .await
+    }
+
+    async fn retry_webhook_delivery(&self, id: &Uuid, now: DateTime<Utc>) -> Result<bool, Self::Error> {
+        timed("retry_webhook_delivery", self.inner.retry_webhook_delivery(id, now)).await
+    }
+
+    async fn get_webhook_dispatch_cursor(&self) -> Result<Option<OperationPosition>, Self::Error> {
+        timed("get_webhook_dispatch_cursor", self.inner.get_webhook_dispatch_cursor()).await
+    }
+
+    async fn set_webhook_dispatch_cursor(&self, position: &OperationPosition) -> Result<(), Self::Error> {
+        timed(
+            "set_webhook_dispatch_cursor",
+            self.inner.set_webhook_dispatch_cursor(position),
+        )
+        .await
+    }
+}
@@ -1,3 +1,4 @@
+pub mod instrumented;
 pub mod postgres;
 mod shared;
 
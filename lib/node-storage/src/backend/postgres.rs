@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use identus_apollo::hash::Sha256Digest;
 use identus_apollo::hex::HexStr;
 use identus_did_prism::did::operation::OperationId;
@@ -5,16 +6,22 @@ use identus_did_prism::dlt::{BlockNo, DltCursor, OperationMetadata, SlotNo, TxId
 use identus_did_prism::prelude::*;
 use identus_did_prism::utils::paging::Paginated;
 use identus_did_prism_indexer::repo::{
-    DltCursorRepo, IndexedOperation, IndexedOperationRepo, IndexerStateRepo, RawOperationRecord, RawOperationRepo,
+    DltCursorRepo, IndexedOperation, IndexedOperationRepo, IndexerStateRepo, OperationPosition, RawOperationRecord,
+    RawOperationRepo,
 };
 use lazybe::db::DbOps;
 use lazybe::db::postgres::PostgresDbCtx;
 use lazybe::filter::Filter;
 use lazybe::page::PaginationInput;
 use lazybe::sort::Sort;
+use lazybe::uuid::Uuid;
 use sqlx::PgPool;
 
-use super::shared::parse_raw_operation;
+use super::shared::{parse_raw_operation, parse_webhook_delivery, parse_webhook_subscription};
+use crate::webhook::{
+    NewWebhookDelivery, NewWebhookSubscription, WebhookDelivery, WebhookDeliveryStatus, WebhookRepo,
+    WebhookSubscription,
+};
 use crate::{Error, entity};
 
 #[derive(Debug, Clone)]
@@ -167,6 +174,83 @@ impl RawOperationRepo for PostgresDb {
         Ok(result)
     }
 
+    async fn get_raw_operations_by_did_page(
+        &self,
+        did: &CanonicalPrismDid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Paginated<RawOperationRecord>, Self::Error> {
+        let suffix_bytes = did.suffix().to_vec();
+        let mut tx = self.pool.begin().await?;
+        let operation_page = self
+            .db_ctx
+            .list::<entity::RawOperationByDid>(
+                &mut tx,
+                Filter::all([entity::RawOperationByDidFilter::did().eq(suffix_bytes.into())]),
+                Sort::new([
+                    entity::RawOperationByDidSort::block_number().asc(),
+                    entity::RawOperationByDidSort::absn().asc(),
+                    entity::RawOperationByDidSort::osn().asc(),
+                ]),
+                Some(PaginationInput { page, limit: page_size }),
+            )
+            .await?;
+        tx.commit().await?;
+
+        let items = operation_page
+            .data
+            .into_iter()
+            .map(|i| parse_raw_operation(i.into()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Paginated {
+            items,
+            current_page: operation_page.page,
+            page_size: operation_page.page_size,
+            total_items: operation_page.total_records,
+        })
+    }
+
+    async fn get_raw_operations_by_dids(
+        &self,
+        dids: &[CanonicalPrismDid],
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        if dids.is_empty() {
+            return Ok(vec![]);
+        }
+        let did_filters = dids
+            .iter()
+            .map(|did| entity::RawOperationByDidFilter::did().eq(did.suffix().to_vec().into()))
+            .collect::<Vec<_>>();
+        let mut tx = self.pool.begin().await?;
+        let result = self
+            .db_ctx
+            .list::<entity::RawOperationByDid>(
+                &mut tx,
+                Filter::any(did_filters),
+                Sort::new([
+                    entity::RawOperationByDidSort::block_number().asc(),
+                    entity::RawOperationByDidSort::absn().asc(),
+                    entity::RawOperationByDidSort::osn().asc(),
+                ]),
+                None,
+            )
+            .await?
+            .data
+            .into_iter()
+            .map(|ro| {
+                let did_suffix = HexStr::from(ro.did.as_bytes());
+                parse_raw_operation(ro.into()).and_then(|i| {
+                    CanonicalPrismDid::from_suffix(did_suffix)
+                        .map_err(|e| e.into())
+                        .map(|j| (i, j))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        tx.commit().await?;
+        Ok(result)
+    }
+
     async fn get_raw_operation_vdr_by_operation_hash(
         &self,
         operation_hash: &Sha256Digest,
@@ -232,6 +316,14 @@ impl RawOperationRepo for PostgresDb {
         Ok(result)
     }
 
+    async fn get_prism_object_bytes(&self, tx_id: &TxId) -> Result<Option<Vec<u8>>, Self::Error> {
+        let result = sqlx::query_scalar("SELECT data FROM prism_object WHERE tx_hash = $1")
+            .bind(tx_id.to_vec())
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(result)
+    }
+
     async fn get_raw_operation_by_operation_id(
         &self,
         operation_id: &OperationId,
@@ -262,6 +354,42 @@ impl RawOperationRepo for PostgresDb {
         Ok(result)
     }
 
+    async fn get_raw_operations_after(
+        &self,
+        position: &OperationPosition,
+        limit: u32,
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        let block_number: i64 = position
+            .block_number
+            .inner()
+            .try_into()
+            .expect("block_number does not fit in i64");
+        let rows: Vec<entity::RawOperationByDid> = sqlx::query_as(
+            r#"
+SELECT *
+FROM raw_operation_by_did
+WHERE (block_number, absn, osn) > ($1, $2, $3)
+ORDER BY block_number, absn, osn
+LIMIT $4
+            "#,
+        )
+        .bind(block_number)
+        .bind(i64::from(position.absn))
+        .bind(i64::from(position.osn))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|ro| -> Result<(RawOperationRecord, CanonicalPrismDid), Error> {
+                let did_suffix = HexStr::from(ro.did.as_bytes());
+                let record = parse_raw_operation(ro.into())?;
+                let did = CanonicalPrismDid::from_suffix(did_suffix)?;
+                Ok((record, did))
+            })
+            .collect()
+    }
+
     async fn insert_raw_operations(
         &self,
         operations: Vec<(OperationMetadata, SignedPrismOperation)>,
@@ -299,6 +427,15 @@ impl RawOperationRepo for PostgresDb {
         tx.commit().await?;
         Ok(())
     }
+
+    async fn insert_prism_object_bytes(&self, tx_id: &TxId, prism_object_bytes: Vec<u8>) -> Result<(), Self::Error> {
+        sqlx::query("INSERT INTO prism_object (tx_hash, data) VALUES ($1, $2) ON CONFLICT (tx_hash) DO NOTHING")
+            .bind(tx_id.to_vec())
+            .bind(prism_object_bytes)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -408,3 +545,201 @@ impl DltCursorRepo for PostgresDb {
         Ok(())
     }
 }
+
+#[async_trait::async_trait]
+impl WebhookRepo for PostgresDb {
+    type Error = Error;
+
+    async fn insert_webhook_subscription(
+        &self,
+        subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, Self::Error> {
+        let row: entity::WebhookSubscription = sqlx::query_as(
+            r#"
+INSERT INTO webhook_subscription (id, url, secret, filter, created_at)
+VALUES ($1, $2, $3, $4, $5)
+RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(subscription.url)
+        .bind(subscription.secret)
+        .bind(serde_json::to_string(&subscription.filter)?)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+        parse_webhook_subscription(row)
+    }
+
+    async fn get_webhook_subscriptions(&self) -> Result<Vec<WebhookSubscription>, Self::Error> {
+        let rows: Vec<entity::WebhookSubscription> =
+            sqlx::query_as("SELECT * FROM webhook_subscription ORDER BY created_at")
+                .fetch_all(&self.pool)
+                .await?;
+        rows.into_iter().map(parse_webhook_subscription).collect()
+    }
+
+    async fn get_webhook_subscription(&self, id: &Uuid) -> Result<Option<WebhookSubscription>, Self::Error> {
+        let row: Option<entity::WebhookSubscription> =
+            sqlx::query_as("SELECT * FROM webhook_subscription WHERE id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+        row.map(parse_webhook_subscription).transpose()
+    }
+
+    async fn delete_webhook_subscription(&self, id: &Uuid) -> Result<bool, Self::Error> {
+        let result = sqlx::query("DELETE FROM webhook_subscription WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn insert_webhook_deliveries(&self, deliveries: Vec<NewWebhookDelivery>) -> Result<(), Self::Error> {
+        let mut tx = self.pool.begin().await?;
+        let created_at = Utc::now();
+        for delivery in deliveries {
+            sqlx::query(
+                r#"
+INSERT INTO webhook_delivery (id, subscription_id, payload, status, attempts, next_attempt_at, created_at)
+VALUES ($1, $2, $3, $4, 0, $5, $6)
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(delivery.subscription_id)
+            .bind(delivery.payload)
+            .bind(WebhookDeliveryStatus::Pending.to_string())
+            .bind(delivery.next_attempt_at)
+            .bind(created_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_webhook_deliveries_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, Self::Error> {
+        let rows: Vec<entity::WebhookDelivery> = sqlx::query_as(
+            r#"
+SELECT *
+FROM webhook_delivery
+WHERE status = $1 AND next_attempt_at <= $2
+ORDER BY next_attempt_at, created_at
+LIMIT $3
+            "#,
+        )
+        .bind(WebhookDeliveryStatus::Pending.to_string())
+        .bind(now)
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(parse_webhook_delivery).collect())
+    }
+
+    async fn get_webhook_deliveries_dead(&self, subscription_id: &Uuid) -> Result<Vec<WebhookDelivery>, Self::Error> {
+        let rows: Vec<entity::WebhookDelivery> = sqlx::query_as(
+            r#"
+SELECT *
+FROM webhook_delivery
+WHERE subscription_id = $1 AND status = $2
+ORDER BY created_at
+            "#,
+        )
+        .bind(subscription_id)
+        .bind(WebhookDeliveryStatus::Dead.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(parse_webhook_delivery).collect())
+    }
+
+    async fn delete_webhook_delivery(&self, id: &Uuid) -> Result<(), Self::Error> {
+        sqlx::query("DELETE FROM webhook_delivery WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_webhook_delivery_failed(
+        &self,
+        id: &Uuid,
+        error: String,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), Self::Error> {
+        let status = match next_attempt_at {
+            Some(_) => WebhookDeliveryStatus::Pending,
+            None => WebhookDeliveryStatus::Dead,
+        };
+        sqlx::query(
+            r#"
+UPDATE webhook_delivery
+SET attempts = attempts + 1, last_error = $2, status = $3, next_attempt_at = COALESCE($4, next_attempt_at)
+WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .bind(status.to_string())
+        .bind(next_attempt_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn retry_webhook_delivery(&self, id: &Uuid, now: DateTime<Utc>) -> Result<bool, Self::Error> {
+        let result = sqlx::query(
+            r#"
+UPDATE webhook_delivery
+SET status = $2, attempts = 0, next_attempt_at = $3
+WHERE id = $1 AND status = $4
+            "#,
+        )
+        .bind(id)
+        .bind(WebhookDeliveryStatus::Pending.to_string())
+        .bind(now)
+        .bind(WebhookDeliveryStatus::Dead.to_string())
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_webhook_dispatch_cursor(&self) -> Result<Option<OperationPosition>, Self::Error> {
+        let row: Option<(i64, i32, i32)> =
+            sqlx::query_as("SELECT block_number, absn, osn FROM webhook_dispatch_cursor WHERE id = 1")
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(row.map(|(block_number, absn, osn)| OperationPosition {
+            block_number: u64::try_from(block_number)
+                .expect("block_number value does not fit in u64")
+                .into(),
+            absn: absn.try_into().expect("absn value does not fit in u32"),
+            osn: osn.try_into().expect("osn value does not fit in u32"),
+        }))
+    }
+
+    async fn set_webhook_dispatch_cursor(&self, position: &OperationPosition) -> Result<(), Self::Error> {
+        let block_number: i64 = position
+            .block_number
+            .inner()
+            .try_into()
+            .expect("block_number does not fit in i64");
+        sqlx::query(
+            r#"
+INSERT INTO webhook_dispatch_cursor (id, block_number, absn, osn)
+VALUES (1, $1, $2, $3)
+ON CONFLICT (id) DO UPDATE SET block_number = excluded.block_number, absn = excluded.absn, osn = excluded.osn
+            "#,
+        )
+        .bind(block_number)
+        .bind(i64::from(position.absn))
+        .bind(i64::from(position.osn))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use identus_did_prism::prelude::*;
 use identus_did_prism::proto::prism::SignedPrismOperation;
 use identus_did_prism_indexer::repo::RawOperationRecord;
 
+use crate::webhook::{WebhookDelivery, WebhookSubscription};
 use crate::{Error, entity};
 
 pub fn parse_raw_operation(value: entity::RawOperation) -> Result<RawOperationRecord, Error> {
@@ -36,3 +37,29 @@ impl entity::RawOperation {
         })
     }
 }
+
+pub fn parse_webhook_subscription(value: entity::WebhookSubscription) -> Result<WebhookSubscription, Error> {
+    Ok(WebhookSubscription {
+        id: value.id,
+        url: value.url,
+        secret: value.secret,
+        filter: serde_json::from_str(&value.filter)?,
+        created_at: value.created_at,
+    })
+}
+
+pub fn parse_webhook_delivery(value: entity::WebhookDelivery) -> WebhookDelivery {
+    WebhookDelivery {
+        id: value.id,
+        subscription_id: value.subscription_id,
+        payload: value.payload,
+        status: value
+            .status
+            .parse()
+            .expect("invalid webhook delivery status in database"),
+        attempts: value.attempts.try_into().expect("attempts value does not fit in u32"),
+        next_attempt_at: value.next_attempt_at,
+        last_error: value.last_error,
+        created_at: value.created_at,
+    }
+}
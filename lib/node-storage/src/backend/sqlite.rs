@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use chrono::{DateTime, Utc};
 use identus_apollo::hash::Sha256Digest;
 use identus_apollo::hex::HexStr;
 use identus_did_prism::did::operation::OperationId;
@@ -7,7 +8,8 @@ use identus_did_prism::dlt::{BlockNo, DltCursor, OperationMetadata, SlotNo, TxId
 use identus_did_prism::prelude::*;
 use identus_did_prism::utils::paging::Paginated;
 use identus_did_prism_indexer::repo::{
-    DltCursorRepo, IndexedOperation, IndexedOperationRepo, IndexerStateRepo, RawOperationRecord, RawOperationRepo,
+    DltCursorRepo, IndexedOperation, IndexedOperationRepo, IndexerStateRepo, OperationPosition, RawOperationRecord,
+    RawOperationRepo,
 };
 use lazybe::db::DbOps;
 use lazybe::db::sqlite::SqliteDbCtx;
@@ -18,8 +20,12 @@ use lazybe::uuid::Uuid;
 use sqlx::SqlitePool;
 use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
 
-use super::shared::parse_raw_operation;
+use super::shared::{parse_raw_operation, parse_webhook_delivery, parse_webhook_subscription};
 use crate::entity::DidSuffix;
+use crate::webhook::{
+    NewWebhookDelivery, NewWebhookSubscription, WebhookDelivery, WebhookDeliveryStatus, WebhookRepo,
+    WebhookSubscription,
+};
 use crate::{Error, entity};
 
 #[derive(Debug, Clone)]
@@ -179,6 +185,83 @@ impl RawOperationRepo for SqliteDb {
         Ok(result)
     }
 
+    async fn get_raw_operations_by_did_page(
+        &self,
+        did: &CanonicalPrismDid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Paginated<RawOperationRecord>, Self::Error> {
+        let suffix_bytes = did.suffix().to_vec();
+        let mut tx = self.pool.begin().await?;
+        let operation_page = self
+            .db_ctx
+            .list::<entity::RawOperationByDid>(
+                &mut tx,
+                Filter::all([entity::RawOperationByDidFilter::did().eq(suffix_bytes.into())]),
+                Sort::new([
+                    entity::RawOperationByDidSort::block_number().asc(),
+                    entity::RawOperationByDidSort::absn().asc(),
+                    entity::RawOperationByDidSort::osn().asc(),
+                ]),
+                Some(PaginationInput { page, limit: page_size }),
+            )
+            .await?;
+        tx.commit().await?;
+
+        let items = operation_page
+            .data
+            .into_iter()
+            .map(|i| parse_raw_operation(i.into()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Paginated {
+            items,
+            current_page: operation_page.page,
+            page_size: operation_page.page_size,
+            total_items: operation_page.total_records,
+        })
+    }
+
+    async fn get_raw_operations_by_dids(
+        &self,
+        dids: &[CanonicalPrismDid],
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        if dids.is_empty() {
+            return Ok(vec![]);
+        }
+        let did_filters = dids
+            .iter()
+            .map(|did| entity::RawOperationByDidFilter::did().eq(did.suffix().to_vec().into()))
+            .collect::<Vec<_>>();
+        let mut tx = self.pool.begin().await?;
+        let result = self
+            .db_ctx
+            .list::<entity::RawOperationByDid>(
+                &mut tx,
+                Filter::any(did_filters),
+                Sort::new([
+                    entity::RawOperationByDidSort::block_number().asc(),
+                    entity::RawOperationByDidSort::absn().asc(),
+                    entity::RawOperationByDidSort::osn().asc(),
+                ]),
+                None,
+            )
+            .await?
+            .data
+            .into_iter()
+            .map(|ro| {
+                let did_suffix = HexStr::from(ro.did.as_bytes());
+                parse_raw_operation(ro.into()).and_then(|i| {
+                    CanonicalPrismDid::from_suffix(did_suffix)
+                        .map_err(|e| e.into())
+                        .map(|j| (i, j))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        tx.commit().await?;
+        Ok(result)
+    }
+
     async fn get_raw_operation_vdr_by_operation_hash(
         &self,
         operation_hash: &Sha256Digest,
@@ -247,6 +330,14 @@ impl RawOperationRepo for SqliteDb {
         Ok(result)
     }
 
+    async fn get_prism_object_bytes(&self, tx_id: &TxId) -> Result<Option<Vec<u8>>, Self::Error> {
+        let result = sqlx::query_scalar("SELECT data FROM prism_object WHERE tx_hash = ?1")
+            .bind(tx_id.to_vec())
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(result)
+    }
+
     async fn get_raw_operation_by_operation_id(
         &self,
         operation_id: &OperationId,
@@ -277,6 +368,42 @@ impl RawOperationRepo for SqliteDb {
         Ok(result)
     }
 
+    async fn get_raw_operations_after(
+        &self,
+        position: &OperationPosition,
+        limit: u32,
+    ) -> Result<Vec<(RawOperationRecord, CanonicalPrismDid)>, Self::Error> {
+        let block_number: i64 = position
+            .block_number
+            .inner()
+            .try_into()
+            .expect("block_number does not fit in i64");
+        let rows: Vec<entity::RawOperationByDid> = sqlx::query_as(
+            r#"
+SELECT *
+FROM raw_operation_by_did
+WHERE (block_number, absn, osn) > (?1, ?2, ?3)
+ORDER BY block_number, absn, osn
+LIMIT ?4
+            "#,
+        )
+        .bind(block_number)
+        .bind(i64::from(position.absn))
+        .bind(i64::from(position.osn))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|ro| -> Result<(RawOperationRecord, CanonicalPrismDid), Error> {
+                let did_suffix = HexStr::from(ro.did.as_bytes());
+                let record = parse_raw_operation(ro.into())?;
+                let did = CanonicalPrismDid::from_suffix(did_suffix)?;
+                Ok((record, did))
+            })
+            .collect()
+    }
+
     async fn insert_raw_operations(
         &self,
         operations: Vec<(OperationMetadata, SignedPrismOperation)>,
@@ -325,6 +452,15 @@ ON CONFLICT(block_number, absn, osn) DO NOTHING
         tx.commit().await?;
         Ok(())
     }
+
+    async fn insert_prism_object_bytes(&self, tx_id: &TxId, prism_object_bytes: Vec<u8>) -> Result<(), Self::Error> {
+        sqlx::query("INSERT INTO prism_object (tx_hash, data) VALUES (?1, ?2) ON CONFLICT (tx_hash) DO NOTHING")
+            .bind(tx_id.to_vec())
+            .bind(prism_object_bytes)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -429,6 +565,204 @@ impl DltCursorRepo for SqliteDb {
     }
 }
 
+#[async_trait::async_trait]
+impl WebhookRepo for SqliteDb {
+    type Error = Error;
+
+    async fn insert_webhook_subscription(
+        &self,
+        subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, Self::Error> {
+        let row: entity::WebhookSubscription = sqlx::query_as(
+            r#"
+INSERT INTO webhook_subscription (id, url, secret, filter, created_at)
+VALUES (?1, ?2, ?3, ?4, ?5)
+RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(subscription.url)
+        .bind(subscription.secret)
+        .bind(serde_json::to_string(&subscription.filter)?)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+        parse_webhook_subscription(row)
+    }
+
+    async fn get_webhook_subscriptions(&self) -> Result<Vec<WebhookSubscription>, Self::Error> {
+        let rows: Vec<entity::WebhookSubscription> =
+            sqlx::query_as("SELECT * FROM webhook_subscription ORDER BY created_at")
+                .fetch_all(&self.pool)
+                .await?;
+        rows.into_iter().map(parse_webhook_subscription).collect()
+    }
+
+    async fn get_webhook_subscription(&self, id: &Uuid) -> Result<Option<WebhookSubscription>, Self::Error> {
+        let row: Option<entity::WebhookSubscription> =
+            sqlx::query_as("SELECT * FROM webhook_subscription WHERE id = ?1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+        row.map(parse_webhook_subscription).transpose()
+    }
+
+    async fn delete_webhook_subscription(&self, id: &Uuid) -> Result<bool, Self::Error> {
+        let result = sqlx::query("DELETE FROM webhook_subscription WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn insert_webhook_deliveries(&self, deliveries: Vec<NewWebhookDelivery>) -> Result<(), Self::Error> {
+        let mut tx = self.pool.begin().await?;
+        let created_at = Utc::now();
+        for delivery in deliveries {
+            sqlx::query(
+                r#"
+INSERT INTO webhook_delivery (id, subscription_id, payload, status, attempts, next_attempt_at, created_at)
+VALUES (?1, ?2, ?3, ?4, 0, ?5, ?6)
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(delivery.subscription_id)
+            .bind(delivery.payload)
+            .bind(WebhookDeliveryStatus::Pending.to_string())
+            .bind(delivery.next_attempt_at)
+            .bind(created_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_webhook_deliveries_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, Self::Error> {
+        let rows: Vec<entity::WebhookDelivery> = sqlx::query_as(
+            r#"
+SELECT *
+FROM webhook_delivery
+WHERE status = ?1 AND next_attempt_at <= ?2
+ORDER BY next_attempt_at, created_at
+LIMIT ?3
+            "#,
+        )
+        .bind(WebhookDeliveryStatus::Pending.to_string())
+        .bind(now)
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(parse_webhook_delivery).collect())
+    }
+
+    async fn get_webhook_deliveries_dead(&self, subscription_id: &Uuid) -> Result<Vec<WebhookDelivery>, Self::Error> {
+        let rows: Vec<entity::WebhookDelivery> = sqlx::query_as(
+            r#"
+SELECT *
+FROM webhook_delivery
+WHERE subscription_id = ?1 AND status = ?2
+ORDER BY created_at
+            "#,
+        )
+        .bind(subscription_id)
+        .bind(WebhookDeliveryStatus::Dead.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(parse_webhook_delivery).collect())
+    }
+
+    async fn delete_webhook_delivery(&self, id: &Uuid) -> Result<(), Self::Error> {
+        sqlx::query("DELETE FROM webhook_delivery WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_webhook_delivery_failed(
+        &self,
+        id: &Uuid,
+        error: String,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), Self::Error> {
+        let status = match next_attempt_at {
+            Some(_) => WebhookDeliveryStatus::Pending,
+            None => WebhookDeliveryStatus::Dead,
+        };
+        sqlx::query(
+            r#"
+UPDATE webhook_delivery
+SET attempts = attempts + 1, last_error = ?2, status = ?3, next_attempt_at = COALESCE(?4, next_attempt_at)
+WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .bind(status.to_string())
+        .bind(next_attempt_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn retry_webhook_delivery(&self, id: &Uuid, now: DateTime<Utc>) -> Result<bool, Self::Error> {
+        let result = sqlx::query(
+            r#"
+UPDATE webhook_delivery
+SET status = ?2, attempts = 0, next_attempt_at = ?3
+WHERE id = ?1 AND status = ?4
+            "#,
+        )
+        .bind(id)
+        .bind(WebhookDeliveryStatus::Pending.to_string())
+        .bind(now)
+        .bind(WebhookDeliveryStatus::Dead.to_string())
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_webhook_dispatch_cursor(&self) -> Result<Option<OperationPosition>, Self::Error> {
+        let row: Option<(i64, i64, i64)> =
+            sqlx::query_as("SELECT block_number, absn, osn FROM webhook_dispatch_cursor WHERE id = 1")
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(row.map(|(block_number, absn, osn)| OperationPosition {
+            block_number: u64::try_from(block_number)
+                .expect("block_number value does not fit in u64")
+                .into(),
+            absn: absn.try_into().expect("absn value does not fit in u32"),
+            osn: osn.try_into().expect("osn value does not fit in u32"),
+        }))
+    }
+
+    async fn set_webhook_dispatch_cursor(&self, position: &OperationPosition) -> Result<(), Self::Error> {
+        let block_number: i64 = position
+            .block_number
+            .inner()
+            .try_into()
+            .expect("block_number does not fit in i64");
+        sqlx::query(
+            r#"
+INSERT INTO webhook_dispatch_cursor (id, block_number, absn, osn)
+VALUES (1, ?1, ?2, ?3)
+ON CONFLICT (id) DO UPDATE SET block_number = excluded.block_number, absn = excluded.absn, osn = excluded.osn
+            "#,
+        )
+        .bind(block_number)
+        .bind(i64::from(position.absn))
+        .bind(i64::from(position.osn))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
@@ -439,11 +773,12 @@ mod tests {
     use identus_did_prism::prelude::*;
     use identus_did_prism::proto;
     use identus_did_prism_indexer::repo::{
-        DltCursorRepo, IndexedOperation, IndexedOperationRepo, IndexerStateRepo, RawOperationRepo,
+        DltCursorRepo, IndexedOperation, IndexedOperationRepo, IndexerStateRepo, OperationPosition, RawOperationRepo,
     };
     use tempfile::TempDir;
 
     use super::*;
+    use crate::webhook::WebhookFilter;
 
     const MASTER_KEY: [u8; 32] = [1; 32];
     const MASTER_KEY_NAME: &str = "master-0";
@@ -637,6 +972,140 @@ mod tests {
         assert!(ops.is_empty());
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_raw_operations_by_did_page_returns_page_in_order() {
+        let (_tmp_dir, db) = setup_db().await;
+        let mut did = None;
+        for (block, absn, osn) in [(11, 0, 0), (10, 1, 0), (10, 0, 0)] {
+            let rec = insert_one(&db, block, absn, osn).await;
+            let rec_did = did_from_signed_op(&rec.signed_operation);
+            db.insert_indexed_operations(vec![IndexedOperation::Ssi {
+                raw_operation_id: rec.id,
+                did: rec_did.clone(),
+            }])
+            .await
+            .expect("index");
+            did = Some(rec_did);
+        }
+        let did = did.expect("did");
+
+        let first_page = db.get_raw_operations_by_did_page(&did, 0, 2).await.expect("fetch page");
+        assert_eq!(first_page.total_items, 3);
+        let positions = first_page
+            .items
+            .iter()
+            .map(|i| {
+                (
+                    i.metadata.block_metadata.block_number.inner(),
+                    i.metadata.block_metadata.absn,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![(10, 0), (10, 1)]);
+
+        let last_page = db.get_raw_operations_by_did_page(&did, 1, 2).await.expect("fetch page");
+        assert_eq!(last_page.current_page, 1);
+        assert_eq!(last_page.items.len(), 1);
+        assert_eq!(last_page.items[0].metadata.block_metadata.block_number.inner(), 11);
+    }
+
+    // ── RawOperationRepo: get_raw_operations_by_dids ──
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_raw_operations_by_dids_returns_empty_for_empty_input() {
+        let (_tmp_dir, db) = setup_db().await;
+        let result = db.get_raw_operations_by_dids(&[]).await.expect("fetch by dids");
+        assert!(result.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_raw_operations_by_dids_returns_operations_for_each_did() {
+        let (_tmp_dir, db) = setup_db().await;
+        let did_2 = CanonicalPrismDid::from_suffix(HexStr::from(sha256([7u8; 32]).to_vec())).expect("did");
+        let unknown_did = CanonicalPrismDid::from_suffix(HexStr::from(sha256([8u8; 32]).to_vec())).expect("did");
+
+        // Index each operation before inserting the next one so insert_one returns the new record
+        let rec_1 = insert_one(&db, 10, 0, 0).await;
+        let did_1 = did_from_signed_op(&rec_1.signed_operation);
+        db.insert_indexed_operations(vec![IndexedOperation::Ssi {
+            raw_operation_id: rec_1.id,
+            did: did_1.clone(),
+        }])
+        .await
+        .expect("index");
+
+        let rec_2 = insert_one(&db, 11, 0, 0).await;
+        db.insert_indexed_operations(vec![IndexedOperation::Ssi {
+            raw_operation_id: rec_2.id,
+            did: did_2.clone(),
+        }])
+        .await
+        .expect("index");
+
+        let result = db
+            .get_raw_operations_by_dids(&[did_1.clone(), did_2.clone(), unknown_did])
+            .await
+            .expect("fetch by dids");
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].1, did_1);
+        assert_eq!(result[0].0.metadata.block_metadata.block_number.inner(), 10);
+        assert_eq!(result[1].1, did_2);
+        assert_eq!(result[1].0.metadata.block_metadata.block_number.inner(), 11);
+    }
+
+    // ── RawOperationRepo: get_raw_operations_after ──
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_raw_operations_after_returns_indexed_operations_in_order() {
+        let (_tmp_dir, db) = setup_db().await;
+        let mut records = vec![];
+        for (block, absn, osn) in [(10, 0, 0), (10, 0, 1), (10, 1, 0), (11, 0, 0)] {
+            let rec = insert_one(&db, block, absn, osn).await;
+            let did = did_from_signed_op(&rec.signed_operation);
+            db.insert_indexed_operations(vec![IndexedOperation::Ssi {
+                raw_operation_id: rec.id,
+                did,
+            }])
+            .await
+            .expect("index");
+            records.push(rec);
+        }
+        // Unindexed operations are not returned
+        insert_one(&db, 12, 0, 0).await;
+
+        let position = OperationPosition::from(&records[0].metadata);
+        let result = db.get_raw_operations_after(&position, 10).await.expect("fetch after");
+        let positions = result
+            .iter()
+            .map(|(rec, _)| OperationPosition::from(&rec.metadata))
+            .collect::<Vec<_>>();
+        let expected = records[1..]
+            .iter()
+            .map(|rec| OperationPosition::from(&rec.metadata))
+            .collect::<Vec<_>>();
+        assert_eq!(positions, expected);
+
+        let result = db.get_raw_operations_after(&position, 2).await.expect("fetch after");
+        assert_eq!(result.len(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_raw_operations_after_returns_empty_at_end() {
+        let (_tmp_dir, db) = setup_db().await;
+        let rec = insert_one(&db, 10, 0, 0).await;
+        let did = did_from_signed_op(&rec.signed_operation);
+        db.insert_indexed_operations(vec![IndexedOperation::Ssi {
+            raw_operation_id: rec.id,
+            did,
+        }])
+        .await
+        .expect("index");
+
+        let position = OperationPosition::from(&rec.metadata);
+        let result = db.get_raw_operations_after(&position, 10).await.expect("fetch after");
+        assert!(result.is_empty());
+    }
+
     // ── RawOperationRepo: get_raw_operation_vdr_by_operation_hash ──
 
     #[tokio::test(flavor = "multi_thread")]
@@ -703,6 +1172,27 @@ mod tests {
         assert_eq!(result[0].1, did);
     }
 
+    // ── RawOperationRepo: prism object bytes ──
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn prism_object_bytes_roundtrip_keeps_first_insert() {
+        let (_tmp_dir, db) = setup_db().await;
+        let tx_id = dummy_metadata(10, 0, 0).block_metadata.tx_id;
+        assert!(db.get_prism_object_bytes(&tx_id).await.expect("fetch").is_none());
+
+        db.insert_prism_object_bytes(&tx_id, vec![1, 2, 3])
+            .await
+            .expect("insert");
+        db.insert_prism_object_bytes(&tx_id, vec![4, 5, 6])
+            .await
+            .expect("insert again");
+
+        let result = db.get_prism_object_bytes(&tx_id).await.expect("fetch");
+        assert_eq!(result, Some(vec![1, 2, 3]));
+        let other_tx_id = dummy_metadata(11, 0, 0).block_metadata.tx_id;
+        assert!(db.get_prism_object_bytes(&other_tx_id).await.expect("fetch").is_none());
+    }
+
     // ── RawOperationRepo: get_raw_operation_by_operation_id ──
 
     #[tokio::test(flavor = "multi_thread")]
@@ -1047,4 +1537,121 @@ mod tests {
         let result = db.get_cursor().await.expect("get");
         assert_eq!(result, Some(cursor));
     }
+
+    // ── WebhookRepo ──
+
+    fn new_subscription() -> NewWebhookSubscription {
+        NewWebhookSubscription {
+            url: "https://example.com/hook".to_string(),
+            secret: "secret".to_string(),
+            filter: WebhookFilter {
+                operation_types: vec!["update_did".to_string()],
+                ..Default::default()
+            },
+        }
+    }
+
+    fn new_delivery(subscription_id: Uuid, next_attempt_at: DateTime<Utc>) -> NewWebhookDelivery {
+        NewWebhookDelivery {
+            subscription_id,
+            payload: "{}".to_string(),
+            next_attempt_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn webhook_subscription_roundtrip() {
+        let (_dir, db) = setup_db().await;
+        let created = db.insert_webhook_subscription(new_subscription()).await.unwrap();
+
+        let fetched = db.get_webhook_subscription(&created.id).await.unwrap().unwrap();
+        assert_eq!(fetched.url, "https://example.com/hook");
+        assert_eq!(fetched.filter, new_subscription().filter);
+        assert_eq!(db.get_webhook_subscriptions().await.unwrap().len(), 1);
+
+        assert!(db.delete_webhook_subscription(&created.id).await.unwrap());
+        assert!(!db.delete_webhook_subscription(&created.id).await.unwrap());
+        assert!(db.get_webhook_subscription(&created.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn get_webhook_deliveries_due_skips_future_deliveries() {
+        let (_dir, db) = setup_db().await;
+        let subscription = db.insert_webhook_subscription(new_subscription()).await.unwrap();
+        let now = Utc::now();
+        db.insert_webhook_deliveries(vec![
+            new_delivery(subscription.id, now - chrono::Duration::seconds(1)),
+            new_delivery(subscription.id, now + chrono::Duration::hours(1)),
+        ])
+        .await
+        .unwrap();
+
+        let due = db.get_webhook_deliveries_due(now, 10).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].status, WebhookDeliveryStatus::Pending);
+        assert_eq!(due[0].attempts, 0);
+    }
+
+    #[tokio::test]
+    async fn failed_webhook_delivery_is_dead_lettered_and_retried() {
+        let (_dir, db) = setup_db().await;
+        let subscription = db.insert_webhook_subscription(new_subscription()).await.unwrap();
+        let now = Utc::now();
+        db.insert_webhook_deliveries(vec![new_delivery(subscription.id, now)])
+            .await
+            .unwrap();
+        let delivery = db.get_webhook_deliveries_due(now, 10).await.unwrap().remove(0);
+
+        db.update_webhook_delivery_failed(&delivery.id, "HTTP 500".to_string(), None)
+            .await
+            .unwrap();
+        assert!(db.get_webhook_deliveries_due(now, 10).await.unwrap().is_empty());
+        let dead = db.get_webhook_deliveries_dead(&subscription.id).await.unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].attempts, 1);
+        assert_eq!(dead[0].last_error.as_deref(), Some("HTTP 500"));
+
+        assert!(db.retry_webhook_delivery(&delivery.id, now).await.unwrap());
+        assert!(!db.retry_webhook_delivery(&delivery.id, now).await.unwrap());
+        let due = db.get_webhook_deliveries_due(now, 10).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].attempts, 0);
+
+        db.delete_webhook_delivery(&delivery.id).await.unwrap();
+        assert!(db.get_webhook_deliveries_due(now, 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn delete_webhook_subscription_deletes_deliveries() {
+        let (_dir, db) = setup_db().await;
+        let subscription = db.insert_webhook_subscription(new_subscription()).await.unwrap();
+        let now = Utc::now();
+        db.insert_webhook_deliveries(vec![new_delivery(subscription.id, now)])
+            .await
+            .unwrap();
+
+        db.delete_webhook_subscription(&subscription.id).await.unwrap();
+        assert!(db.get_webhook_deliveries_due(now, 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn webhook_dispatch_cursor_is_overwritten() {
+        let (_dir, db) = setup_db().await;
+        assert!(db.get_webhook_dispatch_cursor().await.unwrap().is_none());
+
+        let first = OperationPosition {
+            block_number: 10.into(),
+            absn: 1,
+            osn: 2,
+        };
+        let second = OperationPosition {
+            block_number: 11.into(),
+            absn: 0,
+            osn: 0,
+        };
+        db.set_webhook_dispatch_cursor(&first).await.unwrap();
+        assert_eq!(db.get_webhook_dispatch_cursor().await.unwrap(), Some(first));
+        db.set_webhook_dispatch_cursor(&second).await.unwrap();
+        assert_eq!(db.get_webhook_dispatch_cursor().await.unwrap(), Some(second));
+    }
 }
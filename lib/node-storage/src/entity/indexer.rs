@@ -73,7 +73,7 @@ pub struct DidStats {
     pub first_cbt: DateTime<Utc>,
 }
 
-#[derive(Entity)]
+#[derive(Entity, sqlx::FromRow)]
 #[lazybe(table = "raw_operation_by_did")]
 #[allow(unused)]
 pub struct RawOperationByDid {
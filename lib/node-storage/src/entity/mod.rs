@@ -4,8 +4,10 @@ use lazybe::macros::Newtype;
 use serde::{Deserialize, Serialize};
 
 mod indexer;
+mod webhook;
 
 pub use indexer::*;
+pub use webhook::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Newtype, derive_more::From)]
 pub struct DidSuffix(Vec<u8>);
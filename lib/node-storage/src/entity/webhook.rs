@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use lazybe::macros::Entity;
+use lazybe::uuid::Uuid;
+
+#[derive(Entity, sqlx::FromRow)]
+#[lazybe(table = "webhook_subscription")]
+#[allow(unused)]
+pub struct WebhookSubscription {
+    #[lazybe(primary_key)]
+    pub id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub filter: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Entity, sqlx::FromRow)]
+#[lazybe(table = "webhook_delivery")]
+#[allow(unused)]
+pub struct WebhookDelivery {
+    #[lazybe(primary_key)]
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub payload: String,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
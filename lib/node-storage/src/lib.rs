@@ -1,10 +1,13 @@
 use identus_did_prism::did::Error as DidError;
 use identus_did_prism::did::error::DidSyntaxError;
 use identus_did_prism_indexer::repo::{DltCursorRepo, IndexedOperationRepo, IndexerStateRepo, RawOperationRepo};
+use webhook::WebhookRepo;
 
 pub mod backend;
 mod entity;
+pub mod webhook;
 
+pub use backend::instrumented::InstrumentedDb;
 pub use backend::postgres::PostgresDb;
 #[cfg(feature = "sqlite-storage")]
 pub use backend::sqlite::SqliteDb;
@@ -14,6 +17,7 @@ pub trait StorageBackend:
     + IndexedOperationRepo<Error = Error>
     + IndexerStateRepo<Error = Error>
     + DltCursorRepo<Error = Error>
+    + WebhookRepo<Error = Error>
     + Send
     + Sync
     + 'static
@@ -25,6 +29,7 @@ impl<T> StorageBackend for T where
         + IndexedOperationRepo<Error = Error>
         + IndexerStateRepo<Error = Error>
         + DltCursorRepo<Error = Error>
+        + WebhookRepo<Error = Error>
         + Send
         + Sync
         + 'static
@@ -50,6 +55,9 @@ pub enum Error {
     #[from]
     #[display("failed to decode did from stored data")]
     DidDecode { source: DidSyntaxError },
+    #[from]
+    #[display("failed to encode or decode webhook filter")]
+    WebhookFilter { source: serde_json::Error },
 }
 
 #[cfg(test)]
@@ -72,6 +80,12 @@ mod tests {
         assert_backend::<SqliteDb>();
     }
 
+    #[test]
+    fn instrumented_backend_implements_storage_backend() {
+        assert_backend::<InstrumentedDb<PostgresDb>>();
+        assert_backend::<InstrumentedDb<std::sync::Arc<dyn StorageBackend>>>();
+    }
+
     #[test]
     fn sqlite_and_postgres_migrations_are_in_sync() {
         fn collect(dir: &str) -> Vec<String> {
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use identus_did_prism_indexer::repo::OperationPosition;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Operations a webhook subscription is notified about. An empty list matches every value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookFilter {
+    #[serde(default)]
+    pub dids: Vec<String>,
+    #[serde(default)]
+    pub vdr_entry_hashes: Vec<String>,
+    #[serde(default)]
+    pub operation_types: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewWebhookSubscription {
+    pub url: String,
+    pub secret: String,
+    pub filter: WebhookFilter,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub url: String,
+    /// Key used to sign the payloads delivered to the subscription.
+    pub secret: String,
+    pub filter: WebhookFilter,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum WebhookDeliveryStatus {
+    /// The delivery is waiting for its next attempt.
+    #[display("pending")]
+    Pending,
+    /// The delivery exhausted its attempts and is kept in the dead-letter list.
+    #[display("dead")]
+    Dead,
+}
+
+impl std::str::FromStr for WebhookDeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "dead" => Ok(Self::Dead),
+            _ => Err(format!("unknown webhook delivery status {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewWebhookDelivery {
+    pub subscription_id: Uuid,
+    pub payload: String,
+    pub next_attempt_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    /// JSON body posted to the subscription URL.
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[async_trait::async_trait]
+pub trait WebhookRepo {
+    type Error: std::error::Error;
+
+    async fn insert_webhook_subscription(
+        &self,
+        subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, Self::Error>;
+
+    async fn get_webhook_subscriptions(&self) -> Result<Vec<WebhookSubscription>, Self::Error>;
+
+    async fn get_webhook_subscription(&self, id: &Uuid) -> Result<Option<WebhookSubscription>, Self::Error>;
+
+    /// Delete a subscription with all its deliveries. Returns `false` if the subscription does not exist.
+    async fn delete_webhook_subscription(&self, id: &Uuid) -> Result<bool, Self::Error>;
+
+    async fn insert_webhook_deliveries(&self, deliveries: Vec<NewWebhookDelivery>) -> Result<(), Self::Error>;
+
+    /// Pending deliveries whose next attempt is due at `now`, oldest first.
+    async fn get_webhook_deliveries_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, Self::Error>;
+
+    /// Dead-lettered deliveries of a subscription, oldest first.
+    async fn get_webhook_deliveries_dead(&self, subscription_id: &Uuid) -> Result<Vec<WebhookDelivery>, Self::Error>;
+
+    /// Remove a successfully delivered delivery.
+    async fn delete_webhook_delivery(&self, id: &Uuid) -> Result<(), Self::Error>;
+
+    /// Record a failed attempt. The delivery is retried at `next_attempt_at`,
+    /// or moved to the dead-letter list if it is `None`.
+    async fn update_webhook_delivery_failed(
+        &self,
+        id: &Uuid,
+        error: String,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), Self::Error>;
+
+    /// Move a dead-lettered delivery back to pending with its attempts reset.
+    /// Returns `false` if there is no such dead-lettered delivery.
+    async fn retry_webhook_delivery(&self, id: &Uuid, now: DateTime<Utc>) -> Result<bool, Self::Error>;
+
+    /// Position of the last operation whose deliveries were enqueued.
+    async fn get_webhook_dispatch_cursor(&self) -> Result<Option<OperationPosition>, Self::Error>;
+
+    async fn set_webhook_dispatch_cursor(&self, position: &OperationPosition) -> Result<(), Self::Error>;
+}
+
+#[async_trait::async_trait]
+impl<T> WebhookRepo for Arc<T>
+where
+    T: WebhookRepo + Send + Sync + ?Sized,
+    <T as WebhookRepo>::Error: Send + Sync,
+{
+    type Error = T::Error;
+
+    async fn insert_webhook_subscription(
+        &self,
+        subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, Self::Error> {
+        self.as_ref().insert_webhook_subscription(subscription).await
+    }
+
+    async fn get_webhook_subscriptions(&self) -> Result<Vec<WebhookSubscription>, Self::Error> {
+        self.as_ref().get_webhook_subscriptions().await
+    }
+
+    async fn get_webhook_subscription(&self, id: &Uuid) -> Result<Option<WebhookSubscription>, Self::Error> {
+        self.as_ref().get_webhook_subscription(id).await
+    }
+
+    async fn delete_webhook_subscription(&self, id: &Uuid) -> Result<bool, Self::Error> {
+        self.as_ref().delete_webhook_subscription(id).await
+    }
+
+    async fn insert_webhook_deliveries(&self, deliveries: Vec<NewWebhookDelivery>) -> Result<(), Self::Error> {
+        self.as_ref().insert_webhook_deliveries(deliveries).await
+    }
+
+    async fn get_webhook_deliveries_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, Self::Error> {
+        self.as_ref().get_webhook_deliveries_due(now, limit).await
+    }
+
+    async fn get_webhook_deliveries_dead(&self, subscription_id: &Uuid) -> Result<Vec<WebhookDelivery>, Self::Error> {
+        self.as_ref().get_webhook_deliveries_dead(subscription_id).await
+    }
+
+    async fn delete_webhook_delivery(&self, id: &Uuid) -> Result<(), Self::Error> {
+        self.as_ref().delete_webhook_delivery(id).await
+    }
+
+    async fn update_webhook_delivery_failed(
+        &self,
+        id: &Uuid,
+        error: String,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), Self::Error> {
+        self.as_ref()
+            .update_webhook_delivery_failed(id, error, next_attempt_at)
+            .await
+    }
+
+    async fn retry_webhook_delivery(&self, id: &Uuid, now: DateTime<Utc>) -> Result<bool, Self::Error> {
+        self.as_ref().retry_webhook_delivery(id, now).await
+    }
+
+    async fn get_webhook_dispatch_cursor(&self) -> Result<Option<OperationPosition>, Self::Error> {
+        self.as_ref().get_webhook_dispatch_cursor().await
+    }
+
+    async fn set_webhook_dispatch_cursor(&self, position: &OperationPosition) -> Result<(), Self::Error> {
+        self.as_ref().set_webhook_dispatch_cursor(position).await
+    }
+}
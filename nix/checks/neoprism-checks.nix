@@ -43,21 +43,26 @@ rustPlatform.buildRustPackage {
     # check individual feature if properly gated
     echo "checking feature gate for identus-apollo"
     cargo clippy -p identus-apollo --all-targets --features base64 -- -D warnings
+    cargo clippy -p identus-apollo --all-targets --features derivation -- -D warnings
     cargo clippy -p identus-apollo --all-targets --features ed25519 -- -D warnings
     cargo clippy -p identus-apollo --all-targets --features hash -- -D warnings
     cargo clippy -p identus-apollo --all-targets --features hex -- -D warnings
     cargo clippy -p identus-apollo --all-targets --features jwk -- -D warnings
+    cargo clippy -p identus-apollo --all-targets --features jws -- -D warnings
     cargo clippy -p identus-apollo --all-targets --features openapi -- -D warnings
     cargo clippy -p identus-apollo --all-targets --features secp256k1 -- -D warnings
     cargo clippy -p identus-apollo --all-targets --features serde -- -D warnings
     cargo clippy -p identus-apollo --all-targets --features x25519 -- -D warnings
 
     echo "checking feature gate for identus-did-core"
+    cargo clippy -p identus-did-core --all-targets --features did-jwk -- -D warnings
+    cargo clippy -p identus-did-core --all-targets --features did-key -- -D warnings
     cargo clippy -p identus-did-core --all-targets --features openapi -- -D warnings
     cargo clippy -p identus-did-core --all-targets --features ts-types -- -D warnings
 
     echo "checking feature gate for identus-did-resolver-http"
     cargo clippy -p identus-did-resolver-http --all-targets --features openapi -- -D warnings
+    cargo clippy -p identus-did-resolver-http --all-targets --features prism-proxy -- -D warnings
 
     echo "checking feature gate for identus-did-prism"
     cargo clippy -p identus-did-prism --all-targets --features openapi -- -D warnings
@@ -6,7 +6,7 @@ from ..models import Service
 class Options(BaseModel):
     image: str = "universalresolver/uni-resolver-web:0.44.0-4922fcc"
     host_port: int
-    prism_driver_url: str = "http://neoprism-indexer:8080/api"
+    prism_driver_url: str = "http://neoprism-indexer:8080"
 
 
 def mk_service(options: Options) -> Service: